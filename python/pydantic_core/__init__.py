@@ -25,6 +25,7 @@ from ._pydantic_core import (
     from_json,
     to_json,
     to_jsonable_python,
+    to_msgpack,
     validate_core_schema,
 )
 from .core_schema import CoreConfig, CoreSchema, CoreSchemaType, ErrorType
@@ -66,6 +67,7 @@ __all__ = [
     'to_json',
     'from_json',
     'to_jsonable_python',
+    'to_msgpack',
     'validate_core_schema',
 ]
 
@@ -79,7 +81,11 @@ class ErrorDetails(_TypedDict):
     `type` is unique for each error message, and can hence be used as an identifier to build custom error messages.
     """
     loc: tuple[int | str, ...]
-    """Tuple of strings and ints identifying where in the schema the error occurred."""
+    """
+    Tuple of strings and ints identifying where in the schema the error occurred.
+
+    This is a string when the `'json_pointer'` or `'dotted'` `loc_format` is used.
+    """
     msg: str
     """A human readable error message."""
     input: _Any
@@ -89,6 +95,10 @@ class ErrorDetails(_TypedDict):
     Values which are required to render the error message, and could hence be useful in rendering custom error messages.
     Also useful for passing custom error data forward.
     """
+    count: _NotRequired[int]
+    """The number of errors grouped into this one, only included when errors are grouped."""
+    sample_locs: _NotRequired[list[tuple[int | str, ...] | str]]
+    """Up to five locations of the grouped errors, only included when errors are grouped."""
 
 
 class InitErrorDetails(_TypedDict):
@@ -122,6 +132,8 @@ class ErrorTypeInfo(_TypedDict):
     """Example of a human readable error message, when the input is JSON data."""
     example_context: dict[str, _Any] | None
     """Example of context values."""
+    placeholders: list[str]
+    """Names of the `{placeholder}`s available to message templates for this error type."""
 
 
 class MultiHostHost(_TypedDict):
@@ -1,6 +1,6 @@
 import datetime
-from collections.abc import Mapping
-from typing import Any, Callable, Generic, Literal, TypeVar, final
+from collections.abc import Iterable, Mapping
+from typing import Any, Callable, Generic, Literal, Protocol, TypeVar, final, overload
 
 from _typeshed import SupportsAllComparisons
 from typing_extensions import LiteralString, Self, TypeAlias
@@ -32,6 +32,7 @@ __all__ = [
     'to_json',
     'from_json',
     'to_jsonable_python',
+    'to_msgpack',
     'list_all_errors',
     'TzInfo',
     'validate_core_schema',
@@ -45,6 +46,9 @@ _T = TypeVar('_T', default=Any, covariant=True)
 
 _StringInput: TypeAlias = 'dict[str, _StringInput]'
 
+class _JsonStreamReader(Protocol):
+    def read(self, size: int, /) -> str | bytes: ...
+
 @final
 class Some(Generic[_T]):
     """
@@ -96,6 +100,8 @@ class SchemaValidator:
         from_attributes: bool | None = None,
         context: Any | None = None,
         self_instance: Any | None = None,
+        allow_partial: bool = False,
+        fail_fast: bool | None = None,
     ) -> Any:
         """
         Validate a Python object against the schema and return the validated object.
@@ -110,6 +116,13 @@ class SchemaValidator:
                 [`info.context`][pydantic_core.core_schema.ValidationInfo.context].
             self_instance: An instance of a model set attributes on from validation, this is used when running
                 validation from the `__init__` method of a model.
+            allow_partial: Whether to allow partial validation; if `True` the last element of sequences and
+                mappings is dropped if it's invalid, unless it's a container itself, in which case the same applies
+                to its last element. Required fields missing from the last mapping are not reported, since they
+                may not have been reached yet, and fields whose value is dropped fall back to their default.
+            fail_fast: Whether to stop validating collections, models, typed dicts, dataclasses and arguments at
+                their first error. If `None`, the schema's own `fail_fast` or the value of
+                [`CoreConfig.fail_fast`][pydantic_core.core_schema.CoreConfig] is used.
 
         Raises:
             ValidationError: If validation fails.
@@ -126,6 +139,7 @@ class SchemaValidator:
         from_attributes: bool | None = None,
         context: Any | None = None,
         self_instance: Any | None = None,
+        fail_fast: bool | None = None,
     ) -> bool:
         """
         Similar to [`validate_python()`][pydantic_core.SchemaValidator.validate_python] but returns a boolean.
@@ -136,6 +150,75 @@ class SchemaValidator:
         Returns:
             `True` if validation succeeds, `False` if validation fails.
         """
+    def explain(
+        self,
+        input: Any,
+        *,
+        strict: bool | None = None,
+        from_attributes: bool | None = None,
+        context: Any | None = None,
+        self_instance: Any | None = None,
+    ) -> tuple[Any, ValidationError | None, dict[str, Any]]:
+        """
+        Validate a Python object like [`validate_python()`][pydantic_core.SchemaValidator.validate_python],
+        while recording a trace of the validators which ran, to help debug validation, e.g. why a smart union
+        chose one member over another.
+
+        Arguments match `validate_python()`. This method will not raise `ValidationError`s but will raise internal
+        errors.
+
+        Each node in the trace is a dict with the following keys:
+
+        * `validator`: the name of the validator
+        * `loc`: the location of the input within the top level input, as in errors
+        * `input`: the input to the validator
+        * `valid`: whether validation succeeded
+        * `exactness`: `'exact'`, `'strict'` or `'lax'` when validating a union member, otherwise `None`
+        * `fields_set_count`: the number of fields set, used by unions to choose between models
+        * `notes`: anything else of interest, e.g. which union member was selected and defaults applied
+        * `children`: the nodes of nested validators
+
+        Validation within the handler of wrap validators isn't included.
+
+        Returns:
+            A tuple of the validated value (or `None` if validation failed), the `ValidationError`
+            (or `None` if validation succeeded) and the trace of the top level validator.
+        """
+    def validate_many(
+        self,
+        inputs: Iterable[Any],
+        *,
+        strict: bool | None = None,
+        from_attributes: bool | None = None,
+        context: Any | None = None,
+        on_error: Literal['raise', 'collect', 'skip'] = 'raise',
+    ) -> tuple[list[Any], list[tuple[int, ValidationError]]]:
+        """
+        Validate each of an iterable of Python objects against the schema in a single call.
+
+        Arguments:
+            inputs: The Python objects to validate.
+            strict: Whether to validate the objects in strict mode.
+                If `None`, the value of [`CoreConfig.strict`][pydantic_core.core_schema.CoreConfig] is used.
+            from_attributes: Whether to validate objects as inputs to models by extracting attributes.
+                If `None`, the value of [`CoreConfig.from_attributes`][pydantic_core.core_schema.CoreConfig] is used.
+            context: The context to use for validation, this is passed to functional validators as
+                [`info.context`][pydantic_core.core_schema.ValidationInfo.context].
+            on_error: How to handle inputs which fail validation:
+
+                - `'raise'` (the default): raise a `ValidationError` for the first invalid input, the index of the
+                  input is prepended to the `loc` of each error
+                - `'collect'`: return an `(index, ValidationError)` pair for each invalid input
+                - `'skip'`: drop invalid inputs without building errors for them
+
+        Raises:
+            ValidationError: If `on_error` is `'raise'` and any input fails validation.
+            Exception: Other error types maybe raised if internal errors occur.
+
+        Returns:
+            A tuple of the validated outputs of all valid inputs, in order, and the list of
+                `(index, ValidationError)` pairs for invalid inputs.
+        """
     def validate_json(
         self,
         input: str | bytes | bytearray,
@@ -143,6 +226,8 @@ class SchemaValidator:
         strict: bool | None = None,
         context: Any | None = None,
         self_instance: Any | None = None,
+        allow_partial: bool | Literal['off', 'on', 'trailing-strings'] = False,
+        fail_fast: bool | None = None,
     ) -> Any:
         """
         Validate JSON data directly against the schema and return the validated Python object.
@@ -160,11 +245,145 @@ class SchemaValidator:
             context: The context to use for validation, this is passed to functional validators as
                 [`info.context`][pydantic_core.core_schema.ValidationInfo.context].
             self_instance: An instance of a model set attributes on from validation.
+            allow_partial: Whether to allow partial validation of truncated JSON, e.g. from a stream; if `True`
+                incomplete JSON is accepted, and required fields missing from an object the data ends inside are
+                not reported, whatever order its keys are in. `'trailing-strings'` additionally includes incomplete
+                strings at the end of the input in the result. A value the data ends part way through is dropped if
+                it's invalid, and a field whose value is dropped falls back to its default. Complete JSON is
+                validated as usual.
+            fail_fast: Whether to stop validating at the first error, see
+                [`validate_python()`][pydantic_core.SchemaValidator.validate_python].
 
         Raises:
             ValidationError: If validation fails or if the JSON data is invalid.
             Exception: Other error types maybe raised if internal errors occur.
 
+        Returns:
+            The validated Python object.
+        """
+    def validate_json_stream(
+        self,
+        input: Iterable[str | bytes | bytearray] | _JsonStreamReader | str | bytes | bytearray,
+        *,
+        strict: bool | None = None,
+        context: Any | None = None,
+        fail_fast: bool | None = None,
+        chunk_size: int = 65536,
+    ) -> Any:
+        """
+        Validate JSON data read from a file-like object or an iterable of chunks against the schema and return the
+        validated Python object.
+
+        When the schema is a list schema and the JSON is an array, items are parsed and validated one at a time as
+        the data is read, so only the item being validated is held in memory rather than the whole input. Errors have the same locations as they would from
+        [`validate_json()`][pydantic_core.SchemaValidator.validate_json], and invalid JSON anywhere in the input is
+        reported instead of any validation errors, as it would be there. Errors about the array as a whole, e.g. its
+        length, have `input` as their input value, since the array is never held in memory.
+
+        Any other schema or JSON is read in full and validated as it would be by `validate_json()`, so it takes as
+        much memory as joining the chunks and calling `validate_json()` would.
+
+        Arguments:
+            input: A readable object with a `read(size)` method returning `str` or `bytes` (e.g. an open file),
+                or an iterable of `str`, `bytes` or `bytearray` chunks.
+            strict: Whether to validate the object in strict mode.
+                If `None`, the value of [`CoreConfig.strict`][pydantic_core.core_schema.CoreConfig] is used.
+            context: The context to use for validation, this is passed to functional validators as
+                [`info.context`][pydantic_core.core_schema.ValidationInfo.context].
+            fail_fast: Whether to stop validating at the first error, see
+                [`validate_python()`][pydantic_core.SchemaValidator.validate_python]. The rest of the input is
+                still read to check it's valid JSON.
+            chunk_size: The number of bytes or characters to request from each `read()` call.
+
+        Raises:
+            ValidationError: If validation fails, if the JSON data is invalid or if a chunk is not
+                `str`, `bytes` or `bytearray`.
+            Exception: Other error types maybe raised if internal errors occur, including errors raised while
+                reading from `input`.
+
+        Returns:
+            The validated Python object.
+        """
+    @overload
+    def validate_json_lines(
+        self,
+        input: str | bytes | bytearray,
+        *,
+        strict: bool | None = None,
+        context: Any | None = None,
+        return_errors: Literal[False] = False,
+    ) -> list[Any]: ...
+    @overload
+    def validate_json_lines(
+        self,
+        input: str | bytes | bytearray,
+        *,
+        strict: bool | None = None,
+        context: Any | None = None,
+        return_errors: Literal[True],
+    ) -> list[tuple[Any, ValidationError | None]]: ...
+    def validate_json_lines(
+        self,
+        input: str | bytes | bytearray,
+        *,
+        strict: bool | None = None,
+        context: Any | None = None,
+        return_errors: bool = False,
+    ) -> list[Any] | list[tuple[Any, ValidationError | None]]:
+        """
+        Validate newline-delimited JSON ([JSON Lines](https://jsonlines.org/)) data against the schema, validating
+        each non-blank line as a separate record.
+
+        The 1-based line number of each record is prepended to the `loc` of every error raised while validating it.
+
+        Each record is validated independently: resource limits such as
+        [`CoreConfig.max_total_items`][pydantic_core.core_schema.CoreConfig] and `max_errors` apply to every record
+        separately, `max_errors` also caps the total number of errors in the combined `ValidationError`.
+
+        Arguments:
+            input: The newline-delimited JSON data to validate.
+            strict: Whether to validate the records in strict mode.
+                If `None`, the value of [`CoreConfig.strict`][pydantic_core.core_schema.CoreConfig] is used.
+            context: The context to use for validation, this is passed to functional validators as
+                [`info.context`][pydantic_core.core_schema.ValidationInfo.context].
+            return_errors: If `True`, return a `(value, error)` pair for each record instead of raising,
+                where `error` is `None` when the record is valid and `value` is `None` when it is not.
+
+        Raises:
+            ValidationError: If `return_errors` is `False` and any record fails validation or is invalid JSON,
+                the error contains the errors from all such records.
+            Exception: Other error types maybe raised if internal errors occur.
+
+        Returns:
+            A list of validated records, or a list of `(value, error)` pairs if `return_errors` is `True`.
+        """
+    def validate_msgpack(
+        self,
+        input: bytes | bytearray,
+        *,
+        strict: bool | None = None,
+        context: Any | None = None,
+        self_instance: Any | None = None,
+    ) -> Any:
+        """
+        Validate MessagePack data directly against the schema and return the validated Python object.
+
+        As with [`validate_json`][pydantic_core.SchemaValidator.validate_json], no intermediate Python objects
+        are created. MessagePack binary data validates as `bytes` and timestamps (extension type `-1`) validate
+        as `datetime`, other data types follow the same rules as JSON.
+
+        Arguments:
+            input: The MessagePack data to validate.
+            strict: Whether to validate the object in strict mode.
+                If `None`, the value of [`CoreConfig.strict`][pydantic_core.core_schema.CoreConfig] is used.
+            context: The context to use for validation, this is passed to functional validators as
+                [`info.context`][pydantic_core.core_schema.ValidationInfo.context].
+            self_instance: An instance of a model set attributes on from validation.
+
+        Raises:
+            ValidationError: If validation fails or if the MessagePack data is invalid.
+            Exception: Other error types maybe raised if internal errors occur.
+
         Returns:
             The validated Python object.
         """
@@ -237,6 +456,23 @@ class SchemaValidator:
         Returns:
             `None` if the schema has no default value, otherwise a [`Some`][pydantic_core.Some] containing the default.
         """
+    def json_schema(self, *, ref_template: str = '#/$defs/{model}') -> dict[str, Any]:
+        """
+        Generate a JSON Schema for the inputs accepted by the validator, in validation mode.
+
+        The schema is generated from the built validator rather than the core schema, so constraints such as
+        `min_length`, `pattern` or `gt` match exactly what's enforced during validation.
+
+        Arguments:
+            ref_template: The format of `$ref` values for definitions, `{model}` is replaced by the definition's `ref`.
+
+        Raises:
+            TypeError: If the schema includes validators which can't be represented in JSON Schema,
+                e.g. plain function validators or `is-instance` schemas.
+
+        Returns:
+            The JSON Schema, with any definitions that are referenced under `$defs`.
+        """
 
 _IncEx: TypeAlias = set[int] | set[str] | Mapping[int, _IncEx | Literal[True]] | Mapping[str, _IncEx | Literal[True]]
 
@@ -268,6 +504,7 @@ class SchemaSerializer:
         exclude_defaults: bool = False,
         exclude_none: bool = False,
         round_trip: bool = False,
+        datetime_mode: Literal['iso8601', 'epoch_seconds', 'epoch_milliseconds', 'epoch_microseconds'] | None = None,
         warnings: bool | Literal['none', 'warn', 'error'] = True,
         fallback: Callable[[Any], Any] | None = None,
         serialize_as_any: bool = False,
@@ -288,6 +525,8 @@ class SchemaSerializer:
             exclude_defaults: Whether to exclude fields that are equal to their default value.
             exclude_none: Whether to exclude fields that have a value of `None`.
             round_trip: Whether to enable serialization and validation round-trip support.
+            datetime_mode: How to serialize `datetime` and `date` objects in JSON, overriding the `ser_json_datetime`
+                config, either `'iso8601'`, `'epoch_seconds'`, `'epoch_milliseconds'` or `'epoch_microseconds'`.
             warnings: How to handle invalid fields. False/"none" ignores them, True/"warn" logs errors,
                 "error" raises a [`PydanticSerializationError`][pydantic_core.PydanticSerializationError].
             fallback: A function to call when an unknown value is encountered,
@@ -307,6 +546,9 @@ class SchemaSerializer:
         value: Any,
         *,
         indent: int | None = None,
+        sort_keys: bool = False,
+        ensure_ascii: bool = False,
+        canonical: bool = False,
         include: _IncEx | None = None,
         exclude: _IncEx | None = None,
         by_alias: bool = True,
@@ -314,6 +556,7 @@ class SchemaSerializer:
         exclude_defaults: bool = False,
         exclude_none: bool = False,
         round_trip: bool = False,
+        datetime_mode: Literal['iso8601', 'epoch_seconds', 'epoch_milliseconds', 'epoch_microseconds'] | None = None,
         warnings: bool | Literal['none', 'warn', 'error'] = True,
         fallback: Callable[[Any], Any] | None = None,
         serialize_as_any: bool = False,
@@ -325,6 +568,13 @@ class SchemaSerializer:
         Arguments:
             value: The Python object to serialize.
             indent: If `None`, the JSON will be compact, otherwise it will be pretty-printed with the indent provided.
+            sort_keys: Whether to sort the keys of all objects, including nested ones, by their Unicode code points.
+                Keys are compared as the strings written to the JSON, so unlike `json.dumps(sort_keys=True)`,
+                integer keys are ordered as text, e.g. `"10"` before `"2"`.
+            ensure_ascii: Whether to escape all non-ASCII characters as `\\uXXXX` sequences, as `json.dumps` does.
+            canonical: Whether to produce [RFC 8785](https://www.rfc-editor.org/rfc/rfc8785) canonical JSON,
+                with sorted keys, no whitespace and ECMAScript number formatting. Numbers which can't be represented
+                exactly as a double raise an error. Can't be combined with `indent` or `ensure_ascii`.
             include: A set of fields to include, if `None` all fields are included.
             exclude: A set of fields to exclude, if `None` no fields are excluded.
             by_alias: Whether to use the alias names of fields.
@@ -333,6 +583,8 @@ class SchemaSerializer:
             exclude_defaults: Whether to exclude fields that are equal to their default value.
             exclude_none: Whether to exclude fields that have a value of `None`.
             round_trip: Whether to enable serialization and validation round-trip support.
+            datetime_mode: How to serialize `datetime` and `date` objects in JSON, overriding the `ser_json_datetime`
+                config, either `'iso8601'`, `'epoch_seconds'`, `'epoch_milliseconds'` or `'epoch_microseconds'`.
             warnings: How to handle invalid fields. False/"none" ignores them, True/"warn" logs errors,
                 "error" raises a [`PydanticSerializationError`][pydantic_core.PydanticSerializationError].
             fallback: A function to call when an unknown value is encountered,
@@ -347,17 +599,76 @@ class SchemaSerializer:
         Returns:
            JSON bytes.
         """
+    def to_msgpack(
+        self,
+        value: Any,
+        *,
+        include: _IncEx | None = None,
+        exclude: _IncEx | None = None,
+        by_alias: bool = True,
+        exclude_unset: bool = False,
+        exclude_defaults: bool = False,
+        exclude_none: bool = False,
+        round_trip: bool = False,
+        datetime_mode: Literal['iso8601', 'epoch_seconds', 'epoch_milliseconds', 'epoch_microseconds'] | None = None,
+        bytes_mode: Literal['binary', 'utf8', 'base64', 'hex'] | None = None,
+        warnings: bool | Literal['none', 'warn', 'error'] = True,
+        fallback: Callable[[Any], Any] | None = None,
+        serialize_as_any: bool = False,
+        context: Any | None = None,
+    ) -> bytes:
+        """
+        Serialize a Python object to [MessagePack](https://msgpack.org/) including transforming and filtering data.
+
+        Values are serialized as they would be by [`to_json()`][pydantic_core.SchemaSerializer.to_json], except
+        that `bytes` are serialized as MessagePack binary data unless `bytes_mode` selects a string encoding.
+        Integers outside the range of MessagePack's 64 bit integers can't be serialized.
+
+        Arguments:
+            value: The Python object to serialize.
+            include: A set of fields to include, if `None` all fields are included.
+            exclude: A set of fields to exclude, if `None` no fields are excluded.
+            by_alias: Whether to use the alias names of fields.
+            exclude_unset: Whether to exclude fields that are not set,
+                e.g. are not included in `__pydantic_fields_set__`.
+            exclude_defaults: Whether to exclude fields that are equal to their default value.
+            exclude_none: Whether to exclude fields that have a value of `None`.
+            round_trip: Whether to enable serialization and validation round-trip support.
+            datetime_mode: How to serialize `datetime` and `date` objects in JSON, overriding the `ser_json_datetime`
+                config, either `'iso8601'`, `'epoch_seconds'`, `'epoch_milliseconds'` or `'epoch_microseconds'`.
+            bytes_mode: How to serialize `bytes` objects, either `'binary'` for MessagePack binary data, or
+                `'utf8'`, `'base64'` or `'hex'` for strings, overriding the `ser_json_bytes` config.
+                If `None`, `'binary'` is used.
+            warnings: How to handle invalid fields. False/"none" ignores them, True/"warn" logs errors,
+                "error" raises a [`PydanticSerializationError`][pydantic_core.PydanticSerializationError].
+            fallback: A function to call when an unknown value is encountered,
+                if `None` a [`PydanticSerializationError`][pydantic_core.PydanticSerializationError] error is raised.
+            serialize_as_any: Whether to serialize fields with duck-typing serialization behavior.
+            context: The context to use for serialization, this is passed to functional serializers as
+                [`info.context`][pydantic_core.core_schema.SerializationInfo.context].
+
+        Raises:
+            PydanticSerializationError: If serialization fails and no `fallback` function is provided, or an integer
+                is outside the range of MessagePack's 64 bit integers.
+
+        Returns:
+            MessagePack bytes.
+        """
 
 def to_json(
     value: Any,
     *,
     indent: int | None = None,
+    sort_keys: bool = False,
+    ensure_ascii: bool = False,
+    canonical: bool = False,
     include: _IncEx | None = None,
     exclude: _IncEx | None = None,
     by_alias: bool = True,
     exclude_none: bool = False,
     round_trip: bool = False,
     timedelta_mode: Literal['iso8601', 'seconds_float', 'milliseconds_float'] = 'iso8601',
+    datetime_mode: Literal['iso8601', 'epoch_seconds', 'epoch_milliseconds', 'epoch_microseconds'] = 'iso8601',
     bytes_mode: Literal['utf8', 'base64', 'hex'] = 'utf8',
     inf_nan_mode: Literal['null', 'constants', 'strings'] = 'constants',
     serialize_unknown: bool = False,
@@ -373,12 +684,21 @@ def to_json(
     Arguments:
         value: The Python object to serialize.
         indent: If `None`, the JSON will be compact, otherwise it will be pretty-printed with the indent provided.
+        sort_keys: Whether to sort the keys of all objects, including nested ones, by their Unicode code points.
+            Keys are compared as the strings written to the JSON, so unlike `json.dumps(sort_keys=True)`,
+            integer keys are ordered as text, e.g. `"10"` before `"2"`.
+        ensure_ascii: Whether to escape all non-ASCII characters as `\\uXXXX` sequences, as `json.dumps` does.
+        canonical: Whether to produce [RFC 8785](https://www.rfc-editor.org/rfc/rfc8785) canonical JSON,
+            with sorted keys, no whitespace and ECMAScript number formatting. Numbers which can't be represented
+            exactly as a double raise an error. Can't be combined with `indent` or `ensure_ascii`.
         include: A set of fields to include, if `None` all fields are included.
         exclude: A set of fields to exclude, if `None` no fields are excluded.
         by_alias: Whether to use the alias names of fields.
         exclude_none: Whether to exclude fields that have a value of `None`.
         round_trip: Whether to enable serialization and validation round-trip support.
         timedelta_mode: How to serialize `timedelta` objects, either `'iso8601'`, `'seconds_float'` or `'milliseconds_float'`.
+        datetime_mode: How to serialize `datetime` and `date` objects, either `'iso8601'`, `'epoch_seconds'`,
+            `'epoch_milliseconds'` or `'epoch_microseconds'`.
         bytes_mode: How to serialize `bytes` objects, either `'utf8'`, `'base64'`, or `'hex'`.
         inf_nan_mode: How to serialize `Infinity`, `-Infinity` and `NaN` values, either `'null'`, `'constants'`, or `'strings'`.
         serialize_unknown: Attempt to serialize unknown types, `str(value)` will be used, if that fails
@@ -396,12 +716,64 @@ def to_json(
        JSON bytes.
     """
 
+def to_msgpack(
+    value: Any,
+    *,
+    include: _IncEx | None = None,
+    exclude: _IncEx | None = None,
+    by_alias: bool = True,
+    exclude_none: bool = False,
+    round_trip: bool = False,
+    timedelta_mode: Literal['iso8601', 'seconds_float', 'milliseconds_float'] = 'iso8601',
+    datetime_mode: Literal['iso8601', 'epoch_seconds', 'epoch_milliseconds', 'epoch_microseconds'] = 'iso8601',
+    bytes_mode: Literal['binary', 'utf8', 'base64', 'hex'] = 'binary',
+    inf_nan_mode: Literal['null', 'constants', 'strings'] = 'constants',
+    serialize_unknown: bool = False,
+    fallback: Callable[[Any], Any] | None = None,
+    serialize_as_any: bool = False,
+    context: Any | None = None,
+) -> bytes:
+    """
+    Serialize a Python object to [MessagePack](https://msgpack.org/) including transforming and filtering data.
+
+    This is effectively a standalone version of
+    [`SchemaSerializer.to_msgpack`][pydantic_core.SchemaSerializer.to_msgpack].
+
+    Arguments:
+        value: The Python object to serialize.
+        include: A set of fields to include, if `None` all fields are included.
+        exclude: A set of fields to exclude, if `None` no fields are excluded.
+        by_alias: Whether to use the alias names of fields.
+        exclude_none: Whether to exclude fields that have a value of `None`.
+        round_trip: Whether to enable serialization and validation round-trip support.
+        timedelta_mode: How to serialize `timedelta` objects, either `'iso8601'`, `'seconds_float'` or `'milliseconds_float'`.
+        datetime_mode: How to serialize `datetime` and `date` objects, either `'iso8601'`, `'epoch_seconds'`,
+            `'epoch_milliseconds'` or `'epoch_microseconds'`.
+        bytes_mode: How to serialize `bytes` objects, either `'binary'` for MessagePack binary data, or `'utf8'`,
+            `'base64'` or `'hex'` for strings.
+        inf_nan_mode: How to serialize `Infinity`, `-Infinity` and `NaN` values, either `'null'`, `'constants'`, or `'strings'`.
+        serialize_unknown: Attempt to serialize unknown types, `str(value)` will be used, if that fails
+            `"<Unserializable {value_type} object>"` will be used.
+        fallback: A function to call when an unknown value is encountered,
+            if `None` a [`PydanticSerializationError`][pydantic_core.PydanticSerializationError] error is raised.
+        serialize_as_any: Whether to serialize fields with duck-typing serialization behavior.
+        context: The context to use for serialization, this is passed to functional serializers as
+            [`info.context`][pydantic_core.core_schema.SerializationInfo.context].
+
+    Raises:
+        PydanticSerializationError: If serialization fails and no `fallback` function is provided.
+
+    Returns:
+       MessagePack bytes.
+    """
+
 def from_json(
     data: str | bytes | bytearray,
     *,
     allow_inf_nan: bool = True,
     cache_strings: bool | Literal['all', 'keys', 'none'] = True,
     allow_partial: bool | Literal['off', 'on', 'trailing-strings'] = False,
+    float_mode: Literal['float', 'decimal'] = 'float',
 ) -> Any:
     """
     Deserialize JSON data to a Python object.
@@ -416,6 +788,8 @@ def from_json(
             `all/True` means cache all strings, `keys` means cache only dict keys, `none/False` means no caching.
         allow_partial: Whether to allow partial deserialization, if `True` JSON data is returned if the end of the
             input is reached before the full object is deserialized, e.g. `["aa", "bb", "c` would return `['aa', 'bb']`.
+        float_mode: How to return floats, `float` means as python `float`s, `decimal` means as `decimal.Decimal`s
+            created from the number exactly as it's written in the JSON, without losing precision.
 
     Raises:
         ValueError: If deserialization fails.
@@ -433,6 +807,7 @@ def to_jsonable_python(
     exclude_none: bool = False,
     round_trip: bool = False,
     timedelta_mode: Literal['iso8601', 'seconds_float', 'milliseconds_float'] = 'iso8601',
+    datetime_mode: Literal['iso8601', 'epoch_seconds', 'epoch_milliseconds', 'epoch_microseconds'] = 'iso8601',
     bytes_mode: Literal['utf8', 'base64', 'hex'] = 'utf8',
     inf_nan_mode: Literal['null', 'constants', 'strings'] = 'constants',
     serialize_unknown: bool = False,
@@ -454,6 +829,8 @@ def to_jsonable_python(
         exclude_none: Whether to exclude fields that have a value of `None`.
         round_trip: Whether to enable serialization and validation round-trip support.
         timedelta_mode: How to serialize `timedelta` objects, either `'iso8601'`, `'seconds_float'`, or`'milliseconds_float'`.
+        datetime_mode: How to serialize `datetime` and `date` objects, either `'iso8601'`, `'epoch_seconds'`,
+            `'epoch_milliseconds'` or `'epoch_microseconds'`.
         bytes_mode: How to serialize `bytes` objects, either `'utf8'`, `'base64'`, or `'hex'`.
         inf_nan_mode: How to serialize `Infinity`, `-Infinity` and `NaN` values, either `'null'`, `'constants'`, or `'strings'`.
         serialize_unknown: Attempt to serialize unknown types, `str(value)` will be used, if that fails
@@ -755,8 +1132,11 @@ class ValidationError(ValueError):
     def from_exception_data(
         title: str,
         line_errors: list[InitErrorDetails],
-        input_type: Literal['python', 'json'] = 'python',
+        input_type: Literal['python', 'json', 'msgpack'] = 'python',
         hide_input: bool = False,
+        error_messages: dict[str, str] | None = None,
+        loc_format: Literal['tuple', 'json_pointer', 'dotted'] = 'tuple',
+        group_errors: bool = False,
     ) -> ValidationError:
         """
         Python constructor for a Validation Error.
@@ -768,8 +1148,13 @@ class ValidationError(ValueError):
             title: The title of the error, as used in the heading of `str(validation_error)`
             line_errors: A list of [`InitErrorDetails`][pydantic_core.InitErrorDetails] which contain information
                 about errors that occurred during validation.
-            input_type: Whether the error is for a Python object or JSON.
+            input_type: Whether the error is for a Python object, JSON or MessagePack.
             hide_input: Whether to hide the input value in the error message.
+            error_messages: Message templates keyed by error type, used instead of the default messages.
+            loc_format: How error locations are rendered by default, see
+                [`errors()`][pydantic_core.ValidationError.errors].
+            group_errors: Whether similar errors are grouped by default, see
+                [`errors()`][pydantic_core.ValidationError.errors].
         """
     @property
     def title(self) -> str:
@@ -781,9 +1166,41 @@ class ValidationError(ValueError):
         Returns:
             The number of errors in the validation error.
         """
+    @overload
     def errors(
-        self, *, include_url: bool = True, include_context: bool = True, include_input: bool = True
-    ) -> list[ErrorDetails]:
+        self,
+        *,
+        include_url: bool = True,
+        include_context: bool = True,
+        include_input: bool = True,
+        error_messages: dict[str, str] | None = None,
+        format: Literal['pydantic'] = 'pydantic',
+        loc_format: Literal['tuple', 'json_pointer', 'dotted'] | None = None,
+        group_errors: bool | None = None,
+    ) -> list[ErrorDetails]: ...
+    @overload
+    def errors(
+        self,
+        *,
+        include_url: bool = True,
+        include_context: bool = True,
+        include_input: bool = True,
+        error_messages: dict[str, str] | None = None,
+        format: Literal['rfc7807', 'jsonapi'],
+        loc_format: Literal['tuple', 'json_pointer', 'dotted'] | None = None,
+        group_errors: bool | None = None,
+    ) -> dict[str, Any]: ...
+    def errors(
+        self,
+        *,
+        include_url: bool = True,
+        include_context: bool = True,
+        include_input: bool = True,
+        error_messages: dict[str, str] | None = None,
+        format: Literal['pydantic', 'rfc7807', 'jsonapi'] = 'pydantic',
+        loc_format: Literal['tuple', 'json_pointer', 'dotted'] | None = None,
+        group_errors: bool | None = None,
+    ) -> list[ErrorDetails] | dict[str, Any]:
         """
         Details about each error in the validation error.
 
@@ -791,9 +1208,35 @@ class ValidationError(ValueError):
             include_url: Whether to include a URL to documentation on the error each error.
             include_context: Whether to include the context of each error.
             include_input: Whether to include the input value of each error.
+            error_messages: Message templates keyed by error type, used to render `msg` instead of the
+                `error_messages` config, error types missing from it use the default English message.
+            format: The shape of the error details:
+
+                - `'pydantic'`: a list of [`ErrorDetails`][pydantic_core.ErrorDetails]
+                - `'rfc7807'`: an [RFC 7807](https://www.rfc-editor.org/rfc/rfc7807) problem details object,
+                  with `title`, `detail` and an `errors` list where each error has `type`, `detail` and a
+                  [JSON Pointer](https://www.rfc-editor.org/rfc/rfc6901) to the error location under `source.pointer`,
+                  plus `input`, `ctx` and `url` as for `'pydantic'`
+                - `'jsonapi'`: a [JSON:API](https://jsonapi.org/format/#errors) document with an `errors` list
+                  where each error has `code`, `detail` and `source.pointer`, with `input` and `ctx` under `meta`
+                  and the documentation URL under `links.about`
+
+                JSON Pointers are relative to the validated input, e.g. `/items/0/name`.
+            loc_format: How `loc` is rendered, defaults to the `loc_format` config which also applies to
+                `str(validation_error)`:
+
+                - `'tuple'`: a tuple of keys and indices, e.g. `('items', 0, 'name')`
+                - `'json_pointer'`: an RFC 6901 JSON Pointer string, e.g. `'/items/0/name'`
+                - `'dotted'`: a dotted path string with bracketed indices, e.g. `'items[0].name'`
+            group_errors: Whether errors with the same type and message, at locations which differ only by
+                index, are reported once, defaults to the `group_errors` config which also applies to
+                `str(validation_error)`. Each grouped error is the first of its group with the number of errors
+                in the group as `count` and up to five locations as `sample_locs`, or `sample_pointers` for the
+                `'rfc7807'` and `'jsonapi'` formats, under `meta` for `'jsonapi'`.
 
         Returns:
-            A list of [`ErrorDetails`][pydantic_core.ErrorDetails] for each error in the validation error.
+            A list of [`ErrorDetails`][pydantic_core.ErrorDetails] for each error in the validation error, or a
+                dict for the `'rfc7807'` and `'jsonapi'` formats.
         """
     def json(
         self,
@@ -802,6 +1245,10 @@ class ValidationError(ValueError):
         include_url: bool = True,
         include_context: bool = True,
         include_input: bool = True,
+        error_messages: dict[str, str] | None = None,
+        format: Literal['pydantic', 'rfc7807', 'jsonapi'] = 'pydantic',
+        loc_format: Literal['tuple', 'json_pointer', 'dotted'] | None = None,
+        group_errors: bool | None = None,
     ) -> str:
         """
         Same as [`errors()`][pydantic_core.ValidationError.errors] but returns a JSON string.
@@ -811,6 +1258,11 @@ class ValidationError(ValueError):
             include_url: Whether to include a URL to documentation on the error each error.
             include_context: Whether to include the context of each error.
             include_input: Whether to include the input value of each error.
+            error_messages: Message templates keyed by error type, used to render `msg` instead of the
+                `error_messages` config, error types missing from it use the default English message.
+            format: The shape of the error details, see [`errors()`][pydantic_core.ValidationError.errors].
+            loc_format: How `loc` is rendered, see [`errors()`][pydantic_core.ValidationError.errors].
+            group_errors: Whether similar errors are grouped, see [`errors()`][pydantic_core.ValidationError.errors].
 
         Returns:
             a JSON string.
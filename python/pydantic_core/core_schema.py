@@ -67,17 +67,60 @@ class CoreConfig(TypedDict, total=False):
         str_to_upper: Whether to convert string fields to uppercase.
         allow_inf_nan: Whether to allow infinity and NaN values for float fields. Default is `True`.
         ser_json_timedelta: The serialization option for `timedelta` values. Default is 'iso8601'.
+        ser_json_datetime: The serialization option for `datetime` and `date` values, either ISO 8601 strings or unix
+            timestamps, naive datetimes are treated as UTC. Default is 'iso8601'.
         ser_json_bytes: The serialization option for `bytes` values. Default is 'utf8'.
         ser_json_inf_nan: The serialization option for infinity and NaN values
             in float fields. Default is 'null'.
+        ser_json_float_precision: The number of decimal places float and decimal values are rounded to in JSON,
+            exactly this many are always written. Floats of 1e16 or more are written in exponent notation instead.
+            By default values aren't rounded.
+        ser_json_number_as_string: Whether float and decimal values are serialized as strings in JSON. By default
+            floats are numbers and decimals are strings.
         val_json_bytes: The validation option for `bytes` values, complementing ser_json_bytes. Default is 'utf8'.
         hide_input_in_errors: Whether to hide input data from `ValidationError` representation.
         validation_error_cause: Whether to add user-python excs to the __cause__ of a ValidationError.
             Requires exceptiongroup backport pre Python 3.11.
+        fail_fast: Whether lists, tuples, sets, frozensets, dicts, models, typed dicts, dataclasses and arguments
+            stop validating at their first error, useful when only whether the input is valid matters. A schema's own
+            `fail_fast` takes precedence. Default is `False`.
+        error_messages: Message templates keyed by error type used instead of the default English messages, e.g. to
+            translate errors. Templates use the same `{placeholder}`s as the defaults, see `list_all_errors()`;
+            unknown error types or placeholders raise a `ValueError`. Custom errors always use their own template.
+        loc_format: How error locations are rendered in `ValidationError.errors()`, `.json()` and `str()`, either a
+            tuple like `('a', 0)`, an RFC 6901 JSON Pointer like `/a/0` or a dotted path like `a[0]`.
+            Default is 'tuple'.
+        group_errors: Whether errors of the same type and message at locations which differ only by list, tuple or
+            set index are reported as a single error with a `count` and up to five `sample_locs`, e.g. when the same
+            field is invalid in every item of a large list. Default is `False`.
         coerce_numbers_to_str: Whether to enable coercion of any `Number` type to `str` (not applicable in `strict` mode).
         regex_engine: The regex engine to use for regex pattern validation. Default is 'rust-regex'. See `StringSchema`.
+        regex_backtrack_limit: The backtracking limit for the 'fancy-regex' engine. See `StringSchema`.
         cache_strings: Whether to cache strings. Default is `True`, `True` or `'all'` is required to cache strings
             during general validation since validators don't know if they're in a key or a value.
+        recursion_limit: The maximum depth of recursive definitions during validation and serialization, the default
+            depends on the platform, see `pydantic_core._pydantic_core._recursion_limit`. It can be raised to at most
+            four times the default, higher values could overflow the stack.
+        cyclic_references: How objects which are seen more than once by the same recursive definition are handled
+            during validation and `to_python` serialization. Default is 'error'.
+            - `error`: objects are validated or serialized each time they're seen, and cyclic references
+              raise a `recursion_loop` error
+            - `reuse`: the output from the first time an object was seen is reused, preserving shared references;
+              cyclic references still raise an error, since the output doesn't exist until the object is finished
+        max_total_items: The maximum total number of items in all lists, tuples, sets, frozensets and dicts in the
+            input to a single validation call. By default there's no limit.
+        max_total_string_bytes: The maximum total length in bytes (UTF-8 encoded) of all strings in the input to
+            a single validation call. By default there's no limit.
+        max_json_depth: The maximum nesting depth of arrays and objects in JSON input, checked before the JSON is
+            parsed. By default there's no limit beyond the parser's own.
+            JSON input is counted towards all of these limits before it's parsed, including object keys and
+            values which aren't validated. Values accepted by `any`, or by a list without an `items_schema`, are
+            counted along with everything nested in them.
+            Exceeding any of these limits stops validation immediately with a single `resource_limit_exceeded`
+            error, rather than the usual error for every invalid item.
+        max_errors: The maximum number of errors in a `ValidationError`, lists, sets, frozensets and dicts stop
+            validating their items once they've found this many errors and any further errors are dropped.
+            By default there's no limit.
     """
 
     title: str
@@ -106,15 +149,31 @@ class CoreConfig(TypedDict, total=False):
     allow_inf_nan: bool  # default: True
     # the config options are used to customise serialization to JSON
     ser_json_timedelta: Literal['iso8601', 'seconds_float', 'milliseconds_float']  # default: 'iso8601'
+    # default: 'iso8601'
+    ser_json_datetime: Literal['iso8601', 'epoch_seconds', 'epoch_milliseconds', 'epoch_microseconds']
     ser_json_bytes: Literal['utf8', 'base64', 'hex']  # default: 'utf8'
     ser_json_inf_nan: Literal['null', 'constants', 'strings']  # default: 'null'
+    ser_json_float_precision: int
+    ser_json_number_as_string: bool
     val_json_bytes: Literal['utf8', 'base64', 'hex']  # default: 'utf8'
     # used to hide input data from ValidationError repr
     hide_input_in_errors: bool
     validation_error_cause: bool  # default: False
+    fail_fast: bool  # default: False
+    error_messages: Dict[str, str]
+    loc_format: Literal['tuple', 'json_pointer', 'dotted']  # default: 'tuple'
+    group_errors: bool  # default: False
     coerce_numbers_to_str: bool  # default: False
-    regex_engine: Literal['rust-regex', 'python-re']  # default: 'rust-regex'
+    regex_engine: Literal['rust-regex', 'fancy-regex', 'python-re']  # default: 'rust-regex'
+    regex_backtrack_limit: int
     cache_strings: Union[bool, Literal['all', 'keys', 'none']]  # default: 'True'
+    recursion_limit: int
+    cyclic_references: Literal['error', 'reuse']  # default: 'error'
+    # limits on the size of untrusted input
+    max_total_items: int
+    max_total_string_bytes: int
+    max_json_depth: int
+    max_errors: int
 
 
 IncExCall: TypeAlias = 'set[int | str] | dict[int | str, IncExCall] | None'
@@ -179,7 +238,7 @@ class ValidationInfo(Protocol):
         ...
 
     @property
-    def mode(self) -> Literal['python', 'json']:
+    def mode(self) -> Literal['python', 'json', 'msgpack']:
         """The type of input data we are currently validating"""
         ...
 
@@ -215,10 +274,15 @@ ExpectedSerializationTypes = Literal[
     'date',
     'time',
     'timedelta',
+    'timezone',
     'url',
     'multi-host-url',
     'json',
     'uuid',
+    'ip-address',
+    'ip-network',
+    'ip-interface',
+    'path',
     'any',
 ]
 
@@ -645,6 +709,8 @@ class FloatSchema(TypedDict, total=False):
     lt: float
     gt: float
     strict: bool
+    float_precision: int
+    number_as_string: bool
     ref: str
     metadata: Dict[str, Any]
     serialization: SerSchema
@@ -659,6 +725,8 @@ def float_schema(
     lt: float | None = None,
     gt: float | None = None,
     strict: bool | None = None,
+    float_precision: int | None = None,
+    number_as_string: bool | None = None,
     ref: str | None = None,
     metadata: Dict[str, Any] | None = None,
     serialization: SerSchema | None = None,
@@ -682,6 +750,9 @@ def float_schema(
         lt: The value must be strictly less than this number
         gt: The value must be strictly greater than this number
         strict: Whether the value should be a float or a value that can be converted to a float
+        float_precision: The number of decimal places to round to when serializing to JSON, exactly this many
+            are always written, values of 1e16 or more are written in exponent notation instead
+        number_as_string: Whether to serialize the value as a string rather than a number in JSON
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -695,6 +766,8 @@ def float_schema(
         lt=lt,
         gt=gt,
         strict=strict,
+        float_precision=float_precision,
+        number_as_string=number_as_string,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -712,6 +785,8 @@ class DecimalSchema(TypedDict, total=False):
     max_digits: int
     decimal_places: int
     strict: bool
+    float_precision: int
+    number_as_string: bool
     ref: str
     metadata: Dict[str, Any]
     serialization: SerSchema
@@ -728,6 +803,8 @@ def decimal_schema(
     max_digits: int | None = None,
     decimal_places: int | None = None,
     strict: bool | None = None,
+    float_precision: int | None = None,
+    number_as_string: bool | None = None,
     ref: str | None = None,
     metadata: Dict[str, Any] | None = None,
     serialization: SerSchema | None = None,
@@ -754,6 +831,10 @@ def decimal_schema(
         max_digits: The maximum number of decimal digits allowed
         decimal_places: The maximum number of decimal places allowed
         strict: Whether the value should be a float or a value that can be converted to a float
+        float_precision: The number of decimal places to round to when serializing to JSON, exactly this many
+            are always written
+        number_as_string: Whether to serialize the value as a string rather than a number in JSON, by default
+            decimals are serialized as strings
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -769,6 +850,8 @@ def decimal_schema(
         multiple_of=multiple_of,
         allow_inf_nan=allow_inf_nan,
         strict=strict,
+        float_precision=float_precision,
+        number_as_string=number_as_string,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -825,7 +908,8 @@ class StringSchema(TypedDict, total=False):
     strip_whitespace: bool
     to_lower: bool
     to_upper: bool
-    regex_engine: Literal['rust-regex', 'python-re']  # default: 'rust-regex'
+    regex_engine: Literal['rust-regex', 'fancy-regex', 'python-re']  # default: 'rust-regex'
+    regex_backtrack_limit: int
     strict: bool
     coerce_numbers_to_str: bool
     ref: str
@@ -841,7 +925,8 @@ def str_schema(
     strip_whitespace: bool | None = None,
     to_lower: bool | None = None,
     to_upper: bool | None = None,
-    regex_engine: Literal['rust-regex', 'python-re'] | None = None,
+    regex_engine: Literal['rust-regex', 'fancy-regex', 'python-re'] | None = None,
+    regex_backtrack_limit: int | None = None,
     strict: bool | None = None,
     coerce_numbers_to_str: bool | None = None,
     ref: str | None = None,
@@ -870,8 +955,14 @@ def str_schema(
             - `rust-regex` uses the [`regex`](https://docs.rs/regex) Rust
               crate, which is non-backtracking and therefore more DDoS
               resistant, but does not support all regex features.
+            - `fancy-regex` uses the [`fancy-regex`](https://docs.rs/fancy-regex) Rust crate,
+              which adds support for lookaround and backreferences, falling back to backtracking
+              only for patterns which need it, see `regex_backtrack_limit`.
             - `python-re` use the [`re`](https://docs.python.org/3/library/re.html) module,
               which supports all regex features, but may be slower.
+        regex_backtrack_limit: The maximum number of backtracking steps the 'fancy-regex' engine may take
+            when matching a value, values which exceed it fail with a `resource_limit_exceeded` error rather than
+            `string_pattern_mismatch`. Default is 1,000,000.
         strict: Whether the value should be a string or a value that can be converted to a string
         coerce_numbers_to_str: Whether to enable coercion of any `Number` type to `str` (not applicable in `strict` mode).
         ref: optional unique identifier of the schema, used to reference the schema in other places
@@ -887,6 +978,7 @@ def str_schema(
         to_lower=to_lower,
         to_upper=to_upper,
         regex_engine=regex_engine,
+        regex_backtrack_limit=regex_backtrack_limit,
         strict=strict,
         coerce_numbers_to_str=coerce_numbers_to_str,
         ref=ref,
@@ -955,6 +1047,8 @@ class DateSchema(TypedDict, total=False):
     # defaults to current local utc offset from `time.localtime().tm_gmtoff`
     # value is restricted to -86_400 < offset < 86_400 by bounds in generate_self_schema.py
     now_utc_offset: int
+    formats: List[str]
+    ser_format: str
     ref: str
     metadata: Dict[str, Any]
     serialization: SerSchema
@@ -969,6 +1063,8 @@ def date_schema(
     gt: date | None = None,
     now_op: Literal['past', 'future'] | None = None,
     now_utc_offset: int | None = None,
+    formats: List[str] | None = None,
+    ser_format: str | None = None,
     ref: str | None = None,
     metadata: Dict[str, Any] | None = None,
     serialization: SerSchema | None = None,
@@ -993,6 +1089,8 @@ def date_schema(
         gt: The value must be strictly greater than this date
         now_op: The value must be in the past or future relative to the current date
         now_utc_offset: The value must be in the past or future relative to the current date with this utc offset
+        formats: `strptime` style formats like `'%d/%m/%Y'`, tried in turn in lax mode if the input isn't ISO 8601
+        ser_format: `strftime` style format used instead of ISO 8601 when serializing to JSON
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -1006,6 +1104,8 @@ def date_schema(
         gt=gt,
         now_op=now_op,
         now_utc_offset=now_utc_offset,
+        formats=formats,
+        ser_format=ser_format,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -1021,6 +1121,8 @@ class TimeSchema(TypedDict, total=False):
     gt: time
     tz_constraint: Union[Literal['aware', 'naive'], int]
     microseconds_precision: Literal['truncate', 'error']
+    formats: List[str]
+    ser_format: str
     ref: str
     metadata: Dict[str, Any]
     serialization: SerSchema
@@ -1035,6 +1137,8 @@ def time_schema(
     gt: time | None = None,
     tz_constraint: Literal['aware', 'naive'] | int | None = None,
     microseconds_precision: Literal['truncate', 'error'] = 'truncate',
+    formats: List[str] | None = None,
+    ser_format: str | None = None,
     ref: str | None = None,
     metadata: Dict[str, Any] | None = None,
     serialization: SerSchema | None = None,
@@ -1059,6 +1163,8 @@ def time_schema(
         gt: The value must be strictly greater than this time
         tz_constraint: The value must be timezone aware or naive, or an int to indicate required tz offset
         microseconds_precision: The behavior when seconds have more than 6 digits or microseconds is too large
+        formats: `strptime` style formats like `'%d/%m/%Y'`, tried in turn in lax mode if the input isn't ISO 8601
+        ser_format: `strftime` style format used instead of ISO 8601 when serializing to JSON
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -1072,6 +1178,8 @@ def time_schema(
         gt=gt,
         tz_constraint=tz_constraint,
         microseconds_precision=microseconds_precision,
+        formats=formats,
+        ser_format=ser_format,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -1091,6 +1199,9 @@ class DatetimeSchema(TypedDict, total=False):
     # value is restricted to -86_400 < offset < 86_400 by bounds in generate_self_schema.py
     now_utc_offset: int
     microseconds_precision: Literal['truncate', 'error']  # default: 'truncate'
+    normalize_tz: str
+    formats: List[str]
+    ser_format: str
     ref: str
     metadata: Dict[str, Any]
     serialization: SerSchema
@@ -1107,6 +1218,9 @@ def datetime_schema(
     tz_constraint: Literal['aware', 'naive'] | int | None = None,
     now_utc_offset: int | None = None,
     microseconds_precision: Literal['truncate', 'error'] = 'truncate',
+    normalize_tz: str | None = None,
+    formats: List[str] | None = None,
+    ser_format: str | None = None,
     ref: str | None = None,
     metadata: Dict[str, Any] | None = None,
     serialization: SerSchema | None = None,
@@ -1135,6 +1249,10 @@ def datetime_schema(
             TODO: use of a tzinfo where offset changes based on the datetime is not yet supported
         now_utc_offset: The value must be in the past or future relative to the current datetime with this utc offset
         microseconds_precision: The behavior when seconds have more than 6 digits or microseconds is too large
+        normalize_tz: Convert aware datetimes to this time zone, either an IANA name like `'Europe/Helsinki'`
+            or a UTC offset like `'+02:00'`, naive datetimes are left unchanged
+        formats: `strptime` style formats like `'%d/%m/%Y'`, tried in turn in lax mode if the input isn't ISO 8601
+        ser_format: `strftime` style format used instead of ISO 8601 when serializing to JSON
         ref: optional unique identifier of the schema, used to reference the schema in other places
         metadata: Any other information you want to include with the schema, not used by pydantic-core
         serialization: Custom serialization schema
@@ -1150,6 +1268,9 @@ def datetime_schema(
         tz_constraint=tz_constraint,
         now_utc_offset=now_utc_offset,
         microseconds_precision=microseconds_precision,
+        normalize_tz=normalize_tz,
+        formats=formats,
+        ser_format=ser_format,
         ref=ref,
         metadata=metadata,
         serialization=serialization,
@@ -1218,6 +1339,45 @@ def timedelta_schema(
     )
 
 
+class TimezoneSchema(TypedDict, total=False):
+    type: Required[Literal['timezone']]
+    strict: bool
+    ref: str
+    metadata: Dict[str, Any]
+    serialization: SerSchema
+
+
+def timezone_schema(
+    *,
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: Dict[str, Any] | None = None,
+    serialization: SerSchema | None = None,
+) -> TimezoneSchema:
+    """
+    Returns a schema that matches a time zone, e.g.:
+
+    ```py
+    from zoneinfo import ZoneInfo
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.timezone_schema()
+    v = SchemaValidator(schema)
+    assert v.validate_python('Europe/Helsinki') == ZoneInfo('Europe/Helsinki')
+    ```
+
+    IANA names are converted to `zoneinfo.ZoneInfo`, UTC offsets like `'+02:00'` or an integer number of
+    seconds are converted to a fixed offset `tzinfo`.
+
+    Args:
+        strict: Whether the value should be a `tzinfo` instance or a value that can be converted to one
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(type='timezone', strict=strict, ref=ref, metadata=metadata, serialization=serialization)
+
+
 class LiteralSchema(TypedDict, total=False):
     type: Required[Literal['literal']]
     expected: Required[List[Any]]
@@ -1460,6 +1620,191 @@ def uuid_schema(
     )
 
 
+class IpAddressSchema(TypedDict, total=False):
+    type: Required[Literal['ip-address']]
+    version: Literal[4, 6]
+    strict: bool
+    ref: str
+    metadata: Dict[str, Any]
+    serialization: SerSchema
+
+
+def ip_address_schema(
+    *,
+    version: Literal[4, 6] | None = None,
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: Dict[str, Any] | None = None,
+    serialization: SerSchema | None = None,
+) -> IpAddressSchema:
+    """
+    Returns a schema that matches an `ipaddress` address, e.g.:
+
+    ```py
+    from ipaddress import IPv4Address
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.ip_address_schema()
+    v = SchemaValidator(schema)
+    assert v.validate_python('192.168.0.1') == IPv4Address('192.168.0.1')
+    ```
+
+    Args:
+        version: The IP version to accept, 4 or 6, by default both are accepted
+        strict: Whether the value should be an `ipaddress` address instance or a string, integer or bytes
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(
+        type='ip-address', version=version, strict=strict, ref=ref, metadata=metadata, serialization=serialization
+    )
+
+
+class IpNetworkSchema(TypedDict, total=False):
+    type: Required[Literal['ip-network']]
+    version: Literal[4, 6]
+    strict: bool
+    ref: str
+    metadata: Dict[str, Any]
+    serialization: SerSchema
+
+
+def ip_network_schema(
+    *,
+    version: Literal[4, 6] | None = None,
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: Dict[str, Any] | None = None,
+    serialization: SerSchema | None = None,
+) -> IpNetworkSchema:
+    """
+    Returns a schema that matches an `ipaddress` network, e.g.:
+
+    ```py
+    from ipaddress import IPv4Network
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.ip_network_schema()
+    v = SchemaValidator(schema)
+    assert v.validate_python('192.168.0.0/24') == IPv4Network('192.168.0.0/24')
+    ```
+
+    Args:
+        version: The IP version to accept, 4 or 6, by default both are accepted
+        strict: Whether the value should be an `ipaddress` network instance or a string, integer, bytes or address
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(
+        type='ip-network', version=version, strict=strict, ref=ref, metadata=metadata, serialization=serialization
+    )
+
+
+class IpInterfaceSchema(TypedDict, total=False):
+    type: Required[Literal['ip-interface']]
+    version: Literal[4, 6]
+    strict: bool
+    ref: str
+    metadata: Dict[str, Any]
+    serialization: SerSchema
+
+
+def ip_interface_schema(
+    *,
+    version: Literal[4, 6] | None = None,
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: Dict[str, Any] | None = None,
+    serialization: SerSchema | None = None,
+) -> IpInterfaceSchema:
+    """
+    Returns a schema that matches an `ipaddress` interface, e.g.:
+
+    ```py
+    from ipaddress import IPv4Interface
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.ip_interface_schema()
+    v = SchemaValidator(schema)
+    assert v.validate_python('192.168.0.1/24') == IPv4Interface('192.168.0.1/24')
+    ```
+
+    Args:
+        version: The IP version to accept, 4 or 6, by default both are accepted
+        strict: Whether the value should be an `ipaddress` interface instance or a string, integer, bytes or address
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(
+        type='ip-interface', version=version, strict=strict, ref=ref, metadata=metadata, serialization=serialization
+    )
+
+
+class PathSchema(TypedDict, total=False):
+    type: Required[Literal['path']]
+    absolute: bool
+    allowed_suffixes: List[str]
+    exists: bool
+    is_file: bool
+    is_dir: bool
+    strict: bool
+    ref: str
+    metadata: Dict[str, Any]
+    serialization: SerSchema
+
+
+def path_schema(
+    *,
+    absolute: bool | None = None,
+    allowed_suffixes: List[str] | None = None,
+    exists: bool | None = None,
+    is_file: bool | None = None,
+    is_dir: bool | None = None,
+    strict: bool | None = None,
+    ref: str | None = None,
+    metadata: Dict[str, Any] | None = None,
+    serialization: SerSchema | None = None,
+) -> PathSchema:
+    """
+    Returns a schema that matches a `pathlib.Path`, e.g.:
+
+    ```py
+    from pathlib import Path
+    from pydantic_core import SchemaValidator, core_schema
+
+    schema = core_schema.path_schema(allowed_suffixes=['.txt'])
+    v = SchemaValidator(schema)
+    assert v.validate_python('notes.txt') == Path('notes.txt')
+    ```
+
+    Args:
+        absolute: Whether the path must be absolute
+        allowed_suffixes: The suffixes the path may have, e.g. `['.txt', '.csv']`
+        exists: Whether the path must exist
+        is_file: Whether the path must point to an existing file
+        is_dir: Whether the path must point to an existing directory
+        strict: Whether the value should be a `Path` instance or a str, bytes or `os.PathLike` object
+        ref: optional unique identifier of the schema, used to reference the schema in other places
+        metadata: Any other information you want to include with the schema, not used by pydantic-core
+        serialization: Custom serialization schema
+    """
+    return _dict_not_none(
+        type='path',
+        absolute=absolute,
+        allowed_suffixes=allowed_suffixes,
+        exists=exists,
+        is_file=is_file,
+        is_dir=is_dir,
+        strict=strict,
+        ref=ref,
+        metadata=metadata,
+        serialization=serialization,
+    )
+
+
 class IncExSeqSerSchema(TypedDict, total=False):
     type: Required[Literal['include-exclude-sequence']]
     include: Set[int]
@@ -3862,6 +4207,7 @@ if not MYPY:
         TimeSchema,
         DatetimeSchema,
         TimedeltaSchema,
+        TimezoneSchema,
         LiteralSchema,
         EnumSchema,
         IsInstanceSchema,
@@ -3898,6 +4244,10 @@ if not MYPY:
         DefinitionsSchema,
         DefinitionReferenceSchema,
         UuidSchema,
+        IpAddressSchema,
+        IpNetworkSchema,
+        IpInterfaceSchema,
+        PathSchema,
         ComplexSchema,
     ]
 elif False:
@@ -3919,6 +4269,7 @@ CoreSchemaType = Literal[
     'time',
     'datetime',
     'timedelta',
+    'timezone',
     'literal',
     'enum',
     'is-instance',
@@ -3955,6 +4306,10 @@ CoreSchemaType = Literal[
     'definitions',
     'definition-ref',
     'uuid',
+    'ip-address',
+    'ip-network',
+    'ip-interface',
+    'path',
     'complex',
 ]
 
@@ -3968,7 +4323,10 @@ ErrorType = Literal[
     'json_invalid',
     'json_type',
     'needs_python_object',
+    'msgpack_invalid',
+    'msgpack_type',
     'recursion_loop',
+    'resource_limit_exceeded',
     'missing',
     'frozen_field',
     'frozen_instance',
@@ -4029,10 +4387,13 @@ ErrorType = Literal[
     'datetime_parsing',
     'datetime_object_invalid',
     'datetime_from_date_parsing',
+    'datetime_format',
     'datetime_past',
     'datetime_future',
     'timezone_naive',
     'timezone_aware',
+    'timezone_type',
+    'timezone_parsing',
     'timezone_offset',
     'time_delta_type',
     'time_delta_parsing',
@@ -4057,6 +4418,16 @@ ErrorType = Literal[
     'uuid_type',
     'uuid_parsing',
     'uuid_version',
+    'ip_type',
+    'ip_address_parsing',
+    'ip_network_parsing',
+    'ip_interface_parsing',
+    'path_type',
+    'path_not_absolute',
+    'path_suffix',
+    'path_not_exists',
+    'path_not_file',
+    'path_not_directory',
     'decimal_type',
     'decimal_parsing',
     'decimal_max_digits',
@@ -6,7 +6,7 @@ use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList, PyString};
 use pyo3::{intern, FromPyObject, PyErrArguments};
 
-use crate::errors::ValError;
+use crate::errors::{ErrorFormat, ErrorOutputConfig, ValError};
 use crate::input::InputType;
 use crate::tools::SchemaDict;
 use crate::ValidationError;
@@ -84,10 +84,16 @@ impl SchemaError {
 
     pub fn from_val_error(py: Python, error: ValError) -> PyErr {
         match error {
+            ValError::LimitExceeded(raw_error) => Self::from_val_error(py, ValError::LineErrors(vec![*raw_error])),
             ValError::LineErrors(raw_errors) => {
                 let line_errors = raw_errors.into_iter().map(|e| e.into_py(py)).collect();
-                let validation_error =
-                    ValidationError::new(line_errors, "Schema".to_object(py), InputType::Python, false);
+                let validation_error = ValidationError::new(
+                    line_errors,
+                    "Schema".to_object(py),
+                    InputType::Python,
+                    false,
+                    ErrorOutputConfig::default(),
+                );
                 let schema_error = SchemaError(SchemaErrorEnum::ValidationError(validation_error));
                 match Py::new(py, schema_error) {
                     Ok(err) => PyErr::from_value_bound(err.into_bound(py).into_any()),
@@ -125,7 +131,14 @@ impl SchemaError {
     fn errors(&self, py: Python) -> PyResult<Py<PyList>> {
         match &self.0 {
             SchemaErrorEnum::Message(_) => Ok(PyList::empty_bound(py).unbind()),
-            SchemaErrorEnum::ValidationError(error) => error.errors(py, false, false, true),
+            SchemaErrorEnum::ValidationError(error) => error.errors_list(
+                py,
+                false,
+                false,
+                true,
+                ErrorFormat::Pydantic,
+                &ErrorOutputConfig::default(),
+            ),
         }
     }
 
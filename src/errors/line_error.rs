@@ -3,10 +3,10 @@ use pyo3::prelude::*;
 use pyo3::DowncastError;
 use pyo3::DowncastIntoError;
 
-use jiter::JsonValue;
-
 use crate::input::BorrowInput;
 use crate::input::Input;
+use crate::input::JsonValue;
+use crate::input::MsgpackValue;
 
 use super::location::{LocItem, Location};
 use super::types::ErrorType;
@@ -32,6 +32,9 @@ impl ToErrorValue for &'_ dyn ToErrorValue {
 #[cfg_attr(debug_assertions, derive(Debug))]
 pub enum ValError {
     LineErrors(Vec<ValLineError>),
+    // a resource limit from config was exceeded, unlike `LineErrors` this isn't collected by containers or
+    // unions, so validation stops immediately
+    LimitExceeded(Box<ValLineError>),
     InternalErr(PyErr),
     Omit,
     UseDefault,
@@ -84,6 +87,10 @@ impl ValError {
                 }
                 Self::LineErrors(line_errors)
             }
+            Self::LimitExceeded(mut line_error) => {
+                line_error.location.with_outer(loc_item);
+                Self::LimitExceeded(line_error)
+            }
             other => other,
         }
     }
@@ -152,6 +159,7 @@ impl ValLineError {
 pub enum InputValue {
     Python(PyObject),
     Json(JsonValue<'static>),
+    Msgpack(MsgpackValue),
 }
 
 impl ToPyObject for InputValue {
@@ -159,6 +167,7 @@ impl ToPyObject for InputValue {
         match self {
             Self::Python(input) => input.clone_ref(py),
             Self::Json(input) => input.to_object(py),
+            Self::Msgpack(input) => input.to_object(py),
         }
     }
 }
@@ -1,4 +1,4 @@
-use pyo3::exceptions::PyTypeError;
+use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::sync::GILOnceCell;
 use std::borrow::Cow;
 use std::fmt;
@@ -180,6 +180,123 @@ impl Location {
             }
         };
     }
+
+    /// The location as an RFC 6901 JSON Pointer, e.g. `/foo/2`, an empty location points at the whole document.
+    pub fn json_pointer(&self) -> String {
+        let mut pointer = String::new();
+        if let Self::List(loc) = self {
+            for item in loc.iter().rev() {
+                pointer.push('/');
+                match item {
+                    LocItem::S(s) => pointer.push_str(&s.replace('~', "~0").replace('/', "~1")),
+                    LocItem::I(i) => pointer.push_str(&i.to_string()),
+                }
+            }
+        }
+        pointer
+    }
+
+    /// The location as a dotted path with bracketed indices, e.g. `foo.bar[2]`, keys containing `.` or `[`
+    /// are quoted with backticks as in `str(validation_error)`.
+    pub fn dotted(&self) -> String {
+        let mut path = String::new();
+        if let Self::List(loc) = self {
+            for item in loc.iter().rev() {
+                match item {
+                    LocItem::S(s) => {
+                        if !path.is_empty() {
+                            path.push('.');
+                        }
+                        if s.contains(['.', '[']) {
+                            path.push('`');
+                            path.push_str(s);
+                            path.push('`');
+                        } else {
+                            path.push_str(s);
+                        }
+                    }
+                    LocItem::I(i) => {
+                        path.push('[');
+                        path.push_str(&i.to_string());
+                        path.push(']');
+                    }
+                }
+            }
+        }
+        path
+    }
+
+    /// The string keys of the location with indices blanked out, so errors at `('a', 1, 'b')` and
+    /// `('a', 2, 'b')` compare equal.
+    pub fn without_indices(&self) -> Vec<Option<&str>> {
+        match self {
+            Self::List(loc) => loc
+                .iter()
+                .rev()
+                .map(|item| match item {
+                    LocItem::S(s) => Some(s.as_str()),
+                    LocItem::I(_) => None,
+                })
+                .collect(),
+            Self::Empty => Vec::new(),
+        }
+    }
+
+    /// The location as a single line of text, as it's shown in `str(validation_error)`.
+    pub fn to_string_as(&self, loc_format: LocFormat) -> String {
+        match (loc_format, self) {
+            (LocFormat::Tuple, Self::List(loc)) => {
+                loc.iter().rev().map(ToString::to_string).collect::<Vec<_>>().join(".")
+            }
+            (LocFormat::Tuple, Self::Empty) => String::new(),
+            (LocFormat::JsonPointer, _) => self.json_pointer(),
+            (LocFormat::Dotted, _) => self.dotted(),
+        }
+    }
+
+    pub fn to_object_as(&self, py: Python, loc_format: LocFormat) -> PyObject {
+        match loc_format {
+            LocFormat::Tuple => self.to_object(py),
+            LocFormat::JsonPointer => self.json_pointer().to_object(py),
+            LocFormat::Dotted => self.dotted().to_object(py),
+        }
+    }
+}
+
+/// How error locations are rendered in `ValidationError.errors()`, `.json()` and `str()`
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum LocFormat {
+    /// a tuple of keys and indices, e.g. `('foo', 2)`
+    #[default]
+    Tuple,
+    /// an RFC 6901 JSON Pointer, e.g. `/foo/2`
+    JsonPointer,
+    /// a dotted path with bracketed indices, e.g. `foo[2]`
+    Dotted,
+}
+
+impl<'py> FromPyObject<'py> for LocFormat {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        match ob.extract::<&str>() {
+            Ok("tuple") => Ok(Self::Tuple),
+            Ok("json_pointer") => Ok(Self::JsonPointer),
+            Ok("dotted") => Ok(Self::Dotted),
+            _ => Err(PyValueError::new_err(
+                "Invalid loc_format, should be `'tuple'`, `'json_pointer'` or `'dotted'`",
+            )),
+        }
+    }
+}
+
+impl ToPyObject for LocFormat {
+    fn to_object(&self, py: Python<'_>) -> PyObject {
+        match self {
+            Self::Tuple => "tuple",
+            Self::JsonPointer => "json_pointer",
+            Self::Dotted => "dotted",
+        }
+        .to_object(py)
+    }
 }
 
 impl Serialize for Location {
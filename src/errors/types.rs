@@ -1,8 +1,9 @@
 use std::any::type_name;
 use std::borrow::Cow;
 use std::fmt;
+use std::sync::Arc;
 
-use pyo3::exceptions::{PyKeyError, PyTypeError};
+use pyo3::exceptions::{PyKeyError, PyTypeError, PyValueError};
 use pyo3::prelude::*;
 use pyo3::sync::GILOnceCell;
 use pyo3::types::{PyDict, PyList};
@@ -36,12 +37,89 @@ pub fn list_all_errors(py: Python) -> PyResult<Bound<'_, PyList>> {
                 d.set_item("example_message_json", error_type.render_message(py, InputType::Json)?)?;
             }
             d.set_item("example_context", error_type.py_dict(py)?)?;
+            d.set_item(
+                "placeholders",
+                template_placeholders(&[message_template_python, message_template_json]),
+            )?;
             errors.push(d);
         }
     }
     Ok(PyList::new_bound(py, errors))
 }
 
+/// Names of the `{placeholder}`s used in the given templates, in order of first appearance.
+fn template_placeholders<'a>(templates: &[&'a str]) -> Vec<&'a str> {
+    let mut placeholders = Vec::new();
+    for template in templates {
+        let mut rest = *template;
+        while let Some(start) = rest.find('{') {
+            rest = &rest[start + 1..];
+            let Some(end) = rest.find('}') else { break };
+            let name = &rest[..end];
+            if !placeholders.contains(&name) {
+                placeholders.push(name);
+            }
+            rest = &rest[end + 1..];
+        }
+    }
+    placeholders
+}
+
+/// Error message templates keyed by error type, e.g. to translate error messages.
+///
+/// Templates use the same `{placeholder}` syntax as the built-in messages, any error type missing from the
+/// catalog uses the default English message. Unknown error types and placeholders are rejected when the catalog
+/// is created, rather than silently never being used or left in the message.
+#[derive(Debug, Clone)]
+pub struct MessageCatalog(Arc<AHashMap<String, String>>);
+
+impl MessageCatalog {
+    pub fn get(&self, error_type: &str) -> Option<&str> {
+        self.0.get(error_type).map(String::as_str)
+    }
+}
+
+impl FromPyObject<'_> for MessageCatalog {
+    fn extract_bound(ob: &Bound<'_, PyAny>) -> PyResult<Self> {
+        let dict = ob.downcast::<PyDict>()?;
+        let mut templates = AHashMap::with_capacity(dict.len());
+        for (key, value) in dict {
+            let error_type: String = key.extract()?;
+            let template: String = value.extract()?;
+            let Some(known) =
+                ErrorType::iter().find(|e| !matches!(e, ErrorType::CustomError { .. }) && e.to_string() == error_type)
+            else {
+                return Err(PyValueError::new_err(format!(
+                    "Invalid error_messages: unknown error type `{error_type}`, see `list_all_errors()`"
+                )));
+            };
+            let placeholders = template_placeholders(&[known.message_template_python(), known.message_template_json()]);
+            let unknown = template_placeholders(&[&template])
+                .into_iter()
+                .find(|name| !placeholders.contains(name));
+            if let Some(unknown) = unknown {
+                let expected = if placeholders.is_empty() {
+                    "no placeholders".to_string()
+                } else {
+                    let placeholders: Vec<_> = placeholders.iter().map(|name| format!("`{{{name}}}`")).collect();
+                    placeholders.join(", ")
+                };
+                return Err(PyValueError::new_err(format!(
+                    "Invalid error_messages: `{error_type}` has no placeholder `{{{unknown}}}`, expected {expected}"
+                )));
+            }
+            templates.insert(error_type, template);
+        }
+        Ok(Self(Arc::new(templates)))
+    }
+}
+
+impl ToPyObject for MessageCatalog {
+    fn to_object(&self, py: Python<'_>) -> PyObject {
+        self.0.to_object(py)
+    }
+}
+
 fn field_from_context<'py, T: FromPyObject<'py>>(
     context: Option<&Bound<'py, PyDict>>,
     field_name: &str,
@@ -169,9 +247,21 @@ error_types! {
     JsonType {},
     NeedsPythonObject { method_name: {ctx_type: String, ctx_fn: field_from_context} },
     // ---------------------
+    // MessagePack errors
+    MsgpackInvalid {
+        error: {ctx_type: String, ctx_fn: field_from_context},
+    },
+    MsgpackType {},
+    // ---------------------
     // recursion error
     RecursionLoop {},
     // ---------------------
+    // resource limits set in config
+    ResourceLimitExceeded {
+        limit: {ctx_type: String, ctx_fn: field_from_context},
+        limit_value: {ctx_type: usize, ctx_fn: field_from_context},
+    },
+    // ---------------------
     // typed dict specific errors
     Missing {},
     FrozenField {},
@@ -344,12 +434,17 @@ error_types! {
     DatetimeFromDateParsing {
         error: {ctx_type: Cow<'static, str>, ctx_fn: cow_field_from_context<String, _>},
     },
+    DatetimeFormat {
+        formats: {ctx_type: String, ctx_fn: field_from_context},
+    },
     DatetimePast {},
     DatetimeFuture {},
     // ---------------------
     // timezone errors
     TimezoneNaive {},
     TimezoneAware {},
+    TimezoneType {},
+    TimezoneParsing {},
     TimezoneOffset {
         tz_expected: {ctx_type: i32, ctx_fn: field_from_context},
         tz_actual: {ctx_type: i32, ctx_fn: field_from_context},
@@ -415,6 +510,26 @@ error_types! {
     UuidVersion {
         expected_version: {ctx_type: usize, ctx_fn: field_from_context},
     },
+    // IP address errors
+    IpType {},
+    IpAddressParsing {
+        expected: {ctx_type: String, ctx_fn: field_from_context},
+    },
+    IpNetworkParsing {
+        expected: {ctx_type: String, ctx_fn: field_from_context},
+    },
+    IpInterfaceParsing {
+        expected: {ctx_type: String, ctx_fn: field_from_context},
+    },
+    // Path errors
+    PathType {},
+    PathNotAbsolute {},
+    PathSuffix {
+        expected_suffixes: {ctx_type: String, ctx_fn: field_from_context},
+    },
+    PathNotExists {},
+    PathNotFile {},
+    PathNotDirectory {},
     // Decimal errors
     DecimalType {},
     DecimalParsing {},
@@ -479,7 +594,10 @@ impl ErrorType {
             Self::JsonInvalid {..} => "Invalid JSON: {error}",
             Self::JsonType {..} => "JSON input should be string, bytes or bytearray",
             Self::NeedsPythonObject {..} => "Cannot check `{method_name}` when validating from json, use a JsonOrPython validator instead",
+            Self::MsgpackInvalid {..} => "Invalid MessagePack: {error}",
+            Self::MsgpackType {..} => "MessagePack input should be bytes or bytearray",
             Self::RecursionLoop {..} => "Recursion error - cyclic reference detected",
+            Self::ResourceLimitExceeded {..} => "Input exceeds the {limit} limit of {limit_value}",
             Self::Missing {..} => "Field required",
             Self::FrozenField {..} => "Field is frozen",
             Self::FrozenInstance {..} => "Instance is frozen",
@@ -541,10 +659,13 @@ impl ErrorType {
             Self::DatetimeParsing {..} => "Input should be a valid datetime, {error}",
             Self::DatetimeObjectInvalid {..} => "Invalid datetime object, got {error}",
             Self::DatetimeFromDateParsing {..} => "Input should be a valid datetime or date, {error}",
+            Self::DatetimeFormat {..} => "Input should be in ISO 8601 format or match the format {formats}",
             Self::DatetimePast {..} => "Input should be in the past",
             Self::DatetimeFuture {..} => "Input should be in the future",
             Self::TimezoneNaive {..} => "Input should not have timezone info",
             Self::TimezoneAware {..} => "Input should have timezone info",
+            Self::TimezoneType {..} => "Input should be a time zone name, UTC offset or tzinfo object",
+            Self::TimezoneParsing {..} => "Input should be a valid IANA time zone name or UTC offset",
             Self::TimezoneOffset {..} => "Timezone offset of {tz_expected} required, got {tz_actual}",
             Self::TimeDeltaType {..} => "Input should be a valid timedelta",
             Self::TimeDeltaParsing {..} => "Input should be a valid timedelta, {error}",
@@ -569,6 +690,16 @@ impl ErrorType {
             Self::UuidType {..} => "UUID input should be a string, bytes or UUID object",
             Self::UuidParsing {..} => "Input should be a valid UUID, {error}",
             Self::UuidVersion {..} => "UUID version {expected_version} expected",
+            Self::IpType {..} => "Input should be a string, integer, bytes or ipaddress object",
+            Self::IpAddressParsing {..} => "Input should be a valid {expected} address",
+            Self::IpNetworkParsing {..} => "Input should be a valid {expected} network",
+            Self::IpInterfaceParsing {..} => "Input should be a valid {expected} interface",
+            Self::PathType {..} => "Path input should be a string, bytes or os.PathLike object",
+            Self::PathNotAbsolute {..} => "Path should be absolute",
+            Self::PathSuffix {..} => "Path suffix should be {expected_suffixes}",
+            Self::PathNotExists {..} => "Path does not exist",
+            Self::PathNotFile {..} => "Path does not point to a file",
+            Self::PathNotDirectory {..} => "Path does not point to a directory",
             Self::DecimalType {..} => "Decimal input should be an integer, float, string or Decimal object",
             Self::DecimalParsing {..} => "Input should be a valid decimal",
             Self::DecimalMaxDigits {..} => "Decimal input should have no more than {max_digits} digit{expected_plural} in total",
@@ -621,13 +752,35 @@ impl ErrorType {
     }
 
     pub fn render_message(&self, py: Python, input_type: InputType) -> PyResult<String> {
+        self.render_message_from(py, input_type, None)
+    }
+
+    /// Render the message for this error, using the template from `catalog` if it has one for this error type,
+    /// otherwise falling back to the default English template.
+    pub fn render_message_from(
+        &self,
+        py: Python,
+        input_type: InputType,
+        catalog: Option<&MessageCatalog>,
+    ) -> PyResult<String> {
+        // custom errors bring their own message template, catalogs only have templates for the built-in errors
+        let catalog = catalog.filter(|_| !matches!(self, Self::CustomError { .. }));
+        if let Some(tmpl) = catalog.and_then(|c| c.get(&self.type_string())) {
+            return self.render_template(py, tmpl);
+        }
         let tmpl = match input_type {
             InputType::Python => self.message_template_python(),
             _ => self.message_template_json(),
         };
+        self.render_template(py, tmpl)
+    }
+
+    fn render_template(&self, py: Python, tmpl: &str) -> PyResult<String> {
         match self {
             Self::NoSuchAttribute { attribute, .. } => render!(tmpl, attribute),
             Self::JsonInvalid { error, .. } => render!(tmpl, error),
+            Self::MsgpackInvalid { error, .. } => render!(tmpl, error),
+            Self::ResourceLimitExceeded { limit, limit_value, .. } => to_string_render!(tmpl, limit, limit_value),
             Self::NeedsPythonObject { method_name, .. } => render!(tmpl, method_name),
             Self::GetAttributeError { error, .. } => render!(tmpl, error),
             Self::ModelType { class_name, .. } => render!(tmpl, class_name),
@@ -705,6 +858,7 @@ impl ErrorType {
             Self::TimeParsing { error, .. } => render!(tmpl, error),
             Self::DatetimeParsing { error, .. } => render!(tmpl, error),
             Self::DatetimeFromDateParsing { error, .. } => render!(tmpl, error),
+            Self::DatetimeFormat { formats, .. } => render!(tmpl, formats),
             Self::DatetimeObjectInvalid { error, .. } => render!(tmpl, error),
             Self::TimezoneOffset {
                 tz_expected, tz_actual, ..
@@ -728,6 +882,10 @@ impl ErrorType {
             Self::UrlScheme { expected_schemes, .. } => render!(tmpl, expected_schemes),
             Self::UuidParsing { error, .. } => render!(tmpl, error),
             Self::UuidVersion { expected_version, .. } => to_string_render!(tmpl, expected_version),
+            Self::IpAddressParsing { expected, .. } => render!(tmpl, expected),
+            Self::IpNetworkParsing { expected, .. } => render!(tmpl, expected),
+            Self::IpInterfaceParsing { expected, .. } => render!(tmpl, expected),
+            Self::PathSuffix { expected_suffixes, .. } => render!(tmpl, expected_suffixes),
             Self::DecimalMaxDigits { max_digits, .. } => {
                 let expected_plural = plural_s(*max_digits);
                 to_string_render!(tmpl, max_digits, expected_plural)
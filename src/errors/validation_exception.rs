@@ -2,6 +2,7 @@ use std::fmt;
 use std::fmt::{Display, Write};
 use std::str::from_utf8;
 
+use ahash::AHashMap;
 use pyo3::exceptions::{PyKeyError, PyTypeError, PyValueError};
 use pyo3::ffi;
 use pyo3::intern;
@@ -13,8 +14,8 @@ use serde::{Serialize, Serializer};
 
 use serde_json::ser::PrettyFormatter;
 
-use crate::build_tools::py_schema_error_type;
-use crate::errors::LocItem;
+use crate::build_tools::{py_schema_err, py_schema_error_type};
+use crate::errors::{LocFormat, LocItem};
 use crate::get_pydantic_version;
 use crate::input::InputType;
 use crate::serializers::{DuckTypingSerMode, Extra, SerMode, SerializationState};
@@ -22,7 +23,7 @@ use crate::tools::{safe_repr, write_truncated_to_limited_bytes, SchemaDict};
 
 use super::line_error::ValLineError;
 use super::location::Location;
-use super::types::ErrorType;
+use super::types::{ErrorType, MessageCatalog};
 use super::value_exception::PydanticCustomError;
 use super::{InputValue, ValError};
 
@@ -34,18 +35,27 @@ pub struct ValidationError {
     title: PyObject,
     input_type: InputType,
     hide_input: bool,
+    output: ErrorOutputConfig,
 }
 
 impl ValidationError {
-    pub fn new(line_errors: Vec<PyLineError>, title: PyObject, input_type: InputType, hide_input: bool) -> Self {
+    pub fn new(
+        line_errors: Vec<PyLineError>,
+        title: PyObject,
+        input_type: InputType,
+        hide_input: bool,
+        output: ErrorOutputConfig,
+    ) -> Self {
         Self {
             line_errors,
             title,
             input_type,
             hide_input,
+            output,
         }
     }
 
+    #[allow(clippy::too_many_arguments)]
     pub fn from_val_error(
         py: Python,
         title: PyObject,
@@ -54,6 +64,7 @@ impl ValidationError {
         outer_location: Option<LocItem>,
         hide_input: bool,
         validation_error_cause: bool,
+        output: &ErrorOutputConfig,
     ) -> PyErr {
         match error {
             ValError::LineErrors(raw_errors) => {
@@ -64,7 +75,7 @@ impl ValidationError {
                         .collect(),
                     None => raw_errors.into_iter().map(|e| e.into_py(py)).collect(),
                 };
-                let validation_error = Self::new(line_errors, title, input_type, hide_input);
+                let validation_error = Self::new(line_errors, title, input_type, hide_input, output.clone());
                 match Py::new(py, validation_error) {
                     Ok(err) => {
                         if validation_error_cause {
@@ -78,6 +89,16 @@ impl ValidationError {
                     Err(err) => err,
                 }
             }
+            ValError::LimitExceeded(line_error) => Self::from_val_error(
+                py,
+                title,
+                input_type,
+                ValError::LineErrors(vec![*line_error]),
+                outer_location,
+                hide_input,
+                validation_error_cause,
+                output,
+            ),
             ValError::InternalErr(err) => err,
             ValError::Omit => Self::omit_error(),
             ValError::UseDefault => Self::use_default_error(),
@@ -86,14 +107,79 @@ impl ValidationError {
 
     pub fn display(&self, py: Python, prefix_override: Option<&'static str>, hide_input: bool) -> String {
         let url_prefix = get_url_prefix(py, include_url_env(py));
-        let line_errors = pretty_py_line_errors(py, self.input_type, self.line_errors.iter(), url_prefix, hide_input);
+        let line_errors = pretty_py_line_errors(
+            py,
+            self.input_type,
+            &self.line_errors,
+            url_prefix,
+            hide_input,
+            &self.output,
+        );
         if let Some(prefix) = prefix_override {
             format!("{prefix}\n{line_errors}")
         } else {
-            let count = self.line_errors.len();
-            let plural = if count == 1 { "" } else { "s" };
-            let title: &str = self.title.extract(py).unwrap();
-            format!("{count} validation error{plural} for {title}\n{line_errors}")
+            format!("{}\n{line_errors}", self.summary(py))
+        }
+    }
+
+    /// e.g. "2 validation errors for Model", used as the heading of `str(error)`
+    fn summary(&self, py: Python) -> String {
+        let count = self.line_errors.len();
+        let plural = if count == 1 { "" } else { "s" };
+        let title: &str = self.title.extract(py).unwrap();
+        format!("{count} validation error{plural} for {title}")
+    }
+
+    pub fn errors_list(
+        &self,
+        py: Python,
+        include_url: bool,
+        include_context: bool,
+        include_input: bool,
+        format: ErrorFormat,
+        output: &ErrorOutputConfig,
+    ) -> PyResult<Py<PyList>> {
+        let url_prefix = get_url_prefix(py, include_url);
+        let groups = output
+            .group_errors
+            .then(|| group_line_errors(py, self.input_type, &self.line_errors, output));
+        let mut iteration_error = None;
+        let mut to_dict = |line_error: &PyLineError, group: Option<&ErrorGroup>| -> PyObject {
+            if iteration_error.is_some() {
+                return py.None();
+            }
+            line_error
+                .as_dict(
+                    py,
+                    url_prefix,
+                    include_context,
+                    self.input_type,
+                    include_input,
+                    format,
+                    output,
+                )
+                .and_then(|dict| {
+                    if let Some(group) = group {
+                        group.add_to_dict(dict.downcast_bound(py)?, format, output.loc_format)?;
+                    }
+                    Ok(dict)
+                })
+                .unwrap_or_else(|err| {
+                    iteration_error = Some(err);
+                    py.None()
+                })
+        };
+        // PyList::new takes ExactSizeIterator, so if an error occurs during iteration we
+        // fill the list with None before returning the error; the list will then be thrown
+        // away safely.
+        let list = match groups {
+            Some(groups) => PyList::new_bound(py, groups.iter().map(|g| to_dict(g.line_error, Some(g)))),
+            None => PyList::new_bound(py, self.line_errors.iter().map(|e| to_dict(e, None))),
+        };
+        if let Some(err) = iteration_error {
+            Err(err)
+        } else {
+            Ok(list.into())
         }
     }
 
@@ -239,6 +325,93 @@ fn get_url_prefix(py: Python, include_url: bool) -> Option<&str> {
     }
 }
 
+/// The shape of the error details from `ValidationError.errors()` and `ValidationError.json()`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ErrorFormat {
+    /// a list of pydantic's own error details
+    Pydantic,
+    /// an RFC 7807 problem details object, with the error details under "errors"
+    Rfc7807,
+    /// a JSON:API document with a list of error objects under "errors"
+    JsonApi,
+}
+
+impl<'py> FromPyObject<'py> for ErrorFormat {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<Self> {
+        match ob.extract::<&str>() {
+            Ok("pydantic") => Ok(Self::Pydantic),
+            Ok("rfc7807") => Ok(Self::Rfc7807),
+            Ok("jsonapi") => Ok(Self::JsonApi),
+            _ => Err(PyValueError::new_err(
+                "Invalid format, should be `'pydantic'`, `'rfc7807'` or `'jsonapi'`",
+            )),
+        }
+    }
+}
+
+/// "title" of RFC 7807 problem details, this shouldn't change between occurrences of the problem
+const PROBLEM_TITLE: &str = "Validation Error";
+
+/// How a `ValidationError` is rendered, set from config, these are used by `str()` and are the defaults for
+/// `errors()` and `json()`, `max_errors` also limits how many errors are reported
+#[derive(Debug, Clone, Default)]
+pub struct ErrorOutputConfig {
+    error_messages: Option<MessageCatalog>,
+    loc_format: LocFormat,
+    group_errors: bool,
+    max_errors: Option<usize>,
+}
+
+impl ErrorOutputConfig {
+    pub fn from_config(config: Option<&Bound<'_, PyDict>>) -> PyResult<Self> {
+        let Some(config) = config else {
+            return Ok(Self::default());
+        };
+        let py = config.py();
+        let max_errors = match config.get_as::<usize>(intern!(py, "max_errors"))? {
+            Some(0) => return py_schema_err!("`max_errors` must be greater than 0"),
+            max_errors => max_errors,
+        };
+        Ok(Self {
+            error_messages: config.get_as(intern!(py, "error_messages"))?,
+            loc_format: config.get_as(intern!(py, "loc_format"))?.unwrap_or_default(),
+            group_errors: config.get_as(intern!(py, "group_errors"))?.unwrap_or(false),
+            max_errors,
+        })
+    }
+
+    /// The maximum number of errors reported, collections stop validating items once they've found this many
+    pub fn max_errors(&self) -> Option<usize> {
+        self.max_errors
+    }
+
+    /// Drop errors beyond the `max_errors` limit, nested collections can each stop at the limit so together
+    /// they may find more
+    pub fn truncate_errors(&self, error: ValError) -> ValError {
+        match (error, self.max_errors) {
+            (ValError::LineErrors(mut line_errors), Some(max_errors)) => {
+                line_errors.truncate(max_errors);
+                ValError::LineErrors(line_errors)
+            }
+            (error, _) => error,
+        }
+    }
+
+    fn with_overrides(
+        &self,
+        error_messages: Option<MessageCatalog>,
+        loc_format: Option<LocFormat>,
+        group_errors: Option<bool>,
+    ) -> Self {
+        Self {
+            error_messages: error_messages.or_else(|| self.error_messages.clone()),
+            loc_format: loc_format.unwrap_or(self.loc_format),
+            group_errors: group_errors.unwrap_or(self.group_errors),
+            max_errors: self.max_errors,
+        }
+    }
+}
+
 // used to convert a validation error back to ValError for wrap functions
 impl ValidationError {
     pub(crate) fn into_val_error(self) -> ValError {
@@ -246,16 +419,118 @@ impl ValidationError {
     }
 }
 
+/// At most this many locations are included as samples of a group of errors.
+const MAX_GROUP_SAMPLES: usize = 5;
+
+/// Errors with the same type and message, at locations which only differ in their indices,
+/// e.g. the same field failing on every item of a list.
+struct ErrorGroup<'a> {
+    /// the first error in the group, used for the type, message, input and context
+    line_error: &'a PyLineError,
+    locations: Vec<&'a Location>,
+    count: usize,
+}
+
+impl ErrorGroup<'_> {
+    fn sample_locs<'py>(&self, py: Python<'py>, loc_format: LocFormat) -> Bound<'py, PyList> {
+        PyList::new_bound(py, self.locations.iter().map(|loc| loc.to_object_as(py, loc_format)))
+    }
+
+    /// Add the size of the group and some sample locations to an error from `as_dict`.
+    fn add_to_dict(&self, dict: &Bound<'_, PyDict>, format: ErrorFormat, loc_format: LocFormat) -> PyResult<()> {
+        let py = dict.py();
+        match format {
+            ErrorFormat::Pydantic => {
+                dict.set_item("count", self.count)?;
+                dict.set_item("sample_locs", self.sample_locs(py, loc_format))?;
+            }
+            ErrorFormat::Rfc7807 => {
+                dict.set_item("count", self.count)?;
+                dict.set_item("sample_pointers", self.sample_locs(py, LocFormat::JsonPointer))?;
+            }
+            ErrorFormat::JsonApi => {
+                let meta = match dict.get_item("meta")? {
+                    Some(meta) => meta.downcast_into::<PyDict>()?,
+                    None => {
+                        let meta = PyDict::new_bound(py);
+                        dict.set_item("meta", &meta)?;
+                        meta
+                    }
+                };
+                meta.set_item("count", self.count)?;
+                meta.set_item("sample_pointers", self.sample_locs(py, LocFormat::JsonPointer))?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// Errors are grouped by type, message and location with indices replaced by `None`
+type GroupKey<'a> = (String, Option<String>, Vec<Option<&'a str>>);
+
+/// Group errors by type, message and location ignoring indices, groups are returned in the order their
+/// first error appears.
+fn group_line_errors<'a>(
+    py: Python,
+    input_type: InputType,
+    line_errors: &'a [PyLineError],
+    output: &ErrorOutputConfig,
+) -> Vec<ErrorGroup<'a>> {
+    let mut groups: Vec<ErrorGroup<'a>> = Vec::new();
+    let mut index: AHashMap<GroupKey<'_>, usize> = AHashMap::new();
+    for line_error in line_errors {
+        let message = line_error
+            .error_type
+            .render_message_from(py, input_type, output.error_messages.as_ref())
+            .ok();
+        let key = (
+            line_error.error_type.type_string(),
+            message,
+            line_error.location.without_indices(),
+        );
+        match index.get(&key) {
+            Some(&i) => {
+                let group = &mut groups[i];
+                group.count += 1;
+                if group.locations.len() < MAX_GROUP_SAMPLES {
+                    group.locations.push(&line_error.location);
+                }
+            }
+            None => {
+                index.insert(key, groups.len());
+                groups.push(ErrorGroup {
+                    line_error,
+                    locations: vec![&line_error.location],
+                    count: 1,
+                });
+            }
+        }
+    }
+    groups
+}
+
 #[pymethods]
 impl ValidationError {
     #[staticmethod]
-    #[pyo3(signature = (title, line_errors, input_type="python", hide_input=false))]
+    #[pyo3(signature = (
+        title,
+        line_errors,
+        input_type="python",
+        hide_input=false,
+        error_messages=None,
+        loc_format=LocFormat::Tuple,
+        group_errors=false
+    ))]
+    #[allow(clippy::too_many_arguments)]
     fn from_exception_data(
         py: Python,
         title: PyObject,
         line_errors: Bound<'_, PyList>,
         input_type: &str,
         hide_input: bool,
+        error_messages: Option<MessageCatalog>,
+        loc_format: LocFormat,
+        group_errors: bool,
     ) -> PyResult<Py<Self>> {
         Py::new(
             py,
@@ -267,6 +542,12 @@ impl ValidationError {
                 title,
                 input_type: InputType::try_from(input_type)?,
                 hide_input,
+                output: ErrorOutputConfig {
+                    error_messages,
+                    loc_format,
+                    group_errors,
+                    max_errors: None,
+                },
             },
         )
     }
@@ -280,40 +561,59 @@ impl ValidationError {
         self.line_errors.len()
     }
 
-    #[pyo3(signature = (*, include_url = true, include_context = true, include_input = true))]
+    #[pyo3(signature = (
+        *,
+        include_url = true,
+        include_context = true,
+        include_input = true,
+        error_messages = None,
+        format = ErrorFormat::Pydantic,
+        loc_format = None,
+        group_errors = None
+    ))]
+    #[allow(clippy::too_many_arguments)]
     pub fn errors(
         &self,
         py: Python,
         include_url: bool,
         include_context: bool,
         include_input: bool,
-    ) -> PyResult<Py<PyList>> {
-        let url_prefix = get_url_prefix(py, include_url);
-        let mut iteration_error = None;
-        let list = PyList::new_bound(
-            py,
-            // PyList::new takes ExactSizeIterator, so if an error occurs during iteration we
-            // fill the list with None before returning the error; the list will then be thrown
-            // away safely.
-            self.line_errors.iter().map(|e| -> PyObject {
-                if iteration_error.is_some() {
-                    return py.None();
-                }
-                e.as_dict(py, url_prefix, include_context, self.input_type, include_input)
-                    .unwrap_or_else(|err| {
-                        iteration_error = Some(err);
-                        py.None()
-                    })
-            }),
-        );
-        if let Some(err) = iteration_error {
-            Err(err)
-        } else {
-            Ok(list.into())
+        error_messages: Option<MessageCatalog>,
+        format: ErrorFormat,
+        loc_format: Option<LocFormat>,
+        group_errors: Option<bool>,
+    ) -> PyResult<PyObject> {
+        let output = self.output.with_overrides(error_messages, loc_format, group_errors);
+        let list = self.errors_list(py, include_url, include_context, include_input, format, &output)?;
+        match format {
+            ErrorFormat::Pydantic => Ok(list.into_py(py)),
+            ErrorFormat::Rfc7807 => {
+                let dict = PyDict::new_bound(py);
+                dict.set_item(intern!(py, "title"), PROBLEM_TITLE)?;
+                dict.set_item(intern!(py, "detail"), self.summary(py))?;
+                dict.set_item(intern!(py, "errors"), list)?;
+                Ok(dict.into_py(py))
+            }
+            ErrorFormat::JsonApi => {
+                let dict = PyDict::new_bound(py);
+                dict.set_item(intern!(py, "errors"), list)?;
+                Ok(dict.into_py(py))
+            }
         }
     }
 
-    #[pyo3(signature = (*, indent = None, include_url = true, include_context = true, include_input = true))]
+    #[pyo3(signature = (
+        *,
+        indent = None,
+        include_url = true,
+        include_context = true,
+        include_input = true,
+        error_messages = None,
+        format = ErrorFormat::Pydantic,
+        loc_format = None,
+        group_errors = None
+    ))]
+    #[allow(clippy::too_many_arguments)]
     pub fn json<'py>(
         &self,
         py: Python<'py>,
@@ -321,8 +621,16 @@ impl ValidationError {
         include_url: bool,
         include_context: bool,
         include_input: bool,
+        error_messages: Option<MessageCatalog>,
+        format: ErrorFormat,
+        loc_format: Option<LocFormat>,
+        group_errors: Option<bool>,
     ) -> PyResult<Bound<'py, PyString>> {
-        let state = SerializationState::new("iso8601", "utf8", "constants")?;
+        let output = self.output.with_overrides(error_messages, loc_format, group_errors);
+        let groups = output
+            .group_errors
+            .then(|| group_line_errors(py, self.input_type, &self.line_errors, &output));
+        let state = SerializationState::new("iso8601", "iso8601", "utf8", "constants")?;
         let extra = state.extra(
             py,
             &SerMode::Json,
@@ -342,6 +650,13 @@ impl ValidationError {
             include_input,
             extra: &extra,
             input_type: &self.input_type,
+            groups: groups.as_deref(),
+            format,
+            output: &output,
+            summary: match format {
+                ErrorFormat::Rfc7807 => Some(self.summary(py)),
+                _ => None,
+            },
         };
 
         let writer: Vec<u8> = Vec::with_capacity(self.line_errors.len() * 200);
@@ -377,25 +692,67 @@ impl ValidationError {
         let borrow = slf.try_borrow()?;
         let args = (
             borrow.title.bind(py),
-            borrow.errors(py, include_url_env(py), true, true)?,
+            borrow.errors_list(
+                py,
+                include_url_env(py),
+                true,
+                true,
+                ErrorFormat::Pydantic,
+                &ErrorOutputConfig {
+                    loc_format: LocFormat::Tuple,
+                    group_errors: false,
+                    ..borrow.output.clone()
+                },
+            )?,
             borrow.input_type.into_py(py),
             borrow.hide_input,
+            borrow.output.error_messages.to_object(py),
+            borrow.output.loc_format.to_object(py),
+            borrow.output.group_errors,
         )
             .into_py(slf.py());
         Ok((callable, args))
     }
 }
 
-pub fn pretty_py_line_errors<'a>(
+pub fn pretty_py_line_errors(
     py: Python,
     input_type: InputType,
-    line_errors_iter: impl Iterator<Item = &'a PyLineError>,
+    line_errors: &[PyLineError],
     url_prefix: Option<&str>,
     hide_input: bool,
+    output: &ErrorOutputConfig,
 ) -> String {
-    line_errors_iter
-        .map(|i| i.pretty(py, input_type, url_prefix, hide_input))
-        .collect::<Result<Vec<_>, _>>()
+    let pretty = if output.group_errors {
+        group_line_errors(py, input_type, line_errors, output)
+            .iter()
+            .map(|group| {
+                let mut pretty = group
+                    .line_error
+                    .pretty(py, input_type, url_prefix, hide_input, output)?;
+                if group.count > 1 {
+                    let samples: Vec<String> = group
+                        .locations
+                        .iter()
+                        .map(|loc| loc.to_string_as(output.loc_format))
+                        .collect();
+                    write!(
+                        pretty,
+                        "\n    Same error at {} locations, e.g. {}",
+                        group.count,
+                        samples.join(", ")
+                    )?;
+                }
+                Ok(pretty)
+            })
+            .collect::<Result<Vec<_>, fmt::Error>>()
+    } else {
+        line_errors
+            .iter()
+            .map(|i| i.pretty(py, input_type, url_prefix, hide_input, output))
+            .collect::<Result<Vec<_>, _>>()
+    };
+    pretty
         .unwrap_or_else(|err| vec![format!("[error formatting line errors: {err}]")])
         .join("\n")
 }
@@ -473,6 +830,15 @@ impl PyLineError {
         format!("{url_prefix}{}", self.error_type.type_string())
     }
 
+    /// The documentation URL for this error, custom errors don't have one
+    fn error_url(&self, url_prefix: &str) -> Option<String> {
+        match self.error_type {
+            ErrorType::CustomError { .. } => None,
+            _ => Some(self.get_error_url(url_prefix)),
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
     pub fn as_dict(
         &self,
         py: Python,
@@ -480,27 +846,51 @@ impl PyLineError {
         include_context: bool,
         input_type: InputType,
         include_input: bool,
+        format: ErrorFormat,
+        output: &ErrorOutputConfig,
     ) -> PyResult<PyObject> {
         let dict = PyDict::new_bound(py);
-        dict.set_item("type", self.error_type.type_string())?;
-        dict.set_item("loc", self.location.to_object(py))?;
-        dict.set_item("msg", self.error_type.render_message(py, input_type)?)?;
+        let msg = self
+            .error_type
+            .render_message_from(py, input_type, output.error_messages.as_ref())?;
+        match format {
+            ErrorFormat::Pydantic => {
+                dict.set_item("type", self.error_type.type_string())?;
+                dict.set_item("loc", self.location.to_object_as(py, output.loc_format))?;
+                dict.set_item("msg", msg)?;
+            }
+            ErrorFormat::Rfc7807 | ErrorFormat::JsonApi => {
+                let key = if format == ErrorFormat::JsonApi { "code" } else { "type" };
+                dict.set_item(key, self.error_type.type_string())?;
+                dict.set_item("detail", msg)?;
+                let source = PyDict::new_bound(py);
+                source.set_item("pointer", self.location.json_pointer())?;
+                dict.set_item("source", source)?;
+            }
+        }
+        // JSON:API only allows non-standard members inside "meta"
+        let meta = match format {
+            ErrorFormat::JsonApi => PyDict::new_bound(py),
+            _ => dict.clone(),
+        };
         if include_input {
-            dict.set_item("input", &self.input_value)?;
+            meta.set_item("input", &self.input_value)?;
         }
         if include_context {
             if let Some(context) = self.error_type.py_dict(py)? {
-                dict.set_item("ctx", context)?;
+                meta.set_item("ctx", context)?;
             }
         }
-        if let Some(url_prefix) = url_prefix {
-            match self.error_type {
-                ErrorType::CustomError { .. } => {
-                    // Don't add URLs for custom errors
-                }
-                _ => {
-                    dict.set_item("url", self.get_error_url(url_prefix))?;
-                }
+        if format == ErrorFormat::JsonApi && !meta.is_empty() {
+            dict.set_item("meta", meta)?;
+        }
+        if let Some(url) = url_prefix.and_then(|url_prefix| self.error_url(url_prefix)) {
+            if format == ErrorFormat::JsonApi {
+                let links = PyDict::new_bound(py);
+                links.set_item("about", url)?;
+                dict.set_item("links", links)?;
+            } else {
+                dict.set_item("url", url)?;
             }
         }
         Ok(dict.into_py(py))
@@ -512,11 +902,20 @@ impl PyLineError {
         input_type: InputType,
         url_prefix: Option<&str>,
         hide_input: bool,
+        config: &ErrorOutputConfig,
     ) -> Result<String, fmt::Error> {
         let mut output = String::with_capacity(200);
-        write!(output, "{}", self.location)?;
+        match (config.loc_format, &self.location) {
+            (LocFormat::Tuple, location) => write!(output, "{location}")?,
+            (_, Location::Empty) => (),
+            (LocFormat::JsonPointer, location) => writeln!(output, "{}", location.json_pointer())?,
+            (LocFormat::Dotted, location) => writeln!(output, "{}", location.dotted())?,
+        }
 
-        let message = match self.error_type.render_message(py, input_type) {
+        let message = match self
+            .error_type
+            .render_message_from(py, input_type, config.error_messages.as_ref())
+        {
             Ok(message) => message,
             Err(err) => format!("(error rendering message: {err})"),
         };
@@ -572,27 +971,71 @@ struct ValidationErrorSerializer<'py> {
     include_input: bool,
     extra: &'py Extra<'py>,
     input_type: &'py InputType,
+    format: ErrorFormat,
+    output: &'py ErrorOutputConfig,
+    // set when errors are grouped, used instead of `line_errors`
+    groups: Option<&'py [ErrorGroup<'py>]>,
+    // used as the problem details "detail"
+    summary: Option<String>,
 }
 
-impl<'py> Serialize for ValidationErrorSerializer<'py> {
+impl Serialize for ValidationErrorSerializer<'_> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        let mut seq = serializer.serialize_seq(Some(self.line_errors.len()))?;
-        for line_error in self.line_errors {
-            let line_s = PyLineErrorSerializer {
-                py: self.py,
-                line_error,
-                url_prefix: self.url_prefix,
-                include_context: self.include_context,
-                include_input: self.include_input,
-                extra: self.extra,
-                input_type: self.input_type,
-            };
-            seq.serialize_element(&line_s)?;
+        let line_errors = LineErrorsSerializer(self);
+        match self.format {
+            ErrorFormat::Pydantic => line_errors.serialize(serializer),
+            ErrorFormat::Rfc7807 => {
+                let mut map = serializer.serialize_map(Some(3))?;
+                map.serialize_entry("title", PROBLEM_TITLE)?;
+                map.serialize_entry("detail", &self.summary)?;
+                map.serialize_entry("errors", &line_errors)?;
+                map.end()
+            }
+            ErrorFormat::JsonApi => {
+                let mut map = serializer.serialize_map(Some(1))?;
+                map.serialize_entry("errors", &line_errors)?;
+                map.end()
+            }
+        }
+    }
+}
+
+struct LineErrorsSerializer<'a, 'py>(&'a ValidationErrorSerializer<'py>);
+
+impl Serialize for LineErrorsSerializer<'_, '_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let errors = self.0;
+        let line_s = |line_error, group| PyLineErrorSerializer {
+            py: errors.py,
+            line_error,
+            url_prefix: errors.url_prefix,
+            include_context: errors.include_context,
+            include_input: errors.include_input,
+            extra: errors.extra,
+            input_type: errors.input_type,
+            format: errors.format,
+            output: errors.output,
+            group,
+        };
+        if let Some(groups) = errors.groups {
+            let mut seq = serializer.serialize_seq(Some(groups.len()))?;
+            for group in groups {
+                seq.serialize_element(&line_s(group.line_error, Some(group)))?;
+            }
+            seq.end()
+        } else {
+            let mut seq = serializer.serialize_seq(Some(errors.line_errors.len()))?;
+            for line_error in errors.line_errors {
+                seq.serialize_element(&line_s(line_error, None))?;
+            }
+            seq.end()
         }
-        seq.end()
     }
 }
 
@@ -604,46 +1047,164 @@ struct PyLineErrorSerializer<'py> {
     include_input: bool,
     extra: &'py Extra<'py>,
     input_type: &'py InputType,
+    format: ErrorFormat,
+    output: &'py ErrorOutputConfig,
+    group: Option<&'py ErrorGroup<'py>>,
+}
+
+impl PyLineErrorSerializer<'_> {
+    fn serialize_input_and_ctx<M: SerializeMap>(&self, map: &mut M) -> Result<(), M::Error> {
+        let py = self.py;
+        if self.include_input {
+            map.serialize_entry(
+                "input",
+                &self.extra.serialize_infer(self.line_error.input_value.bind(py)),
+            )?;
+        }
+
+        if self.include_context {
+            let context = self
+                .line_error
+                .error_type
+                .py_dict(py)
+                .map_err(|err| M::Error::custom(err.to_string()))?;
+            if let Some(context) = context {
+                map.serialize_entry("ctx", &self.extra.serialize_infer(context.bind(py)))?;
+            }
+        }
+        Ok(())
+    }
+
+    fn serialize_group<M: SerializeMap>(&self, map: &mut M) -> Result<(), M::Error> {
+        if let Some(group) = self.group {
+            map.serialize_entry("count", &group.count)?;
+            if self.format == ErrorFormat::Pydantic {
+                let loc_format = self.output.loc_format;
+                map.serialize_entry("sample_locs", &SampleLocsSerializer(&group.locations, loc_format))?;
+            } else {
+                let pointers = SampleLocsSerializer(&group.locations, LocFormat::JsonPointer);
+                map.serialize_entry("sample_pointers", &pointers)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn has_meta(&self) -> PyResult<bool> {
+        Ok(self.include_input
+            || self.group.is_some()
+            || (self.include_context && self.line_error.error_type.py_dict(self.py)?.is_some()))
+    }
+
+    /// Serialize an RFC 7807 or JSON:API error object, the same shape as `PyLineError::as_dict`
+    fn serialize_source_error<S: Serializer>(&self, serializer: S, msg: &str) -> Result<S::Ok, S::Error> {
+        let json_api = self.format == ErrorFormat::JsonApi;
+        let mut map = serializer.serialize_map(None)?;
+
+        let key = if json_api { "code" } else { "type" };
+        map.serialize_entry(key, &self.line_error.error_type.type_string())?;
+        map.serialize_entry("detail", msg)?;
+        map.serialize_entry(
+            "source",
+            &SingleEntryMap("pointer", &self.line_error.location.json_pointer()),
+        )?;
+
+        if !json_api {
+            self.serialize_input_and_ctx(&mut map)?;
+            self.serialize_group(&mut map)?;
+        } else if self.has_meta().map_err(py_err_json::<S>)? {
+            map.serialize_entry("meta", &MetaSerializer(self))?;
+        }
+
+        if let Some(url) = self.url_prefix.and_then(|p| self.line_error.error_url(p)) {
+            if json_api {
+                map.serialize_entry("links", &SingleEntryMap("about", &url))?;
+            } else {
+                map.serialize_entry("url", &url)?;
+            }
+        }
+        map.end()
+    }
 }
 
-impl<'py> Serialize for PyLineErrorSerializer<'py> {
+impl Serialize for PyLineErrorSerializer<'_> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
         let py = self.py;
-        let size = 3 + [self.url_prefix.is_some(), self.include_context, self.include_input]
-            .into_iter()
-            .filter(|b| *b)
-            .count();
-        let mut map = serializer.serialize_map(Some(size))?;
-
-        map.serialize_entry("type", &self.line_error.error_type.type_string())?;
-
-        map.serialize_entry("loc", &self.line_error.location)?;
-
         let msg = self
             .line_error
             .error_type
-            .render_message(py, *self.input_type)
+            .render_message_from(py, *self.input_type, self.output.error_messages.as_ref())
             .map_err(py_err_json::<S>)?;
-        map.serialize_entry("msg", &msg)?;
 
-        if self.include_input {
-            map.serialize_entry(
-                "input",
-                &self.extra.serialize_infer(self.line_error.input_value.bind(py)),
-            )?;
+        if self.format != ErrorFormat::Pydantic {
+            return self.serialize_source_error(serializer, &msg);
         }
 
-        if self.include_context {
-            if let Some(context) = self.line_error.error_type.py_dict(py).map_err(py_err_json::<S>)? {
-                map.serialize_entry("ctx", &self.extra.serialize_infer(context.bind(py)))?;
-            }
+        let group_size = if self.group.is_some() { 2 } else { 0 };
+        let size = 3
+            + group_size
+            + [self.url_prefix.is_some(), self.include_context, self.include_input]
+                .into_iter()
+                .filter(|b| *b)
+                .count();
+        let mut map = serializer.serialize_map(Some(size))?;
+
+        map.serialize_entry("type", &self.line_error.error_type.type_string())?;
+
+        match self.output.loc_format {
+            LocFormat::Tuple => map.serialize_entry("loc", &self.line_error.location)?,
+            LocFormat::JsonPointer => map.serialize_entry("loc", &self.line_error.location.json_pointer())?,
+            LocFormat::Dotted => map.serialize_entry("loc", &self.line_error.location.dotted())?,
         }
+
+        map.serialize_entry("msg", &msg)?;
+
+        self.serialize_input_and_ctx(&mut map)?;
+
         if let Some(url_prefix) = self.url_prefix {
             map.serialize_entry("url", &self.line_error.get_error_url(url_prefix))?;
         }
+
+        self.serialize_group(&mut map)?;
+        map.end()
+    }
+}
+
+struct SingleEntryMap<'a>(&'static str, &'a str);
+
+impl Serialize for SingleEntryMap<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(Some(1))?;
+        map.serialize_entry(self.0, self.1)?;
         map.end()
     }
 }
+
+struct MetaSerializer<'a, 'py>(&'a PyLineErrorSerializer<'py>);
+
+impl Serialize for MetaSerializer<'_, '_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut map = serializer.serialize_map(None)?;
+        self.0.serialize_input_and_ctx(&mut map)?;
+        self.0.serialize_group(&mut map)?;
+        map.end()
+    }
+}
+
+struct SampleLocsSerializer<'a>(&'a [&'a Location], LocFormat);
+
+impl Serialize for SampleLocsSerializer<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.0.len()))?;
+        for location in self.0 {
+            match self.1 {
+                LocFormat::Tuple => seq.serialize_element(location)?,
+                LocFormat::JsonPointer => seq.serialize_element(&location.json_pointer())?,
+                LocFormat::Dotted => seq.serialize_element(&location.dotted())?,
+            }
+        }
+        seq.end()
+    }
+}
@@ -0,0 +1,372 @@
+use std::fmt::Write;
+
+use speedate::{Date, DateTime, Time};
+
+const MONTH_NAMES: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+
+const WEEKDAY_NAMES: [&str; 7] = [
+    "Monday",
+    "Tuesday",
+    "Wednesday",
+    "Thursday",
+    "Friday",
+    "Saturday",
+    "Sunday",
+];
+
+/// A `strptime`/`strftime` style format such as `"%d/%m/%Y %H:%M"`, parsed once when the schema is built.
+///
+/// Supported directives are `%Y %y %m %d %H %I %p %M %S %f %z %b %B %a %A` and `%%`, names are English only.
+#[derive(Debug, Clone)]
+pub struct DateTimeFormat {
+    items: Vec<FormatItem>,
+}
+
+#[derive(Debug, Clone)]
+enum FormatItem {
+    Literal(char),
+    /// a run of whitespace in the format matches one or more whitespace characters, as with `strptime`
+    Whitespace(String),
+    Directive(Directive),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Directive {
+    Year,
+    ShortYear,
+    Month,
+    MonthAbbr,
+    MonthName,
+    Day,
+    WeekdayAbbr,
+    WeekdayName,
+    Hour,
+    Hour12,
+    AmPm,
+    Minute,
+    Second,
+    Microsecond,
+    UtcOffset,
+}
+
+impl Directive {
+    fn from_char(c: char) -> Option<Self> {
+        let directive = match c {
+            'Y' => Self::Year,
+            'y' => Self::ShortYear,
+            'm' => Self::Month,
+            'b' => Self::MonthAbbr,
+            'B' => Self::MonthName,
+            'd' => Self::Day,
+            'a' => Self::WeekdayAbbr,
+            'A' => Self::WeekdayName,
+            'H' => Self::Hour,
+            'I' => Self::Hour12,
+            'p' => Self::AmPm,
+            'M' => Self::Minute,
+            'S' => Self::Second,
+            'f' => Self::Microsecond,
+            'z' => Self::UtcOffset,
+            _ => return None,
+        };
+        Some(directive)
+    }
+}
+
+impl DateTimeFormat {
+    pub fn new(format: &str) -> Result<Self, String> {
+        let mut items: Vec<FormatItem> = Vec::new();
+        let mut chars = format.chars();
+        while let Some(c) = chars.next() {
+            let item = match c {
+                '%' => match chars.next() {
+                    Some('%') => FormatItem::Literal('%'),
+                    Some(d) => match Directive::from_char(d) {
+                        Some(directive) => FormatItem::Directive(directive),
+                        None => return Err(format!("unsupported directive '%{d}'")),
+                    },
+                    None => return Err("stray '%' at the end of the format".to_string()),
+                },
+                c if c.is_whitespace() => {
+                    if let Some(FormatItem::Whitespace(whitespace)) = items.last_mut() {
+                        whitespace.push(c);
+                        continue;
+                    }
+                    FormatItem::Whitespace(c.to_string())
+                }
+                c => FormatItem::Literal(c),
+            };
+            items.push(item);
+        }
+        Ok(Self { items })
+    }
+
+    /// Parse a string which must match the whole format, as with python's `datetime.strptime` missing fields
+    /// default to `1900-01-01T00:00:00`. `None` means the string doesn't match or isn't a valid datetime.
+    pub fn parse(&self, s: &str) -> Option<DateTime> {
+        let mut parser = Parser { rest: s.as_bytes() };
+        let mut year: u16 = 1900;
+        let mut month: u8 = 1;
+        let mut day: u8 = 1;
+        let mut hour: u8 = 0;
+        let mut hour12: Option<u8> = None;
+        let mut pm = false;
+        let mut minute: u8 = 0;
+        let mut second: u8 = 0;
+        let mut microsecond: u32 = 0;
+        let mut tz_offset: Option<i32> = None;
+
+        for item in &self.items {
+            match item {
+                FormatItem::Literal(c) => parser.literal(*c)?,
+                FormatItem::Whitespace(_) => parser.whitespace()?,
+                FormatItem::Directive(directive) => match directive {
+                    Directive::Year => year = parser.number(4, 4, 1, 9999)? as u16,
+                    Directive::ShortYear => {
+                        // same pivot as `strptime`, 69-99 are 1969-1999, 0-68 are 2000-2068
+                        let short_year = parser.number(2, 2, 0, 99)? as u16;
+                        year = short_year + if short_year >= 69 { 1900 } else { 2000 };
+                    }
+                    Directive::Month => month = parser.number(1, 2, 1, 12)? as u8,
+                    Directive::MonthAbbr => month = parser.name(&MONTH_NAMES, true)? + 1,
+                    Directive::MonthName => month = parser.name(&MONTH_NAMES, false)? + 1,
+                    Directive::Day => day = parser.number(1, 2, 1, 31)? as u8,
+                    // the weekday is checked for a valid name, but like `strptime` it doesn't affect the date
+                    Directive::WeekdayAbbr => _ = parser.name(&WEEKDAY_NAMES, true)?,
+                    Directive::WeekdayName => _ = parser.name(&WEEKDAY_NAMES, false)?,
+                    Directive::Hour => hour = parser.number(1, 2, 0, 23)? as u8,
+                    Directive::Hour12 => hour12 = Some(parser.number(1, 2, 1, 12)? as u8),
+                    Directive::AmPm => pm = parser.name(&["AM", "PM"], false)? == 1,
+                    Directive::Minute => minute = parser.number(1, 2, 0, 59)? as u8,
+                    Directive::Second => second = parser.number(1, 2, 0, 59)? as u8,
+                    Directive::Microsecond => microsecond = parser.fraction()?,
+                    Directive::UtcOffset => tz_offset = Some(parser.utc_offset()?),
+                },
+            }
+        }
+        if !parser.rest.is_empty() || day > days_in_month(year, month) {
+            return None;
+        }
+        // `%p` only has an effect when the hour was parsed with `%I`
+        if let Some(hour12) = hour12 {
+            hour = hour12 % 12 + if pm { 12 } else { 0 };
+        }
+        Some(DateTime {
+            date: Date { year, month, day },
+            time: Time {
+                hour,
+                minute,
+                second,
+                microsecond,
+                tz_offset,
+            },
+        })
+    }
+
+    pub fn format_datetime(&self, datetime: &DateTime) -> String {
+        self.format(&datetime.date, &datetime.time)
+    }
+
+    /// Format a date, time directives are zero as with python's `date.strftime`
+    pub fn format_date(&self, date: &Date) -> String {
+        let midnight = Time {
+            hour: 0,
+            minute: 0,
+            second: 0,
+            microsecond: 0,
+            tz_offset: None,
+        };
+        self.format(date, &midnight)
+    }
+
+    /// Format a time, date directives use `1900-01-01` as with python's `time.strftime`
+    pub fn format_time(&self, time: &Time) -> String {
+        let date = Date {
+            year: 1900,
+            month: 1,
+            day: 1,
+        };
+        self.format(&date, time)
+    }
+
+    fn format(&self, date: &Date, time: &Time) -> String {
+        let mut s = String::new();
+        for item in &self.items {
+            // writing to a `String` can't fail
+            let _ = match item {
+                FormatItem::Literal(c) => write!(s, "{c}"),
+                FormatItem::Whitespace(whitespace) => write!(s, "{whitespace}"),
+                FormatItem::Directive(directive) => match directive {
+                    Directive::Year => write!(s, "{:04}", date.year),
+                    Directive::ShortYear => write!(s, "{:02}", date.year % 100),
+                    Directive::Month => write!(s, "{:02}", date.month),
+                    Directive::MonthAbbr => write!(s, "{}", &MONTH_NAMES[date.month as usize - 1][..3]),
+                    Directive::MonthName => write!(s, "{}", MONTH_NAMES[date.month as usize - 1]),
+                    Directive::Day => write!(s, "{:02}", date.day),
+                    Directive::WeekdayAbbr => write!(s, "{}", &WEEKDAY_NAMES[weekday(date)][..3]),
+                    Directive::WeekdayName => write!(s, "{}", WEEKDAY_NAMES[weekday(date)]),
+                    Directive::Hour => write!(s, "{:02}", time.hour),
+                    Directive::Hour12 => write!(s, "{:02}", (time.hour + 11) % 12 + 1),
+                    Directive::AmPm => write!(s, "{}", if time.hour < 12 { "AM" } else { "PM" }),
+                    Directive::Minute => write!(s, "{:02}", time.minute),
+                    Directive::Second => write!(s, "{:02}", time.second),
+                    Directive::Microsecond => write!(s, "{:06}", time.microsecond),
+                    // naive values have an empty offset, as with `strftime`
+                    Directive::UtcOffset => match time.tz_offset {
+                        Some(offset) => write_utc_offset(&mut s, offset),
+                        None => Ok(()),
+                    },
+                },
+            };
+        }
+        s
+    }
+}
+
+struct Parser<'a> {
+    rest: &'a [u8],
+}
+
+impl Parser<'_> {
+    fn literal(&mut self, c: char) -> Option<()> {
+        let mut buf = [0; 4];
+        let expected = c.encode_utf8(&mut buf).as_bytes();
+        match self.rest.get(..expected.len()) {
+            Some(actual) if actual.eq_ignore_ascii_case(expected) => {
+                self.rest = &self.rest[expected.len()..];
+                Some(())
+            }
+            _ => None,
+        }
+    }
+
+    fn whitespace(&mut self) -> Option<()> {
+        let count = self.rest.iter().take_while(|b| b.is_ascii_whitespace()).count();
+        if count == 0 {
+            return None;
+        }
+        self.rest = &self.rest[count..];
+        Some(())
+    }
+
+    /// Consume between `min_digits` and `max_digits` digits, greedily
+    fn digits(&mut self, min_digits: usize, max_digits: usize) -> Option<&[u8]> {
+        let count = self
+            .rest
+            .iter()
+            .take(max_digits)
+            .take_while(|b| b.is_ascii_digit())
+            .count();
+        if count < min_digits {
+            return None;
+        }
+        let (digits, rest) = self.rest.split_at(count);
+        self.rest = rest;
+        Some(digits)
+    }
+
+    fn number(&mut self, min_digits: usize, max_digits: usize, min: u32, max: u32) -> Option<u32> {
+        let value = self
+            .digits(min_digits, max_digits)?
+            .iter()
+            .fold(0, |value, digit| value * 10 + u32::from(digit - b'0'));
+        (min..=max).contains(&value).then_some(value)
+    }
+
+    /// Up to 6 digits of a fraction of a second, e.g. `5` is 500,000 microseconds
+    fn fraction(&mut self) -> Option<u32> {
+        let digits = self.digits(1, 6)?;
+        let value = digits
+            .iter()
+            .fold(0, |value, digit| value * 10 + u32::from(digit - b'0'));
+        Some(value * 10_u32.pow(6 - digits.len() as u32))
+    }
+
+    /// Match one of `names` case-insensitively, returning its index
+    fn name(&mut self, names: &[&str], abbreviated: bool) -> Option<u8> {
+        names.iter().enumerate().find_map(|(index, name)| {
+            let name = if abbreviated { &name[..3] } else { name };
+            match self.rest.get(..name.len()) {
+                Some(actual) if actual.eq_ignore_ascii_case(name.as_bytes()) => {
+                    self.rest = &self.rest[name.len()..];
+                    Some(index as u8)
+                }
+                _ => None,
+            }
+        })
+    }
+
+    /// `Z`, `±HHMM[SS]` or `±HH:MM[:SS]`, in seconds
+    fn utc_offset(&mut self) -> Option<i32> {
+        let sign = match self.rest.first()? {
+            b'Z' | b'z' => {
+                self.rest = &self.rest[1..];
+                return Some(0);
+            }
+            b'+' => 1,
+            b'-' => -1,
+            _ => return None,
+        };
+        self.rest = &self.rest[1..];
+        let hours = self.number(2, 2, 0, 23)?;
+        let colon = self.rest.first() == Some(&b':');
+        if colon {
+            self.rest = &self.rest[1..];
+        }
+        let minutes = self.number(2, 2, 0, 59)?;
+        let seconds = match self.rest.first() {
+            Some(b':') if colon => {
+                self.rest = &self.rest[1..];
+                self.number(2, 2, 0, 59)?
+            }
+            Some(b'0'..=b'9') if !colon => self.number(2, 2, 0, 59)?,
+            _ => 0,
+        };
+        Some(sign * (hours * 3600 + minutes * 60 + seconds) as i32)
+    }
+}
+
+fn write_utc_offset(s: &mut String, offset: i32) -> std::fmt::Result {
+    let sign = if offset < 0 { '-' } else { '+' };
+    let offset = offset.unsigned_abs();
+    write!(s, "{sign}{:02}{:02}", offset / 3600, offset % 3600 / 60)?;
+    if offset % 60 != 0 {
+        write!(s, "{:02}", offset % 60)?;
+    }
+    Ok(())
+}
+
+fn is_leap_year(year: u16) -> bool {
+    year % 4 == 0 && (year % 100 != 0 || year % 400 == 0)
+}
+
+fn days_in_month(year: u16, month: u8) -> u8 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Day of the week with Monday as 0, using Sakamoto's method
+fn weekday(date: &Date) -> usize {
+    const MONTH_OFFSETS: [u32; 12] = [0, 3, 2, 5, 0, 3, 5, 1, 4, 6, 2, 4];
+    let year = u32::from(date.year) - u32::from(date.month < 3);
+    let from_sunday =
+        (year + year / 4 - year / 100 + year / 400 + MONTH_OFFSETS[date.month as usize - 1] + u32::from(date.day)) % 7;
+    ((from_sunday + 6) % 7) as usize
+}
@@ -17,6 +17,7 @@ use super::{EitherFloat, GenericIterator, ValidationMatch};
 pub enum InputType {
     Python,
     Json,
+    Msgpack,
     String,
 }
 
@@ -24,6 +25,7 @@ impl IntoPy<PyObject> for InputType {
     fn into_py(self, py: Python<'_>) -> PyObject {
         match self {
             Self::Json => intern!(py, "json").into_py(py),
+            Self::Msgpack => intern!(py, "msgpack").into_py(py),
             Self::Python => intern!(py, "python").into_py(py),
             Self::String => intern!(py, "string").into_py(py),
         }
@@ -37,6 +39,7 @@ impl TryFrom<&str> for InputType {
         match error_mode {
             "python" => Ok(Self::Python),
             "json" => Ok(Self::Json),
+            "msgpack" => Ok(Self::Msgpack),
             "string" => Ok(Self::String),
             s => py_err!(PyValueError; "Invalid error mode: {}", s),
         }
@@ -232,6 +235,10 @@ pub trait ValidatedDict<'py> {
     fn is_py_get_attr(&self) -> bool {
         false
     }
+    /// The last key in the input, whose value may be incomplete when validating partial input
+    fn last_key(&self) -> Option<Self::Key<'_>> {
+        None
+    }
     fn iterate<'a, R>(
         &'a self,
         consumer: impl ConsumeIterator<ValResult<(Self::Key<'a>, Self::Item<'a>)>, Output = R>,
@@ -1,6 +1,6 @@
 use std::borrow::Cow;
 
-use jiter::{JsonArray, JsonObject, JsonValue, LazyIndexMap};
+use jiter::LazyIndexMap;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList, PyString};
 use smallvec::SmallVec;
@@ -23,7 +23,8 @@ use super::return_enums::ValidationMatch;
 use super::shared::{float_as_int, int_as_bool, str_as_bool, str_as_float, str_as_int};
 use super::{
     Arguments, BorrowInput, EitherBytes, EitherFloat, EitherInt, EitherString, EitherTimedelta, GenericIterator, Input,
-    KeywordArgs, PositionalArgs, ValidatedDict, ValidatedList, ValidatedSet, ValidatedTuple,
+    JsonArray, JsonObject, JsonValue, KeywordArgs, PositionalArgs, ValidatedDict, ValidatedList, ValidatedSet,
+    ValidatedTuple,
 };
 
 /// This is required but since JSON object keys are always strings, I don't think it can be called
@@ -104,7 +105,7 @@ impl<'py, 'data> Input<'py> for JsonValue<'data> {
             JsonValue::Str(s) => Ok(ValidationMatch::strict(s.as_ref().into())),
             JsonValue::Int(i) if !strict && coerce_numbers_to_str => Ok(ValidationMatch::lax(i.to_string().into())),
             JsonValue::BigInt(b) if !strict && coerce_numbers_to_str => Ok(ValidationMatch::lax(b.to_string().into())),
-            JsonValue::Float(f) if !strict && coerce_numbers_to_str => Ok(ValidationMatch::lax(f.to_string().into())),
+            JsonValue::Float(f, _) if !strict && coerce_numbers_to_str => Ok(ValidationMatch::lax(f.to_string().into())),
             _ => Err(ValError::new(ErrorTypeDefaults::StringType, self)),
         }
     }
@@ -128,7 +129,7 @@ impl<'py, 'data> Input<'py> for JsonValue<'data> {
             JsonValue::Bool(b) => Ok(ValidationMatch::exact(*b)),
             JsonValue::Str(s) if !strict => str_as_bool(self, s).map(ValidationMatch::lax),
             JsonValue::Int(int) if !strict => int_as_bool(self, *int).map(ValidationMatch::lax),
-            JsonValue::Float(float) if !strict => match float_as_int(self, *float) {
+            JsonValue::Float(float, _) if !strict => match float_as_int(self, *float) {
                 Ok(int) => int
                     .as_bool()
                     .ok_or_else(|| ValError::new(ErrorTypeDefaults::BoolParsing, self))
@@ -144,7 +145,7 @@ impl<'py, 'data> Input<'py> for JsonValue<'data> {
             JsonValue::Int(i) => Ok(ValidationMatch::exact(EitherInt::I64(*i))),
             JsonValue::BigInt(b) => Ok(ValidationMatch::exact(EitherInt::BigInt(b.clone()))),
             JsonValue::Bool(b) if !strict => Ok(ValidationMatch::lax(EitherInt::I64((*b).into()))),
-            JsonValue::Float(f) if !strict => float_as_int(self, *f).map(ValidationMatch::lax),
+            JsonValue::Float(f, _) if !strict => float_as_int(self, *f).map(ValidationMatch::lax),
             JsonValue::Str(str) if !strict => str_as_int(self, str).map(ValidationMatch::lax),
             _ => Err(ValError::new(ErrorTypeDefaults::IntType, self)),
         }
@@ -159,7 +160,7 @@ impl<'py, 'data> Input<'py> for JsonValue<'data> {
 
     fn validate_float(&self, strict: bool) -> ValResult<ValidationMatch<EitherFloat<'_>>> {
         match self {
-            JsonValue::Float(f) => Ok(ValidationMatch::exact(EitherFloat::F64(*f))),
+            JsonValue::Float(f, _) => Ok(ValidationMatch::exact(EitherFloat::F64(*f))),
             JsonValue::Int(i) => Ok(ValidationMatch::strict(EitherFloat::F64(*i as f64))),
             JsonValue::Bool(b) if !strict => Ok(ValidationMatch::lax(EitherFloat::F64(if *b { 1.0 } else { 0.0 }))),
             JsonValue::Str(str) if !strict => str_as_float(self, str).map(ValidationMatch::lax),
@@ -169,8 +170,9 @@ impl<'py, 'data> Input<'py> for JsonValue<'data> {
 
     fn validate_decimal(&self, _strict: bool, py: Python<'py>) -> ValMatch<Bound<'py, PyAny>> {
         match self {
-            JsonValue::Float(f) => {
-                create_decimal(&PyString::new_bound(py, &f.to_string()), self).map(ValidationMatch::strict)
+            // decimals are created from the text of the float, rather than the nearest `f64` to it
+            JsonValue::Float(_, text) => {
+                create_decimal(&PyString::new_bound(py, text), self).map(ValidationMatch::strict)
             }
             JsonValue::Str(..) | JsonValue::Int(..) | JsonValue::BigInt(..) => {
                 create_decimal(self.to_object(py).bind(py), self).map(ValidationMatch::strict)
@@ -258,7 +260,7 @@ impl<'py, 'data> Input<'py> for JsonValue<'data> {
                 bytes_as_time(self, v.as_bytes(), microseconds_overflow_behavior).map(ValidationMatch::strict)
             }
             JsonValue::Int(v) if !strict => int_as_time(self, *v, 0).map(ValidationMatch::lax),
-            JsonValue::Float(v) if !strict => float_as_time(self, *v).map(ValidationMatch::lax),
+            JsonValue::Float(v, _) if !strict => float_as_time(self, *v).map(ValidationMatch::lax),
             JsonValue::BigInt(_) if !strict => Err(ValError::new(
                 ErrorType::TimeParsing {
                     error: Cow::Borrowed(
@@ -284,7 +286,7 @@ impl<'py, 'data> Input<'py> for JsonValue<'data> {
                 bytes_as_datetime(self, v.as_bytes(), microseconds_overflow_behavior).map(ValidationMatch::strict)
             }
             JsonValue::Int(v) if !strict => int_as_datetime(self, *v, 0).map(ValidationMatch::lax),
-            JsonValue::Float(v) if !strict => float_as_datetime(self, *v).map(ValidationMatch::lax),
+            JsonValue::Float(v, _) if !strict => float_as_datetime(self, *v).map(ValidationMatch::lax),
             _ => Err(ValError::new(ErrorTypeDefaults::DatetimeType, self)),
         }
     }
@@ -301,7 +303,7 @@ impl<'py, 'data> Input<'py> for JsonValue<'data> {
             JsonValue::Int(v) if !strict => {
                 int_as_duration(self, *v).map(|duration| ValidationMatch::lax(duration.into()))
             }
-            JsonValue::Float(v) if !strict => {
+            JsonValue::Float(v, _) if !strict => {
                 float_as_duration(self, *v).map(|duration| ValidationMatch::lax(duration.into()))
             }
             _ => Err(ValError::new(ErrorTypeDefaults::TimeDeltaType, self)),
@@ -314,7 +316,7 @@ impl<'py, 'data> Input<'py> for JsonValue<'data> {
                 &PyString::new_bound(py, s),
                 self,
             )?))),
-            JsonValue::Float(f) => {
+            JsonValue::Float(f, _) => {
                 if !strict {
                     Ok(ValidationMatch::lax(EitherComplex::Complex([*f, 0.0])))
                 } else {
@@ -510,6 +512,10 @@ impl<'py, 'data> ValidatedDict<'py> for &'_ JsonObject<'data> {
         key.json_get(self)
     }
 
+    fn last_key(&self) -> Option<Self::Key<'_>> {
+        self.keys().last().map(AsRef::as_ref)
+    }
+
     fn iterate<'a, R>(
         &'a self,
         consumer: impl ConsumeIterator<ValResult<(Self::Key<'a>, Self::Item<'a>)>, Output = R>,
@@ -0,0 +1,481 @@
+use std::borrow::Cow;
+use std::str::from_utf8;
+
+use pyo3::prelude::*;
+use pyo3::types::{PyByteArray, PyBytes, PyDict, PyList, PyString};
+use speedate::MicrosecondsPrecisionOverflowBehavior;
+use strum::EnumMessage;
+
+use crate::errors::{ErrorType, ErrorTypeDefaults, InputValue, LocItem, ValError, ValResult};
+use crate::input::return_enums::EitherComplex;
+use crate::lookup_key::{LookupKey, LookupPath};
+use crate::validators::complex::string_to_complex;
+use crate::validators::decimal::create_decimal;
+use crate::validators::ValBytesMode;
+
+use super::datetime::{
+    bytes_as_date, bytes_as_datetime, bytes_as_time, bytes_as_timedelta, float_as_datetime, float_as_duration,
+    float_as_time, int_as_datetime, int_as_duration, int_as_time, EitherDate, EitherDateTime, EitherTime,
+};
+use super::input_abstract::ValMatch;
+use super::parse_msgpack::{MsgpackArray, MsgpackError, MsgpackMap, MsgpackValue};
+use super::return_enums::ValidationMatch;
+use super::shared::{float_as_int, int_as_bool, str_as_bool, str_as_float, str_as_int};
+use super::{
+    Arguments, BorrowInput, ConsumeIterator, EitherBytes, EitherFloat, EitherInt, EitherString, EitherTimedelta,
+    GenericIterator, Input, KeywordArgs, PositionalArgs, ValidatedDict, ValidatedList, ValidatedSet, ValidatedTuple,
+};
+
+/// MessagePack map keys can be any scalar, so unlike JSON this is reachable, e.g. for `Dict[int, ...]`,
+/// other scalars are rendered as they would be in locations from `validate_python`, except timestamps which are
+/// rendered in ISO 8601 format since there's no python datetime (and so no `repr`) without the GIL
+impl From<&MsgpackValue> for LocItem {
+    fn from(msgpack_value: &MsgpackValue) -> Self {
+        match msgpack_value {
+            MsgpackValue::Int(i) => (*i).into(),
+            MsgpackValue::Str(s) => s.as_str().into(),
+            MsgpackValue::BigInt(b) => b.to_string().into(),
+            MsgpackValue::Bin(b) => String::from_utf8_lossy(b).into(),
+            MsgpackValue::Nil => "None".into(),
+            MsgpackValue::Bool(true) => "True".into(),
+            MsgpackValue::Bool(false) => "False".into(),
+            MsgpackValue::Float(f) if f.is_nan() => "nan".into(),
+            MsgpackValue::Float(f) => format!("{f:?}").into(),
+            MsgpackValue::Timestamp(dt) => dt.to_string().into(),
+            // keys are checked to be hashable during decoding
+            MsgpackValue::Array(_) | MsgpackValue::Map(_) => unreachable!(),
+        }
+    }
+}
+
+impl<'py> Input<'py> for MsgpackValue {
+    fn as_error_value(&self) -> InputValue {
+        // cloning MsgpackValue is cheap for arrays and maps due to use of Arc
+        InputValue::Msgpack(self.clone())
+    }
+
+    fn is_none(&self) -> bool {
+        matches!(self, MsgpackValue::Nil)
+    }
+
+    fn as_kwargs(&self, py: Python<'py>) -> Option<Bound<'py, PyDict>> {
+        match self {
+            MsgpackValue::Map(map) => {
+                let dict = PyDict::new_bound(py);
+                for (k, v) in map.iter() {
+                    dict.set_item(k.to_object(py), v.to_object(py)).unwrap();
+                }
+                Some(dict)
+            }
+            _ => None,
+        }
+    }
+
+    type Arguments<'a> = MsgpackArgs<'a>;
+
+    fn validate_args(&self) -> ValResult<MsgpackArgs<'_>> {
+        match self {
+            MsgpackValue::Map(map) => Ok(MsgpackArgs::new(None, Some(map))),
+            MsgpackValue::Array(array) => Ok(MsgpackArgs::new(Some(array), None)),
+            _ => Err(ValError::new(ErrorTypeDefaults::ArgumentsType, self)),
+        }
+    }
+
+    fn validate_dataclass_args<'a>(&'a self, class_name: &str) -> ValResult<MsgpackArgs<'a>> {
+        match self {
+            MsgpackValue::Map(map) => Ok(MsgpackArgs::new(None, Some(map))),
+            _ => {
+                let class_name = class_name.to_string();
+                Err(ValError::new(
+                    ErrorType::DataclassType {
+                        class_name,
+                        context: None,
+                    },
+                    self,
+                ))
+            }
+        }
+    }
+
+    fn validate_str(&self, strict: bool, coerce_numbers_to_str: bool) -> ValResult<ValidationMatch<EitherString<'_>>> {
+        // as with JSON, strings are used to represent other datatypes such as UUID and date, so
+        // string is a converting input
+        match self {
+            MsgpackValue::Str(s) => Ok(ValidationMatch::strict(s.as_str().into())),
+            MsgpackValue::Bin(b) if !strict => match from_utf8(b) {
+                Ok(s) => Ok(ValidationMatch::lax(s.into())),
+                Err(_) => Err(ValError::new(ErrorTypeDefaults::StringUnicode, self)),
+            },
+            MsgpackValue::Int(i) if !strict && coerce_numbers_to_str => Ok(ValidationMatch::lax(i.to_string().into())),
+            MsgpackValue::BigInt(b) if !strict && coerce_numbers_to_str => {
+                Ok(ValidationMatch::lax(b.to_string().into()))
+            }
+            MsgpackValue::Float(f) if !strict && coerce_numbers_to_str => {
+                Ok(ValidationMatch::lax(f.to_string().into()))
+            }
+            _ => Err(ValError::new(ErrorTypeDefaults::StringType, self)),
+        }
+    }
+
+    fn exact_str(&self) -> ValResult<EitherString<'_>> {
+        match self {
+            MsgpackValue::Str(s) => Ok(s.as_str().into()),
+            _ => Err(ValError::new(ErrorTypeDefaults::StringType, self)),
+        }
+    }
+
+    fn validate_bytes<'a>(
+        &'a self,
+        strict: bool,
+        mode: ValBytesMode,
+    ) -> ValResult<ValidationMatch<EitherBytes<'a, 'py>>> {
+        match self {
+            MsgpackValue::Bin(b) => Ok(ValidationMatch::exact(EitherBytes::Cow(Cow::Borrowed(b)))),
+            MsgpackValue::Str(s) if !strict => match mode.deserialize_string(s) {
+                Ok(b) => Ok(ValidationMatch::lax(b)),
+                Err(e) => Err(ValError::new(e, self)),
+            },
+            _ => Err(ValError::new(ErrorTypeDefaults::BytesType, self)),
+        }
+    }
+
+    fn validate_bool(&self, strict: bool) -> ValResult<ValidationMatch<bool>> {
+        match self {
+            MsgpackValue::Bool(b) => Ok(ValidationMatch::exact(*b)),
+            MsgpackValue::Str(s) if !strict => str_as_bool(self, s).map(ValidationMatch::lax),
+            MsgpackValue::Int(int) if !strict => int_as_bool(self, *int).map(ValidationMatch::lax),
+            MsgpackValue::Float(float) if !strict => match float_as_int(self, *float) {
+                Ok(int) => int
+                    .as_bool()
+                    .ok_or_else(|| ValError::new(ErrorTypeDefaults::BoolParsing, self))
+                    .map(ValidationMatch::lax),
+                _ => Err(ValError::new(ErrorTypeDefaults::BoolType, self)),
+            },
+            _ => Err(ValError::new(ErrorTypeDefaults::BoolType, self)),
+        }
+    }
+
+    fn validate_int(&self, strict: bool) -> ValResult<ValidationMatch<EitherInt<'_>>> {
+        match self {
+            MsgpackValue::Int(i) => Ok(ValidationMatch::exact(EitherInt::I64(*i))),
+            MsgpackValue::BigInt(b) => Ok(ValidationMatch::exact(EitherInt::BigInt(b.clone()))),
+            MsgpackValue::Bool(b) if !strict => Ok(ValidationMatch::lax(EitherInt::I64((*b).into()))),
+            MsgpackValue::Float(f) if !strict => float_as_int(self, *f).map(ValidationMatch::lax),
+            MsgpackValue::Str(str) if !strict => str_as_int(self, str).map(ValidationMatch::lax),
+            _ => Err(ValError::new(ErrorTypeDefaults::IntType, self)),
+        }
+    }
+
+    fn validate_float(&self, strict: bool) -> ValResult<ValidationMatch<EitherFloat<'_>>> {
+        match self {
+            MsgpackValue::Float(f) => Ok(ValidationMatch::exact(EitherFloat::F64(*f))),
+            MsgpackValue::Int(i) => Ok(ValidationMatch::strict(EitherFloat::F64(*i as f64))),
+            MsgpackValue::Bool(b) if !strict => Ok(ValidationMatch::lax(EitherFloat::F64(if *b { 1.0 } else { 0.0 }))),
+            MsgpackValue::Str(str) if !strict => str_as_float(self, str).map(ValidationMatch::lax),
+            _ => Err(ValError::new(ErrorTypeDefaults::FloatType, self)),
+        }
+    }
+
+    fn validate_decimal(&self, _strict: bool, py: Python<'py>) -> ValMatch<Bound<'py, PyAny>> {
+        match self {
+            MsgpackValue::Float(f) => {
+                create_decimal(&PyString::new_bound(py, &f.to_string()), self).map(ValidationMatch::strict)
+            }
+            MsgpackValue::Str(..) | MsgpackValue::Int(..) | MsgpackValue::BigInt(..) => {
+                create_decimal(self.to_object(py).bind(py), self).map(ValidationMatch::strict)
+            }
+            _ => Err(ValError::new(ErrorTypeDefaults::DecimalType, self)),
+        }
+    }
+
+    type Dict<'a> = &'a MsgpackMap;
+
+    fn validate_dict(&self, _strict: bool) -> ValResult<Self::Dict<'_>> {
+        match self {
+            MsgpackValue::Map(map) => Ok(map),
+            _ => Err(ValError::new(ErrorTypeDefaults::DictType, self)),
+        }
+    }
+    #[cfg_attr(has_coverage_attribute, coverage(off))]
+    fn strict_dict(&self) -> ValResult<Self::Dict<'_>> {
+        self.validate_dict(false)
+    }
+
+    type List<'a> = &'a MsgpackArray;
+
+    fn validate_list(&self, _strict: bool) -> ValMatch<&MsgpackArray> {
+        match self {
+            MsgpackValue::Array(a) => Ok(ValidationMatch::exact(a)),
+            _ => Err(ValError::new(ErrorTypeDefaults::ListType, self)),
+        }
+    }
+
+    type Tuple<'a> = &'a MsgpackArray;
+
+    fn validate_tuple(&self, _strict: bool) -> ValMatch<&MsgpackArray> {
+        // MessagePack has no tuple type, so arrays have to be allowed
+        match self {
+            MsgpackValue::Array(a) => Ok(ValidationMatch::strict(a)),
+            _ => Err(ValError::new(ErrorTypeDefaults::TupleType, self)),
+        }
+    }
+
+    type Set<'a> = &'a MsgpackArray;
+
+    fn validate_set(&self, _strict: bool) -> ValMatch<&MsgpackArray> {
+        // MessagePack has no set type, so arrays have to be allowed
+        match self {
+            MsgpackValue::Array(a) => Ok(ValidationMatch::strict(a)),
+            _ => Err(ValError::new(ErrorTypeDefaults::SetType, self)),
+        }
+    }
+
+    fn validate_frozenset(&self, _strict: bool) -> ValMatch<&MsgpackArray> {
+        match self {
+            MsgpackValue::Array(a) => Ok(ValidationMatch::strict(a)),
+            _ => Err(ValError::new(ErrorTypeDefaults::FrozenSetType, self)),
+        }
+    }
+
+    fn validate_iter(&self) -> ValResult<GenericIterator<'static>> {
+        match self {
+            MsgpackValue::Array(a) => Ok(GenericIterator::from(a.clone())),
+            MsgpackValue::Str(s) => Ok(GenericIterator::from(string_to_array(s))),
+            MsgpackValue::Map(map) => {
+                // return keys iterator to match python's behavior
+                let keys: MsgpackArray = map.iter().map(|(k, _)| k.clone()).collect();
+                Ok(GenericIterator::from(keys))
+            }
+            _ => Err(ValError::new(ErrorTypeDefaults::IterableType, self)),
+        }
+    }
+
+    fn validate_date(&self, _strict: bool) -> ValResult<ValidationMatch<EitherDate<'py>>> {
+        match self {
+            MsgpackValue::Str(v) => bytes_as_date(self, v.as_bytes()).map(ValidationMatch::strict),
+            _ => Err(ValError::new(ErrorTypeDefaults::DateType, self)),
+        }
+    }
+
+    fn validate_time(
+        &self,
+        strict: bool,
+        microseconds_overflow_behavior: MicrosecondsPrecisionOverflowBehavior,
+    ) -> ValResult<ValidationMatch<EitherTime<'py>>> {
+        match self {
+            MsgpackValue::Str(v) => {
+                bytes_as_time(self, v.as_bytes(), microseconds_overflow_behavior).map(ValidationMatch::strict)
+            }
+            MsgpackValue::Int(v) if !strict => int_as_time(self, *v, 0).map(ValidationMatch::lax),
+            MsgpackValue::Float(v) if !strict => float_as_time(self, *v).map(ValidationMatch::lax),
+            MsgpackValue::BigInt(_) if !strict => Err(ValError::new(
+                ErrorType::TimeParsing {
+                    error: Cow::Borrowed(
+                        speedate::ParseError::TimeTooLarge
+                            .get_documentation()
+                            .unwrap_or_default(),
+                    ),
+                    context: None,
+                },
+                self,
+            )),
+            _ => Err(ValError::new(ErrorTypeDefaults::TimeType, self)),
+        }
+    }
+
+    fn validate_datetime(
+        &self,
+        strict: bool,
+        microseconds_overflow_behavior: MicrosecondsPrecisionOverflowBehavior,
+    ) -> ValResult<ValidationMatch<EitherDateTime<'py>>> {
+        match self {
+            MsgpackValue::Timestamp(dt) => Ok(ValidationMatch::exact(dt.clone().into())),
+            MsgpackValue::Str(v) => {
+                bytes_as_datetime(self, v.as_bytes(), microseconds_overflow_behavior).map(ValidationMatch::strict)
+            }
+            MsgpackValue::Int(v) if !strict => int_as_datetime(self, *v, 0).map(ValidationMatch::lax),
+            MsgpackValue::Float(v) if !strict => float_as_datetime(self, *v).map(ValidationMatch::lax),
+            _ => Err(ValError::new(ErrorTypeDefaults::DatetimeType, self)),
+        }
+    }
+
+    fn validate_timedelta(
+        &self,
+        strict: bool,
+        microseconds_overflow_behavior: MicrosecondsPrecisionOverflowBehavior,
+    ) -> ValResult<ValidationMatch<EitherTimedelta<'py>>> {
+        match self {
+            MsgpackValue::Str(v) => {
+                bytes_as_timedelta(self, v.as_bytes(), microseconds_overflow_behavior).map(ValidationMatch::strict)
+            }
+            MsgpackValue::Int(v) if !strict => {
+                int_as_duration(self, *v).map(|duration| ValidationMatch::lax(duration.into()))
+            }
+            MsgpackValue::Float(v) if !strict => {
+                float_as_duration(self, *v).map(|duration| ValidationMatch::lax(duration.into()))
+            }
+            _ => Err(ValError::new(ErrorTypeDefaults::TimeDeltaType, self)),
+        }
+    }
+
+    fn validate_complex(&self, strict: bool, py: Python<'py>) -> ValResult<ValidationMatch<EitherComplex<'py>>> {
+        match self {
+            MsgpackValue::Str(s) => Ok(ValidationMatch::strict(EitherComplex::Py(string_to_complex(
+                &PyString::new_bound(py, s),
+                self,
+            )?))),
+            MsgpackValue::Float(f) if !strict => Ok(ValidationMatch::lax(EitherComplex::Complex([*f, 0.0]))),
+            MsgpackValue::Int(i) if !strict => Ok(ValidationMatch::lax(EitherComplex::Complex([(*i) as f64, 0.0]))),
+            MsgpackValue::Float(_) | MsgpackValue::Int(_) => {
+                Err(ValError::new(ErrorTypeDefaults::ComplexStrParsing, self))
+            }
+            _ => Err(ValError::new(ErrorTypeDefaults::ComplexType, self)),
+        }
+    }
+}
+
+impl BorrowInput<'_> for MsgpackValue {
+    type Input = MsgpackValue;
+    fn borrow_input(&self) -> &Self::Input {
+        self
+    }
+}
+
+fn string_to_array(s: &str) -> MsgpackArray {
+    s.chars().map(|c| MsgpackValue::Str(c.to_string())).collect()
+}
+
+impl ValidatedDict<'_> for &'_ MsgpackMap {
+    type Key<'a>
+        = &'a MsgpackValue
+    where
+        Self: 'a;
+
+    type Item<'a>
+        = &'a MsgpackValue
+    where
+        Self: 'a;
+
+    fn get_item<'k>(&self, key: &'k LookupKey) -> ValResult<Option<(&'k LookupPath, Self::Item<'_>)>> {
+        key.msgpack_get(self)
+    }
+
+    fn last_key(&self) -> Option<Self::Key<'_>> {
+        self.iter().last().map(|(k, _)| k)
+    }
+
+    fn iterate<'a, R>(
+        &'a self,
+        consumer: impl ConsumeIterator<ValResult<(Self::Key<'a>, Self::Item<'a>)>, Output = R>,
+    ) -> ValResult<R> {
+        Ok(consumer.consume_iterator(self.iter().map(|(k, v)| Ok((k, v)))))
+    }
+}
+
+impl<'a, 'py> ValidatedList<'py> for &'a MsgpackArray {
+    type Item = &'a MsgpackValue;
+
+    fn len(&self) -> Option<usize> {
+        Some(<[MsgpackValue]>::len(self))
+    }
+    fn iterate<R>(self, consumer: impl ConsumeIterator<PyResult<Self::Item>, Output = R>) -> ValResult<R> {
+        Ok(consumer.consume_iterator(self.iter().map(Ok)))
+    }
+    fn as_py_list(&self) -> Option<&Bound<'py, PyList>> {
+        None
+    }
+}
+
+impl<'a> ValidatedTuple<'_> for &'a MsgpackArray {
+    type Item = &'a MsgpackValue;
+
+    fn len(&self) -> Option<usize> {
+        Some(<[MsgpackValue]>::len(self))
+    }
+    fn iterate<R>(self, consumer: impl ConsumeIterator<PyResult<Self::Item>, Output = R>) -> ValResult<R> {
+        Ok(consumer.consume_iterator(self.iter().map(Ok)))
+    }
+}
+
+impl<'a> ValidatedSet<'_> for &'a MsgpackArray {
+    type Item = &'a MsgpackValue;
+
+    fn iterate<R>(self, consumer: impl ConsumeIterator<PyResult<Self::Item>, Output = R>) -> ValResult<R> {
+        Ok(consumer.consume_iterator(self.iter().map(Ok)))
+    }
+}
+
+#[cfg_attr(debug_assertions, derive(Debug))]
+pub struct MsgpackArgs<'a> {
+    args: Option<&'a [MsgpackValue]>,
+    kwargs: Option<&'a MsgpackMap>,
+}
+
+impl<'a> MsgpackArgs<'a> {
+    fn new(args: Option<&'a [MsgpackValue]>, kwargs: Option<&'a MsgpackMap>) -> Self {
+        Self { args, kwargs }
+    }
+}
+
+impl Arguments<'_> for MsgpackArgs<'_> {
+    type Args = [MsgpackValue];
+    type Kwargs = MsgpackMap;
+
+    fn args(&self) -> Option<&Self::Args> {
+        self.args
+    }
+
+    fn kwargs(&self) -> Option<&Self::Kwargs> {
+        self.kwargs
+    }
+}
+
+impl PositionalArgs<'_> for [MsgpackValue] {
+    type Item<'a> = &'a MsgpackValue;
+
+    fn len(&self) -> usize {
+        <[MsgpackValue]>::len(self)
+    }
+    fn get_item(&self, index: usize) -> Option<Self::Item<'_>> {
+        self.get(index)
+    }
+    fn iter(&self) -> impl Iterator<Item = Self::Item<'_>> {
+        <[MsgpackValue]>::iter(self)
+    }
+}
+
+impl KeywordArgs<'_> for MsgpackMap {
+    type Key<'a> = &'a MsgpackValue;
+    type Item<'a> = &'a MsgpackValue;
+
+    fn len(&self) -> usize {
+        MsgpackMap::len(self)
+    }
+    fn get_item<'k>(&self, key: &'k LookupKey) -> ValResult<Option<(&'k LookupPath, Self::Item<'_>)>> {
+        key.msgpack_get(self)
+    }
+    fn iter(&self) -> impl Iterator<Item = ValResult<(Self::Key<'_>, Self::Item<'_>)>> {
+        MsgpackMap::iter(self).map(|(k, v)| Ok((k, v)))
+    }
+}
+
+/// Get the raw MessagePack data from the input to `SchemaValidator.validate_msgpack`
+pub fn validate_msgpack_bytes<'a>(input: &'a Bound<'_, PyAny>) -> ValResult<Cow<'a, [u8]>> {
+    if let Ok(py_bytes) = input.downcast::<PyBytes>() {
+        Ok(Cow::Borrowed(py_bytes.as_bytes()))
+    } else if let Ok(py_byte_array) = input.downcast::<PyByteArray>() {
+        Ok(Cow::Owned(py_byte_array.to_vec()))
+    } else {
+        Err(ValError::new(ErrorTypeDefaults::MsgpackType, input))
+    }
+}
+
+pub fn map_msgpack_err<'py>(input: &(impl Input<'py> + ?Sized), error: MsgpackError) -> ValError {
+    ValError::new(
+        ErrorType::MsgpackInvalid {
+            error: error.to_string(),
+            context: None,
+        },
+        input,
+    )
+}
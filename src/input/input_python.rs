@@ -813,6 +813,17 @@ impl<'py> ValidatedDict<'py> for GenericPyMapping<'_, 'py> {
         matches!(self, Self::GetAttr(..))
     }
 
+    fn last_key(&self) -> Option<Self::Key<'_>> {
+        match self {
+            Self::Dict(dict) => dict.keys().iter().last(),
+            Self::Mapping(mapping) => {
+                let keys = mapping.keys().ok()?;
+                keys.get_item(keys.len().ok()?.checked_sub(1)?).ok()
+            }
+            Self::GetAttr(..) => None,
+        }
+    }
+
     fn iterate<'a, R>(
         &'a self,
         consumer: impl ConsumeIterator<ValResult<(Self::Key<'a>, Self::Item<'a>)>, Output = R>,
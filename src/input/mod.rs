@@ -3,14 +3,19 @@ use std::os::raw::c_int;
 use pyo3::prelude::*;
 
 mod datetime;
+mod datetime_format;
 mod input_abstract;
 mod input_json;
+mod input_msgpack;
 mod input_python;
 mod input_string;
+mod parse_json;
+mod parse_msgpack;
 mod return_enums;
 mod shared;
 
 pub use datetime::TzInfo;
+pub(crate) use datetime_format::DateTimeFormat;
 pub(crate) use datetime::{
     duration_as_pytimedelta, pydate_as_date, pydatetime_as_datetime, pytime_as_time, EitherDate, EitherDateTime,
     EitherTime, EitherTimedelta,
@@ -19,8 +24,12 @@ pub(crate) use input_abstract::{
     Arguments, BorrowInput, ConsumeIterator, Input, InputType, KeywordArgs, PositionalArgs, ValidatedDict,
     ValidatedList, ValidatedSet, ValidatedTuple,
 };
+pub(crate) use input_msgpack::{map_msgpack_err, validate_msgpack_bytes};
 pub(crate) use input_python::{downcast_python_input, input_as_python_instance};
 pub(crate) use input_string::StringMapping;
+pub(crate) use parse_json::{JsonArray, JsonObject, JsonParser, JsonValue};
+pub(crate) use parse_msgpack::{MsgpackMap, MsgpackValue};
+pub(crate) use shared::get_enum_meta_object;
 pub(crate) use return_enums::{
     no_validator_iter_to_vec, py_string_str, validate_iter_to_set, validate_iter_to_vec, EitherBytes, EitherFloat,
     EitherInt, EitherString, GenericIterator, Int, MaxLengthCheck, ValidationMatch,
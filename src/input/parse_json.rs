@@ -0,0 +1,319 @@
+use std::borrow::Cow;
+use std::sync::Arc;
+
+use jiter::{
+    Jiter, JiterError, JiterErrorType, JiterResult, JsonError, JsonErrorType, JsonResult, LazyIndexMap, PartialMode,
+    Peek,
+};
+use num_bigint::BigInt;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList};
+use smallvec::SmallVec;
+
+use crate::validators::PartialTail;
+
+/// Maximum depth of nested arrays and objects, matches the default recursion limit used by jiter
+const RECURSION_LIMIT: u16 = 200;
+
+pub type JsonArray<'j> = Arc<SmallVec<[JsonValue<'j>; 8]>>;
+pub type JsonObject<'j> = Arc<LazyIndexMap<Cow<'j, str>, JsonValue<'j>>>;
+
+/// A value parsed from JSON data, strings are borrowed from the data where possible.
+///
+/// This mirrors jiter's `JsonValue`, except that floats keep the text they were written as alongside
+/// the `f64` it parses to, so decimals can be created from exactly what was written.
+#[derive(Debug, Clone)]
+pub enum JsonValue<'j> {
+    Null,
+    Bool(bool),
+    Int(i64),
+    BigInt(BigInt),
+    Float(f64, Cow<'j, str>),
+    Str(Cow<'j, str>),
+    Array(JsonArray<'j>),
+    Object(JsonObject<'j>),
+}
+
+impl<'j> JsonValue<'j> {
+    pub fn parse(data: &'j [u8]) -> JsonResult<Self> {
+        JsonParser::new(data).parse().map(|(value, _)| value)
+    }
+
+    pub fn into_static(self) -> JsonValue<'static> {
+        match self {
+            Self::Null => JsonValue::Null,
+            Self::Bool(b) => JsonValue::Bool(b),
+            Self::Int(i) => JsonValue::Int(i),
+            Self::BigInt(b) => JsonValue::BigInt(b),
+            Self::Float(f, text) => JsonValue::Float(f, Cow::Owned(text.into_owned())),
+            Self::Str(s) => JsonValue::Str(Cow::Owned(s.into_owned())),
+            Self::Array(array) => JsonValue::Array(Arc::new(array.iter().map(Self::to_static).collect())),
+            Self::Object(object) => {
+                let mut static_object = LazyIndexMap::new();
+                for (k, v) in object.iter() {
+                    static_object.insert(Cow::Owned(k.to_string()), v.to_static());
+                }
+                JsonValue::Object(Arc::new(static_object))
+            }
+        }
+    }
+
+    pub fn to_static(&self) -> JsonValue<'static> {
+        self.clone().into_static()
+    }
+}
+
+impl ToPyObject for JsonValue<'_> {
+    fn to_object(&self, py: Python<'_>) -> PyObject {
+        match self {
+            Self::Null => py.None(),
+            Self::Bool(b) => b.to_object(py),
+            Self::Int(i) => i.to_object(py),
+            Self::BigInt(b) => b.to_object(py),
+            Self::Float(f, _) => f.to_object(py),
+            Self::Str(s) => s.to_object(py),
+            Self::Array(array) => PyList::new_bound(py, array.iter().map(|v| v.to_object(py))).into(),
+            Self::Object(object) => {
+                let dict = PyDict::new_bound(py);
+                for (k, v) in object.iter() {
+                    // keys are always strings, so this can't fail
+                    dict.set_item(k, v.to_object(py)).unwrap();
+                }
+                dict.into()
+            }
+        }
+    }
+}
+
+/// Parser for `JsonValue`s, built on jiter.
+///
+/// With a partial mode enabled, data which is truncated is accepted as `from_json(..., allow_partial=...)`
+/// accepts it: incomplete values at the end of the data are omitted and unclosed arrays and objects are closed.
+pub struct JsonParser<'j> {
+    jiter: Jiter<'j>,
+    data: &'j [u8],
+    depth: u16,
+    partial_mode: PartialMode,
+    // whether the data ended part way through a value, which partial mode accepted
+    truncated: bool,
+    // how many arrays and objects were left unclosed at the end of the data
+    open: usize,
+    // whether the last scalar parsed runs to the end of the data, so it may be incomplete
+    incomplete_scalar: bool,
+}
+
+impl<'j> JsonParser<'j> {
+    pub fn new(data: &'j [u8]) -> Self {
+        Self {
+            jiter: Jiter::new(data).with_allow_inf_nan(),
+            data,
+            depth: 0,
+            partial_mode: PartialMode::Off,
+            truncated: false,
+            open: 0,
+            incomplete_scalar: false,
+        }
+    }
+
+    pub fn with_partial_mode(self, partial_mode: PartialMode) -> Self {
+        Self { partial_mode, ..self }
+    }
+
+    /// Parse the whole of the data as a single value.
+    ///
+    /// A `PartialTail` is only returned if the data was actually truncated.
+    pub fn parse(mut self) -> JsonResult<(JsonValue<'j>, Option<PartialTail>)> {
+        let value = self
+            .jiter
+            .peek()
+            .and_then(|peek| self.take_value(peek))
+            .map_err(json_error)?;
+        if !self.truncated {
+            self.jiter.finish().map_err(json_error)?;
+            return Ok((value, None));
+        }
+        let partial_tail = PartialTail::Json {
+            open: self.open,
+            incomplete_scalar: self.incomplete_scalar,
+        };
+        Ok((value, Some(partial_tail)))
+    }
+
+    /// The underlying jiter, for stepping through data outside of values
+    pub fn jiter(&mut self) -> &mut Jiter<'j> {
+        &mut self.jiter
+    }
+
+    pub fn take_value(&mut self, peek: Peek) -> JiterResult<JsonValue<'j>> {
+        match peek {
+            Peek::Array => self.take_array(),
+            Peek::Object => self.take_object(),
+            Peek::String if matches!(self.partial_mode, PartialMode::TrailingStrings) => self.take_trailing_string(),
+            _ => self.take_scalar(peek),
+        }
+    }
+
+    fn take_array(&mut self) -> JiterResult<JsonValue<'j>> {
+        self.enter_container()?;
+        let mut array: SmallVec<[JsonValue<'j>; 8]> = SmallVec::new();
+        let mut step = self.jiter.known_array();
+        loop {
+            match step {
+                Ok(Some(peek)) => match self.take_value(peek) {
+                    Ok(value) => array.push(value),
+                    Err(e) => self.truncate(e)?,
+                },
+                Ok(None) => break,
+                Err(e) => self.truncate(e)?,
+            }
+            if self.truncated {
+                self.open += 1;
+                break;
+            }
+            step = self.jiter.array_step();
+        }
+        self.depth -= 1;
+        Ok(JsonValue::Array(Arc::new(array)))
+    }
+
+    fn take_object(&mut self) -> JiterResult<JsonValue<'j>> {
+        self.enter_container()?;
+        let mut object = LazyIndexMap::new();
+        let mut step = self.take_key(true);
+        loop {
+            match step {
+                // a key without a value is dropped along with any incomplete value
+                Ok(Some(key)) => match self.jiter.peek().and_then(|peek| self.take_value(peek)) {
+                    Ok(value) => object.insert(key, value),
+                    Err(e) => self.truncate(e)?,
+                },
+                Ok(None) => break,
+                Err(e) => self.truncate(e)?,
+            }
+            if self.truncated {
+                self.open += 1;
+                break;
+            }
+            step = self.take_key(false);
+        }
+        self.depth -= 1;
+        Ok(JsonValue::Object(Arc::new(object)))
+    }
+
+    fn take_key(&mut self, first: bool) -> JiterResult<Option<Cow<'j, str>>> {
+        let data = self.data;
+        let start = self.jiter.current_index();
+        let key = if first {
+            self.jiter.known_object()?
+        } else {
+            self.jiter.next_key()?
+        };
+        Ok(key.map(|key| borrow_key(data, start, key)))
+    }
+
+    /// A string which, unlike other values, is kept even if the data ends part way through it
+    fn take_trailing_string(&mut self) -> JiterResult<JsonValue<'j>> {
+        // strings are parsed in full first, so an incomplete string is only accepted at the end of the data
+        let string_start = self.jiter.clone();
+        let error = match self.take_scalar(Peek::String) {
+            Err(e) if self.is_truncation(&e) => e,
+            result => return result,
+        };
+        self.jiter = string_start.with_allow_partial_strings();
+        let s = self.jiter.known_str().map_err(|_| error)?.to_owned();
+        self.truncated = true;
+        self.incomplete_scalar = true;
+        Ok(JsonValue::Str(Cow::Owned(s)))
+    }
+
+    fn take_scalar(&mut self, peek: Peek) -> JiterResult<JsonValue<'j>> {
+        let start = self.jiter.current_index();
+        let value = match self.jiter.known_value(peek)? {
+            jiter::JsonValue::Null => JsonValue::Null,
+            jiter::JsonValue::Bool(b) => JsonValue::Bool(b),
+            jiter::JsonValue::Int(i) => JsonValue::Int(i),
+            jiter::JsonValue::BigInt(b) => JsonValue::BigInt(b),
+            jiter::JsonValue::Float(f) => {
+                let text = &self.data[start..self.jiter.current_index()];
+                // number text is always ASCII, so the fallback is never used
+                let text = std::str::from_utf8(text).map_or_else(|_| Cow::Owned(f.to_string()), Cow::Borrowed);
+                JsonValue::Float(f, text)
+            }
+            jiter::JsonValue::Str(s) => JsonValue::Str(s),
+            // arrays and objects are handled by `take_value`
+            jiter::JsonValue::Array(_) | jiter::JsonValue::Object(_) => unreachable!(),
+        };
+        // a number at the very end of the data may be missing digits
+        self.incomplete_scalar = matches!(value, JsonValue::Int(_) | JsonValue::BigInt(_) | JsonValue::Float(..))
+            && self.jiter.current_index() == self.data.len();
+        Ok(value)
+    }
+
+    fn enter_container(&mut self) -> JiterResult<()> {
+        self.depth += 1;
+        if self.depth > RECURSION_LIMIT {
+            Err(JiterError {
+                error_type: JiterErrorType::JsonError(JsonErrorType::RecursionLimitExceeded),
+                index: self.jiter.current_index(),
+            })
+        } else {
+            Ok(())
+        }
+    }
+
+    /// Whether partial mode accepts `error`, i.e. it's caused by the data ending part way through a value
+    fn is_truncation(&self, error: &JiterError) -> bool {
+        !matches!(self.partial_mode, PartialMode::Off)
+            && error.index == self.data.len()
+            && matches!(&error.error_type, JiterErrorType::JsonError(error_type) if is_partial_error(error_type))
+    }
+
+    /// Accept an error caused by the data being truncated if partial mode is enabled, ending every array and
+    /// object still open, otherwise return it
+    fn truncate(&mut self, error: JiterError) -> JiterResult<()> {
+        if self.is_truncation(&error) {
+            self.truncated = true;
+            Ok(())
+        } else {
+            Err(error)
+        }
+    }
+}
+
+/// Errors caused by the data ending part way through a value, these match those allowed by jiter's `PartialMode`
+fn is_partial_error(error_type: &JsonErrorType) -> bool {
+    matches!(
+        error_type,
+        JsonErrorType::EofWhileParsingList
+            | JsonErrorType::EofWhileParsingObject
+            | JsonErrorType::EofWhileParsingString
+            | JsonErrorType::EofWhileParsingValue
+            | JsonErrorType::ExpectedListCommaOrEnd
+            | JsonErrorType::ExpectedObjectCommaOrEnd
+    )
+}
+
+/// Borrow an object key from the data rather than copying it, when it's written there without escapes.
+///
+/// `start` is where parsing the key began, so the key's opening quote is the first after it.
+fn borrow_key<'j>(data: &'j [u8], start: usize, key: &str) -> Cow<'j, str> {
+    let raw = data[start..]
+        .iter()
+        .position(|b| *b == b'"')
+        .and_then(|quote| data.get(start + quote + 1..start + quote + 1 + key.len()));
+    match raw.filter(|raw| *raw == key.as_bytes()).map(std::str::from_utf8) {
+        Some(Ok(raw)) => Cow::Borrowed(raw),
+        _ => Cow::Owned(key.to_owned()),
+    }
+}
+
+fn json_error(error: JiterError) -> JsonError {
+    match error.error_type {
+        JiterErrorType::JsonError(error_type) => JsonError {
+            error_type,
+            index: error.index,
+        },
+        // values are only ever parsed as the type they were peeked as
+        JiterErrorType::WrongType { .. } => unreachable!(),
+    }
+}
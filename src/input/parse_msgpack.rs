@@ -0,0 +1,426 @@
+use std::fmt;
+use std::sync::Arc;
+
+use num_bigint::BigInt;
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyDict, PyList, PyString};
+use speedate::{Date, DateTime, Time};
+
+use super::EitherDateTime;
+
+/// Maximum depth of nested arrays and maps, matches the default recursion limit used by jiter for JSON
+const RECURSION_LIMIT: u16 = 200;
+
+/// MessagePack extension type used for timestamps, see
+/// https://github.com/msgpack/msgpack/blob/master/spec.md#timestamp-extension-type
+const TIMESTAMP_EXT_TYPE: i8 = -1;
+
+pub type MsgpackArray = Arc<[MsgpackValue]>;
+
+/// A value decoded from MessagePack data.
+///
+/// Unlike JSON, MessagePack distinguishes between strings and binary data, and supports
+/// timestamps natively, so both are preserved here rather than being coerced to strings.
+#[derive(Debug, Clone)]
+pub enum MsgpackValue {
+    Nil,
+    Bool(bool),
+    Int(i64),
+    BigInt(BigInt),
+    Float(f64),
+    Str(String),
+    Bin(Vec<u8>),
+    Array(MsgpackArray),
+    Map(MsgpackMap),
+    Timestamp(DateTime),
+}
+
+/// MessagePack map, keys aren't restricted to strings so entries are stored in order as pairs.
+#[derive(Debug, Clone)]
+pub struct MsgpackMap(Arc<[(MsgpackValue, MsgpackValue)]>);
+
+impl MsgpackMap {
+    /// Get the value for a string key, if a key occurs more than once the last value wins as with JSON
+    pub fn get(&self, key: &str) -> Option<&MsgpackValue> {
+        self.0.iter().rev().find_map(|(k, v)| match k {
+            MsgpackValue::Str(s) if s == key => Some(v),
+            _ => None,
+        })
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &(MsgpackValue, MsgpackValue)> {
+        self.0.iter()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+}
+
+impl MsgpackValue {
+    pub fn parse(data: &[u8]) -> Result<Self, MsgpackError> {
+        let mut decoder = Decoder {
+            data,
+            index: 0,
+            depth: 0,
+        };
+        let value = decoder.decode_value()?;
+        if decoder.index < data.len() {
+            return Err(decoder.error(MsgpackErrorType::TrailingData));
+        }
+        Ok(value)
+    }
+
+    fn is_hashable(&self) -> bool {
+        !matches!(self, Self::Array(_) | Self::Map(_))
+    }
+}
+
+impl ToPyObject for MsgpackValue {
+    fn to_object(&self, py: Python<'_>) -> PyObject {
+        match self {
+            Self::Nil => py.None(),
+            Self::Bool(b) => b.to_object(py),
+            Self::Int(i) => i.to_object(py),
+            Self::BigInt(b) => b.to_object(py),
+            Self::Float(f) => f.to_object(py),
+            Self::Str(s) => PyString::new_bound(py, s).into(),
+            Self::Bin(b) => PyBytes::new_bound(py, b).into(),
+            Self::Array(array) => PyList::new_bound(py, array.iter().map(|v| v.to_object(py))).into(),
+            Self::Map(map) => {
+                let dict = PyDict::new_bound(py);
+                for (k, v) in map.iter() {
+                    // keys are checked to be hashable during decoding, so this can't fail
+                    dict.set_item(k.to_object(py), v.to_object(py)).unwrap();
+                }
+                dict.into()
+            }
+            // timestamps are checked to be in range during decoding, if a datetime still can't be created
+            // the ISO 8601 string is the closest value available
+            Self::Timestamp(dt) => EitherDateTime::from(dt.clone())
+                .try_into_py(py)
+                .unwrap_or_else(|_| dt.to_string().into_py(py)),
+        }
+    }
+}
+
+/// Build a UTC datetime from seconds since the unix epoch, `None` if it's outside the years python supports.
+///
+/// This doesn't use `DateTime::from_timestamp`, since speedate treats large values as milliseconds.
+fn timestamp_to_datetime(seconds: i64, microsecond: u32) -> Option<DateTime> {
+    let days = seconds.div_euclid(86_400);
+    let second_of_day = seconds.rem_euclid(86_400);
+    // see http://howardhinnant.github.io/date_algorithms.html#civil_from_days
+    let days = days.checked_add(719_468)?;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days - era * 146_097;
+    let year_of_era = (day_of_era - day_of_era / 1_460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let shifted_month = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * shifted_month + 2) / 5 + 1;
+    let month = if shifted_month < 10 {
+        shifted_month + 3
+    } else {
+        shifted_month - 9
+    };
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    if !(1..=9999).contains(&year) {
+        return None;
+    }
+    // all the casts below are in range, the year is checked above and the other parts are bounded by construction
+    Some(DateTime {
+        date: Date {
+            year: year as u16,
+            month: month as u8,
+            day: day as u8,
+        },
+        time: Time {
+            hour: (second_of_day / 3_600) as u8,
+            minute: (second_of_day % 3_600 / 60) as u8,
+            second: (second_of_day % 60) as u8,
+            microsecond,
+            tz_offset: Some(0),
+        },
+    })
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MsgpackErrorType {
+    EofWhileParsing,
+    InvalidMarker(u8),
+    InvalidUtf8,
+    UnhashableKey,
+    UnsupportedExtension(i8),
+    InvalidTimestamp,
+    RecursionLimitExceeded,
+    TrailingData,
+}
+
+impl fmt::Display for MsgpackErrorType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::EofWhileParsing => f.write_str("unexpected end of data"),
+            Self::InvalidMarker(marker) => write!(f, "invalid marker 0x{marker:02x}"),
+            Self::InvalidUtf8 => f.write_str("invalid UTF-8 in string"),
+            Self::UnhashableKey => f.write_str("map keys must not be arrays or maps"),
+            Self::UnsupportedExtension(ext_type) => write!(f, "unsupported extension type {ext_type}"),
+            Self::InvalidTimestamp => f.write_str("invalid timestamp"),
+            Self::RecursionLimitExceeded => f.write_str("recursion limit exceeded"),
+            Self::TrailingData => f.write_str("trailing data"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MsgpackError {
+    pub error_type: MsgpackErrorType,
+    pub index: usize,
+}
+
+impl fmt::Display for MsgpackError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} at byte {}", self.error_type, self.index)
+    }
+}
+
+type DecodeResult<T> = Result<T, MsgpackError>;
+
+struct Decoder<'a> {
+    data: &'a [u8],
+    index: usize,
+    depth: u16,
+}
+
+impl<'a> Decoder<'a> {
+    fn error(&self, error_type: MsgpackErrorType) -> MsgpackError {
+        MsgpackError {
+            error_type,
+            index: self.index,
+        }
+    }
+
+    fn read_slice(&mut self, len: usize) -> DecodeResult<&'a [u8]> {
+        match self.data.get(self.index..self.index.saturating_add(len)) {
+            Some(slice) => {
+                self.index += len;
+                Ok(slice)
+            }
+            None => Err(MsgpackError {
+                error_type: MsgpackErrorType::EofWhileParsing,
+                index: self.data.len(),
+            }),
+        }
+    }
+
+    fn read_array<const N: usize>(&mut self) -> DecodeResult<[u8; N]> {
+        // length is checked by `read_slice`, so the conversion can't fail
+        Ok(self.read_slice(N)?.try_into().unwrap())
+    }
+
+    fn read_u8(&mut self) -> DecodeResult<u8> {
+        Ok(self.read_array::<1>()?[0])
+    }
+
+    fn read_u16(&mut self) -> DecodeResult<u16> {
+        Ok(u16::from_be_bytes(self.read_array()?))
+    }
+
+    fn read_u32(&mut self) -> DecodeResult<u32> {
+        Ok(u32::from_be_bytes(self.read_array()?))
+    }
+
+    fn read_u64(&mut self) -> DecodeResult<u64> {
+        Ok(u64::from_be_bytes(self.read_array()?))
+    }
+
+    fn decode_value(&mut self) -> DecodeResult<MsgpackValue> {
+        let marker_index = self.index;
+        let marker = self.read_u8()?;
+        let value = match marker {
+            0x00..=0x7f => MsgpackValue::Int(i64::from(marker)),
+            0x80..=0x8f => self.decode_map(usize::from(marker & 0x0f))?,
+            0x90..=0x9f => self.decode_array(usize::from(marker & 0x0f))?,
+            0xa0..=0xbf => self.decode_str(usize::from(marker & 0x1f))?,
+            0xc0 => MsgpackValue::Nil,
+            0xc1 => {
+                return Err(MsgpackError {
+                    error_type: MsgpackErrorType::InvalidMarker(marker),
+                    index: marker_index,
+                })
+            }
+            0xc2 => MsgpackValue::Bool(false),
+            0xc3 => MsgpackValue::Bool(true),
+            0xc4 => {
+                let len = self.read_u8()?;
+                self.decode_bin(usize::from(len))?
+            }
+            0xc5 => {
+                let len = self.read_u16()?;
+                self.decode_bin(usize::from(len))?
+            }
+            0xc6 => {
+                let len = self.read_u32()?;
+                self.decode_bin(len as usize)?
+            }
+            0xc7 => {
+                let len = self.read_u8()?;
+                self.decode_ext(usize::from(len))?
+            }
+            0xc8 => {
+                let len = self.read_u16()?;
+                self.decode_ext(usize::from(len))?
+            }
+            0xc9 => {
+                let len = self.read_u32()?;
+                self.decode_ext(len as usize)?
+            }
+            0xca => MsgpackValue::Float(f64::from(f32::from_bits(self.read_u32()?))),
+            0xcb => MsgpackValue::Float(f64::from_bits(self.read_u64()?)),
+            0xcc => MsgpackValue::Int(i64::from(self.read_u8()?)),
+            0xcd => MsgpackValue::Int(i64::from(self.read_u16()?)),
+            0xce => MsgpackValue::Int(i64::from(self.read_u32()?)),
+            0xcf => {
+                let int = self.read_u64()?;
+                match i64::try_from(int) {
+                    Ok(int) => MsgpackValue::Int(int),
+                    Err(_) => MsgpackValue::BigInt(int.into()),
+                }
+            }
+            0xd0 => MsgpackValue::Int(i64::from(self.read_u8()? as i8)),
+            0xd1 => MsgpackValue::Int(i64::from(self.read_u16()? as i16)),
+            0xd2 => MsgpackValue::Int(i64::from(self.read_u32()? as i32)),
+            0xd3 => MsgpackValue::Int(self.read_u64()? as i64),
+            0xd4 => self.decode_ext(1)?,
+            0xd5 => self.decode_ext(2)?,
+            0xd6 => self.decode_ext(4)?,
+            0xd7 => self.decode_ext(8)?,
+            0xd8 => self.decode_ext(16)?,
+            0xd9 => {
+                let len = self.read_u8()?;
+                self.decode_str(usize::from(len))?
+            }
+            0xda => {
+                let len = self.read_u16()?;
+                self.decode_str(usize::from(len))?
+            }
+            0xdb => {
+                let len = self.read_u32()?;
+                self.decode_str(len as usize)?
+            }
+            0xdc => {
+                let len = self.read_u16()?;
+                self.decode_array(usize::from(len))?
+            }
+            0xdd => {
+                let len = self.read_u32()?;
+                self.decode_array(len as usize)?
+            }
+            0xde => {
+                let len = self.read_u16()?;
+                self.decode_map(usize::from(len))?
+            }
+            0xdf => {
+                let len = self.read_u32()?;
+                self.decode_map(len as usize)?
+            }
+            0xe0..=0xff => MsgpackValue::Int(i64::from(marker as i8)),
+        };
+        Ok(value)
+    }
+
+    fn decode_str(&mut self, len: usize) -> DecodeResult<MsgpackValue> {
+        let start = self.index;
+        let bytes = self.read_slice(len)?;
+        match std::str::from_utf8(bytes) {
+            Ok(s) => Ok(MsgpackValue::Str(s.to_owned())),
+            Err(e) => Err(MsgpackError {
+                error_type: MsgpackErrorType::InvalidUtf8,
+                index: start + e.valid_up_to(),
+            }),
+        }
+    }
+
+    fn decode_bin(&mut self, len: usize) -> DecodeResult<MsgpackValue> {
+        Ok(MsgpackValue::Bin(self.read_slice(len)?.to_vec()))
+    }
+
+    fn decode_ext(&mut self, len: usize) -> DecodeResult<MsgpackValue> {
+        let ext_type_index = self.index;
+        let ext_type = self.read_u8()? as i8;
+        let data = self.read_slice(len)?;
+        let error = |error_type| MsgpackError {
+            error_type,
+            index: ext_type_index,
+        };
+        if ext_type != TIMESTAMP_EXT_TYPE {
+            return Err(error(MsgpackErrorType::UnsupportedExtension(ext_type)));
+        }
+        // lengths are checked before each conversion, so the `try_into` calls can't fail
+        let (seconds, nanoseconds) = match data.len() {
+            4 => (i64::from(u32::from_be_bytes(data.try_into().unwrap())), 0),
+            8 => {
+                let packed = u64::from_be_bytes(data.try_into().unwrap());
+                ((packed & 0x0003_ffff_ffff) as i64, (packed >> 34) as u32)
+            }
+            12 => {
+                let (nanoseconds, seconds) = data.split_at(4);
+                (
+                    i64::from_be_bytes(seconds.try_into().unwrap()),
+                    u32::from_be_bytes(nanoseconds.try_into().unwrap()),
+                )
+            }
+            _ => return Err(error(MsgpackErrorType::InvalidTimestamp)),
+        };
+        if nanoseconds >= 1_000_000_000 {
+            return Err(error(MsgpackErrorType::InvalidTimestamp));
+        }
+        // python datetimes only support microsecond precision, so any extra precision is truncated
+        match timestamp_to_datetime(seconds, nanoseconds / 1_000) {
+            Some(dt) => Ok(MsgpackValue::Timestamp(dt)),
+            None => Err(error(MsgpackErrorType::InvalidTimestamp)),
+        }
+    }
+
+    fn enter_container(&mut self) -> DecodeResult<()> {
+        self.depth += 1;
+        if self.depth > RECURSION_LIMIT {
+            Err(self.error(MsgpackErrorType::RecursionLimitExceeded))
+        } else {
+            Ok(())
+        }
+    }
+
+    /// every element takes at least one byte, so use the remaining data to limit how much we preallocate
+    fn capacity_hint(&self, len: usize) -> usize {
+        len.min(self.data.len() - self.index)
+    }
+
+    fn decode_array(&mut self, len: usize) -> DecodeResult<MsgpackValue> {
+        self.enter_container()?;
+        let mut array = Vec::with_capacity(self.capacity_hint(len));
+        for _ in 0..len {
+            array.push(self.decode_value()?);
+        }
+        self.depth -= 1;
+        Ok(MsgpackValue::Array(array.into()))
+    }
+
+    fn decode_map(&mut self, len: usize) -> DecodeResult<MsgpackValue> {
+        self.enter_container()?;
+        let mut entries = Vec::with_capacity(self.capacity_hint(len));
+        for _ in 0..len {
+            let key_index = self.index;
+            let key = self.decode_value()?;
+            if !key.is_hashable() {
+                return Err(MsgpackError {
+                    error_type: MsgpackErrorType::UnhashableKey,
+                    index: key_index,
+                });
+            }
+            let value = self.decode_value()?;
+            entries.push((key, value));
+        }
+        self.depth -= 1;
+        Ok(MsgpackValue::Map(MsgpackMap(entries.into())))
+    }
+}
@@ -3,7 +3,7 @@ use std::cmp::Ordering;
 use std::ops::Rem;
 use std::str::FromStr;
 
-use jiter::{JsonArray, JsonValue, StringCacheMode};
+use jiter::StringCacheMode;
 use num_bigint::BigInt;
 
 use pyo3::exceptions::PyTypeError;
@@ -21,8 +21,10 @@ use crate::errors::{
 };
 use crate::py_gc::PyGcTraverse;
 use crate::tools::{extract_i64, extract_int, new_py_string, py_err};
-use crate::validators::{CombinedValidator, Exactness, ValidationState, Validator};
+use crate::validators::{CombinedValidator, Exactness, ValidationState};
 
+use super::parse_json::{JsonArray, JsonValue};
+use super::parse_msgpack::{MsgpackArray, MsgpackValue};
 use super::{py_error_on_minusone, BorrowInput, Input};
 
 pub struct ValidationMatch<T>(T, Exactness);
@@ -84,7 +86,7 @@ impl<'a, INPUT: ?Sized> MaxLengthCheck<'a, INPUT> {
 }
 
 impl<'py, INPUT: Input<'py> + ?Sized> MaxLengthCheck<'_, INPUT> {
-    fn incr(&mut self) -> ValResult<()> {
+    pub(crate) fn incr(&mut self) -> ValResult<()> {
         if let Some(max_length) = self.max_length {
             self.current_length += 1;
             if self.current_length > max_length {
@@ -128,17 +130,26 @@ pub(crate) fn validate_iter_to_vec<'py>(
 ) -> ValResult<Vec<PyObject>> {
     let mut output: Vec<PyObject> = Vec::with_capacity(capacity);
     let mut errors: Vec<ValLineError> = Vec::new();
-    for (index, item_result) in iter.enumerate() {
+    let allow_partial = state.allow_partial;
+    let partial_tail = state.partial_tail;
+    let items = state.enumerate_last_partial(iter);
+    state.partial_tail = partial_tail.last_item();
+    for (index, is_last_partial, item_result) in items {
+        state.allow_partial = is_last_partial;
         let item = item_result.map_err(|e| any_next_error!(py, e, max_length_check.input, index))?;
+        state.count_items(1, max_length_check.input)?;
+        state.trace_loc(|| index);
         match validator.validate(py, item.borrow_input(), state) {
             Ok(item) => {
                 max_length_check.incr()?;
                 output.push(item);
             }
+            // the last item of partial input may be incomplete, if so it's dropped rather than reported
+            Err(ValError::LineErrors(_)) if is_last_partial && state.is_incomplete(item.borrow_input()) => (),
             Err(ValError::LineErrors(line_errors)) => {
                 max_length_check.incr()?;
                 errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index)));
-                if fail_fast {
+                if fail_fast || state.errors_capped(errors.len()) {
                     break;
                 }
             }
@@ -146,6 +157,8 @@ pub(crate) fn validate_iter_to_vec<'py>(
             Err(err) => return Err(err),
         }
     }
+    state.allow_partial = allow_partial;
+    state.partial_tail = partial_tail;
 
     if errors.is_empty() {
         Ok(output)
@@ -199,6 +212,8 @@ pub(crate) fn validate_iter_to_set<'py>(
     let mut errors: Vec<ValLineError> = Vec::new();
     for (index, item_result) in iter.enumerate() {
         let item = item_result.map_err(|e| any_next_error!(py, e, input, index))?;
+        state.count_items(1, input)?;
+        state.trace_loc(|| index);
         match validator.validate(py, item.borrow_input(), state) {
             Ok(item) => {
                 set.build_add(item)?;
@@ -225,7 +240,7 @@ pub(crate) fn validate_iter_to_set<'py>(
             Err(ValError::Omit) => (),
             Err(err) => return Err(err),
         }
-        if fail_fast && !errors.is_empty() {
+        if (fail_fast && !errors.is_empty()) || state.errors_capped(errors.len()) {
             break;
         }
     }
@@ -334,6 +349,7 @@ pub(crate) fn iterate_attributes<'a, 'py>(
 pub enum GenericIterator<'data> {
     PyIterator(GenericPyIterator),
     JsonArray(GenericJsonIterator<'data>),
+    MsgpackArray(GenericMsgpackIterator),
 }
 
 impl PyGcTraverse for GenericIterator<'_> {
@@ -350,6 +366,7 @@ impl GenericIterator<'_> {
         match self {
             GenericIterator::PyIterator(iter) => GenericIterator::PyIterator(iter),
             GenericIterator::JsonArray(iter) => GenericIterator::JsonArray(iter.into_static()),
+            GenericIterator::MsgpackArray(iter) => GenericIterator::MsgpackArray(iter),
         }
     }
 }
@@ -361,6 +378,13 @@ impl<'data> From<JsonArray<'data>> for GenericIterator<'data> {
     }
 }
 
+impl From<MsgpackArray> for GenericIterator<'_> {
+    fn from(array: MsgpackArray) -> Self {
+        let msgpack_iter = GenericMsgpackIterator { array, index: 0 };
+        Self::MsgpackArray(msgpack_iter)
+    }
+}
+
 impl From<&Bound<'_, PyAny>> for GenericIterator<'_> {
     fn from(obj: &Bound<'_, PyAny>) -> Self {
         let py_iter = GenericPyIterator {
@@ -441,6 +465,33 @@ impl<'data> GenericJsonIterator<'data> {
     }
 }
 
+#[derive(Debug, Clone)]
+pub struct GenericMsgpackIterator {
+    array: MsgpackArray,
+    index: usize,
+}
+
+impl GenericMsgpackIterator {
+    pub fn next(&mut self, _py: Python) -> PyResult<Option<(&MsgpackValue, usize)>> {
+        match self.array.get(self.index) {
+            Some(next) => {
+                let a = (next, self.index);
+                self.index += 1;
+                Ok(Some(a))
+            }
+            None => Ok(None),
+        }
+    }
+
+    pub fn input_as_error_value(&self, _py: Python<'_>) -> InputValue {
+        InputValue::Msgpack(MsgpackValue::Array(self.array.clone()))
+    }
+
+    pub fn index(&self) -> usize {
+        self.index
+    }
+}
+
 #[cfg_attr(debug_assertions, derive(Debug))]
 pub enum EitherString<'a> {
     Cow(Cow<'a, str>),
@@ -677,6 +728,16 @@ fn serialize_bigint_as_number<S>(big_int: &BigInt, serializer: S) -> Result<S::O
 where
     S: Serializer,
 {
+    if let Ok(u) = u64::try_from(big_int) {
+        return serializer.serialize_u64(u);
+    }
+    // binary formats like MessagePack have no arbitrary precision number type, writing a string instead would
+    // silently change the type
+    if !serializer.is_human_readable() {
+        return Err(S::Error::custom(format!(
+            "{big_int} is outside the range of 64 bit integers"
+        )));
+    }
     serde_json::Number::from_str(&big_int.to_string())
         .map_err(S::Error::custom)
         .expect("a valid number")
@@ -4,8 +4,10 @@ extern crate core;
 
 use std::sync::OnceLock;
 
-use jiter::{map_json_error, PartialMode, PythonParse, StringCacheMode};
-use pyo3::exceptions::PyTypeError;
+use jiter::{map_json_error, LosslessFloat, PartialMode, PythonParse, StringCacheMode};
+use pyo3::exceptions::{PyTypeError, PyValueError};
+use pyo3::intern;
+use pyo3::types::{PyDict, PyList};
 use pyo3::{prelude::*, sync::GILOnceCell};
 use serializers::BytesMode;
 use validators::ValBytesMode;
@@ -36,20 +38,46 @@ pub use errors::{
     list_all_errors, PydanticCustomError, PydanticKnownError, PydanticOmit, PydanticUseDefault, ValidationError,
 };
 pub use serializers::{
-    to_json, to_jsonable_python, PydanticSerializationError, PydanticSerializationUnexpectedValue, SchemaSerializer,
-    WarningsArg,
+    to_json, to_jsonable_python, to_msgpack, PydanticSerializationError, PydanticSerializationUnexpectedValue,
+    SchemaSerializer, WarningsArg,
 };
 pub use validators::{validate_core_schema, PySome, SchemaValidator};
 
 use crate::input::Input;
 
-#[pyfunction(signature = (data, *, allow_inf_nan=true, cache_strings=StringCacheMode::All, allow_partial=PartialMode::Off))]
+/// How `from_json` should return JSON floats
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FloatMode {
+    /// as python `float`s
+    #[default]
+    Float,
+    /// as `decimal.Decimal`s created from the number exactly as it's written in the JSON
+    Decimal,
+}
+
+impl<'py> FromPyObject<'py> for FloatMode {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<FloatMode> {
+        match ob.extract::<&str>() {
+            Ok("float") => Ok(Self::Float),
+            Ok("decimal") => Ok(Self::Decimal),
+            Ok(_) => Err(PyValueError::new_err(
+                "Invalid float_mode, should be `'float'` or `'decimal'`",
+            )),
+            Err(_) => Err(PyTypeError::new_err(
+                "Invalid float_mode, should be `'float'` or `'decimal'`",
+            )),
+        }
+    }
+}
+
+#[pyfunction(signature = (data, *, allow_inf_nan=true, cache_strings=StringCacheMode::All, allow_partial=PartialMode::Off, float_mode=FloatMode::Float))]
 pub fn from_json<'py>(
     py: Python<'py>,
     data: &Bound<'_, PyAny>,
     allow_inf_nan: bool,
     cache_strings: StringCacheMode,
     allow_partial: PartialMode,
+    float_mode: FloatMode,
 ) -> PyResult<Bound<'py, PyAny>> {
     let v_match = data
         .validate_bytes(false, ValBytesMode { ser: BytesMode::Utf8 })
@@ -61,11 +89,36 @@ pub fn from_json<'py>(
         cache_mode: cache_strings,
         partial_mode: allow_partial,
         catch_duplicate_keys: false,
-        lossless_floats: false,
+        lossless_floats: float_mode == FloatMode::Decimal,
     };
-    parse_builder
+    let value = parse_builder
         .python_parse(py, json_bytes)
-        .map_err(|e| map_json_error(json_bytes, &e))
+        .map_err(|e| map_json_error(json_bytes, &e))?;
+    match float_mode {
+        FloatMode::Float => Ok(value),
+        FloatMode::Decimal => lossless_floats_to_decimals(value),
+    }
+}
+
+/// Replace the `LosslessFloat`s jiter returns when `lossless_floats` is set with `Decimal`s, lists and dicts
+/// are updated in place since they were only just created
+fn lossless_floats_to_decimals(value: Bound<'_, PyAny>) -> PyResult<Bound<'_, PyAny>> {
+    let py = value.py();
+    if let Ok(lossless_float) = value.downcast::<LosslessFloat>() {
+        lossless_float.call_method0(intern!(py, "as_decimal"))
+    } else if let Ok(list) = value.downcast::<PyList>() {
+        for (index, item) in list.iter().enumerate() {
+            list.set_item(index, lossless_floats_to_decimals(item)?)?;
+        }
+        Ok(value)
+    } else if let Ok(dict) = value.downcast::<PyDict>() {
+        for (key, item) in dict.iter() {
+            dict.set_item(key, lossless_floats_to_decimals(item)?)?;
+        }
+        Ok(value)
+    } else {
+        Ok(value)
+    }
 }
 
 pub fn get_pydantic_core_version() -> &'static str {
@@ -132,6 +185,7 @@ fn _pydantic_core(py: Python, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_function(wrap_pyfunction!(to_json, m)?)?;
     m.add_function(wrap_pyfunction!(from_json, m)?)?;
     m.add_function(wrap_pyfunction!(to_jsonable_python, m)?)?;
+    m.add_function(wrap_pyfunction!(to_msgpack, m)?)?;
     m.add_function(wrap_pyfunction!(list_all_errors, m)?)?;
     m.add_function(wrap_pyfunction!(validate_core_schema, m)?)?;
     Ok(())
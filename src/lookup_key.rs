@@ -5,16 +5,14 @@ use pyo3::exceptions::{PyAttributeError, PyTypeError};
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList, PyMapping, PyString};
 
-use jiter::{JsonObject, JsonValue};
-
 use crate::build_tools::py_schema_err;
 use crate::errors::{py_err_string, ErrorType, ToErrorValue, ValError, ValLineError, ValResult};
-use crate::input::StringMapping;
+use crate::input::{JsonObject, JsonValue, MsgpackMap, MsgpackValue, StringMapping};
 use crate::tools::{extract_i64, py_err};
 
 /// Used for getting items from python dicts, python objects, or JSON objects, in different ways
 #[derive(Debug, Clone)]
-pub(crate) enum LookupKey {
+pub enum LookupKey {
     /// simply look up a key in a dict, equivalent to `d.get(key)`
     /// we save both the string and pystring to save creating the pystring for python
     Simple {
@@ -52,6 +50,15 @@ impl fmt::Display for LookupKey {
 }
 
 impl LookupKey {
+    /// The first key looked up, e.g. the alias when `populate_by_name` is set
+    pub fn first_key(&self) -> &str {
+        match self {
+            Self::Simple { key, .. } => key,
+            Self::Choice { key1, .. } => key1,
+            Self::PathChoices(paths) => paths[0].first_key(),
+        }
+    }
+
     pub fn from_py(py: Python, value: &Bound<'_, PyAny>, alt_alias: Option<&str>) -> PyResult<Self> {
         if let Ok(alias_py) = value.downcast::<PyString>() {
             let alias: String = alias_py.extract()?;
@@ -306,6 +313,44 @@ impl LookupKey {
         }
     }
 
+    pub fn msgpack_get<'a, 's>(&'s self, map: &'a MsgpackMap) -> ValResult<Option<(&'s LookupPath, &'a MsgpackValue)>> {
+        match self {
+            Self::Simple { key, path, .. } => match map.get(key) {
+                Some(value) => Ok(Some((path, value))),
+                None => Ok(None),
+            },
+            Self::Choice {
+                key1,
+                path1,
+                key2,
+                path2,
+                ..
+            } => match map.get(key1) {
+                Some(value) => Ok(Some((path1, value))),
+                None => match map.get(key2) {
+                    Some(value) => Ok(Some((path2, value))),
+                    None => Ok(None),
+                },
+            },
+            Self::PathChoices(path_choices) => {
+                for path in path_choices {
+                    let mut path_iter = path.iter();
+
+                    // as with JSON, the first item in the path is always a string key
+                    let v: &MsgpackValue = match path_iter.next().unwrap().msgpack_map_get(map) {
+                        Some(v) => v,
+                        None => continue,
+                    };
+
+                    if let Some(v) = path_iter.try_fold(v, |d, loc| loc.msgpack_get(d)) {
+                        return Ok(Some((path, v)));
+                    }
+                }
+                Ok(None)
+            }
+        }
+    }
+
     pub fn error(
         &self,
         error_type: ErrorType,
@@ -327,7 +372,7 @@ impl LookupKey {
 }
 
 #[derive(Debug, Clone)]
-pub(crate) struct LookupPath(Vec<PathItem>);
+pub struct LookupPath(Vec<PathItem>);
 
 impl fmt::Display for LookupPath {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -388,7 +433,7 @@ impl LookupPath {
 }
 
 #[derive(Debug, Clone)]
-pub(crate) enum PathItem {
+pub enum PathItem {
     /// string type key, used to get or identify items from a dict or anything that implements `__getitem__`
     /// as above we store both the string and pystring to save creating the pystring for python
     S(String, Py<PyString>),
@@ -498,6 +543,25 @@ impl PathItem {
             _ => None,
         }
     }
+
+    pub fn msgpack_get<'a>(&self, any_msgpack: &'a MsgpackValue) -> Option<&'a MsgpackValue> {
+        match any_msgpack {
+            MsgpackValue::Map(map) => self.msgpack_map_get(map),
+            MsgpackValue::Array(array) => match self {
+                Self::Pos(index) => array.get(*index),
+                Self::Neg(index) => array.len().checked_sub(*index).and_then(|index| array.get(index)),
+                Self::S(..) => None,
+            },
+            _ => None,
+        }
+    }
+
+    pub fn msgpack_map_get<'a>(&self, map: &'a MsgpackMap) -> Option<&'a MsgpackValue> {
+        match self {
+            Self::S(key, _) => map.get(key),
+            _ => None,
+        }
+    }
 }
 
 /// wrapper around `getattr` that returns `Ok(None)` for attribute errors, but returns other errors
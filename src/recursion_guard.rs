@@ -1,5 +1,13 @@
-use ahash::AHashSet;
+use ahash::{AHashMap, AHashSet};
 use std::mem::MaybeUninit;
+use std::str::FromStr;
+
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyString};
+
+use crate::build_tools::py_schema_err;
+use crate::tools::SchemaDict;
 
 type RecursionKey = (
     // Identifier for the input object, e.g. the id() of a Python dict
@@ -35,6 +43,9 @@ impl<S: ContainsRecursionState> RecursionGuard<'_, S> {
                 return Err(RecursionError::Cyclic);
             }
             if state.incr_depth() {
+                // no guard is returned to undo these, so undo them now
+                state.decr_depth();
+                state.remove(obj_id, node_id);
                 return Err(RecursionError::Depth);
             }
             Ok(())
@@ -63,19 +74,30 @@ pub(crate) trait ContainsRecursionState {
 }
 
 /// State for the RecursionGuard. Can also be used directly to increase / decrease depth.
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone)]
 pub struct RecursionState {
     ids: RecursionStack,
     // depth could be a hashmap {validator_id => depth} but for simplicity and performance it's easier to just
     // use one number for all validators
-    depth: u8,
+    depth: u16,
+    limit: u16,
+    // objects which have already been validated or serialized, and their output, so that objects referenced more
+    // than once share the same output, only used with `cyclic_references='reuse'`;
+    // the object is kept to make sure its id isn't reused by another object
+    reused: Option<AHashMap<RecursionKey, (PyObject, PyObject)>>,
+}
+
+impl Default for RecursionState {
+    fn default() -> Self {
+        Self::new(RecursionConfig::default())
+    }
 }
 
 // with debug_assertions enabled, function stacks are a bit bigger so need to be a bit more restricted
-const GUARD_OFFSET: u8 = if cfg!(debug_assertions) { 20 } else { 0 };
+const GUARD_OFFSET: u16 = if cfg!(debug_assertions) { 20 } else { 0 };
 
-// A hard limit to avoid stack overflows when rampant recursion occurs
-pub const RECURSION_GUARD_LIMIT: u8 = (if cfg!(any(target_family = "wasm", all(windows, PyPy))) {
+// The default limit to avoid stack overflows when rampant recursion occurs, can be changed with `recursion_limit`
+pub const RECURSION_GUARD_LIMIT: u16 = (if cfg!(any(target_family = "wasm", all(windows, PyPy))) {
     // wasm and windows PyPy have very limited stack sizes
     49
 } else if cfg!(any(PyPy, windows)) {
@@ -85,7 +107,86 @@ pub const RECURSION_GUARD_LIMIT: u8 = (if cfg!(any(target_family = "wasm", all(w
     255
 }) - GUARD_OFFSET;
 
+// The highest `recursion_limit` allowed, a recursive definition uses several stack frames per level so going much
+// beyond the default overflows the stack rather than raising a validation or serialization error
+pub const MAX_RECURSION_LIMIT: u16 = RECURSION_GUARD_LIMIT * 4;
+
+/// What to do when an object is seen again while validating or serializing it, set by `cyclic_references`
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CyclicReferences {
+    /// objects are validated or serialized each time they're seen, cyclic references raise an error
+    #[default]
+    Error,
+    /// the output from the first time an object was seen is reused, cyclic references still raise an error
+    Reuse,
+}
+
+impl FromStr for CyclicReferences {
+    type Err = PyErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "error" => Ok(Self::Error),
+            "reuse" => Ok(Self::Reuse),
+            s => py_schema_err!("Invalid cyclic_references: `{}`, expected `error` or `reuse`", s),
+        }
+    }
+}
+
+/// Recursion settings from the `recursion_limit` and `cyclic_references` config keys
+#[derive(Debug, Clone, Copy)]
+pub struct RecursionConfig {
+    limit: u16,
+    cyclic_references: CyclicReferences,
+}
+
+impl Default for RecursionConfig {
+    fn default() -> Self {
+        Self {
+            limit: RECURSION_GUARD_LIMIT,
+            cyclic_references: CyclicReferences::default(),
+        }
+    }
+}
+
+impl RecursionConfig {
+    pub fn from_config(config: Option<&Bound<'_, PyDict>>) -> PyResult<Self> {
+        let Some(config) = config else {
+            return Ok(Self::default());
+        };
+        let py = config.py();
+        let limit = match config.get_as::<usize>(intern!(py, "recursion_limit"))? {
+            Some(0) => return py_schema_err!("`recursion_limit` must be greater than 0"),
+            Some(limit) => match u16::try_from(limit) {
+                Ok(limit) if limit <= MAX_RECURSION_LIMIT => limit,
+                _ => return py_schema_err!("`recursion_limit` must be at most {}", MAX_RECURSION_LIMIT),
+            },
+            None => RECURSION_GUARD_LIMIT,
+        };
+        let cyclic_references = match config.get_as::<Bound<'_, PyString>>(intern!(py, "cyclic_references"))? {
+            Some(s) => CyclicReferences::from_str(s.to_str()?)?,
+            None => CyclicReferences::default(),
+        };
+        Ok(Self {
+            limit,
+            cyclic_references,
+        })
+    }
+}
+
 impl RecursionState {
+    pub fn new(config: RecursionConfig) -> Self {
+        Self {
+            ids: RecursionStack::default(),
+            depth: 0,
+            limit: config.limit,
+            reused: match config.cyclic_references {
+                CyclicReferences::Error => None,
+                CyclicReferences::Reuse => Some(AHashMap::new()),
+            },
+        }
+    }
+
     // insert a new value
     // * return `false` if the stack already had it in it
     // * return `true` if the stack didn't have it in it and it was inserted
@@ -95,29 +196,11 @@ impl RecursionState {
 
     // see #143 this is used as a backup in case the identity check recursion guard fails
     #[must_use]
-    #[cfg(any(target_family = "wasm", windows, PyPy, debug_assertions))]
     pub fn incr_depth(&mut self) -> bool {
-        #[allow(clippy::assertions_on_constants)]
-        {
-            debug_assert!(RECURSION_GUARD_LIMIT < 255);
-        }
         // use saturating_add as it's faster (since there's no error path)
-        // and the RECURSION_GUARD_LIMIT check will be hit before it overflows
+        // and the limit check will be hit before it overflows
         self.depth = self.depth.saturating_add(1);
-        self.depth > RECURSION_GUARD_LIMIT
-    }
-
-    #[must_use]
-    #[cfg(not(any(target_family = "wasm", windows, PyPy, debug_assertions)))]
-    pub fn incr_depth(&mut self) -> bool {
-        debug_assert_eq!(RECURSION_GUARD_LIMIT, 255);
-        // use checked_add to check if we've hit the limit
-        if let Some(depth) = self.depth.checked_add(1) {
-            self.depth = depth;
-            false
-        } else {
-            true
-        }
+        self.depth > self.limit
     }
 
     pub fn decr_depth(&mut self) {
@@ -128,6 +211,24 @@ impl RecursionState {
     fn remove(&mut self, obj_id: usize, node_id: usize) {
         self.ids.remove(&(obj_id, node_id));
     }
+
+    /// The output from when `obj` was last validated or serialized by `node_id`, with `cyclic_references='reuse'`
+    pub fn get_reused(&self, py: Python, obj: &Bound<'_, PyAny>, node_id: usize) -> Option<PyObject> {
+        let reused = self.reused.as_ref()?;
+        let (_, output) = reused.get(&(obj.as_ptr() as usize, node_id))?;
+        Some(output.clone_ref(py))
+    }
+
+    /// Record the output of `obj` so it can be reused, with `cyclic_references='reuse'`
+    pub fn set_reused(&mut self, obj: &Bound<'_, PyAny>, node_id: usize, output: &PyObject) {
+        if let Some(reused) = self.reused.as_mut() {
+            let py = obj.py();
+            reused.insert(
+                (obj.as_ptr() as usize, node_id),
+                (obj.clone().unbind(), output.clone_ref(py)),
+            );
+        }
+    }
 }
 
 // trial and error suggests this is a good value, going higher causes array lookups to get significantly slower
@@ -35,10 +35,6 @@ impl ComputedFields {
         }
     }
 
-    pub fn len(&self) -> usize {
-        self.0.len()
-    }
-
     pub fn to_python(
         &self,
         model: &Bound<'_, PyAny>,
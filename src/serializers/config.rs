@@ -1,4 +1,5 @@
 use std::borrow::Cow;
+use std::fmt;
 use std::str::{from_utf8, FromStr, Utf8Error};
 
 use base64::Engine;
@@ -7,9 +8,12 @@ use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyString};
 
 use serde::ser::Error;
+use serde::Serialize;
+use speedate::{Date, DateTime};
 
 use crate::build_tools::py_schema_err;
 use crate::input::EitherTimedelta;
+use crate::recursion_guard::RecursionConfig;
 use crate::tools::SchemaDict;
 
 use super::errors::py_err_se_err;
@@ -18,29 +22,57 @@ use super::errors::py_err_se_err;
 #[allow(clippy::struct_field_names)]
 pub(crate) struct SerializationConfig {
     pub timedelta_mode: TimedeltaMode,
+    pub datetime_mode: DatetimeMode,
     pub bytes_mode: BytesMode,
     pub inf_nan_mode: InfNanMode,
+    pub number_format: NumberFormat,
+    pub recursion: RecursionConfig,
 }
 
 impl SerializationConfig {
     pub fn from_config(config: Option<&Bound<'_, PyDict>>) -> PyResult<Self> {
         let timedelta_mode = TimedeltaMode::from_config(config)?;
+        let datetime_mode = DatetimeMode::from_config(config)?;
         let bytes_mode = BytesMode::from_config(config)?;
         let inf_nan_mode = InfNanMode::from_config(config)?;
+        let number_format = NumberFormat::from_config(config)?;
+        let recursion = RecursionConfig::from_config(config)?;
         Ok(Self {
             timedelta_mode,
+            datetime_mode,
             bytes_mode,
             inf_nan_mode,
+            number_format,
+            recursion,
         })
     }
 
-    pub fn from_args(timedelta_mode: &str, bytes_mode: &str, inf_nan_mode: &str) -> PyResult<Self> {
+    pub fn from_args(
+        timedelta_mode: &str,
+        datetime_mode: &str,
+        bytes_mode: &str,
+        inf_nan_mode: &str,
+    ) -> PyResult<Self> {
         Ok(Self {
             timedelta_mode: TimedeltaMode::from_str(timedelta_mode)?,
+            datetime_mode: DatetimeMode::from_str(datetime_mode)?,
             bytes_mode: BytesMode::from_str(bytes_mode)?,
             inf_nan_mode: InfNanMode::from_str(inf_nan_mode)?,
+            number_format: NumberFormat::default(),
+            recursion: RecursionConfig::default(),
         })
     }
+
+    /// Apply the `datetime_mode` passed to a single serialization call, if any
+    pub fn with_datetime_mode(&self, datetime_mode: Option<&str>) -> PyResult<Cow<'_, Self>> {
+        match datetime_mode {
+            Some(datetime_mode) => Ok(Cow::Owned(Self {
+                datetime_mode: DatetimeMode::from_str(datetime_mode)?,
+                ..self.clone()
+            })),
+            None => Ok(Cow::Borrowed(self)),
+        }
+    }
 }
 
 pub trait FromConfig {
@@ -92,6 +124,15 @@ serialization_mode! {
     MillisecondsFloat => "milliseconds_float"
 }
 
+serialization_mode! {
+    DatetimeMode,
+    "ser_json_datetime",
+    Iso8601 => "iso8601",
+    EpochSeconds => "epoch_seconds",
+    EpochMilliseconds => "epoch_milliseconds",
+    EpochMicroseconds => "epoch_microseconds",
+}
+
 serialization_mode! {
     BytesMode,
     "ser_json_bytes",
@@ -100,6 +141,30 @@ serialization_mode! {
     Hex => "hex",
 }
 
+/// How `bytes` are written to MessagePack, which unlike JSON has a native binary type; the string modes
+/// are the same as `BytesMode` and override `ser_json_bytes`
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MsgpackBytesMode {
+    #[default]
+    Binary,
+    String(BytesMode),
+}
+
+impl FromStr for MsgpackBytesMode {
+    type Err = PyErr;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "binary" => Ok(Self::Binary),
+            "utf8" | "base64" | "hex" => BytesMode::from_str(s).map(Self::String),
+            s => py_schema_err!(
+                "Invalid MsgpackBytesMode serialization mode: `{}`, expected binary or utf8 or base64 or hex",
+                s
+            ),
+        }
+    }
+}
+
 serialization_mode! {
     InfNanMode,
     "ser_json_inf_nan",
@@ -165,6 +230,149 @@ impl TimedeltaMode {
     }
 }
 
+/// A unix timestamp in the unit of a `DatetimeMode`, seconds and milliseconds are floats so that
+/// microseconds aren't lost
+#[derive(Debug, Clone, Copy)]
+pub enum EpochTimestamp {
+    Float(f64),
+    Int(i64),
+}
+
+impl DatetimeMode {
+    /// The unix timestamp of a datetime, naive datetimes are treated as UTC, `None` means ISO 8601
+    pub fn timestamp(self, datetime: &DateTime) -> Option<EpochTimestamp> {
+        let seconds = days_since_epoch(&datetime.date) * 86_400 + i64::from(datetime.time.total_seconds())
+            - i64::from(datetime.time.tz_offset.unwrap_or(0));
+        // the float modes are scaled from whole microseconds, so they're rounded once rather than carrying the
+        // error of adding a fractional part, e.g. for sub-second times before 1970
+        let microseconds = seconds * 1_000_000 + i64::from(datetime.time.microsecond);
+        match self {
+            Self::Iso8601 => None,
+            Self::EpochSeconds => Some(EpochTimestamp::Float(microseconds as f64 / 1e6)),
+            Self::EpochMilliseconds => Some(EpochTimestamp::Float(microseconds as f64 / 1e3)),
+            Self::EpochMicroseconds => Some(EpochTimestamp::Int(microseconds)),
+        }
+    }
+}
+
+impl EpochTimestamp {
+    pub fn to_object(self, py: Python) -> PyObject {
+        match self {
+            Self::Float(timestamp) => timestamp.into_py(py),
+            Self::Int(timestamp) => timestamp.into_py(py),
+        }
+    }
+
+    pub fn serialize<S: serde::ser::Serializer>(self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::Float(timestamp) => serializer.serialize_f64(timestamp),
+            Self::Int(timestamp) => serializer.serialize_i64(timestamp),
+        }
+    }
+}
+
+/// Formatted as the timestamp is written in JSON, so dict keys match values, e.g. `1704067200.0` rather than
+/// `1704067200`
+impl fmt::Display for EpochTimestamp {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Float(timestamp) => {
+                let text = serde_json::to_string(timestamp).map_err(|_| fmt::Error)?;
+                f.write_str(&text)
+            }
+            Self::Int(timestamp) => write!(f, "{timestamp}"),
+        }
+    }
+}
+
+/// How floats and decimals are written in JSON, set with `ser_json_float_precision` and `ser_json_number_as_string`
+/// in config, or `float_precision` and `number_as_string` in float and decimal schemas
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NumberFormat {
+    /// round to this many decimal places, always writing exactly that many, floats of 1e16 or more are written in
+    /// exponent notation instead
+    pub precision: Option<usize>,
+    /// whether to write numbers as JSON strings, by default floats are numbers and decimals are strings
+    pub as_string: Option<bool>,
+}
+
+impl FromConfig for NumberFormat {
+    fn from_config(config: Option<&Bound<'_, PyDict>>) -> PyResult<Self> {
+        let Some(config_dict) = config else {
+            return Ok(Self::default());
+        };
+        let py = config_dict.py();
+        Ok(Self {
+            precision: config_dict.get_as(intern!(py, "ser_json_float_precision"))?,
+            as_string: config_dict.get_as(intern!(py, "ser_json_number_as_string"))?,
+        })
+    }
+}
+
+impl NumberFormat {
+    /// `float_precision` and `number_as_string` in the schema take precedence over those set in config
+    pub fn from_schema(schema: &Bound<'_, PyDict>, config: Option<&Bound<'_, PyDict>>) -> PyResult<Self> {
+        let py = schema.py();
+        let config_format = Self::from_config(config)?;
+        Ok(Self {
+            precision: schema
+                .get_as(intern!(py, "float_precision"))?
+                .or(config_format.precision),
+            as_string: schema
+                .get_as(intern!(py, "number_as_string"))?
+                .or(config_format.as_string),
+        })
+    }
+
+    /// The python value of a formatted number in JSON mode, as with `serialize_text` numbers which aren't valid
+    /// JSON numbers (e.g. `NaN` decimals) are left as strings
+    pub fn text_to_python(self, py: Python, text: &str, default_as_string: bool) -> PyObject {
+        if self.as_string.unwrap_or(default_as_string) || serde_json::Number::from_str(text).is_err() {
+            return text.into_py(py);
+        }
+        match text.parse::<f64>() {
+            Ok(v) => v.into_py(py),
+            Err(_) => text.into_py(py),
+        }
+    }
+
+    /// Write a formatted number, with arbitrary precision in JSON, numbers which aren't valid JSON numbers
+    /// (e.g. `NaN` decimals) are written as strings
+    pub fn serialize_text<S: serde::ser::Serializer>(
+        self,
+        text: &str,
+        serializer: S,
+        default_as_string: bool,
+    ) -> Result<S::Ok, S::Error> {
+        if self.as_string.unwrap_or(default_as_string) {
+            serializer.serialize_str(text)
+        } else if !serializer.is_human_readable() {
+            // binary formats like MessagePack have no arbitrary precision number type
+            match text.parse::<f64>() {
+                Ok(v) => serializer.serialize_f64(v),
+                Err(_) => serializer.serialize_str(text),
+            }
+        } else {
+            match serde_json::Number::from_str(text) {
+                Ok(number) => number.serialize(serializer),
+                Err(_) => serializer.serialize_str(text),
+            }
+        }
+    }
+}
+
+/// Days from 1970-01-01 to `date`, unlike `speedate::Date::timestamp` this supports years before 1600
+fn days_since_epoch(date: &Date) -> i64 {
+    // see http://howardhinnant.github.io/date_algorithms.html#days_from_civil
+    let month = i64::from(date.month);
+    let year = i64::from(date.year) - i64::from(month <= 2);
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * ((month + 9) % 12) + 2) / 5 + i64::from(date.day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
 impl BytesMode {
     pub fn bytes_to_string<'a>(self, py: Python, bytes: &'a [u8]) -> PyResult<Cow<'a, str>> {
         match self {
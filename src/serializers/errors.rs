@@ -41,7 +41,18 @@ impl serde::ser::Error for PythonSerializerError {
 
 /// convert a serde serialization error into a `PyErr`
 pub(super) fn se_err_py_err(error: PythonSerializerError) -> PyErr {
-    let s = error.to_string();
+    serde_message_py_err(&error.to_string(), "JSON")
+}
+
+/// convert a MessagePack serialization error into a `PyErr`
+pub(super) fn msgpack_err_py_err(error: rmp_serde::encode::Error) -> PyErr {
+    match error {
+        rmp_serde::encode::Error::Syntax(msg) => serde_message_py_err(&msg, "MessagePack"),
+        error => PydanticSerializationError::new_err(format!("Error serializing to MessagePack: {error}")),
+    }
+}
+
+fn serde_message_py_err(s: &str, format_name: &str) -> PyErr {
     if let Some(msg) = s.strip_prefix(UNEXPECTED_TYPE_SER_MARKER) {
         if msg.is_empty() {
             PydanticSerializationUnexpectedValue::new_err(None)
@@ -51,7 +62,7 @@ pub(super) fn se_err_py_err(error: PythonSerializerError) -> PyErr {
     } else if let Some(msg) = s.strip_prefix(SERIALIZATION_ERR_MARKER) {
         PydanticSerializationError::new_err(msg.to_string())
     } else {
-        let msg = format!("Error serializing to JSON: {s}");
+        let msg = format!("Error serializing to {format_name}: {s}");
         PydanticSerializationError::new_err(msg)
     }
 }
@@ -1,5 +1,6 @@
 use std::cell::RefCell;
 use std::fmt;
+use std::str::FromStr;
 
 use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::intern;
@@ -8,10 +9,11 @@ use pyo3::types::{PyBool, PyString};
 
 use serde::ser::Error;
 
-use super::config::SerializationConfig;
+use super::config::{BytesMode, MsgpackBytesMode, SerializationConfig};
 use super::errors::{PydanticSerializationUnexpectedValue, UNEXPECTED_TYPE_SER_MARKER};
 use super::ob_type::ObTypeLookup;
 use crate::recursion_guard::ContainsRecursionState;
+use crate::recursion_guard::RecursionConfig;
 use crate::recursion_guard::RecursionError;
 use crate::recursion_guard::RecursionGuard;
 use crate::recursion_guard::RecursionState;
@@ -24,6 +26,7 @@ pub(crate) struct SerializationState {
     warnings: CollectWarnings,
     rec_guard: SerRecursionState,
     config: SerializationConfig,
+    msgpack_bytes_mode: Option<MsgpackBytesMode>,
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
@@ -66,17 +69,24 @@ impl DuckTypingSerMode {
 }
 
 impl SerializationState {
-    pub fn new(timedelta_mode: &str, bytes_mode: &str, inf_nan_mode: &str) -> PyResult<Self> {
+    pub fn new(timedelta_mode: &str, datetime_mode: &str, bytes_mode: &str, inf_nan_mode: &str) -> PyResult<Self> {
         let warnings = CollectWarnings::new(WarningsMode::None);
         let rec_guard = SerRecursionState::default();
-        let config = SerializationConfig::from_args(timedelta_mode, bytes_mode, inf_nan_mode)?;
+        let config = SerializationConfig::from_args(timedelta_mode, datetime_mode, bytes_mode, inf_nan_mode)?;
         Ok(Self {
             warnings,
             rec_guard,
             config,
+            msgpack_bytes_mode: None,
         })
     }
 
+    /// Set how `bytes` are written by `to_msgpack`
+    pub fn with_msgpack_bytes_mode(mut self, bytes_mode: &str) -> PyResult<Self> {
+        self.msgpack_bytes_mode = Some(MsgpackBytesMode::from_str(bytes_mode)?);
+        Ok(self)
+    }
+
     #[allow(clippy::too_many_arguments)]
     pub fn extra<'py>(
         &'py self,
@@ -90,7 +100,7 @@ impl SerializationState {
         duck_typing_ser_mode: DuckTypingSerMode,
         context: Option<&'py Bound<'_, PyAny>>,
     ) -> Extra<'py> {
-        Extra::new(
+        let mut extra = Extra::new(
             py,
             mode,
             by_alias,
@@ -105,7 +115,9 @@ impl SerializationState {
             fallback,
             duck_typing_ser_mode,
             context,
-        )
+        );
+        extra.msgpack_bytes_mode = self.msgpack_bytes_mode;
+        extra
     }
 
     pub fn final_check(&self, py: Python) -> PyResult<()> {
@@ -138,6 +150,8 @@ pub(crate) struct Extra<'a> {
     pub fallback: Option<&'a Bound<'a, PyAny>>,
     pub duck_typing_ser_mode: DuckTypingSerMode,
     pub context: Option<&'a Bound<'a, PyAny>>,
+    // how `bytes` are written by `to_msgpack`, `None` when serializing to JSON
+    pub msgpack_bytes_mode: Option<MsgpackBytesMode>,
 }
 
 impl<'a> Extra<'a> {
@@ -176,6 +190,7 @@ impl<'a> Extra<'a> {
             fallback,
             duck_typing_ser_mode,
             context,
+            msgpack_bytes_mode: None,
         }
     }
 
@@ -195,9 +210,34 @@ impl<'a> Extra<'a> {
         })
     }
 
+    /// The output from when `value` was last serialized by `def_ref_id`, with `cyclic_references='reuse'`
+    pub fn get_reused(&self, value: &Bound<'_, PyAny>, def_ref_id: usize) -> Option<PyObject> {
+        self.rec_guard.guard.borrow().get_reused(value.py(), value, def_ref_id)
+    }
+
+    /// Record the output of `value` so it can be reused, with `cyclic_references='reuse'`
+    pub fn set_reused(&self, value: &Bound<'_, PyAny>, def_ref_id: usize, output: &PyObject) {
+        self.rec_guard.guard.borrow_mut().set_reused(value, def_ref_id, output);
+    }
+
     pub fn serialize_infer<'py>(&'py self, value: &'py Bound<'py, PyAny>) -> super::infer::SerializeInfer<'py> {
         super::infer::SerializeInfer::new(value, None, None, self)
     }
+
+    /// Serialize `bytes` as a string with `bytes_mode`, unless `to_msgpack` writes them as binary data
+    /// or overrides the mode
+    pub fn serialize_bytes<S: serde::ser::Serializer>(
+        &self,
+        bytes: &[u8],
+        bytes_mode: BytesMode,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match self.msgpack_bytes_mode {
+            None => bytes_mode.serialize_bytes(bytes, serializer),
+            Some(MsgpackBytesMode::Binary) => serializer.serialize_bytes(bytes),
+            Some(MsgpackBytesMode::String(msgpack_bytes_mode)) => msgpack_bytes_mode.serialize_bytes(bytes, serializer),
+        }
+    }
 }
 
 #[derive(Clone, Copy, PartialEq, Eq)]
@@ -236,6 +276,7 @@ pub(crate) struct ExtraOwned {
     pub fallback: Option<PyObject>,
     duck_typing_ser_mode: DuckTypingSerMode,
     pub context: Option<PyObject>,
+    msgpack_bytes_mode: Option<MsgpackBytesMode>,
 }
 
 impl ExtraOwned {
@@ -257,6 +298,7 @@ impl ExtraOwned {
             fallback: extra.fallback.map(|model| model.clone().into()),
             duck_typing_ser_mode: extra.duck_typing_ser_mode,
             context: extra.context.map(|model| model.clone().into()),
+            msgpack_bytes_mode: extra.msgpack_bytes_mode,
         }
     }
 
@@ -279,6 +321,7 @@ impl ExtraOwned {
             fallback: self.fallback.as_ref().map(|m| m.bind(py)),
             duck_typing_ser_mode: self.duck_typing_ser_mode,
             context: self.context.as_ref().map(|m| m.bind(py)),
+            msgpack_bytes_mode: self.msgpack_bytes_mode,
         }
     }
 }
@@ -476,6 +519,14 @@ pub struct SerRecursionState {
     guard: RefCell<RecursionState>,
 }
 
+impl SerRecursionState {
+    pub fn new(config: RecursionConfig) -> Self {
+        Self {
+            guard: RefCell::new(RecursionState::new(config)),
+        }
+    }
+}
+
 impl ContainsRecursionState for &'_ Extra<'_> {
     fn access_recursion_state<R>(&mut self, f: impl FnOnce(&mut RecursionState) -> R) -> R {
         f(&mut self.rec_guard.guard.borrow_mut())
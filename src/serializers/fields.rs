@@ -105,15 +105,6 @@ pub struct GeneralFieldsSerializer {
     required_fields: usize,
 }
 
-macro_rules! option_length {
-    ($op_has_len:expr) => {
-        match $op_has_len {
-            Some(ref has_len) => has_len.len(),
-            None => 0,
-        }
-    };
-}
-
 impl GeneralFieldsSerializer {
     pub(super) fn new(
         fields: AHashMap<String, SerField>,
@@ -237,7 +228,6 @@ impl GeneralFieldsSerializer {
     pub(crate) fn main_serde_serialize<'py, S: serde::ser::Serializer>(
         &self,
         main_iter: impl Iterator<Item = PyResult<(Bound<'py, PyAny>, Bound<'py, PyAny>)>>,
-        expected_len: usize,
         serializer: S,
         include: Option<&Bound<'py, PyAny>>,
         exclude: Option<&Bound<'py, PyAny>>,
@@ -245,7 +235,8 @@ impl GeneralFieldsSerializer {
     ) -> Result<S::SerializeMap, S::Error> {
         // NOTE! As above, we maintain the order of the input dict assuming that's right
         // we don't both with `used_fields` here because on unions, `to_python(..., mode='json')` is used
-        let mut map = serializer.serialize_map(Some(expected_len))?;
+        // fields may be excluded or omitted, so the length isn't known up front
+        let mut map = serializer.serialize_map(None)?;
 
         for result in main_iter {
             let (key, value) = result.map_err(py_err_se_err)?;
@@ -317,10 +308,6 @@ impl GeneralFieldsSerializer {
         }
         Ok(())
     }
-
-    pub(crate) fn computed_field_count(&self) -> usize {
-        option_length!(self.computed_fields)
-    }
 }
 
 impl_py_gc_traverse!(GeneralFieldsSerializer {
@@ -425,15 +412,10 @@ impl TypeSerializer for GeneralFieldsSerializer {
                 return infer_serialize(value, serializer, include, exclude, &td_extra);
             }
         }
-        let expected_len = match self.mode {
-            FieldsMode::TypedDictAllow => main_dict.len() + self.computed_field_count(),
-            _ => self.fields.len() + option_length!(extra_dict) + self.computed_field_count(),
-        };
         // NOTE! As above, we maintain the order of the input dict assuming that's right
         // we don't both with `used_fields` here because on unions, `to_python(..., mode='json')` is used
         let mut map = self.main_serde_serialize(
             dict_items(&main_dict),
-            expected_len,
             serializer,
             include,
             exclude,
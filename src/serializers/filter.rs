@@ -59,6 +59,29 @@ fn map_negative_indices<'py>(
 
 type NextFilters<'py> = Option<(Option<Bound<'py, PyAny>>, Option<Bound<'py, PyAny>>)>;
 
+/// `len` if there's no call-time `include` or `exclude` which could omit items, `None` otherwise
+fn unfiltered_len(len: usize, include: Option<&Bound<'_, PyAny>>, exclude: Option<&Bound<'_, PyAny>>) -> Option<usize> {
+    let is_unset = |v: Option<&Bound<'_, PyAny>>| v.map_or(true, PyAnyMethods::is_none);
+    (is_unset(include) && is_unset(exclude)).then_some(len)
+}
+
+impl<T> SchemaFilter<T> {
+    /// The number of items serialized from a collection of `len` items, if it's known up front,
+    /// i.e. no items can be filtered out
+    pub fn serialized_len(
+        &self,
+        len: usize,
+        include: Option<&Bound<'_, PyAny>>,
+        exclude: Option<&Bound<'_, PyAny>>,
+    ) -> Option<usize> {
+        if self.include.is_none() && self.exclude.is_none() {
+            unfiltered_len(len, include, exclude)
+        } else {
+            None
+        }
+    }
+}
+
 impl SchemaFilter<usize> {
     pub fn from_schema(schema: &Bound<'_, PyDict>) -> PyResult<Self> {
         let py = schema.py();
@@ -264,6 +287,16 @@ impl AnyFilter {
         AnyFilter {}
     }
 
+    /// The number of items serialized from a collection of `len` items, if it's known up front
+    pub fn serialized_len(
+        &self,
+        len: usize,
+        include: Option<&Bound<'_, PyAny>>,
+        exclude: Option<&Bound<'_, PyAny>>,
+    ) -> Option<usize> {
+        unfiltered_len(len, include, exclude)
+    }
+
     pub fn key_filter<'py>(
         &self,
         key: &Bound<'_, PyAny>,
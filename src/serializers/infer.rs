@@ -102,6 +102,7 @@ pub(crate) fn infer_to_python_known(
             extra.exclude_defaults,
             extra.exclude_none,
             extra.round_trip,
+            &serializer.config,
             extra.rec_guard,
             extra.serialize_unknown,
             extra.fallback,
@@ -128,9 +129,12 @@ pub(crate) fn infer_to_python_known(
                 if (v.is_nan() || v.is_infinite()) && extra.config.inf_nan_mode == InfNanMode::Null {
                     return Ok(py.None().into_py(py));
                 }
-                v.into_py(py)
+                type_serializers::float::f64_to_json_python(py, v, extra.config.number_format)
+            }
+            ObType::Decimal => {
+                let text = type_serializers::decimal::decimal_json_text(value, extra.config.number_format)?;
+                extra.config.number_format.text_to_python(py, &text, true)
             }
-            ObType::Decimal => value.to_string().into_py(py),
             ObType::StrSubclass => value.downcast::<PyString>()?.to_str()?.into_py(py),
             ObType::Bytes => extra
                 .config
@@ -171,12 +175,18 @@ pub(crate) fn infer_to_python_known(
                 })?
             }
             ObType::Datetime => {
-                let iso_dt = super::type_serializers::datetime_etc::datetime_to_string(value.downcast()?)?;
-                iso_dt.into_py(py)
+                let py_dt = value.downcast()?;
+                match super::type_serializers::datetime_etc::datetime_to_epoch(py_dt, extra.config.datetime_mode)? {
+                    Some(timestamp) => timestamp.to_object(py),
+                    None => super::type_serializers::datetime_etc::datetime_to_string(py_dt)?.into_py(py),
+                }
             }
             ObType::Date => {
-                let iso_date = super::type_serializers::datetime_etc::date_to_string(value.downcast()?)?;
-                iso_date.into_py(py)
+                let py_date = value.downcast()?;
+                match super::type_serializers::datetime_etc::date_to_epoch(py_date, extra.config.datetime_mode)? {
+                    Some(timestamp) => timestamp.to_object(py),
+                    None => super::type_serializers::datetime_etc::date_to_string(py_date)?.into_py(py),
+                }
             }
             ObType::Time => {
                 let iso_time = super::type_serializers::datetime_etc::time_to_string(value.downcast()?)?;
@@ -237,7 +247,7 @@ pub(crate) fn infer_to_python_known(
                 let _ = new_dict.set_item("imag", dict.get_item("imag")?);
                 new_dict.into_py(py)
             }
-            ObType::Path => value.str()?.into_py(py),
+            ObType::Path | ObType::IpAddress | ObType::IpNetwork | ObType::IpInterface => value.str()?.into_py(py),
             ObType::Pattern => value.getattr(intern!(py, "pattern"))?.into_py(py),
             ObType::Unknown => {
                 if let Some(fallback) = extra.fallback {
@@ -398,8 +408,9 @@ pub(crate) fn infer_serialize_known<S: Serializer>(
     macro_rules! serialize_seq_filter {
         ($t:ty) => {{
             let py_seq = value.downcast::<$t>().map_err(py_err_se_err)?;
-            let mut seq = serializer.serialize_seq(Some(py_seq.len()))?;
             let filter = AnyFilter::new();
+            // MessagePack writes the length up front, so it's only given if no items can be filtered out
+            let mut seq = serializer.serialize_seq(filter.serialized_len(py_seq.len(), include, exclude))?;
             let len = value.len().ok();
 
             for (index, element) in py_seq.iter().enumerate() {
@@ -429,29 +440,38 @@ pub(crate) fn infer_serialize_known<S: Serializer>(
         }
         ObType::Float | ObType::FloatSubclass => {
             let v = value.extract::<f64>().map_err(py_err_se_err)?;
-            type_serializers::float::serialize_f64(v, serializer, extra.config.inf_nan_mode)
+            let number_format = extra.config.number_format;
+            type_serializers::float::serialize_f64(v, serializer, extra.config.inf_nan_mode, number_format)
+        }
+        ObType::Decimal => {
+            let number_format = extra.config.number_format;
+            let text = type_serializers::decimal::decimal_json_text(value, number_format).map_err(py_err_se_err)?;
+            number_format.serialize_text(&text, serializer, true)
         }
-        ObType::Decimal => value.to_string().serialize(serializer),
         ObType::Str | ObType::StrSubclass => {
             let py_str = value.downcast::<PyString>().map_err(py_err_se_err)?;
             super::type_serializers::string::serialize_py_str(py_str, serializer)
         }
         ObType::Bytes => {
             let py_bytes = value.downcast::<PyBytes>().map_err(py_err_se_err)?;
-            extra.config.bytes_mode.serialize_bytes(py_bytes.as_bytes(), serializer)
+            extra.serialize_bytes(py_bytes.as_bytes(), extra.config.bytes_mode, serializer)
         }
         ObType::Bytearray => {
             let py_byte_array = value.downcast::<PyByteArray>().map_err(py_err_se_err)?;
             // Safety: the GIL is held while serialize_bytes is running; it doesn't run
             // arbitrary Python code, so py_byte_array cannot be mutated.
-            extra
-                .config
-                .bytes_mode
-                .serialize_bytes(unsafe { py_byte_array.as_bytes() }, serializer)
+            extra.serialize_bytes(unsafe { py_byte_array.as_bytes() }, extra.config.bytes_mode, serializer)
         }
         ObType::Dict => {
             let dict = value.downcast::<PyDict>().map_err(py_err_se_err)?;
-            serialize_pairs_json(dict.iter().map(Ok), dict.len(), serializer, include, exclude, extra)
+            serialize_pairs_json(
+                dict.iter().map(Ok),
+                Some(dict.len()),
+                serializer,
+                include,
+                exclude,
+                extra,
+            )
         }
         ObType::List => serialize_seq_filter!(PyList),
         ObType::Tuple => serialize_seq_filter!(PyTuple),
@@ -459,13 +479,29 @@ pub(crate) fn infer_serialize_known<S: Serializer>(
         ObType::Frozenset => serialize_seq!(PyFrozenSet),
         ObType::Datetime => {
             let py_dt = value.downcast().map_err(py_err_se_err)?;
-            let iso_dt = super::type_serializers::datetime_etc::datetime_to_string(py_dt).map_err(py_err_se_err)?;
-            serializer.serialize_str(&iso_dt)
+            let timestamp = super::type_serializers::datetime_etc::datetime_to_epoch(py_dt, extra.config.datetime_mode)
+                .map_err(py_err_se_err)?;
+            match timestamp {
+                Some(timestamp) => timestamp.serialize(serializer),
+                None => {
+                    let iso_dt =
+                        super::type_serializers::datetime_etc::datetime_to_string(py_dt).map_err(py_err_se_err)?;
+                    serializer.serialize_str(&iso_dt)
+                }
+            }
         }
         ObType::Date => {
             let py_date = value.downcast().map_err(py_err_se_err)?;
-            let iso_date = super::type_serializers::datetime_etc::date_to_string(py_date).map_err(py_err_se_err)?;
-            serializer.serialize_str(&iso_date)
+            let timestamp = super::type_serializers::datetime_etc::date_to_epoch(py_date, extra.config.datetime_mode)
+                .map_err(py_err_se_err)?;
+            match timestamp {
+                Some(timestamp) => timestamp.serialize(serializer),
+                None => {
+                    let iso_date =
+                        super::type_serializers::datetime_etc::date_to_string(py_date).map_err(py_err_se_err)?;
+                    serializer.serialize_str(&iso_date)
+                }
+            }
         }
         ObType::Time => {
             let py_time = value.downcast().map_err(py_err_se_err)?;
@@ -493,7 +529,8 @@ pub(crate) fn infer_serialize_known<S: Serializer>(
                 .getattr(intern!(py, "__pydantic_serializer__"))
                 .map_err(py_err_se_err)?;
             let extracted_serializer: PyRef<SchemaSerializer> = py_serializer.extract().map_err(py_err_se_err)?;
-            let extra = extracted_serializer.build_extra(
+            let msgpack_bytes_mode = extra.msgpack_bytes_mode;
+            let mut extra = extracted_serializer.build_extra(
                 py,
                 extra.mode,
                 extra.by_alias,
@@ -502,19 +539,22 @@ pub(crate) fn infer_serialize_known<S: Serializer>(
                 extra.exclude_defaults,
                 extra.exclude_none,
                 extra.round_trip,
+                &extracted_serializer.config,
                 extra.rec_guard,
                 extra.serialize_unknown,
                 extra.fallback,
                 extra.duck_typing_ser_mode,
                 extra.context,
             );
+            extra.msgpack_bytes_mode = msgpack_bytes_mode;
             let pydantic_serializer =
                 PydanticSerializer::new(value, &extracted_serializer.serializer, include, exclude, &extra);
             pydantic_serializer.serialize(serializer)
         }
         ObType::Dataclass => {
-            let (pairs_iter, fields_dict) = any_dataclass_iter(value).map_err(py_err_se_err)?;
-            serialize_pairs_json(pairs_iter, fields_dict.len(), serializer, include, exclude, extra)
+            let (pairs_iter, _) = any_dataclass_iter(value).map_err(py_err_se_err)?;
+            // the dataclass fields dict can include pseudo-fields which aren't serialized
+            serialize_pairs_json(pairs_iter, None, serializer, include, exclude, extra)
         }
         ObType::Uuid => {
             let uuid = super::type_serializers::uuid::uuid_to_string(value).map_err(py_err_se_err)?;
@@ -541,7 +581,7 @@ pub(crate) fn infer_serialize_known<S: Serializer>(
             }
             seq.end()
         }
-        ObType::Path => {
+        ObType::Path | ObType::IpAddress | ObType::IpNetwork | ObType::IpInterface => {
             let s: PyBackedStr = value
                 .str()
                 .and_then(|value_str| value_str.extract())
@@ -609,11 +649,18 @@ pub(crate) fn infer_json_key_known<'a>(
             let v = key.extract::<f64>()?;
             if (v.is_nan() || v.is_infinite()) && extra.config.inf_nan_mode == InfNanMode::Null {
                 super::type_serializers::simple::none_json_key()
+            } else if let Some(text) =
+                type_serializers::float::format_f64(v, extra.config.number_format).filter(|_| v.is_finite())
+            {
+                Ok(Cow::Owned(text))
             } else {
                 super::type_serializers::simple::to_str_json_key(key)
             }
         }
-        ObType::Decimal => Ok(Cow::Owned(key.to_string())),
+        ObType::Decimal => Ok(Cow::Owned(type_serializers::decimal::decimal_json_text(
+            key,
+            extra.config.number_format,
+        )?)),
         ObType::Bool => super::type_serializers::simple::bool_json_key(key),
         ObType::Str | ObType::StrSubclass => {
             let py_str = key.downcast::<PyString>()?;
@@ -638,12 +685,18 @@ pub(crate) fn infer_json_key_known<'a>(
                 .map(|cow| Cow::Owned(cow.into_owned()))
         }
         ObType::Datetime => {
-            let iso_dt = super::type_serializers::datetime_etc::datetime_to_string(key.downcast()?)?;
-            Ok(Cow::Owned(iso_dt))
+            let py_dt = key.downcast()?;
+            match super::type_serializers::datetime_etc::datetime_to_epoch(py_dt, extra.config.datetime_mode)? {
+                Some(timestamp) => Ok(Cow::Owned(timestamp.to_string())),
+                None => super::type_serializers::datetime_etc::datetime_to_string(py_dt).map(Cow::Owned),
+            }
         }
         ObType::Date => {
-            let iso_date = super::type_serializers::datetime_etc::date_to_string(key.downcast()?)?;
-            Ok(Cow::Owned(iso_date))
+            let py_date = key.downcast()?;
+            match super::type_serializers::datetime_etc::date_to_epoch(py_date, extra.config.datetime_mode)? {
+                Some(timestamp) => Ok(Cow::Owned(timestamp.to_string())),
+                None => super::type_serializers::datetime_etc::date_to_string(py_date).map(Cow::Owned),
+            }
         }
         ObType::Time => {
             let iso_time = super::type_serializers::datetime_etc::time_to_string(key.downcast()?)?;
@@ -685,7 +738,7 @@ pub(crate) fn infer_json_key_known<'a>(
             let k = key.getattr(intern!(key.py(), "value"))?;
             infer_json_key(&k, extra).map(|cow| Cow::Owned(cow.into_owned()))
         }
-        ObType::Path => {
+        ObType::Path | ObType::IpAddress | ObType::IpNetwork | ObType::IpInterface => {
             // FIXME it would be nice to have a "PyCow" which carries ownership of the Python type too
             Ok(Cow::Owned(key.str()?.to_string_lossy().into_owned()))
         }
@@ -733,14 +786,16 @@ fn serialize_pairs_python<'py>(
 
 fn serialize_pairs_json<'py, S: Serializer>(
     pairs_iter: impl Iterator<Item = PyResult<(Bound<'py, PyAny>, Bound<'py, PyAny>)>>,
-    iter_size: usize,
+    iter_size: Option<usize>,
     serializer: S,
     include: Option<&Bound<'_, PyAny>>,
     exclude: Option<&Bound<'_, PyAny>>,
     extra: &Extra,
 ) -> Result<S::Ok, S::Error> {
-    let mut map = serializer.serialize_map(Some(iter_size))?;
     let filter = AnyFilter::new();
+    // MessagePack writes the length up front, so it's only given if no entries can be filtered out
+    let map_size = iter_size.and_then(|size| filter.serialized_len(size, include, exclude));
+    let mut map = serializer.serialize_map(map_size)?;
 
     for result in pairs_iter {
         let (key, value) = result.map_err(py_err_se_err)?;
@@ -1,4 +1,5 @@
 use std::fmt::Debug;
+use std::str::FromStr;
 use std::sync::atomic::{AtomicUsize, Ordering};
 
 use pyo3::prelude::*;
@@ -9,12 +10,12 @@ use crate::definitions::{Definitions, DefinitionsBuilder};
 use crate::py_gc::PyGcTraverse;
 
 pub(crate) use config::BytesMode;
-use config::SerializationConfig;
+use config::{MsgpackBytesMode, SerializationConfig};
 pub use errors::{PydanticSerializationError, PydanticSerializationUnexpectedValue};
 use extra::{CollectWarnings, SerRecursionState, WarningsMode};
 pub(crate) use extra::{DuckTypingSerMode, Extra, SerMode, SerializationState};
 pub use shared::CombinedSerializer;
-use shared::{to_json_bytes, BuildSerializer, TypeSerializer};
+use shared::{to_json_bytes, to_msgpack_bytes, BuildSerializer, JsonOptions, TypeSerializer};
 
 mod computed_fields;
 mod config;
@@ -59,6 +60,7 @@ impl SchemaSerializer {
         exclude_defaults: bool,
         exclude_none: bool,
         round_trip: bool,
+        config: &'b SerializationConfig,
         rec_guard: &'a SerRecursionState,
         serialize_unknown: bool,
         fallback: Option<&'a Bound<'a, PyAny>>,
@@ -74,7 +76,7 @@ impl SchemaSerializer {
             exclude_defaults,
             exclude_none,
             round_trip,
-            &self.config,
+            config,
             rec_guard,
             serialize_unknown,
             fallback,
@@ -106,8 +108,8 @@ impl SchemaSerializer {
 
     #[allow(clippy::too_many_arguments)]
     #[pyo3(signature = (value, *, mode = None, include = None, exclude = None, by_alias = true,
-        exclude_unset = false, exclude_defaults = false, exclude_none = false, round_trip = false, warnings = WarningsArg::Bool(true),
-        fallback = None, serialize_as_any = false, context = None))]
+        exclude_unset = false, exclude_defaults = false, exclude_none = false, round_trip = false, datetime_mode = None,
+        warnings = WarningsArg::Bool(true), fallback = None, serialize_as_any = false, context = None))]
     pub fn to_python(
         &self,
         py: Python,
@@ -120,6 +122,7 @@ impl SchemaSerializer {
         exclude_defaults: bool,
         exclude_none: bool,
         round_trip: bool,
+        datetime_mode: Option<&str>,
         warnings: WarningsArg,
         fallback: Option<&Bound<'_, PyAny>>,
         serialize_as_any: bool,
@@ -131,7 +134,8 @@ impl SchemaSerializer {
             WarningsArg::Literal(mode) => mode,
         };
         let warnings = CollectWarnings::new(warnings_mode);
-        let rec_guard = SerRecursionState::default();
+        let rec_guard = SerRecursionState::new(self.config.recursion);
+        let config = self.config.with_datetime_mode(datetime_mode)?;
         let duck_typing_ser_mode = DuckTypingSerMode::from_bool(serialize_as_any);
         let extra = self.build_extra(
             py,
@@ -142,6 +146,7 @@ impl SchemaSerializer {
             exclude_defaults,
             exclude_none,
             round_trip,
+            &config,
             &rec_guard,
             false,
             fallback,
@@ -154,14 +159,18 @@ impl SchemaSerializer {
     }
 
     #[allow(clippy::too_many_arguments)]
-    #[pyo3(signature = (value, *, indent = None, include = None, exclude = None, by_alias = true,
-        exclude_unset = false, exclude_defaults = false, exclude_none = false, round_trip = false, warnings = WarningsArg::Bool(true),
+    #[pyo3(signature = (value, *, indent = None, sort_keys = false, ensure_ascii = false, canonical = false,
+        include = None, exclude = None, by_alias = true, exclude_unset = false, exclude_defaults = false,
+        exclude_none = false, round_trip = false, datetime_mode = None, warnings = WarningsArg::Bool(true),
         fallback = None, serialize_as_any = false, context = None))]
     pub fn to_json(
         &self,
         py: Python,
         value: &Bound<'_, PyAny>,
         indent: Option<usize>,
+        sort_keys: bool,
+        ensure_ascii: bool,
+        canonical: bool,
         include: Option<&Bound<'_, PyAny>>,
         exclude: Option<&Bound<'_, PyAny>>,
         by_alias: bool,
@@ -169,6 +178,7 @@ impl SchemaSerializer {
         exclude_defaults: bool,
         exclude_none: bool,
         round_trip: bool,
+        datetime_mode: Option<&str>,
         warnings: WarningsArg,
         fallback: Option<&Bound<'_, PyAny>>,
         serialize_as_any: bool,
@@ -179,7 +189,8 @@ impl SchemaSerializer {
             WarningsArg::Literal(mode) => mode,
         };
         let warnings = CollectWarnings::new(warnings_mode);
-        let rec_guard = SerRecursionState::default();
+        let rec_guard = SerRecursionState::new(self.config.recursion);
+        let config = self.config.with_datetime_mode(datetime_mode)?;
         let duck_typing_ser_mode = DuckTypingSerMode::from_bool(serialize_as_any);
         let extra = self.build_extra(
             py,
@@ -190,6 +201,7 @@ impl SchemaSerializer {
             exclude_defaults,
             exclude_none,
             round_trip,
+            &config,
             &rec_guard,
             false,
             fallback,
@@ -202,7 +214,12 @@ impl SchemaSerializer {
             include,
             exclude,
             &extra,
-            indent,
+            JsonOptions {
+                indent,
+                sort_keys,
+                ensure_ascii,
+                canonical,
+            },
             self.expected_json_size.load(Ordering::Relaxed),
         )?;
 
@@ -213,6 +230,69 @@ impl SchemaSerializer {
         Ok(py_bytes.into())
     }
 
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(signature = (value, *, include = None, exclude = None, by_alias = true, exclude_unset = false,
+        exclude_defaults = false, exclude_none = false, round_trip = false, datetime_mode = None, bytes_mode = None,
+        warnings = WarningsArg::Bool(true), fallback = None, serialize_as_any = false, context = None))]
+    pub fn to_msgpack(
+        &self,
+        py: Python,
+        value: &Bound<'_, PyAny>,
+        include: Option<&Bound<'_, PyAny>>,
+        exclude: Option<&Bound<'_, PyAny>>,
+        by_alias: bool,
+        exclude_unset: bool,
+        exclude_defaults: bool,
+        exclude_none: bool,
+        round_trip: bool,
+        datetime_mode: Option<&str>,
+        bytes_mode: Option<&str>,
+        warnings: WarningsArg,
+        fallback: Option<&Bound<'_, PyAny>>,
+        serialize_as_any: bool,
+        context: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<PyObject> {
+        let warnings_mode = match warnings {
+            WarningsArg::Bool(b) => b.into(),
+            WarningsArg::Literal(mode) => mode,
+        };
+        let warnings = CollectWarnings::new(warnings_mode);
+        let rec_guard = SerRecursionState::new(self.config.recursion);
+        let config = self.config.with_datetime_mode(datetime_mode)?;
+        let duck_typing_ser_mode = DuckTypingSerMode::from_bool(serialize_as_any);
+        // MessagePack output has the same data model as JSON, so JSON mode is used for all values
+        let mut extra = self.build_extra(
+            py,
+            &SerMode::Json,
+            by_alias,
+            &warnings,
+            exclude_unset,
+            exclude_defaults,
+            exclude_none,
+            round_trip,
+            &config,
+            &rec_guard,
+            false,
+            fallback,
+            duck_typing_ser_mode,
+            context,
+        );
+        extra.msgpack_bytes_mode = Some(bytes_mode.map_or(Ok(MsgpackBytesMode::Binary), MsgpackBytesMode::from_str)?);
+        let bytes = to_msgpack_bytes(
+            value,
+            &self.serializer,
+            include,
+            exclude,
+            &extra,
+            self.expected_json_size.load(Ordering::Relaxed),
+        )?;
+
+        warnings.final_check(py)?;
+
+        let py_bytes = PyBytes::new_bound(py, &bytes);
+        Ok(py_bytes.into())
+    }
+
     pub fn __reduce__(slf: &Bound<Self>) -> PyResult<(PyObject, (PyObject, PyObject))> {
         // Enables support for `pickle` serialization.
         let py = slf.py();
@@ -241,20 +321,79 @@ impl SchemaSerializer {
 
 #[allow(clippy::too_many_arguments)]
 #[pyfunction]
-#[pyo3(signature = (value, *, indent = None, include = None, exclude = None, by_alias = true,
-    exclude_none = false, round_trip = false, timedelta_mode = "iso8601", bytes_mode = "utf8",
-    inf_nan_mode = "constants", serialize_unknown = false, fallback = None, serialize_as_any = false,
-    context = None))]
+#[pyo3(signature = (value, *, indent = None, sort_keys = false, ensure_ascii = false, canonical = false,
+    include = None, exclude = None, by_alias = true, exclude_none = false, round_trip = false,
+    timedelta_mode = "iso8601", datetime_mode = "iso8601", bytes_mode = "utf8", inf_nan_mode = "constants", serialize_unknown = false,
+    fallback = None, serialize_as_any = false, context = None))]
 pub fn to_json(
     py: Python,
     value: &Bound<'_, PyAny>,
     indent: Option<usize>,
+    sort_keys: bool,
+    ensure_ascii: bool,
+    canonical: bool,
+    include: Option<&Bound<'_, PyAny>>,
+    exclude: Option<&Bound<'_, PyAny>>,
+    by_alias: bool,
+    exclude_none: bool,
+    round_trip: bool,
+    timedelta_mode: &str,
+    datetime_mode: &str,
+    bytes_mode: &str,
+    inf_nan_mode: &str,
+    serialize_unknown: bool,
+    fallback: Option<&Bound<'_, PyAny>>,
+    serialize_as_any: bool,
+    context: Option<&Bound<'_, PyAny>>,
+) -> PyResult<PyObject> {
+    let state = SerializationState::new(timedelta_mode, datetime_mode, bytes_mode, inf_nan_mode)?;
+    let duck_typing_ser_mode = DuckTypingSerMode::from_bool(serialize_as_any);
+    let extra = state.extra(
+        py,
+        &SerMode::Json,
+        by_alias,
+        exclude_none,
+        round_trip,
+        serialize_unknown,
+        fallback,
+        duck_typing_ser_mode,
+        context,
+    );
+    let serializer = type_serializers::any::AnySerializer.into();
+    let bytes = to_json_bytes(
+        value,
+        &serializer,
+        include,
+        exclude,
+        &extra,
+        JsonOptions {
+            indent,
+            sort_keys,
+            ensure_ascii,
+            canonical,
+        },
+        1024,
+    )?;
+    state.final_check(py)?;
+    let py_bytes = PyBytes::new_bound(py, &bytes);
+    Ok(py_bytes.into())
+}
+
+#[allow(clippy::too_many_arguments)]
+#[pyfunction]
+#[pyo3(signature = (value, *, include = None, exclude = None, by_alias = true, exclude_none = false, round_trip = false,
+    timedelta_mode = "iso8601", datetime_mode = "iso8601", bytes_mode = "binary", inf_nan_mode = "constants", serialize_unknown = false,
+    fallback = None, serialize_as_any = false, context = None))]
+pub fn to_msgpack(
+    py: Python,
+    value: &Bound<'_, PyAny>,
     include: Option<&Bound<'_, PyAny>>,
     exclude: Option<&Bound<'_, PyAny>>,
     by_alias: bool,
     exclude_none: bool,
     round_trip: bool,
     timedelta_mode: &str,
+    datetime_mode: &str,
     bytes_mode: &str,
     inf_nan_mode: &str,
     serialize_unknown: bool,
@@ -262,7 +401,8 @@ pub fn to_json(
     serialize_as_any: bool,
     context: Option<&Bound<'_, PyAny>>,
 ) -> PyResult<PyObject> {
-    let state = SerializationState::new(timedelta_mode, bytes_mode, inf_nan_mode)?;
+    let state = SerializationState::new(timedelta_mode, datetime_mode, "utf8", inf_nan_mode)?
+        .with_msgpack_bytes_mode(bytes_mode)?;
     let duck_typing_ser_mode = DuckTypingSerMode::from_bool(serialize_as_any);
     let extra = state.extra(
         py,
@@ -276,7 +416,7 @@ pub fn to_json(
         context,
     );
     let serializer = type_serializers::any::AnySerializer.into();
-    let bytes = to_json_bytes(value, &serializer, include, exclude, &extra, indent, 1024)?;
+    let bytes = to_msgpack_bytes(value, &serializer, include, exclude, &extra, 1024)?;
     state.final_check(py)?;
     let py_bytes = PyBytes::new_bound(py, &bytes);
     Ok(py_bytes.into())
@@ -285,7 +425,7 @@ pub fn to_json(
 #[allow(clippy::too_many_arguments)]
 #[pyfunction]
 #[pyo3(signature = (value, *, include = None, exclude = None, by_alias = true, exclude_none = false, round_trip = false,
-    timedelta_mode = "iso8601", bytes_mode = "utf8", inf_nan_mode = "constants", serialize_unknown = false, fallback = None,
+    timedelta_mode = "iso8601", datetime_mode = "iso8601", bytes_mode = "utf8", inf_nan_mode = "constants", serialize_unknown = false, fallback = None,
     serialize_as_any = false, context = None))]
 pub fn to_jsonable_python(
     py: Python,
@@ -296,6 +436,7 @@ pub fn to_jsonable_python(
     exclude_none: bool,
     round_trip: bool,
     timedelta_mode: &str,
+    datetime_mode: &str,
     bytes_mode: &str,
     inf_nan_mode: &str,
     serialize_unknown: bool,
@@ -303,7 +444,7 @@ pub fn to_jsonable_python(
     serialize_as_any: bool,
     context: Option<&Bound<'_, PyAny>>,
 ) -> PyResult<PyObject> {
-    let state = SerializationState::new(timedelta_mode, bytes_mode, inf_nan_mode)?;
+    let state = SerializationState::new(timedelta_mode, datetime_mode, bytes_mode, inf_nan_mode)?;
     let duck_typing_ser_mode = DuckTypingSerMode::from_bool(serialize_as_any);
     let extra = state.extra(
         py,
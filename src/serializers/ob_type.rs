@@ -48,6 +48,13 @@ pub struct ObTypeLookup {
     pattern_object: PyObject,
     // uuid type
     uuid_object: PyObject,
+    // ipaddress types
+    ipv4_address: PyObject,
+    ipv6_address: PyObject,
+    ipv4_network: PyObject,
+    ipv6_network: PyObject,
+    ipv4_interface: PyObject,
+    ipv6_interface: PyObject,
     complex: usize,
 }
 
@@ -102,6 +109,12 @@ impl ObTypeLookup {
                 .to_object(py),
             pattern_object: py.import_bound("re").unwrap().getattr("Pattern").unwrap().to_object(py),
             uuid_object: py.import_bound("uuid").unwrap().getattr("UUID").unwrap().to_object(py),
+            ipv4_address: ip_type(py, "IPv4Address"),
+            ipv6_address: ip_type(py, "IPv6Address"),
+            ipv4_network: ip_type(py, "IPv4Network"),
+            ipv6_network: ip_type(py, "IPv6Network"),
+            ipv4_interface: ip_type(py, "IPv4Interface"),
+            ipv6_interface: ip_type(py, "IPv6Interface"),
             complex: PyComplex::type_object_raw(py) as usize,
         }
     }
@@ -172,6 +185,9 @@ impl ObTypeLookup {
             ObType::Path => self.path_object.as_ptr() as usize == ob_type,
             ObType::Pattern => self.path_object.as_ptr() as usize == ob_type,
             ObType::Uuid => self.uuid_object.as_ptr() as usize == ob_type,
+            ObType::IpAddress => self.is_ip_type(&self.ipv4_address, &self.ipv6_address, ob_type),
+            ObType::IpNetwork => self.is_ip_type(&self.ipv4_network, &self.ipv6_network, ob_type),
+            ObType::IpInterface => self.is_ip_type(&self.ipv4_interface, &self.ipv6_interface, ob_type),
             ObType::Unknown => false,
             ObType::Complex => self.complex == ob_type,
         };
@@ -254,6 +270,12 @@ impl ObTypeLookup {
             ObType::MultiHostUrl
         } else if ob_type == self.uuid_object.as_ptr() as usize {
             ObType::Uuid
+        } else if self.is_ip_type(&self.ipv4_address, &self.ipv6_address, ob_type) {
+            ObType::IpAddress
+        } else if self.is_ip_type(&self.ipv4_network, &self.ipv6_network, ob_type) {
+            ObType::IpNetwork
+        } else if self.is_ip_type(&self.ipv4_interface, &self.ipv6_interface, ob_type) {
+            ObType::IpInterface
         } else if is_pydantic_serializable(op_value) {
             ObType::PydanticSerializable
         } else if is_dataclass(op_value) {
@@ -277,6 +299,15 @@ impl ObTypeLookup {
         }
     }
 
+    fn is_ip_type(&self, v4: &PyObject, v6: &PyObject, ob_type: usize) -> bool {
+        v4.as_ptr() as usize == ob_type || v6.as_ptr() as usize == ob_type
+    }
+
+    fn is_ip_instance(&self, value: &Bound<'_, PyAny>, v4: &PyObject, v6: &PyObject) -> bool {
+        let py = value.py();
+        value.is_instance(v4.bind(py)).unwrap_or(false) || value.is_instance(v6.bind(py)).unwrap_or(false)
+    }
+
     fn is_enum(&self, op_value: Option<&Bound<'_, PyAny>>, py_type: &Bound<'_, PyType>) -> bool {
         // only test on the type itself, not base types
         if op_value.is_some() {
@@ -338,6 +369,13 @@ impl ObTypeLookup {
             ObType::Decimal
         } else if value.is_instance(self.uuid_object.bind(py)).unwrap_or(false) {
             ObType::Uuid
+        } else if self.is_ip_instance(value, &self.ipv4_interface, &self.ipv6_interface) {
+            // interfaces are subclasses of addresses, so must be checked first
+            ObType::IpInterface
+        } else if self.is_ip_instance(value, &self.ipv4_address, &self.ipv6_address) {
+            ObType::IpAddress
+        } else if self.is_ip_instance(value, &self.ipv4_network, &self.ipv6_network) {
+            ObType::IpNetwork
         } else if value.is_instance(self.enum_object.bind(py)).unwrap_or(false) {
             ObType::Enum
         } else if value.is_instance(self.generator_object.bind(py)).unwrap_or(false) {
@@ -352,6 +390,14 @@ impl ObTypeLookup {
     }
 }
 
+fn ip_type(py: Python, name: &str) -> PyObject {
+    py.import_bound("ipaddress")
+        .unwrap()
+        .getattr(name)
+        .unwrap()
+        .to_object(py)
+}
+
 fn is_dataclass(op_value: Option<&Bound<'_, PyAny>>) -> bool {
     if let Some(value) = op_value {
         value
@@ -427,6 +473,10 @@ pub enum ObType {
     Pattern,
     // Uuid
     Uuid,
+    // ipaddress types, both IPv4 and IPv6
+    IpAddress,
+    IpNetwork,
+    IpInterface,
     // unknown type
     Unknown,
     Complex,
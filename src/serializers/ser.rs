@@ -1,4 +1,4 @@
-use std::{io, num::FpCategory};
+use std::{fmt, io, num::FpCategory};
 
 use serde::{ser::Impossible, serde_if_integer128, Serialize, Serializer};
 use serde_json::ser::{CompactFormatter, Formatter, PrettyFormatter, State};
@@ -19,6 +19,9 @@ const TOKEN: &str = "$serde_json::private::Number";
 pub struct PythonSerializer<W, F = CompactFormatter> {
     writer: W,
     formatter: F,
+    sort_keys: bool,
+    utf16_key_order: bool,
+    ensure_ascii: bool,
 }
 
 impl<W> PythonSerializer<W>
@@ -52,7 +55,34 @@ where
     /// specified.
     #[inline]
     pub fn with_formatter(writer: W, formatter: F) -> Self {
-        PythonSerializer { writer, formatter }
+        PythonSerializer {
+            writer,
+            formatter,
+            sort_keys: false,
+            utf16_key_order: false,
+            ensure_ascii: false,
+        }
+    }
+
+    /// Write the entries of objects sorted by key, rather than in the order they're serialized.
+    #[inline]
+    pub fn with_sort_keys(mut self, sort_keys: bool) -> Self {
+        self.sort_keys = sort_keys;
+        self
+    }
+
+    /// Compare keys by their UTF-16 code units when sorting, as RFC 8785 requires, rather than by code points.
+    #[inline]
+    pub fn with_utf16_key_order(mut self, utf16_key_order: bool) -> Self {
+        self.utf16_key_order = utf16_key_order;
+        self
+    }
+
+    /// Escape all non-ASCII characters in strings, as `json.dumps(..., ensure_ascii=True)` does.
+    #[inline]
+    pub fn with_ensure_ascii(mut self, ensure_ascii: bool) -> Self {
+        self.ensure_ascii = ensure_ascii;
+        self
     }
 
     /// Unwrap the `Writer` from the `Serializer`.
@@ -65,7 +95,7 @@ where
 impl<'a, W, F> Serializer for &'a mut PythonSerializer<W, F>
 where
     W: io::Write,
-    F: Formatter,
+    F: Formatter + Clone,
 {
     type Ok = ();
     type Error = PythonSerializerError;
@@ -194,7 +224,7 @@ where
     }
 
     fn serialize_str(self, value: &str) -> Result<Self::Ok> {
-        format_escaped_str(&mut self.writer, &mut self.formatter, value)
+        format_escaped_str(&mut self.writer, &mut self.formatter, value, self.ensure_ascii)
             .map_err(|e| PythonSerializerError { message: e.to_string() })
     }
 
@@ -345,6 +375,11 @@ where
                 ser: self,
                 state: State::Empty,
             })
+        } else if self.sort_keys {
+            Ok(Compound::SortedMap {
+                ser: self,
+                entries: Vec::with_capacity(len.unwrap_or_default()),
+            })
         } else {
             Ok(Compound::Map {
                 ser: self,
@@ -391,7 +426,7 @@ where
 impl<'a, W, F> serde::ser::SerializeSeq for Compound<'a, W, F>
 where
     W: io::Write,
-    F: Formatter,
+    F: Formatter + Clone,
 {
     type Ok = ();
     type Error = PythonSerializerError;
@@ -415,7 +450,7 @@ where
                     .map_err(|e| PythonSerializerError { message: e.to_string() }));
                 Ok(())
             }
-            Compound::Number { .. } => unreachable!(),
+            Compound::Number { .. } | Compound::SortedMap { .. } => unreachable!(),
         }
     }
 
@@ -431,7 +466,7 @@ where
                 }
                 Ok(())
             }
-            Compound::Number { .. } => unreachable!(),
+            Compound::Number { .. } | Compound::SortedMap { .. } => unreachable!(),
         }
     }
 }
@@ -439,7 +474,7 @@ where
 impl<'a, W, F> serde::ser::SerializeTuple for Compound<'a, W, F>
 where
     W: io::Write,
-    F: Formatter,
+    F: Formatter + Clone,
 {
     type Ok = ();
     type Error = PythonSerializerError;
@@ -461,7 +496,7 @@ where
 impl<'a, W, F> serde::ser::SerializeTupleStruct for Compound<'a, W, F>
 where
     W: io::Write,
-    F: Formatter,
+    F: Formatter + Clone,
 {
     type Ok = ();
     type Error = PythonSerializerError;
@@ -483,7 +518,7 @@ where
 impl<'a, W, F> serde::ser::SerializeTupleVariant for Compound<'a, W, F>
 where
     W: io::Write,
-    F: Formatter,
+    F: Formatter + Clone,
 {
     type Ok = ();
     type Error = PythonSerializerError;
@@ -517,7 +552,7 @@ where
                     .map_err(|e| PythonSerializerError { message: e.to_string() }));
                 Ok(())
             }
-            Compound::Number { .. } => unreachable!(),
+            Compound::Number { .. } | Compound::SortedMap { .. } => unreachable!(),
         }
     }
 }
@@ -525,7 +560,7 @@ where
 impl<'a, W, F> serde::ser::SerializeMap for Compound<'a, W, F>
 where
     W: io::Write,
-    F: Formatter,
+    F: Formatter + Clone,
 {
     type Ok = ();
     type Error = PythonSerializerError;
@@ -551,6 +586,15 @@ where
                     .map_err(|e| PythonSerializerError { message: e.to_string() }));
                 Ok(())
             }
+            Compound::SortedMap { entries, .. } => {
+                // capture the unescaped key so entries are sorted by the key itself, not its JSON representation
+                let mut key_ser = PythonSerializer::with_formatter(Vec::new(), RawKeyFormatter);
+                tri!(key.serialize(MapKeySerializer { ser: &mut key_ser }));
+                let key = tri!(String::from_utf8(key_ser.into_inner())
+                    .map_err(|e| PythonSerializerError { message: e.to_string() }));
+                entries.push((key, Vec::new()));
+                Ok(())
+            }
             Compound::Number { .. } => unreachable!(),
         }
     }
@@ -573,6 +617,21 @@ where
                     .map_err(|e| PythonSerializerError { message: e.to_string() }));
                 Ok(())
             }
+            Compound::SortedMap { ser, entries } => {
+                // the formatter is cloned so the buffered value is indented to the current depth
+                let mut value_ser = PythonSerializer {
+                    writer: Vec::new(),
+                    formatter: ser.formatter.clone(),
+                    sort_keys: ser.sort_keys,
+                    utf16_key_order: ser.utf16_key_order,
+                    ensure_ascii: ser.ensure_ascii,
+                };
+                tri!(value.serialize(&mut value_ser));
+                if let Some((_, entry_value)) = entries.last_mut() {
+                    *entry_value = value_ser.into_inner();
+                }
+                Ok(())
+            }
             Compound::Number { .. } => unreachable!(),
         }
     }
@@ -590,6 +649,7 @@ where
                 }
                 Ok(())
             }
+            Compound::SortedMap { ser, entries } => write_sorted_entries(ser, entries),
             Compound::Number { .. } => unreachable!(),
         }
     }
@@ -598,7 +658,7 @@ where
 impl<'a, W, F> serde::ser::SerializeStruct for Compound<'a, W, F>
 where
     W: io::Write,
-    F: Formatter,
+    F: Formatter + Clone,
 {
     type Ok = ();
     type Error = PythonSerializerError;
@@ -609,7 +669,9 @@ where
         T: ?Sized + Serialize,
     {
         match self {
-            Compound::Map { .. } => serde::ser::SerializeMap::serialize_entry(self, key, value),
+            Compound::Map { .. } | Compound::SortedMap { .. } => {
+                serde::ser::SerializeMap::serialize_entry(self, key, value)
+            }
             Compound::Number { ser, .. } => {
                 if key == TOKEN {
                     tri!(value.serialize(NumberStrEmitter(ser)));
@@ -624,7 +686,7 @@ where
     #[inline]
     fn end(self) -> Result<()> {
         match self {
-            Compound::Map { .. } => serde::ser::SerializeMap::end(self),
+            Compound::Map { .. } | Compound::SortedMap { .. } => serde::ser::SerializeMap::end(self),
             Compound::Number { .. } => Ok(()),
         }
     }
@@ -633,7 +695,7 @@ where
 impl<'a, W, F> serde::ser::SerializeStructVariant for Compound<'a, W, F>
 where
     W: io::Write,
-    F: Formatter,
+    F: Formatter + Clone,
 {
     type Ok = ();
     type Error = PythonSerializerError;
@@ -644,7 +706,9 @@ where
         T: ?Sized + Serialize,
     {
         match *self {
-            Compound::Map { .. } => serde::ser::SerializeStruct::serialize_field(self, key, value),
+            Compound::Map { .. } | Compound::SortedMap { .. } => {
+                serde::ser::SerializeStruct::serialize_field(self, key, value)
+            }
             Compound::Number { .. } => unreachable!(),
         }
     }
@@ -670,21 +734,120 @@ where
                     .map_err(|e| PythonSerializerError { message: e.to_string() }));
                 Ok(())
             }
+            Compound::SortedMap { ser, entries } => {
+                tri!(write_sorted_entries(ser, entries));
+                tri!(ser
+                    .formatter
+                    .end_object_value(&mut ser.writer)
+                    .map_err(|e| PythonSerializerError { message: e.to_string() }));
+                tri!(ser
+                    .formatter
+                    .end_object(&mut ser.writer)
+                    .map_err(|e| PythonSerializerError { message: e.to_string() }));
+                Ok(())
+            }
             Compound::Number { .. } => unreachable!(),
         }
     }
 }
 
-fn format_escaped_str<W, F>(writer: &mut W, formatter: &mut F, value: &str) -> io::Result<()>
+/// Write the buffered entries of an object in key order, then close the object.
+fn write_sorted_entries<W, F>(ser: &mut PythonSerializer<W, F>, mut entries: Vec<(String, Vec<u8>)>) -> Result<()>
+where
+    W: io::Write,
+    F: Formatter,
+{
+    // the sort is stable, so duplicate keys keep the order they were serialized in
+    if ser.utf16_key_order {
+        entries.sort_by(|(a, _), (b, _)| a.encode_utf16().cmp(b.encode_utf16()));
+    } else {
+        entries.sort_by(|(a, _), (b, _)| a.cmp(b));
+    }
+    for (index, (key, value)) in entries.iter().enumerate() {
+        tri!(ser
+            .formatter
+            .begin_object_key(&mut ser.writer, index == 0)
+            .map_err(|e| PythonSerializerError { message: e.to_string() }));
+        tri!(
+            format_escaped_str(&mut ser.writer, &mut ser.formatter, key, ser.ensure_ascii)
+                .map_err(|e| PythonSerializerError { message: e.to_string() })
+        );
+        tri!(ser
+            .formatter
+            .end_object_key(&mut ser.writer)
+            .map_err(|e| PythonSerializerError { message: e.to_string() }));
+        tri!(ser
+            .formatter
+            .begin_object_value(&mut ser.writer)
+            .map_err(|e| PythonSerializerError { message: e.to_string() }));
+        tri!(ser
+            .writer
+            .write_all(value)
+            .map_err(|e| PythonSerializerError { message: e.to_string() }));
+        tri!(ser
+            .formatter
+            .end_object_value(&mut ser.writer)
+            .map_err(|e| PythonSerializerError { message: e.to_string() }));
+    }
+    ser.formatter
+        .end_object(&mut ser.writer)
+        .map_err(|e| PythonSerializerError { message: e.to_string() })
+}
+
+fn format_escaped_str<W, F>(writer: &mut W, formatter: &mut F, value: &str, ensure_ascii: bool) -> io::Result<()>
 where
     W: ?Sized + io::Write,
     F: ?Sized + Formatter,
 {
     tri!(formatter.begin_string(writer));
-    tri!(format_escaped_str_contents(writer, formatter, value));
+    if ensure_ascii && !value.is_ascii() {
+        tri!(format_escaped_str_contents_ascii(writer, formatter, value));
+    } else {
+        tri!(format_escaped_str_contents(writer, formatter, value));
+    }
     formatter.end_string(writer)
 }
 
+/// Like `format_escaped_str_contents`, but also escapes non-ASCII characters as `\uXXXX`,
+/// using UTF-16 surrogate pairs for characters outside the basic multilingual plane.
+fn format_escaped_str_contents_ascii<W, F>(writer: &mut W, formatter: &mut F, value: &str) -> io::Result<()>
+where
+    W: ?Sized + io::Write,
+    F: ?Sized + Formatter,
+{
+    let mut start = 0;
+
+    for (i, c) in value.char_indices() {
+        if c.is_ascii() {
+            let byte = c as u8;
+            let escape = ESCAPE[byte as usize];
+            if escape == 0 {
+                continue;
+            }
+            if start < i {
+                tri!(formatter.write_string_fragment(writer, &value[start..i]));
+            }
+            let char_escape = CharEscape::from_escape_table(escape, byte);
+            tri!(formatter.write_char_escape(writer, char_escape));
+        } else {
+            if start < i {
+                tri!(formatter.write_string_fragment(writer, &value[start..i]));
+            }
+            let mut buf = [0; 2];
+            for unit in c.encode_utf16(&mut buf) {
+                tri!(formatter.write_raw_fragment(writer, &format!("\\u{unit:04x}")));
+            }
+        }
+        start = i + c.len_utf8();
+    }
+
+    if start == value.len() {
+        return Ok(());
+    }
+
+    formatter.write_string_fragment(writer, &value[start..])
+}
+
 fn format_escaped_str_contents<W, F>(writer: &mut W, formatter: &mut F, value: &str) -> io::Result<()>
 where
     W: ?Sized + io::Write,
@@ -754,6 +917,13 @@ pub enum Compound<'a, W: 'a, F: 'a> {
         ser: &'a mut PythonSerializer<W, F>,
         state: State,
     },
+    /// entries of an object with `sort_keys` enabled, buffered as the unescaped key and the serialized
+    /// value so they can be written in key order once the object is complete; keys reach the serializer
+    /// already converted to strings, so non-string keys are also ordered as strings
+    SortedMap {
+        ser: &'a mut PythonSerializer<W, F>,
+        entries: Vec<(String, Vec<u8>)>,
+    },
     Number {
         ser: &'a mut PythonSerializer<W, F>,
     },
@@ -779,6 +949,216 @@ impl CharEscape {
     }
 }
 
+/// Formatter which writes strings unquoted and unescaped, used to capture object keys for sorting.
+#[derive(Clone)]
+struct RawKeyFormatter;
+
+impl Formatter for RawKeyFormatter {
+    #[inline]
+    fn begin_string<W>(&mut self, _writer: &mut W) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        Ok(())
+    }
+
+    #[inline]
+    fn end_string<W>(&mut self, _writer: &mut W) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        Ok(())
+    }
+
+    fn write_char_escape<W>(&mut self, writer: &mut W, char_escape: serde_json::ser::CharEscape) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        let byte = match char_escape {
+            serde_json::ser::CharEscape::Quote => b'"',
+            serde_json::ser::CharEscape::ReverseSolidus => b'\\',
+            serde_json::ser::CharEscape::Solidus => b'/',
+            serde_json::ser::CharEscape::Backspace => b'\x08',
+            serde_json::ser::CharEscape::FormFeed => b'\x0c',
+            serde_json::ser::CharEscape::LineFeed => b'\n',
+            serde_json::ser::CharEscape::CarriageReturn => b'\r',
+            serde_json::ser::CharEscape::Tab => b'\t',
+            serde_json::ser::CharEscape::AsciiControl(byte) => byte,
+        };
+        writer.write_all(&[byte])
+    }
+}
+
+/// Formatter implementing the JSON Canonicalization Scheme, [RFC 8785](https://www.rfc-editor.org/rfc/rfc8785).
+///
+/// Output is compact, strings use the minimal escaping already produced by `format_escaped_str`, and numbers
+/// are written as ECMAScript would format the equivalent IEEE 754 double. Numbers which can't be represented
+/// exactly as a double, and `NaN` or `Infinity`, are errors rather than being silently changed.
+/// Object keys must also be sorted by UTF-16 code units, see `PythonSerializer::with_utf16_key_order`.
+#[derive(Clone, Debug, Default)]
+pub struct CanonicalFormatter;
+
+impl CanonicalFormatter {
+    fn write_integer<W>(&mut self, writer: &mut W, value: impl fmt::Display) -> io::Result<()>
+    where
+        W: ?Sized + io::Write,
+    {
+        self.write_number_str(writer, &value.to_string())
+    }
+}
+
+impl Formatter for CanonicalFormatter {
+    fn write_i8<W: ?Sized + io::Write>(&mut self, writer: &mut W, value: i8) -> io::Result<()> {
+        self.write_integer(writer, value)
+    }
+
+    fn write_i16<W: ?Sized + io::Write>(&mut self, writer: &mut W, value: i16) -> io::Result<()> {
+        self.write_integer(writer, value)
+    }
+
+    fn write_i32<W: ?Sized + io::Write>(&mut self, writer: &mut W, value: i32) -> io::Result<()> {
+        self.write_integer(writer, value)
+    }
+
+    fn write_i64<W: ?Sized + io::Write>(&mut self, writer: &mut W, value: i64) -> io::Result<()> {
+        self.write_integer(writer, value)
+    }
+
+    fn write_i128<W: ?Sized + io::Write>(&mut self, writer: &mut W, value: i128) -> io::Result<()> {
+        self.write_integer(writer, value)
+    }
+
+    fn write_u8<W: ?Sized + io::Write>(&mut self, writer: &mut W, value: u8) -> io::Result<()> {
+        self.write_integer(writer, value)
+    }
+
+    fn write_u16<W: ?Sized + io::Write>(&mut self, writer: &mut W, value: u16) -> io::Result<()> {
+        self.write_integer(writer, value)
+    }
+
+    fn write_u32<W: ?Sized + io::Write>(&mut self, writer: &mut W, value: u32) -> io::Result<()> {
+        self.write_integer(writer, value)
+    }
+
+    fn write_u64<W: ?Sized + io::Write>(&mut self, writer: &mut W, value: u64) -> io::Result<()> {
+        self.write_integer(writer, value)
+    }
+
+    fn write_u128<W: ?Sized + io::Write>(&mut self, writer: &mut W, value: u128) -> io::Result<()> {
+        self.write_integer(writer, value)
+    }
+
+    fn write_f32<W: ?Sized + io::Write>(&mut self, writer: &mut W, value: f32) -> io::Result<()> {
+        write_ecmascript_f64(writer, f64::from(value))
+    }
+
+    fn write_f64<W: ?Sized + io::Write>(&mut self, writer: &mut W, value: f64) -> io::Result<()> {
+        write_ecmascript_f64(writer, value)
+    }
+
+    /// Used for integers, for large integers which don't fit in 64 bits, for `NaN` and `Infinity`, and for
+    /// numbers formatted by `float_precision` or decimals written as numbers.
+    fn write_number_str<W: ?Sized + io::Write>(&mut self, writer: &mut W, value: &str) -> io::Result<()> {
+        let float = value.parse::<f64>().ok().filter(|f| f.is_finite());
+        match float {
+            // the number survives the round trip through a double if it's the shortest decimal which identifies
+            // the double, e.g. `0.1` or `1.50`, or its exact value, e.g. `2**64`, which has at most 767 digits
+            Some(float)
+                if [format!("{float:e}"), format!("{float:.767e}")]
+                    .iter()
+                    .any(|text| decimal_parts(text) == decimal_parts(value)) =>
+            {
+                write_ecmascript_f64(writer, float)
+            }
+            Some(_) => Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("{value} cannot be represented exactly as an IEEE 754 double, as canonical JSON requires"),
+            )),
+            None => Err(non_finite_canonical_error()),
+        }
+    }
+}
+
+/// The sign, significant digits and exponent of a decimal number, so numbers can be compared regardless of
+/// how they're written, e.g. `1.50`, `1.5` and `15e-1` are all `(false, "15", -1)`
+fn decimal_parts(text: &str) -> Option<(bool, String, i64)> {
+    let (negative, text) = match text.strip_prefix('-') {
+        Some(text) => (true, text),
+        None => (false, text),
+    };
+    let (mantissa, exponent) = match text.split_once(['e', 'E']) {
+        Some((mantissa, exponent)) => (mantissa, exponent.parse::<i64>().ok()?),
+        None => (text, 0),
+    };
+    let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let digits = format!("{integer}{fraction}");
+    let significant = digits.trim_start_matches('0').trim_end_matches('0');
+    if significant.is_empty() {
+        // zero, including negative zero
+        return Some((false, String::new(), 0));
+    }
+    let trailing_zeros = digits.len() - digits.trim_end_matches('0').len();
+    let exponent = exponent - fraction.len() as i64 + trailing_zeros as i64;
+    Some((negative, significant.to_string(), exponent))
+}
+
+fn non_finite_canonical_error() -> io::Error {
+    io::Error::new(
+        io::ErrorKind::InvalidData,
+        "NaN and Infinity are not permitted in canonical JSON",
+    )
+}
+
+/// Write a double the way ECMAScript's `Number.prototype.toString` does, as required by RFC 8785.
+fn write_ecmascript_f64<W>(writer: &mut W, value: f64) -> io::Result<()>
+where
+    W: ?Sized + io::Write,
+{
+    if !value.is_finite() {
+        return Err(non_finite_canonical_error());
+    }
+    if value == 0.0 {
+        // this includes negative zero
+        return writer.write_all(b"0");
+    }
+    if value.is_sign_negative() {
+        tri!(writer.write_all(b"-"));
+    }
+
+    // `{:e}` gives the shortest digits which round trip, e.g. `1.2345e-7`, from which ECMAScript's
+    // "k" (number of digits) and "n" (position of the decimal point) are derived
+    let shortest = format!("{:e}", value.abs());
+    let k = shortest
+        .split_once('e')
+        .map_or(1, |(mantissa, _)| mantissa.replace('.', "").len());
+    // when two candidates with k digits are equally close, `{:e}` may choose either, but ECMAScript requires the
+    // even one, formatting with an explicit precision rounds correctly with ties to even
+    let repr = format!("{:.*e}", k - 1, value.abs());
+    let (mantissa, exponent) = repr.split_once('e').expect("`{:e}` output contains an exponent");
+    let digits = mantissa.replace('.', "");
+    let k = digits.len() as i32;
+    let n = exponent.parse::<i32>().expect("`{:e}` exponent is an integer") + 1;
+
+    let output = if k <= n && n <= 21 {
+        format!("{digits}{}", "0".repeat((n - k) as usize))
+    } else if 0 < n && n <= 21 {
+        let (integer, fraction) = digits.split_at(n as usize);
+        format!("{integer}.{fraction}")
+    } else if -6 < n && n <= 0 {
+        format!("0.{}{digits}", "0".repeat((-n) as usize))
+    } else {
+        let (first, rest) = digits.split_at(1);
+        let sign = if n > 0 { '+' } else { '-' };
+        let exponent = (n - 1).abs();
+        if rest.is_empty() {
+            format!("{first}e{sign}{exponent}")
+        } else {
+            format!("{first}.{rest}e{sign}{exponent}")
+        }
+    };
+    writer.write_all(output.as_bytes())
+}
+
 struct MapKeySerializer<'a, W: 'a, F: 'a> {
     ser: &'a mut PythonSerializer<W, F>,
 }
@@ -797,7 +1177,7 @@ fn invalid_number() -> PythonSerializerError {
 impl<'a, W, F> serde::ser::Serializer for MapKeySerializer<'a, W, F>
 where
     W: io::Write,
-    F: Formatter,
+    F: Formatter + Clone,
 {
     type Ok = ();
     type Error = PythonSerializerError;
@@ -1,7 +1,7 @@
 use std::borrow::Cow;
 use std::fmt::Debug;
 
-use pyo3::exceptions::PyTypeError;
+use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::prelude::*;
 use pyo3::sync::GILOnceCell;
 use pyo3::types::{PyDict, PyString};
@@ -15,10 +15,10 @@ use crate::build_tools::py_schema_err;
 use crate::build_tools::py_schema_error_type;
 use crate::definitions::DefinitionsBuilder;
 use crate::py_gc::PyGcTraverse;
-use crate::serializers::ser::PythonSerializer;
+use crate::serializers::ser::{CanonicalFormatter, PythonSerializer};
 use crate::tools::{py_err, SchemaDict};
 
-use super::errors::se_err_py_err;
+use super::errors::{msgpack_err_py_err, se_err_py_err};
 use super::extra::Extra;
 use super::infer::infer_json_key;
 use super::ob_type::{IsType, ObType};
@@ -130,6 +130,11 @@ combined_serializer! {
         Url: super::type_serializers::url::UrlSerializer;
         MultiHostUrl: super::type_serializers::url::MultiHostUrlSerializer;
         Uuid: super::type_serializers::uuid::UuidSerializer;
+        IpAddress: super::type_serializers::ip::IpAddressSerializer;
+        IpNetwork: super::type_serializers::ip::IpNetworkSerializer;
+        IpInterface: super::type_serializers::ip::IpInterfaceSerializer;
+        Path: super::type_serializers::path::PathSerializer;
+        Timezone: super::type_serializers::timezone::TimezoneSerializer;
         Any: super::type_serializers::any::AnySerializer;
         Format: super::type_serializers::format::FormatSerializer;
         ToString: super::type_serializers::format::ToStringSerializer;
@@ -253,6 +258,11 @@ impl PyGcTraverse for CombinedSerializer {
             CombinedSerializer::Recursive(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Tuple(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Uuid(inner) => inner.py_gc_traverse(visit),
+            CombinedSerializer::IpAddress(inner) => inner.py_gc_traverse(visit),
+            CombinedSerializer::IpNetwork(inner) => inner.py_gc_traverse(visit),
+            CombinedSerializer::IpInterface(inner) => inner.py_gc_traverse(visit),
+            CombinedSerializer::Path(inner) => inner.py_gc_traverse(visit),
+            CombinedSerializer::Timezone(inner) => inner.py_gc_traverse(visit),
             CombinedSerializer::Complex(inner) => inner.py_gc_traverse(visit),
         }
     }
@@ -339,29 +349,58 @@ impl<'py> Serialize for PydanticSerializer<'py> {
     }
 }
 
-#[allow(clippy::too_many_arguments)]
+/// Output options for `to_json_bytes`, the default is compact JSON with keys in insertion order.
+#[derive(Debug, Clone, Copy, Default)]
+pub(crate) struct JsonOptions {
+    pub indent: Option<usize>,
+    pub sort_keys: bool,
+    pub ensure_ascii: bool,
+    pub canonical: bool,
+}
+
 pub(crate) fn to_json_bytes(
     value: &Bound<'_, PyAny>,
     serializer: &CombinedSerializer,
     include: Option<&Bound<'_, PyAny>>,
     exclude: Option<&Bound<'_, PyAny>>,
     extra: &Extra,
-    indent: Option<usize>,
+    options: JsonOptions,
     expected_json_size: usize,
 ) -> PyResult<Vec<u8>> {
     let serializer = PydanticSerializer::new(value, serializer, include, exclude, extra);
+    let JsonOptions {
+        indent,
+        sort_keys,
+        ensure_ascii,
+        canonical,
+    } = options;
 
     let writer: Vec<u8> = Vec::with_capacity(expected_json_size);
+    if canonical {
+        // RFC 8785 fixes the whitespace and string escaping, so these options would produce non-canonical output
+        if indent.is_some() || ensure_ascii {
+            return py_err!(PyValueError; "`indent` and `ensure_ascii` cannot be used with `canonical=True`");
+        }
+        let mut ser = PythonSerializer::with_formatter(writer, CanonicalFormatter)
+            .with_sort_keys(true)
+            .with_utf16_key_order(true);
+        serializer.serialize(&mut ser).map_err(se_err_py_err)?;
+        return Ok(ser.into_inner());
+    }
     let bytes = match indent {
         Some(indent) => {
             let indent = vec![b' '; indent];
             let formatter = PrettyFormatter::with_indent(&indent);
-            let mut ser = PythonSerializer::with_formatter(writer, formatter);
+            let mut ser = PythonSerializer::with_formatter(writer, formatter)
+                .with_sort_keys(sort_keys)
+                .with_ensure_ascii(ensure_ascii);
             serializer.serialize(&mut ser).map_err(se_err_py_err)?;
             ser.into_inner()
         }
         None => {
-            let mut ser = PythonSerializer::new(writer);
+            let mut ser = PythonSerializer::new(writer)
+                .with_sort_keys(sort_keys)
+                .with_ensure_ascii(ensure_ascii);
             serializer.serialize(&mut ser).map_err(se_err_py_err)?;
             ser.into_inner()
         }
@@ -369,6 +408,22 @@ pub(crate) fn to_json_bytes(
     Ok(bytes)
 }
 
+pub(crate) fn to_msgpack_bytes(
+    value: &Bound<'_, PyAny>,
+    serializer: &CombinedSerializer,
+    include: Option<&Bound<'_, PyAny>>,
+    exclude: Option<&Bound<'_, PyAny>>,
+    extra: &Extra,
+    expected_size: usize,
+) -> PyResult<Vec<u8>> {
+    let serializer = PydanticSerializer::new(value, serializer, include, exclude, extra);
+
+    let writer: Vec<u8> = Vec::with_capacity(expected_size);
+    let mut ser = rmp_serde::Serializer::new(writer);
+    serializer.serialize(&mut ser).map_err(msgpack_err_py_err)?;
+    Ok(ser.into_inner())
+}
+
 pub(super) fn any_dataclass_iter<'a, 'py>(
     dataclass: &'a Bound<'py, PyAny>,
 ) -> PyResult<(
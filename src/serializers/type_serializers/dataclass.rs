@@ -195,10 +195,8 @@ impl TypeSerializer for DataclassSerializer {
         }
         if self.allow_value(value, &dc_extra).map_err(py_err_se_err)? {
             if let CombinedSerializer::Fields(ref fields_serializer) = *self.serializer {
-                let expected_len = self.fields.len() + fields_serializer.computed_field_count();
                 let mut map = fields_serializer.main_serde_serialize(
                     known_dataclass_iter(&self.fields, value),
-                    expected_len,
                     serializer,
                     include,
                     exclude,
@@ -1,10 +1,15 @@
 use std::borrow::Cow;
 
+use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::{PyDate, PyDateTime, PyDict, PyTime};
+use pyo3::types::{PyDate, PyDateTime, PyDict, PyString, PyTime};
+use speedate::{DateTime, Time};
 
+use crate::build_tools::py_schema_err;
 use crate::definitions::DefinitionsBuilder;
-use crate::input::{pydate_as_date, pydatetime_as_datetime, pytime_as_time};
+use crate::input::{pydate_as_date, pydatetime_as_datetime, pytime_as_time, DateTimeFormat};
+use crate::serializers::config::{DatetimeMode, EpochTimestamp};
+use crate::tools::SchemaDict;
 use crate::PydanticSerializationUnexpectedValue;
 
 use super::{
@@ -24,6 +29,45 @@ pub(crate) fn time_to_string(py_time: &Bound<'_, PyTime>) -> PyResult<String> {
     pytime_as_time(py_time, None).map(|dt| dt.to_string())
 }
 
+pub(crate) fn datetime_to_epoch(py_dt: &Bound<'_, PyDateTime>, mode: DatetimeMode) -> PyResult<Option<EpochTimestamp>> {
+    if mode == DatetimeMode::Iso8601 {
+        return Ok(None);
+    }
+    pydatetime_as_datetime(py_dt).map(|dt| mode.timestamp(&dt))
+}
+
+/// Dates are serialized as the timestamp of midnight UTC
+pub(crate) fn date_to_epoch(py_date: &Bound<'_, PyDate>, mode: DatetimeMode) -> PyResult<Option<EpochTimestamp>> {
+    if mode == DatetimeMode::Iso8601 {
+        return Ok(None);
+    }
+    let midnight = Time {
+        hour: 0,
+        minute: 0,
+        second: 0,
+        microsecond: 0,
+        tz_offset: None,
+    };
+    pydate_as_date(py_date).map(|date| mode.timestamp(&DateTime { date, time: midnight }))
+}
+
+/// Times don't have an epoch, so they're always ISO 8601 strings
+fn time_to_epoch(_py_time: &Bound<'_, PyTime>, _mode: DatetimeMode) -> PyResult<Option<EpochTimestamp>> {
+    Ok(None)
+}
+
+fn format_datetime(py_dt: &Bound<'_, PyDateTime>, format: &DateTimeFormat) -> PyResult<String> {
+    pydatetime_as_datetime(py_dt).map(|dt| format.format_datetime(&dt))
+}
+
+fn format_date(py_date: &Bound<'_, PyDate>, format: &DateTimeFormat) -> PyResult<String> {
+    pydate_as_date(py_date).map(|date| format.format_date(&date))
+}
+
+fn format_time(py_time: &Bound<'_, PyTime>, format: &DateTimeFormat) -> PyResult<String> {
+    pytime_as_time(py_time, None).map(|time| format.format_time(&time))
+}
+
 fn downcast_date_reject_datetime<'a, 'py>(py_date: &'a Bound<'py, PyAny>) -> PyResult<&'a Bound<'py, PyDate>> {
     if let Ok(py_date) = py_date.downcast::<PyDate>() {
         // because `datetime` is a subclass of `date` we have to check that the value is not a
@@ -37,19 +81,55 @@ fn downcast_date_reject_datetime<'a, 'py>(py_date: &'a Bound<'py, PyAny>) -> PyR
 }
 
 macro_rules! build_serializer {
-    ($struct_name:ident, $expected_type:literal, $downcast:path, $convert_func:ident $(, $json_check_func:ident)?) => {
+    (
+        $struct_name:ident,
+        $expected_type:literal,
+        $py_type:ty,
+        $downcast:path,
+        $convert_func:ident,
+        $format_func:ident,
+        $epoch_func:ident
+        $(, $json_check_func:ident)?
+    ) => {
         #[derive(Debug)]
-        pub struct $struct_name;
+        pub struct $struct_name {
+            // `strftime` style format used in JSON mode instead of ISO 8601
+            format: Option<DateTimeFormat>,
+        }
 
         impl BuildSerializer for $struct_name {
             const EXPECTED_TYPE: &'static str = $expected_type;
 
             fn build(
-                _schema: &Bound<'_, PyDict>,
+                schema: &Bound<'_, PyDict>,
                 _config: Option<&Bound<'_, PyDict>>,
                 _definitions: &mut DefinitionsBuilder<CombinedSerializer>,
             ) -> PyResult<CombinedSerializer> {
-                Ok(Self {}.into())
+                let format = match schema.get_as::<Bound<'_, PyString>>(intern!(schema.py(), "ser_format"))? {
+                    Some(format) => match DateTimeFormat::new(format.to_str()?) {
+                        Ok(format) => Some(format),
+                        Err(error) => return py_schema_err!("Invalid ser_format {:?}, {}", format.to_str()?, error),
+                    },
+                    None => None,
+                };
+                Ok(Self { format }.into())
+            }
+        }
+
+        impl $struct_name {
+            fn to_string(&self, py_value: &Bound<'_, $py_type>) -> PyResult<String> {
+                match self.format {
+                    Some(ref format) => $format_func(py_value, format),
+                    None => $convert_func(py_value),
+                }
+            }
+
+            /// The unix timestamp when `ser_json_datetime` is an epoch mode, unless the schema has a `ser_format`
+            fn to_epoch(&self, py_value: &Bound<'_, $py_type>, extra: &Extra) -> PyResult<Option<EpochTimestamp>> {
+                match self.format {
+                    Some(_) => Ok(None),
+                    None => $epoch_func(py_value, extra.config.datetime_mode),
+                }
             }
         }
 
@@ -66,10 +146,10 @@ macro_rules! build_serializer {
                 let py = value.py();
                 match $downcast(value) {
                     Ok(py_value) => match extra.mode {
-                        SerMode::Json => {
-                            let s = $convert_func(py_value)?;
-                            Ok(s.into_py(py))
-                        }
+                        SerMode::Json => match self.to_epoch(py_value, extra)? {
+                            Some(timestamp) => Ok(timestamp.to_object(py)),
+                            None => Ok(self.to_string(py_value)?.into_py(py)),
+                        },
                         _ => Ok(value.into_py(py)),
                     },
                     Err(_) => {
@@ -81,7 +161,10 @@ macro_rules! build_serializer {
 
             fn json_key<'a>(&self, key: &'a Bound<'_, PyAny>, extra: &Extra) -> PyResult<Cow<'a, str>> {
                 match $downcast(key) {
-                    Ok(py_value) => Ok(Cow::Owned($convert_func(py_value)?)),
+                    Ok(py_value) => match self.to_epoch(py_value, extra)? {
+                        Some(timestamp) => Ok(Cow::Owned(timestamp.to_string())),
+                        None => Ok(Cow::Owned(self.to_string(py_value)?)),
+                    },
                     Err(_) => {
                         extra.warnings.on_fallback_py(self.get_name(), key, extra)?;
                         infer_json_key(key, extra)
@@ -98,10 +181,13 @@ macro_rules! build_serializer {
                 extra: &Extra,
             ) -> Result<S::Ok, S::Error> {
                 match $downcast(value) {
-                    Ok(py_value) => {
-                        let s = $convert_func(py_value).map_err(py_err_se_err)?;
-                        serializer.serialize_str(&s)
-                    }
+                    Ok(py_value) => match self.to_epoch(py_value, extra).map_err(py_err_se_err)? {
+                        Some(timestamp) => timestamp.serialize(serializer),
+                        None => {
+                            let s = self.to_string(py_value).map_err(py_err_se_err)?;
+                            serializer.serialize_str(&s)
+                        }
+                    },
                     Err(_) => {
                         extra
                             .warnings
@@ -121,8 +207,27 @@ macro_rules! build_serializer {
 build_serializer!(
     DatetimeSerializer,
     "datetime",
+    PyDateTime,
     PyAnyMethods::downcast::<PyDateTime>,
-    datetime_to_string
+    datetime_to_string,
+    format_datetime,
+    datetime_to_epoch
+);
+build_serializer!(
+    DateSerializer,
+    "date",
+    PyDate,
+    downcast_date_reject_datetime,
+    date_to_string,
+    format_date,
+    date_to_epoch
+);
+build_serializer!(
+    TimeSerializer,
+    "time",
+    PyTime,
+    PyAnyMethods::downcast::<PyTime>,
+    time_to_string,
+    format_time,
+    time_to_epoch
 );
-build_serializer!(DateSerializer, "date", downcast_date_reject_datetime, date_to_string);
-build_serializer!(TimeSerializer, "time", PyAnyMethods::downcast::<PyTime>, time_to_string);
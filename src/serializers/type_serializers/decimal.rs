@@ -1,28 +1,47 @@
 use std::borrow::Cow;
 
+use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
 use crate::definitions::DefinitionsBuilder;
-use crate::serializers::infer::{infer_json_key_known, infer_serialize_known, infer_to_python_known};
+use crate::serializers::config::NumberFormat;
+use crate::serializers::errors::py_err_se_err;
+use crate::serializers::infer::infer_to_python_known;
 use crate::serializers::ob_type::{IsType, ObType};
 
 use super::{
-    infer_json_key, infer_serialize, infer_to_python, BuildSerializer, CombinedSerializer, Extra, TypeSerializer,
+    infer_json_key, infer_serialize, infer_to_python, BuildSerializer, CombinedSerializer, Extra, SerMode,
+    TypeSerializer,
 };
 
 #[derive(Debug)]
-pub struct DecimalSerializer {}
+pub struct DecimalSerializer {
+    number_format: NumberFormat,
+}
 
 impl BuildSerializer for DecimalSerializer {
     const EXPECTED_TYPE: &'static str = "decimal";
 
     fn build(
-        _schema: &Bound<'_, PyDict>,
-        _config: Option<&Bound<'_, PyDict>>,
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
         _definitions: &mut DefinitionsBuilder<CombinedSerializer>,
     ) -> PyResult<CombinedSerializer> {
-        Ok(Self {}.into())
+        Ok(Self {
+            number_format: NumberFormat::from_schema(schema, config)?,
+        }
+        .into())
+    }
+}
+
+/// The JSON text of a decimal, rounded to `number_format.precision` decimal places if it's set
+pub(crate) fn decimal_json_text(value: &Bound<'_, PyAny>, number_format: NumberFormat) -> PyResult<String> {
+    match number_format.precision {
+        Some(precision) => value
+            .call_method1(intern!(value.py(), "__format__"), (format!(".{precision}f"),))?
+            .extract(),
+        None => Ok(value.to_string()),
     }
 }
 
@@ -36,9 +55,15 @@ impl TypeSerializer for DecimalSerializer {
         exclude: Option<&Bound<'_, PyAny>>,
         extra: &Extra,
     ) -> PyResult<PyObject> {
-        let _py = value.py();
+        let py = value.py();
         match extra.ob_type_lookup.is_type(value, ObType::Decimal) {
-            IsType::Exact | IsType::Subclass => infer_to_python_known(ObType::Decimal, value, include, exclude, extra),
+            IsType::Exact | IsType::Subclass => match extra.mode {
+                SerMode::Json => {
+                    let text = decimal_json_text(value, self.number_format)?;
+                    Ok(self.number_format.text_to_python(py, &text, true))
+                }
+                _ => infer_to_python_known(ObType::Decimal, value, include, exclude, extra),
+            },
             IsType::False => {
                 extra.warnings.on_fallback_py(self.get_name(), value, extra)?;
                 infer_to_python(value, include, exclude, extra)
@@ -48,7 +73,7 @@ impl TypeSerializer for DecimalSerializer {
 
     fn json_key<'a>(&self, key: &'a Bound<'_, PyAny>, extra: &Extra) -> PyResult<Cow<'a, str>> {
         match extra.ob_type_lookup.is_type(key, ObType::Decimal) {
-            IsType::Exact | IsType::Subclass => infer_json_key_known(ObType::Decimal, key, extra),
+            IsType::Exact | IsType::Subclass => Ok(Cow::Owned(decimal_json_text(key, self.number_format)?)),
             IsType::False => {
                 extra.warnings.on_fallback_py(self.get_name(), key, extra)?;
                 infer_json_key(key, extra)
@@ -66,7 +91,8 @@ impl TypeSerializer for DecimalSerializer {
     ) -> Result<S::Ok, S::Error> {
         match extra.ob_type_lookup.is_type(value, ObType::Decimal) {
             IsType::Exact | IsType::Subclass => {
-                infer_serialize_known(ObType::Decimal, value, serializer, include, exclude, extra)
+                let text = decimal_json_text(value, self.number_format).map_err(py_err_se_err)?;
+                self.number_format.serialize_text(&text, serializer, true)
             }
             IsType::False => {
                 extra.warnings.on_fallback_ser::<S>(self.get_name(), value, extra)?;
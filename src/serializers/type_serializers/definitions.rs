@@ -93,8 +93,20 @@ impl TypeSerializer for DefinitionRefSerializer {
     ) -> PyResult<PyObject> {
         self.definition.read(|comb_serializer| {
             let comb_serializer = comb_serializer.unwrap();
-            let mut guard = extra.recursion_guard(value, self.definition.id())?;
-            comb_serializer.to_python(value, include, exclude, guard.state())
+            let def_ref_id = self.definition.id();
+            // the output depends on include and exclude, so it can only be reused without them
+            let reuse = include.is_none() && exclude.is_none();
+            if reuse {
+                if let Some(output) = extra.get_reused(value, def_ref_id) {
+                    return Ok(output);
+                }
+            }
+            let mut guard = extra.recursion_guard(value, def_ref_id)?;
+            let output = comb_serializer.to_python(value, include, exclude, guard.state())?;
+            if reuse {
+                guard.state().set_reused(value, def_ref_id, &output);
+            }
+            Ok(output)
         })
     }
 
@@ -119,7 +119,8 @@ impl TypeSerializer for DictSerializer {
     ) -> Result<S::Ok, S::Error> {
         match value.downcast::<PyDict>() {
             Ok(py_dict) => {
-                let mut map = serializer.serialize_map(Some(py_dict.len()))?;
+                // MessagePack writes the length up front, so it's only given if no entries can be filtered out
+                let mut map = serializer.serialize_map(self.filter.serialized_len(py_dict.len(), include, exclude))?;
                 let key_serializer = self.key_serializer.as_ref();
                 let value_serializer = self.value_serializer.as_ref();
 
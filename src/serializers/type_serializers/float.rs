@@ -6,7 +6,7 @@ use std::borrow::Cow;
 use serde::Serializer;
 
 use crate::definitions::DefinitionsBuilder;
-use crate::serializers::config::InfNanMode;
+use crate::serializers::config::{FromConfig, InfNanMode, NumberFormat};
 use crate::tools::SchemaDict;
 
 use super::simple::to_str_json_key;
@@ -19,6 +19,7 @@ use crate::serializers::errors::PydanticSerializationUnexpectedValue;
 #[derive(Debug)]
 pub struct FloatSerializer {
     inf_nan_mode: InfNanMode,
+    number_format: NumberFormat,
 }
 
 impl FloatSerializer {
@@ -27,11 +28,42 @@ impl FloatSerializer {
             .and_then(|c| c.get_as(intern!(py, "ser_json_inf_nan")).transpose())
             .transpose()?
             .unwrap_or_default();
-        Ok(Self { inf_nan_mode })
+        Ok(Self {
+            inf_nan_mode,
+            number_format: NumberFormat::from_config(config)?,
+        })
     }
 }
 
-pub fn serialize_f64<S: Serializer>(v: f64, serializer: S, inf_nan_mode: InfNanMode) -> Result<S::Ok, S::Error> {
+/// Floats at least this large have no fractional digits to round, they're written in exponent notation as they
+/// would be without a precision rather than expanded to hundreds of digits
+const FIXED_POINT_LIMIT: f64 = 1e16;
+
+/// The JSON text of a finite float if `number_format` changes how it's written
+pub fn format_f64(v: f64, number_format: NumberFormat) -> Option<String> {
+    match number_format.precision {
+        Some(precision) if v.abs() < FIXED_POINT_LIMIT => Some(format!("{v:.precision$}")),
+        Some(_) => serde_json::to_string(&v).ok(),
+        // formatted as it would be as a JSON number
+        None if number_format.as_string == Some(true) => serde_json::to_string(&v).ok(),
+        None => None,
+    }
+}
+
+/// A float in JSON mode, infinite and NaN values are left to the caller
+pub fn f64_to_json_python(py: Python, v: f64, number_format: NumberFormat) -> PyObject {
+    match format_f64(v, number_format) {
+        Some(text) if v.is_finite() => number_format.text_to_python(py, &text, false),
+        _ => v.into_py(py),
+    }
+}
+
+pub fn serialize_f64<S: Serializer>(
+    v: f64,
+    serializer: S,
+    inf_nan_mode: InfNanMode,
+    number_format: NumberFormat,
+) -> Result<S::Ok, S::Error> {
     if v.is_nan() || v.is_infinite() {
         match inf_nan_mode {
             InfNanMode::Null => serializer.serialize_none(),
@@ -44,6 +76,8 @@ pub fn serialize_f64<S: Serializer>(v: f64, serializer: S, inf_nan_mode: InfNanM
                 }
             }
         }
+    } else if let Some(text) = format_f64(v, number_format) {
+        number_format.serialize_text(&text, serializer, false)
     } else {
         serializer.serialize_f64(v)
     }
@@ -57,7 +91,11 @@ impl BuildSerializer for FloatSerializer {
         config: Option<&Bound<'_, PyDict>>,
         _definitions: &mut DefinitionsBuilder<CombinedSerializer>,
     ) -> PyResult<CombinedSerializer> {
-        Self::new(schema.py(), config).map(Into::into)
+        Ok(Self {
+            number_format: NumberFormat::from_schema(schema, config)?,
+            ..Self::new(schema.py(), config)?
+        }
+        .into())
     }
 }
 
@@ -73,13 +111,18 @@ impl TypeSerializer for FloatSerializer {
     ) -> PyResult<PyObject> {
         let py = value.py();
         match extra.ob_type_lookup.is_type(value, ObType::Float) {
-            IsType::Exact => Ok(value.into_py(py)),
+            IsType::Exact => match extra.mode {
+                SerMode::Json if self.number_format != NumberFormat::default() => {
+                    Ok(f64_to_json_python(py, value.extract()?, self.number_format))
+                }
+                _ => Ok(value.into_py(py)),
+            },
             IsType::Subclass => match extra.check {
                 SerCheck::Strict => Err(PydanticSerializationUnexpectedValue::new_err(None)),
                 SerCheck::Lax | SerCheck::None => match extra.mode {
                     SerMode::Json => {
                         let rust_value = value.extract::<f64>()?;
-                        Ok(rust_value.to_object(py))
+                        Ok(f64_to_json_python(py, rust_value, self.number_format))
                     }
                     _ => infer_to_python(value, include, exclude, extra),
                 },
@@ -93,7 +136,13 @@ impl TypeSerializer for FloatSerializer {
 
     fn json_key<'a>(&self, key: &'a Bound<'_, PyAny>, extra: &Extra) -> PyResult<Cow<'a, str>> {
         match extra.ob_type_lookup.is_type(key, ObType::Float) {
-            IsType::Exact | IsType::Subclass => to_str_json_key(key),
+            IsType::Exact | IsType::Subclass => {
+                let v = key.extract::<f64>()?;
+                match format_f64(v, self.number_format) {
+                    Some(text) if v.is_finite() => Ok(Cow::Owned(text)),
+                    _ => to_str_json_key(key),
+                }
+            }
             IsType::False => {
                 extra.warnings.on_fallback_py(self.get_name(), key, extra)?;
                 infer_json_key(key, extra)
@@ -111,7 +160,7 @@ impl TypeSerializer for FloatSerializer {
         extra: &Extra,
     ) -> Result<S::Ok, S::Error> {
         match value.extract::<f64>() {
-            Ok(v) => serialize_f64(v, serializer, self.inf_nan_mode),
+            Ok(v) => serialize_f64(v, serializer, self.inf_nan_mode, self.number_format),
             Err(_) => {
                 extra.warnings.on_fallback_ser::<S>(self.get_name(), value, extra)?;
                 infer_serialize(value, serializer, include, exclude, extra)
@@ -0,0 +1,98 @@
+use std::borrow::Cow;
+
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+
+use crate::definitions::DefinitionsBuilder;
+
+use super::{
+    infer_json_key, infer_serialize, infer_to_python, py_err_se_err, BuildSerializer, CombinedSerializer, Extra,
+    IsType, ObType, SerMode, TypeSerializer,
+};
+
+fn ip_to_string(value: &Bound<'_, PyAny>) -> PyResult<String> {
+    Ok(value.str()?.to_string_lossy().into_owned())
+}
+
+macro_rules! build_ip_serializer {
+    ($struct_name:ident, $expected_type:literal, $ob_type:expr) => {
+        #[derive(Debug)]
+        pub struct $struct_name;
+
+        impl_py_gc_traverse!($struct_name {});
+
+        impl BuildSerializer for $struct_name {
+            const EXPECTED_TYPE: &'static str = $expected_type;
+
+            fn build(
+                _schema: &Bound<'_, PyDict>,
+                _config: Option<&Bound<'_, PyDict>>,
+                _definitions: &mut DefinitionsBuilder<CombinedSerializer>,
+            ) -> PyResult<CombinedSerializer> {
+                Ok(Self {}.into())
+            }
+        }
+
+        impl TypeSerializer for $struct_name {
+            fn to_python(
+                &self,
+                value: &Bound<'_, PyAny>,
+                include: Option<&Bound<'_, PyAny>>,
+                exclude: Option<&Bound<'_, PyAny>>,
+                extra: &Extra,
+            ) -> PyResult<PyObject> {
+                let py = value.py();
+                match extra.ob_type_lookup.is_type(value, $ob_type) {
+                    IsType::Exact | IsType::Subclass => match extra.mode {
+                        SerMode::Json => Ok(ip_to_string(value)?.into_py(py)),
+                        _ => Ok(value.into_py(py)),
+                    },
+                    IsType::False => {
+                        extra.warnings.on_fallback_py(self.get_name(), value, extra)?;
+                        infer_to_python(value, include, exclude, extra)
+                    }
+                }
+            }
+
+            fn json_key<'a>(&self, key: &'a Bound<'_, PyAny>, extra: &Extra) -> PyResult<Cow<'a, str>> {
+                match extra.ob_type_lookup.is_type(key, $ob_type) {
+                    IsType::Exact | IsType::Subclass => Ok(Cow::Owned(ip_to_string(key)?)),
+                    IsType::False => {
+                        extra.warnings.on_fallback_py(self.get_name(), key, extra)?;
+                        infer_json_key(key, extra)
+                    }
+                }
+            }
+
+            fn serde_serialize<S: serde::ser::Serializer>(
+                &self,
+                value: &Bound<'_, PyAny>,
+                serializer: S,
+                include: Option<&Bound<'_, PyAny>>,
+                exclude: Option<&Bound<'_, PyAny>>,
+                extra: &Extra,
+            ) -> Result<S::Ok, S::Error> {
+                match extra.ob_type_lookup.is_type(value, $ob_type) {
+                    IsType::Exact | IsType::Subclass => {
+                        let s = ip_to_string(value).map_err(py_err_se_err)?;
+                        serializer.serialize_str(&s)
+                    }
+                    IsType::False => {
+                        extra
+                            .warnings
+                            .on_fallback_ser::<S>(self.get_name(), value, extra)?;
+                        infer_serialize(value, serializer, include, exclude, extra)
+                    }
+                }
+            }
+
+            fn get_name(&self) -> &str {
+                Self::EXPECTED_TYPE
+            }
+        }
+    };
+}
+
+build_ip_serializer!(IpAddressSerializer, "ip-address", ObType::IpAddress);
+build_ip_serializer!(IpNetworkSerializer, "ip-network", ObType::IpNetwork);
+build_ip_serializer!(IpInterfaceSerializer, "ip-interface", ObType::IpInterface);
@@ -95,7 +95,8 @@ impl TypeSerializer for ListSerializer {
     ) -> Result<S::Ok, S::Error> {
         match value.downcast::<PyList>() {
             Ok(py_list) => {
-                let mut seq = serializer.serialize_seq(Some(py_list.len()))?;
+                // MessagePack writes the length up front, so it's only given if no items can be filtered out
+                let mut seq = serializer.serialize_seq(self.filter.serialized_len(py_list.len(), include, exclude))?;
                 let item_serializer = self.item_serializer.as_ref();
 
                 for (index, element) in py_list.iter().enumerate() {
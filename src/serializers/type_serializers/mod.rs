@@ -11,6 +11,7 @@ pub mod float;
 pub mod format;
 pub mod function;
 pub mod generator;
+pub mod ip;
 pub mod json;
 pub mod json_or_python;
 pub mod list;
@@ -18,10 +19,12 @@ pub mod literal;
 pub mod model;
 pub mod nullable;
 pub mod other;
+pub mod path;
 pub mod set_frozenset;
 pub mod simple;
 pub mod string;
 pub mod timedelta;
+pub mod timezone;
 pub mod tuple;
 pub mod typed_dict;
 pub mod union;
@@ -37,4 +40,6 @@ use super::fields::{FieldsMode, GeneralFieldsSerializer, SerField};
 use super::filter::{AnyFilter, SchemaFilter};
 use super::infer::{infer_json_key, infer_json_key_known, infer_serialize, infer_to_python};
 use super::ob_type::{IsType, ObType};
-use super::shared::{to_json_bytes, BuildSerializer, CombinedSerializer, PydanticSerializer, TypeSerializer};
+use super::shared::{
+    to_json_bytes, BuildSerializer, CombinedSerializer, JsonOptions, PydanticSerializer, TypeSerializer,
+};
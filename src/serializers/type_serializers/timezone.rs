@@ -0,0 +1,99 @@
+use std::borrow::Cow;
+
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyString, PyTzInfo};
+
+use crate::definitions::DefinitionsBuilder;
+use crate::input::TzInfo;
+
+use super::{
+    infer_json_key, infer_serialize, infer_to_python, py_err_se_err, BuildSerializer, CombinedSerializer, Extra,
+    SerMode, TypeSerializer,
+};
+
+/// Time zones with a name (e.g. `zoneinfo.ZoneInfo`) are serialized as that name, fixed offsets as `+HH:MM`,
+/// so the result can be validated by the `timezone` validator
+pub(crate) fn timezone_to_string(py_tz: &Bound<'_, PyAny>) -> PyResult<String> {
+    let py = py_tz.py();
+    if let Ok(key) = py_tz.getattr(intern!(py, "key")) {
+        if let Ok(key) = key.downcast::<PyString>() {
+            return Ok(key.to_string());
+        }
+    }
+    let offset = py_tz.call_method1(intern!(py, "utcoffset"), (py.None(),))?;
+    if PyAnyMethods::is_none(&offset) {
+        return Ok(py_tz.str()?.to_string());
+    }
+    let seconds: f64 = offset.call_method0(intern!(py, "total_seconds"))?.extract()?;
+    let tz_info = Bound::new(py, TzInfo::try_from(seconds.round() as i32)?)?;
+    Ok(tz_info.str()?.to_string())
+}
+
+#[derive(Debug)]
+pub struct TimezoneSerializer;
+
+impl_py_gc_traverse!(TimezoneSerializer {});
+
+impl BuildSerializer for TimezoneSerializer {
+    const EXPECTED_TYPE: &'static str = "timezone";
+
+    fn build(
+        _schema: &Bound<'_, PyDict>,
+        _config: Option<&Bound<'_, PyDict>>,
+        _definitions: &mut DefinitionsBuilder<CombinedSerializer>,
+    ) -> PyResult<CombinedSerializer> {
+        Ok(Self {}.into())
+    }
+}
+
+impl TypeSerializer for TimezoneSerializer {
+    fn to_python(
+        &self,
+        value: &Bound<'_, PyAny>,
+        include: Option<&Bound<'_, PyAny>>,
+        exclude: Option<&Bound<'_, PyAny>>,
+        extra: &Extra,
+    ) -> PyResult<PyObject> {
+        let py = value.py();
+        if value.is_instance_of::<PyTzInfo>() {
+            match extra.mode {
+                SerMode::Json => Ok(timezone_to_string(value)?.into_py(py)),
+                _ => Ok(value.into_py(py)),
+            }
+        } else {
+            extra.warnings.on_fallback_py(self.get_name(), value, extra)?;
+            infer_to_python(value, include, exclude, extra)
+        }
+    }
+
+    fn json_key<'a>(&self, key: &'a Bound<'_, PyAny>, extra: &Extra) -> PyResult<Cow<'a, str>> {
+        if key.is_instance_of::<PyTzInfo>() {
+            Ok(Cow::Owned(timezone_to_string(key)?))
+        } else {
+            extra.warnings.on_fallback_py(self.get_name(), key, extra)?;
+            infer_json_key(key, extra)
+        }
+    }
+
+    fn serde_serialize<S: serde::ser::Serializer>(
+        &self,
+        value: &Bound<'_, PyAny>,
+        serializer: S,
+        include: Option<&Bound<'_, PyAny>>,
+        exclude: Option<&Bound<'_, PyAny>>,
+        extra: &Extra,
+    ) -> Result<S::Ok, S::Error> {
+        if value.is_instance_of::<PyTzInfo>() {
+            let s = timezone_to_string(value).map_err(py_err_se_err)?;
+            serializer.serialize_str(&s)
+        } else {
+            extra.warnings.on_fallback_ser::<S>(self.get_name(), value, extra)?;
+            infer_serialize(value, serializer, include, exclude, extra)
+        }
+    }
+
+    fn get_name(&self) -> &str {
+        Self::EXPECTED_TYPE
+    }
+}
@@ -126,8 +126,8 @@ impl TypeSerializer for TupleSerializer {
             Ok(py_tuple) => {
                 let py_tuple = py_tuple.downcast::<PyTuple>().map_err(py_err_se_err)?;
 
-                let n_items = py_tuple.len();
-                let mut seq = serializer.serialize_seq(Some(n_items))?;
+                // MessagePack writes the length up front, so it's only given if no items can be filtered out
+                let mut seq = serializer.serialize_seq(self.filter.serialized_len(py_tuple.len(), include, exclude))?;
 
                 self.for_each_tuple_item_and_serializer(py_tuple, include, exclude, extra, |entry| {
                     seq.serialize_element(&PydanticSerializer::new(
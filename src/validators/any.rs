@@ -4,6 +4,7 @@ use pyo3::types::PyDict;
 use crate::errors::ValResult;
 use crate::input::Input;
 
+use super::json_schema::JsonSchemaState;
 use super::{
     validation_state::Exactness, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator,
 };
@@ -35,7 +36,13 @@ impl Validator for AnyValidator {
     ) -> ValResult<PyObject> {
         // in a union, Any should be preferred to doing lax coercions
         state.floor_exactness(Exactness::Strict);
-        Ok(input.to_object(py))
+        let output = input.to_object(py);
+        state.count_value(output.bind(py), input)?;
+        Ok(output)
+    }
+
+    fn json_schema<'py>(&self, state: &mut JsonSchemaState<'py>) -> PyResult<Bound<'py, PyDict>> {
+        Ok(state.any_schema())
     }
 
     fn get_name(&self) -> &str {
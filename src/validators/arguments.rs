@@ -56,6 +56,7 @@ pub struct ArgumentsValidator {
     var_kwargs_validator: Option<Box<CombinedValidator>>,
     loc_by_alias: bool,
     extra: ExtraBehavior,
+    fail_fast: bool,
 }
 
 impl BuildValidator for ArgumentsValidator {
@@ -168,6 +169,7 @@ impl BuildValidator for ArgumentsValidator {
             var_kwargs_validator,
             loc_by_alias: config.get_as(intern!(py, "loc_by_alias"))?.unwrap_or(true),
             extra: ExtraBehavior::from_schema_or_config(py, schema, config, ExtraBehavior::Forbid)?,
+            fail_fast: config.get_as(intern!(py, "fail_fast"))?.unwrap_or(false),
         }
         .into())
     }
@@ -189,6 +191,7 @@ impl Validator for ArgumentsValidator {
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
         let args = input.validate_args()?;
+        let fail_fast = state.fail_fast_or(self.fail_fast);
 
         let mut output_args: Vec<PyObject> = Vec::with_capacity(self.positional_params_count);
         let output_kwargs = PyDict::new_bound(py);
@@ -197,6 +200,9 @@ impl Validator for ArgumentsValidator {
 
         // go through arguments getting the value from args or kwargs and validating it
         for (index, parameter) in self.parameters.iter().enumerate() {
+            if fail_fast && !errors.is_empty() {
+                break;
+            }
             let mut pos_value = None;
             if let Some(args) = args.args() {
                 if parameter.positional {
@@ -213,6 +219,7 @@ impl Validator for ArgumentsValidator {
                 }
             }
 
+            state.trace_loc(|| &parameter.name);
             match (pos_value, kw_value) {
                 (Some(_), Some((_, kw_value))) => {
                     errors.push(ValLineError::new_with_loc(
@@ -268,12 +275,19 @@ impl Validator for ArgumentsValidator {
                 }
             }
         }
+        if fail_fast && !errors.is_empty() {
+            return Err(ValError::LineErrors(errors));
+        }
         // if there are args check any where index > positional_params_count since they won't have been checked yet
         if let Some(args) = args.args() {
             let len = args.len();
             if len > self.positional_params_count {
                 if let Some(ref validator) = self.var_args_validator {
                     for (index, item) in args.iter().enumerate().skip(self.positional_params_count) {
+                        if fail_fast && !errors.is_empty() {
+                            break;
+                        }
+                        state.trace_loc(|| index);
                         match validator.validate(py, item.borrow_input(), state) {
                             Ok(value) => output_args.push(value),
                             Err(ValError::LineErrors(line_errors)) => {
@@ -289,6 +303,9 @@ impl Validator for ArgumentsValidator {
                             item,
                             index,
                         ));
+                        if fail_fast {
+                            break;
+                        }
                     }
                 }
             }
@@ -300,6 +317,9 @@ impl Validator for ArgumentsValidator {
         if let Some(kwargs) = args.kwargs() {
             if kwargs.len() > used_kwargs.len() {
                 for result in kwargs.iter() {
+                    if fail_fast && !errors.is_empty() {
+                        break;
+                    }
                     let (raw_key, value) = result?;
                     let either_str = match raw_key
                         .borrow_input()
@@ -321,18 +341,21 @@ impl Validator for ArgumentsValidator {
                     if !used_kwargs.contains(either_str.as_cow()?.as_ref()) {
                         match self.var_kwargs_mode {
                             VarKwargsMode::Uniform => match &self.var_kwargs_validator {
-                                Some(validator) => match validator.validate(py, value.borrow_input(), state) {
-                                    Ok(value) => {
-                                        output_kwargs
-                                            .set_item(either_str.as_py_string(py, state.cache_str()), value)?;
-                                    }
-                                    Err(ValError::LineErrors(line_errors)) => {
-                                        for err in line_errors {
-                                            errors.push(err.with_outer_location(raw_key.clone()));
+                                Some(validator) => {
+                                    state.trace_loc(|| raw_key.clone());
+                                    match validator.validate(py, value.borrow_input(), state) {
+                                        Ok(value) => {
+                                            output_kwargs
+                                                .set_item(either_str.as_py_string(py, state.cache_str()), value)?;
+                                        }
+                                        Err(ValError::LineErrors(line_errors)) => {
+                                            for err in line_errors {
+                                                errors.push(err.with_outer_location(raw_key.clone()));
+                                            }
                                         }
+                                        Err(err) => return Err(err),
                                     }
-                                    Err(err) => return Err(err),
-                                },
+                                }
                                 None => {
                                     if let ExtraBehavior::Forbid = self.extra {
                                         errors.push(ValLineError::new_with_loc(
@@ -353,6 +376,10 @@ impl Validator for ArgumentsValidator {
             }
         }
 
+        if fail_fast && !errors.is_empty() {
+            return Err(ValError::LineErrors(errors));
+        }
+
         if self.var_kwargs_mode == VarKwargsMode::UnpackedTypedDict {
             // `var_kwargs_validator` is guaranteed to be `Some`:
             match self
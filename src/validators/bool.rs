@@ -5,6 +5,7 @@ use crate::build_tools::is_strict;
 use crate::errors::ValResult;
 use crate::input::Input;
 
+use super::json_schema::JsonSchemaState;
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
 #[derive(Debug, Clone)]
@@ -43,6 +44,10 @@ impl Validator for BoolValidator {
             .map(|val_match| val_match.unpack(state).into_py(py))
     }
 
+    fn json_schema<'py>(&self, state: &mut JsonSchemaState<'py>) -> PyResult<Bound<'py, PyDict>> {
+        state.type_schema("boolean")
+    }
+
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
@@ -9,6 +9,7 @@ use crate::input::Input;
 use crate::tools::SchemaDict;
 
 use super::config::ValBytesMode;
+use super::json_schema::{set_constraint, JsonSchemaState};
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
 #[derive(Debug, Clone)]
@@ -54,6 +55,10 @@ impl Validator for BytesValidator {
             .map(|m| m.unpack(state).into_py(py))
     }
 
+    fn json_schema<'py>(&self, state: &mut JsonSchemaState<'py>) -> PyResult<Bound<'py, PyDict>> {
+        state.format_schema("binary")
+    }
+
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
@@ -106,6 +111,13 @@ impl Validator for BytesConstrainedValidator {
         Ok(either_bytes.into_py(py))
     }
 
+    fn json_schema<'py>(&self, state: &mut JsonSchemaState<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let schema = state.format_schema("binary")?;
+        set_constraint(&schema, "minLength", self.min_length)?;
+        set_constraint(&schema, "maxLength", self.max_length)?;
+        Ok(schema)
+    }
+
     fn get_name(&self) -> &str {
         "constrained-bytes"
     }
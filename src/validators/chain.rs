@@ -7,6 +7,7 @@ use crate::errors::ValResult;
 use crate::input::Input;
 use crate::tools::SchemaDict;
 
+use super::json_schema::JsonSchemaState;
 use super::validation_state::ValidationState;
 use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, Validator};
 
@@ -83,6 +84,11 @@ impl Validator for ChainValidator {
         steps_iter.try_fold(value, |v, step| step.validate(py, v.bind(py), state))
     }
 
+    fn json_schema<'py>(&self, state: &mut JsonSchemaState<'py>) -> PyResult<Bound<'py, PyDict>> {
+        // inputs are validated by the first step, later steps validate its output
+        self.steps[0].json_schema(state)
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
@@ -7,6 +7,7 @@ use crate::build_tools::is_strict;
 use crate::errors::{ErrorTypeDefaults, ToErrorValue, ValError, ValResult};
 use crate::input::Input;
 
+use super::json_schema::JsonSchemaState;
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
 static COMPLEX_TYPE: GILOnceCell<Py<PyType>> = GILOnceCell::new();
@@ -49,6 +50,10 @@ impl Validator for ComplexValidator {
         Ok(res.into_py(py))
     }
 
+    fn json_schema<'py>(&self, state: &mut JsonSchemaState<'py>) -> PyResult<Bound<'py, PyDict>> {
+        state.type_schema("string")
+    }
+
     fn get_name(&self) -> &str {
         "complex"
     }
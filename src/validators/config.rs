@@ -4,6 +4,7 @@ use std::str::FromStr;
 use base64::engine::general_purpose::GeneralPurpose;
 use base64::engine::{DecodePaddingMode, GeneralPurposeConfig};
 use base64::{alphabet, DecodeError, Engine};
+use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::types::{PyDict, PyString};
 use pyo3::{intern, prelude::*};
 
@@ -62,3 +63,34 @@ impl ValBytesMode {
         }
     }
 }
+
+/// How `SchemaValidator.validate_many` should handle inputs which fail validation
+#[derive(Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ManyErrorMode {
+    /// raise a `ValidationError` for the first invalid input
+    #[default]
+    Raise,
+    /// return the errors for invalid inputs alongside the valid outputs
+    Collect,
+    /// silently drop invalid inputs
+    Skip,
+}
+
+impl<'py> FromPyObject<'py> for ManyErrorMode {
+    fn extract_bound(ob: &Bound<'py, PyAny>) -> PyResult<ManyErrorMode> {
+        if let Ok(str_mode) = ob.extract::<&str>() {
+            match str_mode {
+                "raise" => Ok(Self::Raise),
+                "collect" => Ok(Self::Collect),
+                "skip" => Ok(Self::Skip),
+                _ => Err(PyValueError::new_err(
+                    "Invalid on_error parameter, should be `'raise'`, `'collect'` or `'skip'`",
+                )),
+            }
+        } else {
+            Err(PyTypeError::new_err(
+                "Invalid on_error parameter, should be `'raise'`, `'collect'` or `'skip'`",
+            ))
+        }
+    }
+}
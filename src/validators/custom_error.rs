@@ -8,6 +8,7 @@ use crate::errors::{ErrorType, PydanticCustomError, PydanticKnownError, ValError
 use crate::input::Input;
 use crate::tools::SchemaDict;
 
+use super::json_schema::JsonSchemaState;
 use super::validation_state::ValidationState;
 use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, Validator};
 
@@ -94,9 +95,15 @@ impl Validator for CustomErrorValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        self.validator
-            .validate(py, input, state)
-            .map_err(|_| self.custom_error.as_val_error(input))
+        match self.validator.validate(py, input, state) {
+            // resource limits apply to the whole input, so they're never replaced by the custom error
+            Err(err @ ValError::LimitExceeded(_)) => Err(err),
+            result => result.map_err(|_| self.custom_error.as_val_error(input)),
+        }
+    }
+
+    fn json_schema<'py>(&self, state: &mut JsonSchemaState<'py>) -> PyResult<Bound<'py, PyDict>> {
+        self.validator.json_schema(state)
     }
 
     fn get_name(&self) -> &str {
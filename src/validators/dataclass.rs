@@ -15,6 +15,7 @@ use crate::lookup_key::LookupKey;
 use crate::tools::SchemaDict;
 use crate::validators::function::convert_err;
 
+use super::json_schema::{has_default, JsonSchemaState};
 use super::model::{create_class, force_setattr, Revalidate};
 use super::validation_state::Exactness;
 use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
@@ -41,6 +42,7 @@ pub struct DataclassArgsValidator {
     extra_behavior: ExtraBehavior,
     extras_validator: Option<Box<CombinedValidator>>,
     loc_by_alias: bool,
+    fail_fast: bool,
 }
 
 impl BuildValidator for DataclassArgsValidator {
@@ -129,6 +131,7 @@ impl BuildValidator for DataclassArgsValidator {
             extra_behavior,
             extras_validator,
             loc_by_alias: config.get_as(intern!(py, "loc_by_alias"))?.unwrap_or(true),
+            fail_fast: config.get_as(intern!(py, "fail_fast"))?.unwrap_or(false),
         }
         .into())
     }
@@ -146,6 +149,7 @@ impl Validator for DataclassArgsValidator {
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
         let args = input.validate_dataclass_args(&self.dataclass_name)?;
+        let fail_fast = state.fail_fast_or(self.fail_fast);
 
         let output_dict = PyDict::new_bound(py);
         let mut init_only_args = self.init_only_count.map(Vec::with_capacity);
@@ -171,6 +175,9 @@ impl Validator for DataclassArgsValidator {
 
         // go through fields getting the value from args or kwargs and validating it
         for (index, field) in self.fields.iter().enumerate() {
+            if fail_fast && !errors.is_empty() {
+                break;
+            }
             if !field.init {
                 match field.validator.default_value(py, Some(field.name.as_str()), state) {
                     Ok(Some(value)) => {
@@ -205,6 +212,7 @@ impl Validator for DataclassArgsValidator {
             }
             let kw_value = kw_value.as_ref().map(|(path, value)| (path, value.borrow_input()));
 
+            state.trace_loc(|| &field.name);
             match (pos_value, kw_value) {
                 // found both positional and keyword arguments, error
                 (Some(_), Some((_, kw_value))) => {
@@ -271,6 +279,9 @@ impl Validator for DataclassArgsValidator {
                 }
             }
         }
+        if fail_fast && !errors.is_empty() {
+            return Err(ValError::LineErrors(errors));
+        }
         // if there are more args than positional_count, add an error for each one
         if let Some(args) = args.args() {
             let len = args.len();
@@ -281,6 +292,9 @@ impl Validator for DataclassArgsValidator {
                         item,
                         index,
                     ));
+                    if fail_fast {
+                        return Err(ValError::LineErrors(errors));
+                    }
                 }
             }
         }
@@ -288,6 +302,9 @@ impl Validator for DataclassArgsValidator {
         if let Some(kwargs) = args.kwargs() {
             if kwargs.len() != used_keys.len() {
                 for result in kwargs.iter() {
+                    if fail_fast && !errors.is_empty() {
+                        break;
+                    }
                     let (raw_key, value) = result?;
                     match raw_key
                         .borrow_input()
@@ -308,6 +325,7 @@ impl Validator for DataclassArgsValidator {
                                     ExtraBehavior::Ignore => {}
                                     ExtraBehavior::Allow => {
                                         if let Some(ref validator) = self.extras_validator {
+                                            state.trace_loc(|| raw_key.clone());
                                             match validator.validate(py, value.borrow_input(), state) {
                                                 Ok(value) => {
                                                     output_dict.set_item(
@@ -428,6 +446,18 @@ impl Validator for DataclassArgsValidator {
         }
     }
 
+    fn json_schema<'py>(&self, state: &mut JsonSchemaState<'py>) -> PyResult<Bound<'py, PyDict>> {
+        // fields with `init=False` aren't part of the input
+        let fields = self.fields.iter().filter(|field| field.init).map(|field| {
+            (
+                field.lookup_key.first_key(),
+                &field.validator,
+                !has_default(&field.validator),
+            )
+        });
+        state.object_schema(fields, self.extra_behavior, self.extras_validator.as_deref())
+    }
+
     fn get_name(&self) -> &str {
         &self.validator_name
     }
@@ -573,6 +603,12 @@ impl Validator for DataclassValidator {
         Ok(obj.to_object(py))
     }
 
+    fn json_schema<'py>(&self, state: &mut JsonSchemaState<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let schema = self.validator.json_schema(state)?;
+        state.set_class_title(&schema, &self.class)?;
+        Ok(schema)
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
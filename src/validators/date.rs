@@ -9,8 +9,9 @@ use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValResult};
 use crate::input::{EitherDate, Input};
 
 use crate::tools::SchemaDict;
-use crate::validators::datetime::{NowConstraint, NowOp};
+use crate::validators::datetime::{DateTimeFormats, NowConstraint, NowOp};
 
+use super::json_schema::JsonSchemaState;
 use super::Exactness;
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
@@ -18,6 +19,7 @@ use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationSta
 pub struct DateValidator {
     strict: bool,
     constraints: Option<DateConstraints>,
+    formats: Option<DateTimeFormats>,
 }
 
 impl BuildValidator for DateValidator {
@@ -31,6 +33,7 @@ impl BuildValidator for DateValidator {
         Ok(Self {
             strict: is_strict(schema, config)?,
             constraints: DateConstraints::from_py(schema)?,
+            formats: DateTimeFormats::from_py(schema)?,
         }
         .into())
     }
@@ -51,7 +54,18 @@ impl Validator for DateValidator {
             // if the error was a parsing error, in lax mode we allow datetimes at midnight
             Err(line_errors @ ValError::LineErrors(..)) if !strict => {
                 state.floor_exactness(Exactness::Lax);
-                date_from_datetime(input)?.ok_or(line_errors)?
+                match (date_from_datetime(input), &self.formats) {
+                    (Ok(date), _) => date.ok_or(line_errors)?,
+                    // strings which aren't ISO 8601 datetimes either can still match one of the `formats`
+                    (Err(err), Some(formats)) => {
+                        let dt = formats.parse(input, err)?;
+                        if dt.time.hour != 0 || dt.time.minute != 0 || dt.time.second != 0 || dt.time.microsecond != 0 {
+                            return Err(ValError::new(ErrorTypeDefaults::DateFromDatetimeInexact, input));
+                        }
+                        EitherDate::Raw(dt.date)
+                    }
+                    (Err(err), None) => return Err(err),
+                }
             }
             Err(otherwise) => return Err(otherwise),
         };
@@ -100,6 +114,10 @@ impl Validator for DateValidator {
         Ok(date.try_into_py(py)?)
     }
 
+    fn json_schema<'py>(&self, state: &mut JsonSchemaState<'py>) -> PyResult<Bound<'py, PyDict>> {
+        state.format_schema("date")
+    }
+
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
@@ -1,7 +1,7 @@
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::sync::GILOnceCell;
-use pyo3::types::{PyDict, PyString};
+use pyo3::types::{PyDict, PyList, PyString};
 use speedate::{DateTime, Time};
 use std::cmp::Ordering;
 use strum::EnumMessage;
@@ -10,10 +10,13 @@ use crate::build_tools::{is_strict, py_schema_error_type};
 use crate::build_tools::{py_schema_err, schema_or_config_same};
 use crate::errors::ToErrorValue;
 use crate::errors::{py_err_string, ErrorType, ErrorTypeDefaults, ValError, ValResult};
-use crate::input::{EitherDateTime, Input};
+use crate::input::{DateTimeFormat, EitherDateTime, Input};
 
 use crate::tools::SchemaDict;
 
+use super::json_schema::JsonSchemaState;
+use super::literal::expected_repr_name;
+use super::timezone::parse_timezone;
 use super::Exactness;
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
@@ -22,6 +25,8 @@ pub struct DateTimeValidator {
     strict: bool,
     constraints: Option<DateTimeConstraints>,
     microseconds_precision: speedate::MicrosecondsPrecisionOverflowBehavior,
+    normalize_tz: Option<PyObject>,
+    formats: Option<DateTimeFormats>,
 }
 
 pub(crate) fn extract_microseconds_precision(
@@ -46,16 +51,31 @@ impl BuildValidator for DateTimeValidator {
         config: Option<&Bound<'_, PyDict>>,
         _definitions: &mut DefinitionsBuilder<CombinedValidator>,
     ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let normalize_tz = match schema.get_as::<Bound<'_, PyString>>(intern!(py, "normalize_tz"))? {
+            Some(tz) => match parse_timezone(py, tz.to_str()?)? {
+                Some(tz) => Some(tz.unbind()),
+                None => {
+                    return py_schema_err!(
+                        "Invalid normalize_tz {:?}, expected an IANA time zone name or UTC offset",
+                        tz.to_str()?
+                    )
+                }
+            },
+            None => None,
+        };
         Ok(Self {
             strict: is_strict(schema, config)?,
             constraints: DateTimeConstraints::from_py(schema)?,
             microseconds_precision: extract_microseconds_precision(schema, config)?,
+            normalize_tz,
+            formats: DateTimeFormats::from_py(schema)?,
         }
         .into())
     }
 }
 
-impl_py_gc_traverse!(DateTimeValidator {});
+impl_py_gc_traverse!(DateTimeValidator { normalize_tz });
 
 impl Validator for DateTimeValidator {
     fn validate<'py>(
@@ -70,7 +90,12 @@ impl Validator for DateTimeValidator {
             // if the error was a parsing error, in lax mode we allow dates and add the time 00:00:00
             Err(line_errors @ ValError::LineErrors(..)) if !strict => {
                 state.floor_exactness(Exactness::Lax);
-                datetime_from_date(input)?.ok_or(line_errors)?
+                match (datetime_from_date(input), &self.formats) {
+                    (Ok(datetime), _) => datetime.ok_or(line_errors)?,
+                    // strings which aren't ISO 8601 dates either can still match one of the `formats`
+                    (Err(err), Some(formats)) => EitherDateTime::Raw(formats.parse(input, err)?),
+                    (Err(err), None) => return Err(err),
+                }
             }
             Err(otherwise) => return Err(otherwise),
         };
@@ -130,7 +155,28 @@ impl Validator for DateTimeValidator {
                 tz_constraint.tz_check(speedate_dt.time.tz_offset, input)?;
             }
         }
-        Ok(datetime.try_into_py(py)?)
+        let py_datetime = datetime.try_into_py(py)?;
+        if let Some(ref normalize_tz) = self.normalize_tz {
+            // naive datetimes are left as they are, `tz_constraint='aware'` can be used to reject them
+            let py_datetime = py_datetime.bind(py);
+            if !PyAnyMethods::is_none(&py_datetime.getattr(intern!(py, "tzinfo"))?) {
+                return match py_datetime.call_method1(intern!(py, "astimezone"), (normalize_tz,)) {
+                    Ok(normalized) => Ok(normalized.into()),
+                    Err(err) => {
+                        let error = py_err_string(py, err);
+                        Err(ValError::new(
+                            ErrorType::DatetimeObjectInvalid { error, context: None },
+                            input,
+                        ))
+                    }
+                };
+            }
+        }
+        Ok(py_datetime)
+    }
+
+    fn json_schema<'py>(&self, state: &mut JsonSchemaState<'py>) -> PyResult<Bound<'py, PyDict>> {
+        state.format_schema("date-time")
     }
 
     fn get_name(&self) -> &str {
@@ -328,3 +374,54 @@ impl TZConstraint {
         Ok(())
     }
 }
+
+/// The `formats` a string may match in lax mode, once it has failed to parse as ISO 8601
+#[derive(Debug, Clone)]
+pub(super) struct DateTimeFormats {
+    formats: Vec<DateTimeFormat>,
+    repr: String,
+}
+
+impl DateTimeFormats {
+    pub(super) fn from_py(schema: &Bound<'_, PyDict>) -> PyResult<Option<Self>> {
+        let list = match schema.get_as::<Bound<'_, PyList>>(intern!(schema.py(), "formats"))? {
+            Some(list) => list,
+            None => return Ok(None),
+        };
+        if list.is_empty() {
+            return py_schema_err!("`formats` should have length > 0");
+        }
+        let mut formats = Vec::with_capacity(list.len());
+        let mut repr_args = Vec::with_capacity(list.len());
+        for item in list {
+            let format: String = item.extract()?;
+            match DateTimeFormat::new(&format) {
+                Ok(parsed) => formats.push(parsed),
+                Err(error) => return py_schema_err!("Invalid format {:?}, {}", format, error),
+            }
+            repr_args.push(format!("'{format}'"));
+        }
+        let (repr, _) = expected_repr_name(repr_args, "formats");
+        Ok(Some(Self { formats, repr }))
+    }
+
+    /// Parse a string input with the first format it matches, `error` is returned unchanged if the input
+    /// isn't a string
+    pub(super) fn parse<'py>(&self, input: &(impl Input<'py> + ?Sized), error: ValError) -> ValResult<DateTime> {
+        let either_str = match input.validate_str(false, false) {
+            Ok(val_match) => val_match.into_inner(),
+            Err(_) => return Err(error),
+        };
+        let s = either_str.as_cow()?;
+        match self.formats.iter().find_map(|format| format.parse(&s)) {
+            Some(datetime) => Ok(datetime),
+            None => Err(ValError::new(
+                ErrorType::DatetimeFormat {
+                    formats: self.repr.clone(),
+                    context: None,
+                },
+                input,
+            )),
+        }
+    }
+}
@@ -12,6 +12,7 @@ use crate::errors::{ToErrorValue, ValError};
 use crate::input::Input;
 use crate::tools::SchemaDict;
 
+use super::json_schema::{set_number_constraints, JsonSchemaState};
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
 static DECIMAL_TYPE: GILOnceCell<Py<PyType>> = GILOnceCell::new();
@@ -258,6 +259,22 @@ impl Validator for DecimalValidator {
         Ok(decimal.into())
     }
 
+    fn json_schema<'py>(&self, state: &mut JsonSchemaState<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let py = state.py();
+        let as_float = |value: &Option<Py<PyAny>>| value.as_ref().map(|v| v.extract::<f64>(py)).transpose();
+        let number_schema = state.type_schema("number")?;
+        set_number_constraints(
+            &number_schema,
+            as_float(&self.multiple_of)?,
+            as_float(&self.le)?,
+            as_float(&self.lt)?,
+            as_float(&self.ge)?,
+            as_float(&self.gt)?,
+        )?;
+        // decimals are serialized as strings, so strings are accepted too
+        state.choices_schema("anyOf", vec![number_schema, state.type_schema("string")?])
+    }
+
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
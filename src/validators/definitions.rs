@@ -10,7 +10,10 @@ use crate::input::Input;
 use crate::recursion_guard::RecursionGuard;
 use crate::tools::SchemaDict;
 
-use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
+use super::json_schema::JsonSchemaState;
+use super::{
+    build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, ValidationState, Validator,
+};
 
 #[derive(Debug, Clone)]
 pub struct DefinitionsValidatorBuilder;
@@ -77,12 +80,20 @@ impl Validator for DefinitionRefValidator {
     ) -> ValResult<PyObject> {
         self.definition.read(|validator| {
             let validator = validator.unwrap();
-            if let Some(id) = input.as_python().map(py_identity) {
+            if let Some(obj) = input.as_python() {
+                let node_id = self.definition.id();
+                if let Some(output) = state.recursion_guard.get_reused(py, obj, node_id) {
+                    // how exactly `obj` matched the first time isn't kept, so don't let a union prefer this output
+                    state.floor_exactness(Exactness::Lax);
+                    return Ok(output);
+                }
                 // Python objects can be cyclic, so need recursion guard
-                let Ok(mut guard) = RecursionGuard::new(state, id, self.definition.id()) else {
+                let Ok(mut guard) = RecursionGuard::new(state, py_identity(obj), node_id) else {
                     return Err(ValError::new(ErrorTypeDefaults::RecursionLoop, input));
                 };
-                validator.validate(py, input, guard.state())
+                let output = validator.validate(py, input, guard.state())?;
+                guard.state().recursion_guard.set_reused(obj, node_id, &output);
+                Ok(output)
             } else {
                 validator.validate(py, input, state)
             }
@@ -106,6 +117,11 @@ impl Validator for DefinitionRefValidator {
         })
     }
 
+    fn json_schema<'py>(&self, state: &mut JsonSchemaState<'py>) -> PyResult<Bound<'py, PyDict>> {
+        self.definition
+            .read(|validator| state.definition_ref(self.definition.reference(), validator.unwrap()))
+    }
+
     fn get_name(&self) -> &str {
         self.definition.get_or_init_name(|v| v.get_name().into())
     }
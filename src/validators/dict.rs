@@ -11,6 +11,7 @@ use crate::input::{Input, ValidatedDict};
 use crate::tools::SchemaDict;
 
 use super::any::AnyValidator;
+use super::json_schema::{set_constraint, JsonSchemaState};
 use super::list::length_check;
 use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
@@ -22,6 +23,7 @@ pub struct DictValidator {
     min_length: Option<usize>,
     max_length: Option<usize>,
     name: String,
+    fail_fast: bool,
 }
 
 impl BuildValidator for DictValidator {
@@ -54,6 +56,7 @@ impl BuildValidator for DictValidator {
             min_length: schema.get_as(intern!(py, "min_length"))?,
             max_length: schema.get_as(intern!(py, "max_length"))?,
             name,
+            fail_fast: config.get_as(intern!(py, "fail_fast"))?.unwrap_or(false),
         }
         .into())
     }
@@ -73,6 +76,7 @@ impl Validator for DictValidator {
     ) -> ValResult<PyObject> {
         let strict = state.strict_or(self.strict);
         let dict = input.validate_dict(strict)?;
+        let fail_fast = state.fail_fast_or(self.fail_fast);
         dict.iterate(ValidateToDict {
             py,
             input,
@@ -81,9 +85,28 @@ impl Validator for DictValidator {
             key_validator: &self.key_validator,
             value_validator: &self.value_validator,
             state,
+            fail_fast,
         })?
     }
 
+    fn json_schema<'py>(&self, state: &mut JsonSchemaState<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let py = state.py();
+        let schema = state.type_schema("object")?;
+        // JSON keys are always strings, so the key schema is only useful if it constrains them further
+        let key_schema = self.key_validator.json_schema(state)?;
+        let key_type: Option<String> = key_schema.get_as(intern!(py, "type"))?;
+        if key_schema.len() > 1 && key_type.as_deref() == Some("string") {
+            schema.set_item(intern!(py, "propertyNames"), key_schema)?;
+        }
+        schema.set_item(
+            intern!(py, "additionalProperties"),
+            self.value_validator.json_schema(state)?,
+        )?;
+        set_constraint(&schema, "minProperties", self.min_length)?;
+        set_constraint(&schema, "maxProperties", self.max_length)?;
+        Ok(schema)
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
@@ -97,6 +120,7 @@ struct ValidateToDict<'a, 's, 'py, I: Input<'py> + ?Sized> {
     key_validator: &'a CombinedValidator,
     value_validator: &'a CombinedValidator,
     state: &'a mut ValidationState<'s, 'py>,
+    fail_fast: bool,
 }
 
 impl<'py, Key, Value, I: Input<'py> + ?Sized> ConsumeIterator<ValResult<(Key, Value)>>
@@ -109,9 +133,20 @@ where
     fn consume_iterator(self, iterator: impl Iterator<Item = ValResult<(Key, Value)>>) -> ValResult<PyObject> {
         let output = PyDict::new_bound(self.py);
         let mut errors: Vec<ValLineError> = Vec::new();
+        let allow_partial = self.state.allow_partial;
+        let partial_tail = self.state.partial_tail;
+        let items = self.state.enumerate_last_partial(iterator);
+        self.state.partial_tail = partial_tail.last_item();
 
-        for item_result in iterator {
+        for (_, is_last_partial, item_result) in items {
+            if (self.fail_fast && !errors.is_empty()) || self.state.errors_capped(errors.len()) {
+                break;
+            }
             let (key, value) = item_result?;
+            // keys are always complete, only the last value of partial input may not be
+            self.state.allow_partial = false;
+            self.state.count_items(1, self.input)?;
+            self.state.trace_loc(|| key.clone());
             let output_key = match self.key_validator.validate(self.py, key.borrow_input(), self.state) {
                 Ok(value) => Some(value),
                 Err(ValError::LineErrors(line_errors)) => {
@@ -124,8 +159,13 @@ where
                 Err(ValError::Omit) => continue,
                 Err(err) => return Err(err),
             };
+            self.state.allow_partial = is_last_partial;
+            self.state.trace_loc(|| key.clone());
             let output_value = match self.value_validator.validate(self.py, value.borrow_input(), self.state) {
                 Ok(value) => Some(value),
+                Err(ValError::LineErrors(_)) if is_last_partial && self.state.is_incomplete(value.borrow_input()) => {
+                    None
+                }
                 Err(ValError::LineErrors(line_errors)) => {
                     for err in line_errors {
                         errors.push(err.with_outer_location(key.clone()));
@@ -139,6 +179,8 @@ where
                 output.set_item(key, value)?;
             }
         }
+        self.state.allow_partial = allow_partial;
+        self.state.partial_tail = partial_tail;
 
         if errors.is_empty() {
             let input = self.input;
@@ -12,6 +12,7 @@ use crate::input::Input;
 use crate::tools::{safe_repr, SchemaDict};
 
 use super::is_instance::class_repr;
+use super::json_schema::{enum_schema, json_value, JsonSchemaState};
 use super::literal::{expected_repr_name, LiteralLookup};
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, ValidationState, Validator};
 
@@ -160,6 +161,19 @@ impl<T: EnumValidateValue> Validator for EnumValidator<T> {
         ))
     }
 
+    fn json_schema<'py>(&self, state: &mut JsonSchemaState<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let py = state.py();
+        let values = self
+            .lookup
+            .values
+            .iter()
+            .map(|member| json_value(member.bind(py)))
+            .collect::<PyResult<Vec<_>>>()?;
+        let schema = enum_schema(py, &values)?;
+        state.set_class_title(&schema, &self.class)?;
+        Ok(schema)
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
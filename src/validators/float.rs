@@ -9,6 +9,7 @@ use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValResult};
 use crate::input::Input;
 use crate::tools::SchemaDict;
 
+use super::json_schema::{set_number_constraints, JsonSchemaState};
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
 pub struct FloatBuilder;
@@ -77,6 +78,10 @@ impl Validator for FloatValidator {
         Ok(either_float.into_py(py))
     }
 
+    fn json_schema<'py>(&self, state: &mut JsonSchemaState<'py>) -> PyResult<Bound<'py, PyDict>> {
+        state.type_schema("number")
+    }
+
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
@@ -167,6 +172,12 @@ impl Validator for ConstrainedFloatValidator {
         Ok(either_float.into_py(py))
     }
 
+    fn json_schema<'py>(&self, state: &mut JsonSchemaState<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let schema = state.type_schema("number")?;
+        set_number_constraints(&schema, self.multiple_of, self.le, self.lt, self.ge, self.gt)?;
+        Ok(schema)
+    }
+
     fn get_name(&self) -> &str {
         "constrained-float"
     }
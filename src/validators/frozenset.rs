@@ -1,3 +1,4 @@
+use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyFrozenSet};
 
@@ -5,6 +6,7 @@ use crate::errors::ValResult;
 use crate::input::{validate_iter_to_set, BorrowInput, ConsumeIterator, Input, ValidatedSet};
 use crate::tools::SchemaDict;
 
+use super::json_schema::JsonSchemaState;
 use super::list::min_length_check;
 use super::set::set_build;
 use super::validation_state::ValidationState;
@@ -36,6 +38,7 @@ impl Validator for FrozenSetValidator {
     ) -> ValResult<PyObject> {
         let collection = input.validate_frozenset(state.strict_or(self.strict))?.unpack(state);
         let f_set = PyFrozenSet::empty_bound(py)?;
+        let fail_fast = state.fail_fast_or(self.fail_fast);
         collection.iterate(ValidateToFrozenSet {
             py,
             input,
@@ -43,12 +46,18 @@ impl Validator for FrozenSetValidator {
             max_length: self.max_length,
             item_validator: &self.item_validator,
             state,
-            fail_fast: self.fail_fast,
+            fail_fast,
         })??;
         min_length_check!(input, "Frozenset", self.min_length, f_set);
         Ok(f_set.into_py(py))
     }
 
+    fn json_schema<'py>(&self, state: &mut JsonSchemaState<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let schema = state.array_schema(Some(&self.item_validator), self.min_length, self.max_length)?;
+        schema.set_item(intern!(state.py(), "uniqueItems"), true)?;
+        Ok(schema)
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
@@ -6,8 +6,8 @@ use pyo3::types::{PyAny, PyDict, PyString};
 use pyo3::{intern, PyTraverseError, PyVisit};
 
 use crate::errors::{
-    ErrorType, PydanticCustomError, PydanticKnownError, PydanticOmit, ToErrorValue, ValError, ValResult,
-    ValidationError,
+    ErrorOutputConfig, ErrorType, PydanticCustomError, PydanticKnownError, PydanticOmit, ToErrorValue, ValError,
+    ValResult, ValidationError,
 };
 use crate::input::Input;
 use crate::py_gc::PyGcTraverse;
@@ -15,6 +15,7 @@ use crate::tools::{function_name, safe_repr, SchemaDict};
 use crate::PydanticUseDefault;
 
 use super::generator::InternalValidator;
+use super::json_schema::JsonSchemaState;
 use super::{
     build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, Extra, InputType, ValidationState,
     Validator,
@@ -140,6 +141,10 @@ impl Validator for FunctionBeforeValidator {
         self._validate(validate, py, obj, state)
     }
 
+    fn json_schema<'py>(&self, state: &mut JsonSchemaState<'py>) -> PyResult<Bound<'py, PyDict>> {
+        self.validator.json_schema(state)
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
@@ -206,6 +211,10 @@ impl Validator for FunctionAfterValidator {
         self._validate(validate, py, obj, state)
     }
 
+    fn json_schema<'py>(&self, state: &mut JsonSchemaState<'py>) -> PyResult<Bound<'py, PyDict>> {
+        self.validator.json_schema(state)
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
@@ -277,6 +286,7 @@ pub struct FunctionWrapValidator {
     info_arg: bool,
     hide_input_in_errors: bool,
     validation_error_cause: bool,
+    error_output: ErrorOutputConfig,
 }
 
 impl BuildValidator for FunctionWrapValidator {
@@ -292,6 +302,7 @@ impl BuildValidator for FunctionWrapValidator {
         let function_info = destructure_function_schema(schema)?;
         let hide_input_in_errors: bool = config.get_as(intern!(py, "hide_input_in_errors"))?.unwrap_or(false);
         let validation_error_cause: bool = config.get_as(intern!(py, "validation_error_cause"))?.unwrap_or(false);
+        let error_output = ErrorOutputConfig::from_config(config)?;
         Ok(Self {
             validator: Arc::new(validator),
             func: function_info.function.clone(),
@@ -304,6 +315,7 @@ impl BuildValidator for FunctionWrapValidator {
             info_arg: function_info.info_arg,
             hide_input_in_errors,
             validation_error_cause,
+            error_output,
         }
         .into())
     }
@@ -348,6 +360,7 @@ impl Validator for FunctionWrapValidator {
                 state,
                 self.hide_input_in_errors,
                 self.validation_error_cause,
+                self.error_output.clone(),
             ),
         };
         let handler = Bound::new(py, handler)?;
@@ -372,6 +385,7 @@ impl Validator for FunctionWrapValidator {
                 state,
                 self.hide_input_in_errors,
                 self.validation_error_cause,
+                self.error_output.clone(),
             ),
             updated_field_name: field_name.to_string(),
             updated_field_value: field_value.to_object(py),
@@ -379,6 +393,10 @@ impl Validator for FunctionWrapValidator {
         self._validate(Bound::new(py, handler)?.as_any(), py, obj, state)
     }
 
+    fn json_schema<'py>(&self, state: &mut JsonSchemaState<'py>) -> PyResult<Bound<'py, PyDict>> {
+        self.validator.json_schema(state)
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
@@ -4,16 +4,18 @@ use std::sync::Arc;
 use pyo3::types::PyDict;
 use pyo3::{prelude::*, PyTraverseError, PyVisit};
 
-use crate::errors::{ErrorType, LocItem, ValError, ValResult};
+use crate::errors::{ErrorOutputConfig, ErrorType, LocItem, ValError, ValResult};
 use crate::input::{BorrowInput, GenericIterator, Input};
 use crate::py_gc::PyGcTraverse;
 use crate::recursion_guard::RecursionState;
 use crate::tools::SchemaDict;
 use crate::ValidationError;
 
+use super::json_schema::JsonSchemaState;
 use super::list::get_items_schema;
 use super::{
-    BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, Extra, InputType, ValidationState, Validator,
+    BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, Extra, InputType, ResourceLimits,
+    ValidationState, Validator,
 };
 
 #[derive(Debug, Clone)]
@@ -24,6 +26,7 @@ pub struct GeneratorValidator {
     name: String,
     hide_input_in_errors: bool,
     validation_error_cause: bool,
+    error_output: ErrorOutputConfig,
 }
 
 impl BuildValidator for GeneratorValidator {
@@ -45,6 +48,7 @@ impl BuildValidator for GeneratorValidator {
         let validation_error_cause: bool = config
             .get_as(pyo3::intern!(schema.py(), "validation_error_cause"))?
             .unwrap_or(false);
+        let error_output = ErrorOutputConfig::from_config(config)?;
         Ok(Self {
             item_validator,
             name,
@@ -52,6 +56,7 @@ impl BuildValidator for GeneratorValidator {
             max_length: schema.get_as(pyo3::intern!(schema.py(), "max_length"))?,
             hide_input_in_errors,
             validation_error_cause,
+            error_output,
         }
         .into())
     }
@@ -75,6 +80,7 @@ impl Validator for GeneratorValidator {
                 state,
                 self.hide_input_in_errors,
                 self.validation_error_cause,
+                self.error_output.clone(),
             )
         });
 
@@ -85,10 +91,15 @@ impl Validator for GeneratorValidator {
             max_length: self.max_length,
             hide_input_in_errors: self.hide_input_in_errors,
             validation_error_cause: self.validation_error_cause,
+            error_output: self.error_output.clone(),
         };
         Ok(v_iterator.into_py(py))
     }
 
+    fn json_schema<'py>(&self, state: &mut JsonSchemaState<'py>) -> PyResult<Bound<'py, PyDict>> {
+        state.array_schema(self.item_validator.as_deref(), self.min_length, self.max_length)
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
@@ -103,6 +114,7 @@ struct ValidatorIterator {
     max_length: Option<usize>,
     hide_input_in_errors: bool,
     validation_error_cause: bool,
+    error_output: ErrorOutputConfig,
 }
 
 #[pymethods]
@@ -116,6 +128,7 @@ impl ValidatorIterator {
         let max_length = slf.max_length;
         let hide_input_in_errors = slf.hide_input_in_errors;
         let validation_error_cause = slf.validation_error_cause;
+        let error_output = slf.error_output.clone();
         let Self {
             validator, iterator, ..
         } = &mut *slf;
@@ -143,6 +156,7 @@ impl ValidatorIterator {
                                         None,
                                         hide_input_in_errors,
                                         validation_error_cause,
+                                        &error_output,
                                     ));
                                 }
                             }
@@ -172,6 +186,7 @@ impl ValidatorIterator {
                                     None,
                                     hide_input_in_errors,
                                     validation_error_cause,
+                                    &error_output,
                                 ));
                             }
                         }
@@ -184,6 +199,7 @@ impl ValidatorIterator {
         match iterator {
             GenericIterator::PyIterator(ref mut iter) => next!(iter),
             GenericIterator::JsonArray(ref mut iter) => next!(iter),
+            GenericIterator::MsgpackArray(ref mut iter) => next!(iter),
         }
     }
 
@@ -192,6 +208,7 @@ impl ValidatorIterator {
         match self.iterator {
             GenericIterator::PyIterator(ref iter) => iter.index(),
             GenericIterator::JsonArray(ref iter) => iter.index(),
+            GenericIterator::MsgpackArray(ref iter) => iter.index(),
         }
     }
 
@@ -219,6 +236,7 @@ pub struct InternalValidator {
     data: Option<Py<PyDict>>,
     strict: Option<bool>,
     from_attributes: Option<bool>,
+    fail_fast: Option<bool>,
     context: Option<PyObject>,
     self_instance: Option<PyObject>,
     recursion_guard: RecursionState,
@@ -226,7 +244,10 @@ pub struct InternalValidator {
     validation_mode: InputType,
     hide_input_in_errors: bool,
     validation_error_cause: bool,
+    error_output: ErrorOutputConfig,
     cache_str: jiter::StringCacheMode,
+    resource_limits: ResourceLimits,
+    max_errors: Option<usize>,
 }
 
 impl fmt::Debug for InternalValidator {
@@ -243,6 +264,7 @@ impl InternalValidator {
         state: &ValidationState,
         hide_input_in_errors: bool,
         validation_error_cause: bool,
+        error_output: ErrorOutputConfig,
     ) -> Self {
         let extra = state.extra();
         Self {
@@ -251,6 +273,7 @@ impl InternalValidator {
             data: extra.data.as_ref().map(|d| d.clone().into()),
             strict: extra.strict,
             from_attributes: extra.from_attributes,
+            fail_fast: extra.fail_fast,
             context: extra.context.map(|d| d.into_py(py)),
             self_instance: extra.self_instance.map(|d| d.into_py(py)),
             recursion_guard: state.recursion_guard.clone(),
@@ -258,7 +281,10 @@ impl InternalValidator {
             validation_mode: extra.input_type,
             hide_input_in_errors,
             validation_error_cause,
+            error_output,
             cache_str: extra.cache_str,
+            resource_limits: extra.resource_limits,
+            max_errors: extra.max_errors,
         }
     }
 
@@ -275,9 +301,12 @@ impl InternalValidator {
             data: self.data.as_ref().map(|data| data.bind(py).clone()),
             strict: self.strict,
             from_attributes: self.from_attributes,
+            fail_fast: self.fail_fast,
             context: self.context.as_ref().map(|data| data.bind(py)),
             self_instance: self.self_instance.as_ref().map(|data| data.bind(py)),
             cache_str: self.cache_str,
+            resource_limits: self.resource_limits,
+            max_errors: self.max_errors,
         };
         let mut state = ValidationState::new(extra, &mut self.recursion_guard);
         state.exactness = self.exactness;
@@ -293,6 +322,7 @@ impl InternalValidator {
                     outer_location,
                     self.hide_input_in_errors,
                     self.validation_error_cause,
+                    &self.error_output,
                 )
             });
         self.exactness = state.exactness;
@@ -310,9 +340,12 @@ impl InternalValidator {
             data: self.data.as_ref().map(|data| data.bind(py).clone()),
             strict: self.strict,
             from_attributes: self.from_attributes,
+            fail_fast: self.fail_fast,
             context: self.context.as_ref().map(|data| data.bind(py)),
             self_instance: self.self_instance.as_ref().map(|data| data.bind(py)),
             cache_str: self.cache_str,
+            resource_limits: self.resource_limits,
+            max_errors: self.max_errors,
         };
         let mut state = ValidationState::new(extra, &mut self.recursion_guard);
         state.exactness = self.exactness;
@@ -325,6 +358,7 @@ impl InternalValidator {
                 outer_location,
                 self.hide_input_in_errors,
                 self.validation_error_cause,
+                &self.error_output,
             )
         });
         self.exactness = state.exactness;
@@ -8,6 +8,7 @@ use crate::errors::{ErrorType, ValError, ValResult};
 use crate::input::{Input, Int};
 use crate::tools::SchemaDict;
 
+use super::json_schema::{set_number_constraints, JsonSchemaState};
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
 #[derive(Debug, Clone)]
@@ -54,6 +55,10 @@ impl Validator for IntValidator {
             .map(|val_match| val_match.unpack(state).into_py(py))
     }
 
+    fn json_schema<'py>(&self, state: &mut JsonSchemaState<'py>) -> PyResult<Bound<'py, PyDict>> {
+        state.type_schema("integer")
+    }
+
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
@@ -139,6 +144,19 @@ impl Validator for ConstrainedIntValidator {
         Ok(either_int.into_py(py))
     }
 
+    fn json_schema<'py>(&self, state: &mut JsonSchemaState<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let schema = state.type_schema("integer")?;
+        set_number_constraints(
+            &schema,
+            self.multiple_of.as_ref(),
+            self.le.as_ref(),
+            self.lt.as_ref(),
+            self.ge.as_ref(),
+            self.gt.as_ref(),
+        )?;
+        Ok(schema)
+    }
+
     fn get_name(&self) -> &str {
         "constrained-int"
     }
@@ -0,0 +1,332 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use std::str::FromStr;
+
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::sync::GILOnceCell;
+use pyo3::types::{PyDict, PyType};
+
+use crate::build_tools::{is_strict, py_schema_err};
+use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValResult};
+use crate::input::input_as_python_instance;
+use crate::input::Input;
+use crate::input::InputType;
+use crate::input::Int;
+use crate::input::ValidationMatch;
+use crate::serializers::BytesMode;
+use crate::tools::SchemaDict;
+
+use super::config::ValBytesMode;
+use super::json_schema::JsonSchemaState;
+use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, ValidationState, Validator};
+
+/// The `ipaddress` classes, indexed by `[IpKind][IpVersion]`
+static IP_TYPES: GILOnceCell<[[Py<PyType>; 2]; 3]> = GILOnceCell::new();
+
+fn get_ip_type(py: Python<'_>, kind: IpKind, version: IpVersion) -> PyResult<&Bound<'_, PyType>> {
+    let types = IP_TYPES.get_or_try_init(py, || {
+        let module = py.import_bound(intern!(py, "ipaddress"))?;
+        let get = |name: &str| module.getattr(name)?.extract::<Py<PyType>>();
+        PyResult::Ok([
+            [get("IPv4Address")?, get("IPv6Address")?],
+            [get("IPv4Network")?, get("IPv6Network")?],
+            [get("IPv4Interface")?, get("IPv6Interface")?],
+        ])
+    })?;
+    Ok(types[kind as usize][version as usize].bind(py))
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum IpKind {
+    Address,
+    Network,
+    Interface,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum IpVersion {
+    V4,
+    V6,
+}
+
+impl IpVersion {
+    fn of(addr: &IpAddr) -> Self {
+        match addr {
+            IpAddr::V4(_) => Self::V4,
+            IpAddr::V6(_) => Self::V6,
+        }
+    }
+
+    fn max_prefix(self) -> u8 {
+        match self {
+            Self::V4 => 32,
+            Self::V6 => 128,
+        }
+    }
+}
+
+macro_rules! ip_validator_builder {
+    ($builder:ident, $expected_type:literal, $kind:expr) => {
+        pub struct $builder;
+
+        impl BuildValidator for $builder {
+            const EXPECTED_TYPE: &'static str = $expected_type;
+
+            fn build(
+                schema: &Bound<'_, PyDict>,
+                config: Option<&Bound<'_, PyDict>>,
+                _definitions: &mut DefinitionsBuilder<CombinedValidator>,
+            ) -> PyResult<CombinedValidator> {
+                IpValidator::build(schema, config, $kind, Self::EXPECTED_TYPE)
+            }
+        }
+    };
+}
+
+ip_validator_builder!(IpAddressBuilder, "ip-address", IpKind::Address);
+ip_validator_builder!(IpNetworkBuilder, "ip-network", IpKind::Network);
+ip_validator_builder!(IpInterfaceBuilder, "ip-interface", IpKind::Interface);
+
+#[derive(Debug, Clone)]
+pub struct IpValidator {
+    kind: IpKind,
+    strict: bool,
+    version: Option<IpVersion>,
+    name: &'static str,
+}
+
+impl IpValidator {
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        kind: IpKind,
+        name: &'static str,
+    ) -> PyResult<CombinedValidator> {
+        let version = match schema.get_as::<u8>(intern!(schema.py(), "version"))? {
+            Some(4) => Some(IpVersion::V4),
+            Some(6) => Some(IpVersion::V6),
+            Some(v) => return py_schema_err!("Invalid IP version: {}, expected 4 or 6", v),
+            None => None,
+        };
+        Ok(Self {
+            kind,
+            strict: is_strict(schema, config)?,
+            version,
+            name,
+        }
+        .into())
+    }
+}
+
+impl_py_gc_traverse!(IpValidator {});
+
+impl Validator for IpValidator {
+    fn validate<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        for &version in self.versions() {
+            if let Some(py_input) = input_as_python_instance(input, get_ip_type(py, self.kind, version)?) {
+                return Ok(py_input.to_object(py));
+            }
+        }
+        if state.strict_or(self.strict) && state.extra().input_type == InputType::Python {
+            let class = self
+                .versions()
+                .iter()
+                .map(|&version| self.class_name(version))
+                .collect::<Vec<_>>()
+                .join(" or ");
+            return Err(ValError::new(ErrorType::IsInstanceOf { class, context: None }, input));
+        }
+        // as with UUIDs, in python mode this is a coercion, in JSON mode a string is an exact match
+        if state.extra().input_type == InputType::Python {
+            state.floor_exactness(Exactness::Lax);
+        }
+        let (addr, prefix) = self.get_ip(py, input)?;
+        let version = IpVersion::of(&addr);
+        let int = match addr {
+            IpAddr::V4(v4) => u32::from(v4).into_py(py),
+            IpAddr::V6(v6) => u128::from(v6).into_py(py),
+        };
+        let class = get_ip_type(py, self.kind, version)?;
+        let output = match self.kind {
+            IpKind::Address => class.call1((int,))?,
+            // the tuple form avoids parsing a string again, networks and interfaces both accept it
+            IpKind::Network | IpKind::Interface => class.call1(((int, prefix.unwrap_or(version.max_prefix())),))?,
+        };
+        Ok(output.into())
+    }
+
+    fn json_schema<'py>(&self, state: &mut JsonSchemaState<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let format = match (self.kind, self.version) {
+            (IpKind::Address, Some(IpVersion::V4)) => "ipv4",
+            (IpKind::Address, Some(IpVersion::V6)) => "ipv6",
+            (IpKind::Address, None) => "ipvanyaddress",
+            (IpKind::Network, Some(IpVersion::V4)) => "ipv4network",
+            (IpKind::Network, Some(IpVersion::V6)) => "ipv6network",
+            (IpKind::Network, None) => "ipvanynetwork",
+            (IpKind::Interface, Some(IpVersion::V4)) => "ipv4interface",
+            (IpKind::Interface, Some(IpVersion::V6)) => "ipv6interface",
+            (IpKind::Interface, None) => "ipvanyinterface",
+        };
+        state.format_schema(format)
+    }
+
+    fn get_name(&self) -> &str {
+        self.name
+    }
+}
+
+impl IpValidator {
+    fn versions(&self) -> &'static [IpVersion] {
+        match self.version {
+            Some(IpVersion::V4) => &[IpVersion::V4],
+            Some(IpVersion::V6) => &[IpVersion::V6],
+            None => &[IpVersion::V4, IpVersion::V6],
+        }
+    }
+
+    fn class_name(&self, version: IpVersion) -> String {
+        let version = match version {
+            IpVersion::V4 => "IPv4",
+            IpVersion::V6 => "IPv6",
+        };
+        let kind = match self.kind {
+            IpKind::Address => "Address",
+            IpKind::Network => "Network",
+            IpKind::Interface => "Interface",
+        };
+        format!("{version}{kind}")
+    }
+
+    fn parsing_error(&self) -> ErrorType {
+        let expected = match self.version {
+            Some(IpVersion::V4) => "IPv4",
+            Some(IpVersion::V6) => "IPv6",
+            None => "IPv4 or IPv6",
+        }
+        .to_string();
+        match self.kind {
+            IpKind::Address => ErrorType::IpAddressParsing {
+                expected,
+                context: None,
+            },
+            IpKind::Network => ErrorType::IpNetworkParsing {
+                expected,
+                context: None,
+            },
+            IpKind::Interface => ErrorType::IpInterfaceParsing {
+                expected,
+                context: None,
+            },
+        }
+    }
+
+    /// Parse the input as an address and, for networks and interfaces, an optional prefix length,
+    /// following the rules of python's `ipaddress` module
+    fn get_ip<'py>(&self, py: Python<'py>, input: &(impl Input<'py> + ?Sized)) -> ValResult<(IpAddr, Option<u8>)> {
+        let parsing_error = || ValError::new(self.parsing_error(), input);
+        let (addr, prefix) =
+            if let Some(either_str) = input.validate_str(true, false).ok().map(ValidationMatch::into_inner) {
+                let cow = either_str.as_cow()?;
+                let ip_str = cow.as_ref();
+                match (self.kind, ip_str.split_once('/')) {
+                    (IpKind::Address, _) | (_, None) => (IpAddr::from_str(ip_str).map_err(|_| parsing_error())?, None),
+                    (_, Some((addr_str, prefix_str))) => {
+                        let addr = IpAddr::from_str(addr_str).map_err(|_| parsing_error())?;
+                        let prefix = parse_prefix(&addr, prefix_str).ok_or_else(parsing_error)?;
+                        (addr, Some(prefix))
+                    }
+                }
+            } else if let Some(either_int) = input.validate_int(true).ok().map(ValidationMatch::into_inner) {
+                let int = match either_int.as_int()? {
+                    Int::I64(i) => u128::try_from(i).ok(),
+                    Int::Big(b) => u128::try_from(&b).ok(),
+                };
+                (
+                    int.and_then(|int| self.addr_from_int(int)).ok_or_else(parsing_error)?,
+                    None,
+                )
+            } else if let Ok(either_bytes) = input.validate_bytes(true, ValBytesMode { ser: BytesMode::Utf8 }) {
+                // bytes are the packed form of an address, as in `ipaddress`
+                let addr = match either_bytes.into_inner().as_slice() {
+                    &[a, b, c, d] => IpAddr::V4(Ipv4Addr::new(a, b, c, d)),
+                    bytes => IpAddr::V6(<[u8; 16]>::try_from(bytes).map_err(|_| parsing_error())?.into()),
+                };
+                (addr, None)
+            } else if let Some(addr) = as_py_address(py, input)? {
+                // networks and interfaces also accept addresses, with the maximum prefix length
+                (addr, None)
+            } else {
+                return Err(ValError::new(ErrorTypeDefaults::IpType, input));
+            };
+
+        match self.version {
+            Some(version) if version != IpVersion::of(&addr) => return Err(parsing_error()),
+            _ => (),
+        }
+        if self.kind == IpKind::Network {
+            // like `ipaddress.ip_network` with `strict=True`, host bits may not be set
+            let host_bits = match (addr, prefix) {
+                (IpAddr::V4(v4), Some(prefix)) => u128::from(u32::from(v4)) & (u128::from(u32::MAX) >> prefix),
+                (IpAddr::V6(v6), Some(prefix)) => u128::from(v6).checked_shl(u32::from(prefix)).unwrap_or(0),
+                (_, None) => 0,
+            };
+            if host_bits != 0 {
+                return Err(parsing_error());
+            }
+        }
+        Ok((addr, prefix))
+    }
+
+    /// Integers up to 2**32 - 1 are IPv4 addresses unless IPv6 is required, as in `ipaddress.ip_address`
+    fn addr_from_int(&self, int: u128) -> Option<IpAddr> {
+        match (self.version, u32::try_from(int)) {
+            (Some(IpVersion::V6), _) | (None, Err(_)) => Some(IpAddr::V6(Ipv6Addr::from(int))),
+            (_, Ok(int)) => Some(IpAddr::V4(Ipv4Addr::from(int))),
+            (Some(IpVersion::V4), Err(_)) => None,
+        }
+    }
+}
+
+/// Convert an `ipaddress` address instance of either version, addresses of the wrong version are
+/// converted too so they fail the version check with a parsing error
+fn as_py_address<'py>(py: Python<'py>, input: &(impl Input<'py> + ?Sized)) -> PyResult<Option<IpAddr>> {
+    for version in [IpVersion::V4, IpVersion::V6] {
+        if let Some(py_addr) = input_as_python_instance(input, get_ip_type(py, IpKind::Address, version)?) {
+            let int: u128 = py_addr.call_method0(intern!(py, "__int__"))?.extract()?;
+            let addr = match version {
+                IpVersion::V4 => IpAddr::V4(Ipv4Addr::from(u32::try_from(int)?)),
+                IpVersion::V6 => IpAddr::V6(Ipv6Addr::from(int)),
+            };
+            return Ok(Some(addr));
+        }
+    }
+    Ok(None)
+}
+
+/// Parse a prefix length, or for IPv4 a netmask or hostmask, as `ipaddress` does
+fn parse_prefix(addr: &IpAddr, prefix_str: &str) -> Option<u8> {
+    let max_prefix = IpVersion::of(addr).max_prefix();
+    if !prefix_str.is_empty() && prefix_str.bytes().all(|b| b.is_ascii_digit()) {
+        return prefix_str.parse::<u8>().ok().filter(|prefix| *prefix <= max_prefix);
+    }
+    match addr {
+        IpAddr::V4(_) => {
+            let mask = u32::from(Ipv4Addr::from_str(prefix_str).ok()?);
+            if mask.leading_ones() + mask.trailing_zeros() == 32 {
+                // a netmask, e.g. 255.255.255.0
+                u8::try_from(mask.leading_ones()).ok()
+            } else if mask.leading_zeros() + mask.trailing_ones() == 32 {
+                // a hostmask, e.g. 0.0.0.255
+                u8::try_from(mask.leading_zeros()).ok()
+            } else {
+                None
+            }
+        }
+        IpAddr::V6(_) => None,
+    }
+}
@@ -1,15 +1,18 @@
+use std::ops::Range;
+
 use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::PyDict;
+use pyo3::types::{PyByteArray, PyBytes, PyDict, PyIterator, PyString};
 
-use jiter::{JsonValue, PartialMode, PythonParse};
+use jiter::{Jiter, JiterError, LinePosition, PartialMode, PythonParse};
 
 use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValLineError, ValResult};
-use crate::input::{EitherBytes, Input, InputType, ValidationMatch};
+use crate::input::{EitherBytes, Input, InputType, JsonParser, JsonValue, ValidationMatch};
 use crate::serializers::BytesMode;
 use crate::tools::SchemaDict;
 
 use super::config::ValBytesMode;
+use super::json_schema::JsonSchemaState;
 use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
 #[derive(Debug)]
@@ -57,9 +60,10 @@ impl Validator for JsonValidator {
         let v_match = validate_json_bytes(input)?;
         let json_either_bytes = v_match.unpack(state);
         let json_bytes = json_either_bytes.as_slice();
+        state.count_json(json_bytes, 0, input)?;
         match self.validator {
             Some(ref validator) => {
-                let json_value = JsonValue::parse(json_bytes, true).map_err(|e| map_json_err(input, e, json_bytes))?;
+                let json_value = JsonValue::parse(json_bytes).map_err(|e| map_json_err(input, e, json_bytes))?;
                 let mut json_state = state.rebind_extra(|e| {
                     e.input_type = InputType::Json;
                 });
@@ -81,6 +85,16 @@ impl Validator for JsonValidator {
         }
     }
 
+    fn json_schema<'py>(&self, state: &mut JsonSchemaState<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let py = state.py();
+        let schema = state.type_schema("string")?;
+        schema.set_item(intern!(py, "contentMediaType"), "application/json")?;
+        if let Some(validator) = &self.validator {
+            schema.set_item(intern!(py, "contentSchema"), validator.json_schema(state)?)?;
+        }
+        Ok(schema)
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
@@ -107,6 +121,210 @@ fn map_bytes_error(line_error: ValLineError) -> ValLineError {
     }
 }
 
+/// Where `JsonStream` reads its data from
+enum JsonChunks<'py> {
+    /// the `read` method of a file-like object, called with the chunk size
+    Read(Bound<'py, PyAny>, usize),
+    /// an iterable of `str`, `bytes` or `bytearray` chunks
+    Iter(Bound<'py, PyIterator>),
+    /// a single `str`, `bytes` or `bytearray`, until it's been read
+    Once(Option<Bound<'py, PyAny>>),
+}
+
+/// JSON read incrementally from a file-like object or an iterable of chunks, for `validate_json_stream`.
+///
+/// The items of a top-level array are parsed one at a time and only the data from the start of the current item
+/// onwards is kept, so neither the whole input nor a `JsonValue` of the whole array is ever held in memory.
+pub struct JsonStream<'a, 'py> {
+    input: &'a Bound<'py, PyAny>,
+    chunks: JsonChunks<'py>,
+    exhausted: bool,
+    buffer: Vec<u8>,
+    // start of the data in `buffer` which hasn't been parsed yet
+    position: usize,
+    // lines and columns in the data dropped from the start of `buffer`, so errors are positioned as they
+    // would be in the whole input
+    dropped_lines: usize,
+    dropped_columns: usize,
+    // whether the opening `[` of the array has been parsed
+    in_array: bool,
+}
+
+impl<'a, 'py> JsonStream<'a, 'py> {
+    pub fn new(input: &'a Bound<'py, PyAny>, chunk_size: usize) -> ValResult<Self> {
+        let chunks = if is_json_chunk(input) {
+            JsonChunks::Once(Some(input.clone()))
+        } else if let Ok(read) = input.getattr(intern!(input.py(), "read")) {
+            JsonChunks::Read(read, chunk_size)
+        } else if let Ok(iter) = input.iter() {
+            JsonChunks::Iter(iter)
+        } else {
+            return Err(ValError::new(ErrorTypeDefaults::JsonType, input));
+        };
+        Ok(Self {
+            input,
+            chunks,
+            exhausted: false,
+            buffer: Vec::new(),
+            position: 0,
+            dropped_lines: 0,
+            dropped_columns: 0,
+            in_array: false,
+        })
+    }
+
+    /// Whether the JSON is an array, reading only as far as its first character
+    pub fn is_array(&mut self) -> ValResult<bool> {
+        loop {
+            if let Some(byte) = self.buffer[self.position..].iter().find(|b| !is_json_whitespace(**b)) {
+                return Ok(*byte == b'[');
+            }
+            if self.exhausted {
+                return Ok(false);
+            }
+            self.read_more()?;
+        }
+    }
+
+    /// Read the whole input, for JSON which isn't an array and so can't be parsed incrementally
+    pub fn read_to_end(mut self) -> ValResult<Vec<u8>> {
+        while !self.exhausted {
+            self.read_chunk()?;
+        }
+        Ok(self.buffer)
+    }
+
+    /// Parse the next item of the array, returning it along with its range for `item_data`. `None` is returned
+    /// at the end of the array, once the rest of the input has been checked to be whitespace.
+    pub fn next_item(&mut self) -> ValResult<Option<(JsonValue<'static>, Range<usize>)>> {
+        loop {
+            let data = &self.buffer[self.position..];
+            let mut parser = JsonParser::new(data);
+            let step = if self.in_array {
+                parser.jiter().array_step()
+            } else {
+                parser.jiter().next_array()
+            };
+            let result = step.and_then(|peek| match peek {
+                Some(peek) => {
+                    let start = parser.jiter().current_index();
+                    parser.take_value(peek).map(|value| Some((value.into_static(), start)))
+                }
+                None => Ok(None),
+            });
+            let end = parser.jiter().current_index();
+            match result {
+                // a value at the end of the data read so far may continue in the next chunk, e.g. a number
+                Ok(Some(_)) if !self.exhausted && data[end..].iter().copied().all(is_json_whitespace) => (),
+                Ok(Some((value, start))) => {
+                    let item_range = self.position + start..self.position + end;
+                    self.position += end;
+                    self.in_array = true;
+                    return Ok(Some((value, item_range)));
+                }
+                Ok(None) => {
+                    self.position += end;
+                    self.finish()?;
+                    return Ok(None);
+                }
+                // errors at the end of the data read so far are because it ends part way through a value
+                Err(error) if !self.exhausted && error.index >= data.len() => (),
+                Err(error) => return Err(self.json_error(&error)),
+            }
+            self.read_more()?;
+        }
+    }
+
+    /// The JSON text of an item returned by `next_item`, valid until `next_item` is called again
+    pub fn item_data(&self, range: Range<usize>) -> &[u8] {
+        &self.buffer[range]
+    }
+
+    /// Check there's nothing but whitespace after the end of the array
+    fn finish(&mut self) -> ValResult<()> {
+        loop {
+            if let Err(error) = Jiter::new(&self.buffer[self.position..]).finish() {
+                return Err(self.json_error(&error));
+            }
+            if self.exhausted {
+                return Ok(());
+            }
+            self.position = self.buffer.len();
+            self.read_more()?;
+        }
+    }
+
+    /// Drop the data which has been parsed, then read at least as much again as is left, so a value spanning
+    /// many chunks is only parsed a few times
+    fn read_more(&mut self) -> ValResult<()> {
+        self.drop_parsed();
+        let min_len = self.buffer.len() * 2;
+        while !self.exhausted && self.buffer.len() <= min_len {
+            self.read_chunk()?;
+        }
+        Ok(())
+    }
+
+    fn drop_parsed(&mut self) {
+        for byte in &self.buffer[..self.position] {
+            if *byte == b'\n' {
+                self.dropped_lines += 1;
+                self.dropped_columns = 0;
+            } else {
+                self.dropped_columns += 1;
+            }
+        }
+        self.buffer.drain(..self.position);
+        self.position = 0;
+    }
+
+    /// Append the next chunk to `buffer`, or set `exhausted` if there are none left
+    fn read_chunk(&mut self) -> ValResult<()> {
+        let (chunk, empty_is_end) = match &mut self.chunks {
+            JsonChunks::Read(read, chunk_size) => (Some(read.call1((*chunk_size,))?), true),
+            JsonChunks::Iter(iter) => (iter.next().transpose()?, false),
+            JsonChunks::Once(input) => (input.take(), false),
+        };
+        let Some(chunk) = chunk else {
+            self.exhausted = true;
+            return Ok(());
+        };
+        if !is_json_chunk(&chunk) {
+            return Err(ValError::new(ErrorTypeDefaults::JsonType, &chunk));
+        }
+        let chunk_bytes = validate_json_bytes(&chunk)?.into_inner();
+        let chunk_bytes = chunk_bytes.as_slice();
+        // `read` only returns an empty chunk at the end of the input
+        self.exhausted = empty_is_end && chunk_bytes.is_empty();
+        self.buffer.extend_from_slice(chunk_bytes);
+        Ok(())
+    }
+
+    /// An invalid JSON error, positioned within the whole input rather than the data in `buffer`
+    fn json_error(&self, error: &JiterError) -> ValError {
+        let mut position = LinePosition::find(&self.buffer, self.position + error.index);
+        if position.line == 1 {
+            position.column += self.dropped_columns;
+        }
+        position.line += self.dropped_lines;
+        ValError::new(
+            ErrorType::JsonInvalid {
+                error: format!("{} at {position}", error.error_type),
+                context: None,
+            },
+            self.input,
+        )
+    }
+}
+
+fn is_json_chunk(chunk: &Bound<'_, PyAny>) -> bool {
+    chunk.is_instance_of::<PyBytes>() || chunk.is_instance_of::<PyString>() || chunk.is_instance_of::<PyByteArray>()
+}
+
+fn is_json_whitespace(byte: u8) -> bool {
+    matches!(byte, b' ' | b'\t' | b'\n' | b'\r')
+}
+
 pub fn map_json_err<'py>(input: &(impl Input<'py> + ?Sized), error: jiter::JsonError, json_bytes: &[u8]) -> ValError {
     ValError::new(
         ErrorType::JsonInvalid {
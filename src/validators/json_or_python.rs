@@ -7,6 +7,7 @@ use crate::errors::ValResult;
 use crate::input::Input;
 use crate::tools::SchemaDict;
 
+use super::json_schema::JsonSchemaState;
 use super::{build_validator, BuildValidator, CombinedValidator, InputType, ValidationState, Validator};
 
 #[derive(Debug)]
@@ -61,6 +62,10 @@ impl Validator for JsonOrPython {
         }
     }
 
+    fn json_schema<'py>(&self, state: &mut JsonSchemaState<'py>) -> PyResult<Bound<'py, PyDict>> {
+        self.json.json_schema(state)
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
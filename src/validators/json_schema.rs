@@ -0,0 +1,214 @@
+use pyo3::exceptions::PyTypeError;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyBool, PyDict, PyFloat, PyInt, PyList, PyString, PyType};
+
+use crate::build_tools::ExtraBehavior;
+use crate::input::get_enum_meta_object;
+
+use super::{CombinedValidator, Validator};
+
+/// State used when generating a JSON Schema from the validator tree, see `Validator::json_schema`.
+///
+/// Definitions are generated once, the first time they're referenced, and collected under `$defs`,
+/// this is also what stops recursive definitions from recursing forever.
+pub struct JsonSchemaState<'py> {
+    py: Python<'py>,
+    ref_template: String,
+    definitions: Bound<'py, PyDict>,
+}
+
+impl<'py> JsonSchemaState<'py> {
+    pub fn new(py: Python<'py>, ref_template: &str) -> Self {
+        Self {
+            py,
+            ref_template: ref_template.to_string(),
+            definitions: PyDict::new_bound(py),
+        }
+    }
+
+    pub fn py(&self) -> Python<'py> {
+        self.py
+    }
+
+    /// An empty schema, which matches any value
+    pub fn any_schema(&self) -> Bound<'py, PyDict> {
+        PyDict::new_bound(self.py)
+    }
+
+    /// A schema with just `type` set, the starting point for most validators
+    pub fn type_schema(&self, json_type: &str) -> PyResult<Bound<'py, PyDict>> {
+        let schema = self.any_schema();
+        schema.set_item(intern!(self.py, "type"), json_type)?;
+        Ok(schema)
+    }
+
+    /// A string schema with the given `format`, used by validators of types which are strings in JSON
+    pub fn format_schema(&self, format: &str) -> PyResult<Bound<'py, PyDict>> {
+        let schema = self.type_schema("string")?;
+        schema.set_item(intern!(self.py, "format"), format)?;
+        Ok(schema)
+    }
+
+    /// Set `title` to the class name, as for models, dataclasses and enums
+    pub fn set_class_title(&self, schema: &Bound<'py, PyDict>, class: &Py<PyType>) -> PyResult<()> {
+        let name = class.bind(self.py).getattr(intern!(self.py, "__name__"))?;
+        schema.set_item(intern!(self.py, "title"), name)
+    }
+
+    /// `{"anyOf": [...]}` or similar for unions
+    pub fn choices_schema(&self, key: &str, choices: Vec<Bound<'py, PyDict>>) -> PyResult<Bound<'py, PyDict>> {
+        let schema = self.any_schema();
+        schema.set_item(key, PyList::new_bound(self.py, choices))?;
+        Ok(schema)
+    }
+
+    /// An array schema, used by lists, sets and similar
+    pub fn array_schema(
+        &mut self,
+        item_validator: Option<&CombinedValidator>,
+        min_length: Option<usize>,
+        max_length: Option<usize>,
+    ) -> PyResult<Bound<'py, PyDict>> {
+        let schema = self.type_schema("array")?;
+        if let Some(item_validator) = item_validator {
+            schema.set_item(intern!(self.py, "items"), item_validator.json_schema(self)?)?;
+        }
+        set_constraint(&schema, "minItems", min_length)?;
+        set_constraint(&schema, "maxItems", max_length)?;
+        Ok(schema)
+    }
+
+    /// An object schema from `(key, validator, required)` for each field, used by models, typed dicts and dataclasses
+    pub fn object_schema<'a>(
+        &mut self,
+        fields: impl Iterator<Item = (&'a str, &'a CombinedValidator, bool)>,
+        extra_behavior: ExtraBehavior,
+        extras_validator: Option<&CombinedValidator>,
+    ) -> PyResult<Bound<'py, PyDict>> {
+        let schema = self.type_schema("object")?;
+        let properties = PyDict::new_bound(self.py);
+        let mut required = Vec::new();
+        for (key, validator, is_required) in fields {
+            properties.set_item(key, validator.json_schema(self)?)?;
+            if is_required {
+                required.push(key);
+            }
+        }
+        schema.set_item(intern!(self.py, "properties"), properties)?;
+        if !required.is_empty() {
+            schema.set_item(intern!(self.py, "required"), required)?;
+        }
+        let additional_properties = intern!(self.py, "additionalProperties");
+        match (extra_behavior, extras_validator) {
+            (ExtraBehavior::Allow, Some(extras_validator)) => {
+                schema.set_item(additional_properties, extras_validator.json_schema(self)?)?;
+            }
+            (ExtraBehavior::Allow, None) => schema.set_item(additional_properties, true)?,
+            (ExtraBehavior::Forbid, _) => schema.set_item(additional_properties, false)?,
+            (ExtraBehavior::Ignore, _) => (),
+        }
+        Ok(schema)
+    }
+
+    /// A reference to the definition `reference`, the definition itself is generated the first time it's used
+    pub fn definition_ref(&mut self, reference: &str, validator: &CombinedValidator) -> PyResult<Bound<'py, PyDict>> {
+        if !self.definitions.contains(reference)? {
+            // add a placeholder first so recursive references don't generate the definition again
+            self.definitions.set_item(reference, self.any_schema())?;
+            let definition = validator.json_schema(self)?;
+            self.definitions.set_item(reference, definition)?;
+        }
+        let schema = self.any_schema();
+        schema.set_item(
+            intern!(self.py, "$ref"),
+            self.ref_template.replace("{model}", reference),
+        )?;
+        Ok(schema)
+    }
+
+    /// Add the definitions collected while generating `schema` to it
+    pub fn finish(self, schema: Bound<'py, PyDict>) -> PyResult<Bound<'py, PyDict>> {
+        if !self.definitions.is_empty() {
+            schema.set_item(intern!(self.py, "$defs"), self.definitions)?;
+        }
+        Ok(schema)
+    }
+}
+
+/// Error for validators which can't be represented in JSON Schema, e.g. plain function validators
+pub fn unsupported<T>(name: &str) -> PyResult<T> {
+    Err(PyTypeError::new_err(format!(
+        "Cannot generate a JSON Schema for `{name}`"
+    )))
+}
+
+/// Set `key` on the schema if the constraint is set
+pub fn set_constraint(schema: &Bound<'_, PyDict>, key: &str, value: Option<impl ToPyObject>) -> PyResult<()> {
+    match value {
+        Some(value) => schema.set_item(key, value),
+        None => Ok(()),
+    }
+}
+
+/// Set the JSON Schema equivalents of the `multiple_of`, `le`, `lt`, `ge` and `gt` constraints
+pub fn set_number_constraints<T: ToPyObject>(
+    schema: &Bound<'_, PyDict>,
+    multiple_of: Option<T>,
+    le: Option<T>,
+    lt: Option<T>,
+    ge: Option<T>,
+    gt: Option<T>,
+) -> PyResult<()> {
+    set_constraint(schema, "multipleOf", multiple_of)?;
+    set_constraint(schema, "maximum", le)?;
+    set_constraint(schema, "exclusiveMaximum", lt)?;
+    set_constraint(schema, "minimum", ge)?;
+    set_constraint(schema, "exclusiveMinimum", gt)
+}
+
+/// Whether a field has a default, and so isn't required
+pub fn has_default(validator: &CombinedValidator) -> bool {
+    matches!(validator, CombinedValidator::WithDefault(validator) if validator.has_default())
+}
+
+/// The value of `Literal` and `Enum` members as it's represented in JSON
+pub fn json_value<'py>(value: &Bound<'py, PyAny>) -> PyResult<Bound<'py, PyAny>> {
+    if value.get_type().is_instance(get_enum_meta_object(value.py()))? {
+        value.getattr(intern!(value.py(), "value"))
+    } else {
+        Ok(value.clone())
+    }
+}
+
+/// `{"const": ...}` or `{"enum": [...]}` for a set of allowed values, with `type` set if the values share a type
+pub fn enum_schema<'py>(py: Python<'py>, values: &[Bound<'py, PyAny>]) -> PyResult<Bound<'py, PyDict>> {
+    let schema = PyDict::new_bound(py);
+    let mut json_types = values.iter().map(json_type);
+    if let Some(first) = json_types.next().flatten() {
+        if json_types.all(|json_type| json_type == Some(first)) {
+            schema.set_item(intern!(py, "type"), first)?;
+        }
+    }
+    match values {
+        [value] => schema.set_item(intern!(py, "const"), value)?,
+        values => schema.set_item(intern!(py, "enum"), PyList::new_bound(py, values))?,
+    }
+    Ok(schema)
+}
+
+fn json_type(value: &Bound<'_, PyAny>) -> Option<&'static str> {
+    if value.is_none() {
+        Some("null")
+    } else if value.is_exact_instance_of::<PyBool>() {
+        Some("boolean")
+    } else if value.is_exact_instance_of::<PyInt>() {
+        Some("integer")
+    } else if value.is_exact_instance_of::<PyFloat>() {
+        Some("number")
+    } else if value.is_exact_instance_of::<PyString>() {
+        Some("string")
+    } else {
+        None
+    }
+}
@@ -7,6 +7,7 @@ use crate::errors::ValResult;
 use crate::input::Input;
 use crate::tools::SchemaDict;
 
+use super::json_schema::JsonSchemaState;
 use super::Exactness;
 use super::ValidationState;
 use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, Validator};
@@ -78,6 +79,14 @@ impl Validator for LaxOrStrictValidator {
         }
     }
 
+    fn json_schema<'py>(&self, state: &mut JsonSchemaState<'py>) -> PyResult<Bound<'py, PyDict>> {
+        if self.strict {
+            self.strict_validator.json_schema(state)
+        } else {
+            self.lax_validator.json_schema(state)
+        }
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
@@ -3,12 +3,15 @@ use std::sync::OnceLock;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
 
-use crate::errors::ValResult;
+use crate::build_tools::schema_or_config_same;
+use crate::errors::{ValError, ValLineError, ValResult};
 use crate::input::{
     no_validator_iter_to_vec, validate_iter_to_vec, BorrowInput, ConsumeIterator, Input, MaxLengthCheck, ValidatedList,
 };
 use crate::tools::SchemaDict;
 
+use super::json::JsonStream;
+use super::json_schema::JsonSchemaState;
 use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
 #[derive(Debug)]
@@ -110,7 +113,7 @@ impl BuildValidator for ListValidator {
             min_length: schema.get_as(pyo3::intern!(py, "min_length"))?,
             max_length: schema.get_as(pyo3::intern!(py, "max_length"))?,
             name: OnceLock::new(),
-            fail_fast: schema.get_as(pyo3::intern!(py, "fail_fast"))?.unwrap_or(false),
+            fail_fast: schema_or_config_same(schema, config, pyo3::intern!(py, "fail_fast"))?.unwrap_or(false),
         }
         .into())
     }
@@ -118,6 +121,61 @@ impl BuildValidator for ListValidator {
 
 impl_py_gc_traverse!(ListValidator { item_validator });
 
+impl ListValidator {
+    /// Validate the items of a JSON array as `stream` parses them, as `validate` would validate the whole array.
+    ///
+    /// Errors about the array itself, e.g. its length, have `input` as their input since the array is never held
+    /// as a whole.
+    pub fn validate_json_stream<'py>(
+        &self,
+        py: Python<'py>,
+        input: &Bound<'py, PyAny>,
+        stream: &mut JsonStream<'_, 'py>,
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        let fail_fast = state.fail_fast_or(self.fail_fast);
+        let mut max_length_check = MaxLengthCheck::new(self.max_length, "List", input, None);
+        let mut output: Vec<PyObject> = Vec::new();
+        let mut errors: Vec<ValLineError> = Vec::new();
+        let mut stopped = false;
+        // the rest of the input is still read after validation stops, so invalid JSON is reported as it would be
+        // by `validate_json`
+        let mut next_index: usize = 0;
+        while let Some((item, item_range)) = stream.next_item()? {
+            let index = next_index;
+            next_index += 1;
+            let item_data = stream.item_data(item_range);
+            state.count_json(item_data, 1, input)?;
+            if stopped {
+                continue;
+            }
+            let Some(ref item_validator) = self.item_validator else {
+                max_length_check.incr()?;
+                output.push(item.to_object(py));
+                continue;
+            };
+            match item_validator.validate(py, &item, state) {
+                Ok(item) => {
+                    max_length_check.incr()?;
+                    output.push(item);
+                }
+                Err(ValError::LineErrors(line_errors)) => {
+                    max_length_check.incr()?;
+                    errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index)));
+                    stopped = fail_fast || state.errors_capped(errors.len());
+                }
+                Err(ValError::Omit) => (),
+                Err(err) => return Err(err),
+            }
+        }
+        if !errors.is_empty() {
+            return Err(ValError::LineErrors(errors));
+        }
+        min_length_check!(input, "List", self.min_length, output);
+        Ok(output.into_py(py))
+    }
+}
+
 impl Validator for ListValidator {
     fn validate<'py>(
         &self,
@@ -128,6 +186,7 @@ impl Validator for ListValidator {
         let seq = input.validate_list(state.strict_or(self.strict))?.unpack(state);
 
         let actual_length = seq.len();
+        let fail_fast = state.fail_fast_or(self.fail_fast);
         let output = match self.item_validator {
             Some(ref v) => seq.iterate(ValidateToVec {
                 py,
@@ -137,28 +196,39 @@ impl Validator for ListValidator {
                 field_type: "List",
                 item_validator: v,
                 state,
-                fail_fast: self.fail_fast,
+                fail_fast,
             })??,
+            // items are accepted as they are, so as with `any` they're counted along with their contents
             None => {
                 if let Some(py_list) = seq.as_py_list() {
+                    state.count_value(py_list.as_any(), input)?;
                     length_check!(input, "List", self.min_length, self.max_length, py_list);
                     let list_copy = py_list.get_slice(0, usize::MAX);
                     return Ok(list_copy.into_py(py));
                 }
 
-                seq.iterate(ToVec {
+                let output = seq.iterate(ToVec {
                     py,
                     input,
                     actual_length,
                     max_length: self.max_length,
                     field_type: "List",
-                })??
+                })??;
+                state.count_items(output.len(), input)?;
+                for item in &output {
+                    state.count_value(item.bind(py), input)?;
+                }
+                output
             }
         };
         min_length_check!(input, "List", self.min_length, output);
         Ok(output.into_py(py))
     }
 
+    fn json_schema<'py>(&self, state: &mut JsonSchemaState<'py>) -> PyResult<Bound<'py, PyDict>> {
+        state.array_schema(self.item_validator.as_deref(), self.min_length, self.max_length)
+    }
+
     fn get_name(&self) -> &str {
         // The logic here is a little janky, it's done to try to cache the formatted name
         // while also trying to render definitions correctly when possible.
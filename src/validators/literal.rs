@@ -14,6 +14,7 @@ use crate::input::{Input, ValidationMatch};
 use crate::py_gc::PyGcTraverse;
 use crate::tools::SchemaDict;
 
+use super::json_schema::{enum_schema, json_value, JsonSchemaState};
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
 #[derive(Debug, Clone, Default)]
@@ -95,6 +96,18 @@ impl<T: Debug> LiteralLookup<T> {
         })
     }
 
+    /// The expected strings and the index of their value, in the order they were defined
+    pub fn str_keys(&self) -> Vec<(&str, usize)> {
+        let mut keys: Vec<(&str, usize)> = self
+            .expected_str
+            .iter()
+            .flatten()
+            .map(|(key, id)| (key.as_str(), *id))
+            .collect();
+        keys.sort_unstable_by_key(|(_, id)| *id);
+        keys
+    }
+
     pub fn validate<'a, 'py, I: Input<'py> + ?Sized>(
         &self,
         py: Python<'py>,
@@ -289,6 +302,17 @@ impl Validator for LiteralValidator {
         }
     }
 
+    fn json_schema<'py>(&self, state: &mut JsonSchemaState<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let py = state.py();
+        let values = self
+            .lookup
+            .values
+            .iter()
+            .map(|value| json_value(value.bind(py)))
+            .collect::<PyResult<Vec<_>>>()?;
+        enum_schema(py, &values)
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
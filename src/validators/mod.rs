@@ -1,21 +1,24 @@
 use std::fmt::Debug;
 
 use enum_dispatch::enum_dispatch;
-use jiter::StringCacheMode;
+use jiter::{PartialMode, StringCacheMode};
 
-use pyo3::exceptions::PyTypeError;
+use pyo3::exceptions::{PyTypeError, PyValueError};
 use pyo3::prelude::*;
 use pyo3::sync::GILOnceCell;
-use pyo3::types::{PyAny, PyDict, PyString, PyTuple, PyType};
+use pyo3::types::{PyAny, PyDict, PyList, PyString, PyTuple, PyType};
 use pyo3::{intern, PyTraverseError, PyVisit};
 
 use crate::build_tools::{py_schema_err, py_schema_error_type, SchemaError};
 use crate::definitions::{Definitions, DefinitionsBuilder};
-use crate::errors::{LocItem, ValError, ValResult, ValidationError};
-use crate::input::{Input, InputType, StringMapping};
+use crate::errors::{ErrorOutputConfig, LocItem, ValError, ValLineError, ValResult, ValidationError};
+use crate::input::{
+    map_msgpack_err, validate_msgpack_bytes, Input, InputType, JsonParser, JsonValue, MsgpackValue, StringMapping,
+};
 use crate::py_gc::PyGcTraverse;
-use crate::recursion_guard::RecursionState;
+use crate::recursion_guard::{RecursionConfig, RecursionState};
 use crate::tools::SchemaDict;
+use config::ManyErrorMode;
 pub(crate) use config::ValBytesMode;
 
 mod any;
@@ -40,10 +43,12 @@ mod frozenset;
 mod function;
 mod generator;
 mod int;
+mod ip;
 mod is_instance;
 mod is_subclass;
 mod json;
 mod json_or_python;
+mod json_schema;
 mod lax_or_strict;
 mod list;
 mod literal;
@@ -51,10 +56,13 @@ mod model;
 mod model_fields;
 mod none;
 mod nullable;
+mod path;
 mod set;
 mod string;
 mod time;
 mod timedelta;
+mod timezone;
+mod trace;
 mod tuple;
 mod typed_dict;
 mod union;
@@ -63,7 +71,9 @@ mod uuid;
 mod validation_state;
 mod with_default;
 
-pub use self::validation_state::{Exactness, ValidationState};
+pub use self::json_schema::JsonSchemaState;
+use self::trace::ValidationTracer;
+pub use self::validation_state::{Exactness, PartialTail, ResourceLimits, ResourceUsage, ValidationState};
 pub use with_default::DefaultType;
 
 #[pyclass(module = "pydantic_core._pydantic_core", name = "Some")]
@@ -114,7 +124,10 @@ pub struct SchemaValidator {
     title: PyObject,
     hide_input_in_errors: bool,
     validation_error_cause: bool,
+    error_output: ErrorOutputConfig,
     cache_str: StringCacheMode,
+    recursion: RecursionConfig,
+    resource_limits: ResourceLimits,
 }
 
 #[pymethods]
@@ -141,9 +154,12 @@ impl SchemaValidator {
         };
         let hide_input_in_errors: bool = config.get_as(intern!(py, "hide_input_in_errors"))?.unwrap_or(false);
         let validation_error_cause: bool = config.get_as(intern!(py, "validation_error_cause"))?.unwrap_or(false);
+        let error_output = ErrorOutputConfig::from_config(config)?;
         let cache_str: StringCacheMode = config
             .get_as(intern!(py, "cache_strings"))?
             .unwrap_or(StringCacheMode::All);
+        let recursion = RecursionConfig::from_config(config)?;
+        let resource_limits = ResourceLimits::from_config(config)?;
         Ok(Self {
             validator,
             definitions,
@@ -152,7 +168,10 @@ impl SchemaValidator {
             title,
             hide_input_in_errors,
             validation_error_cause,
+            error_output,
             cache_str,
+            recursion,
+            resource_limits,
         })
     }
 
@@ -164,8 +183,18 @@ impl SchemaValidator {
         Ok((cls, init_args))
     }
 
-    #[pyo3(signature = (input, *, strict=None, from_attributes=None, context=None, self_instance=None))]
-    pub fn validate_python(
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(name = "validate_python", signature = (
+        input,
+        *,
+        strict=None,
+        from_attributes=None,
+        context=None,
+        self_instance=None,
+        allow_partial=false,
+        fail_fast=None
+    ))]
+    pub fn py_validate_python(
         &self,
         py: Python,
         input: &Bound<'_, PyAny>,
@@ -173,6 +202,8 @@ impl SchemaValidator {
         from_attributes: Option<bool>,
         context: Option<&Bound<'_, PyAny>>,
         self_instance: Option<&Bound<'_, PyAny>>,
+        allow_partial: bool,
+        fail_fast: Option<bool>,
     ) -> PyResult<PyObject> {
         self._validate(
             py,
@@ -180,14 +211,21 @@ impl SchemaValidator {
             InputType::Python,
             strict,
             from_attributes,
+            fail_fast,
             context,
             self_instance,
+            allow_partial.then_some(PartialTail::Unknown),
+            ResourceUsage::default(),
         )
         .map_err(|e| self.prepare_validation_err(py, e, InputType::Python))
     }
 
-    #[pyo3(signature = (input, *, strict=None, from_attributes=None, context=None, self_instance=None))]
-    pub fn isinstance_python(
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(
+        name = "isinstance_python",
+        signature = (input, *, strict=None, from_attributes=None, context=None, self_instance=None, fail_fast=None)
+    )]
+    pub fn py_isinstance_python(
         &self,
         py: Python,
         input: &Bound<'_, PyAny>,
@@ -195,6 +233,7 @@ impl SchemaValidator {
         from_attributes: Option<bool>,
         context: Option<&Bound<'_, PyAny>>,
         self_instance: Option<&Bound<'_, PyAny>>,
+        fail_fast: Option<bool>,
     ) -> PyResult<bool> {
         match self._validate(
             py,
@@ -202,25 +241,77 @@ impl SchemaValidator {
             InputType::Python,
             strict,
             from_attributes,
+            fail_fast,
             context,
             self_instance,
+            None,
+            ResourceUsage::default(),
         ) {
             Ok(_) => Ok(true),
             Err(ValError::InternalErr(err)) => Err(err),
             Err(ValError::Omit) => Err(ValidationError::omit_error()),
             Err(ValError::UseDefault) => Err(ValidationError::use_default_error()),
-            Err(ValError::LineErrors(_)) => Ok(false),
+            Err(ValError::LineErrors(_) | ValError::LimitExceeded(_)) => Ok(false),
         }
     }
 
-    #[pyo3(signature = (input, *, strict=None, context=None, self_instance=None))]
-    pub fn validate_json(
+    #[pyo3(signature = (input, *, strict=None, from_attributes=None, context=None, self_instance=None))]
+    pub fn explain<'py>(
+        &self,
+        py: Python<'py>,
+        input: &Bound<'py, PyAny>,
+        strict: Option<bool>,
+        from_attributes: Option<bool>,
+        context: Option<&Bound<'py, PyAny>>,
+        self_instance: Option<&Bound<'py, PyAny>>,
+    ) -> PyResult<(Option<PyObject>, Option<PyObject>, Bound<'py, PyDict>)> {
+        let mut recursion_guard = RecursionState::new(self.recursion);
+        let mut extra = Extra::new(
+            strict,
+            from_attributes,
+            context,
+            self_instance,
+            InputType::Python,
+            self.cache_str,
+            self.resource_limits,
+        );
+        extra.max_errors = self.error_output.max_errors();
+        let mut state = ValidationState::new(extra, &mut recursion_guard);
+        state.tracer = Some(ValidationTracer::new(py));
+        let result = self.validator.validate(py, input, &mut state);
+        let trace = match state.tracer.take() {
+            Some(tracer) => tracer.into_py_dict(py)?,
+            None => PyDict::new_bound(py),
+        };
+        match result {
+            Ok(value) => Ok((Some(value), None, trace)),
+            Err(e @ (ValError::LineErrors(_) | ValError::LimitExceeded(_))) => {
+                let err = self.prepare_validation_err(py, e, InputType::Python);
+                Ok((None, Some(err.into_value(py).into_any()), trace))
+            }
+            Err(e) => Err(self.prepare_validation_err(py, e, InputType::Python)),
+        }
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(name = "validate_json", signature = (
+        input,
+        *,
+        strict=None,
+        context=None,
+        self_instance=None,
+        allow_partial=PartialMode::Off,
+        fail_fast=None
+    ))]
+    pub fn py_validate_json(
         &self,
         py: Python,
         input: &Bound<'_, PyAny>,
         strict: Option<bool>,
         context: Option<&Bound<'_, PyAny>>,
         self_instance: Option<&Bound<'_, PyAny>>,
+        allow_partial: PartialMode,
+        fail_fast: Option<bool>,
     ) -> PyResult<PyObject> {
         let r = match json::validate_json_bytes(input) {
             Ok(v_match) => self._validate_json(
@@ -228,14 +319,155 @@ impl SchemaValidator {
                 input,
                 v_match.into_inner().as_slice(),
                 strict,
+                fail_fast,
                 context,
                 self_instance,
+                allow_partial,
             ),
             Err(err) => Err(err),
         };
         r.map_err(|e| self.prepare_validation_err(py, e, InputType::Json))
     }
 
+    #[pyo3(signature = (input, *, strict=None, context=None, fail_fast=None, chunk_size=65536))]
+    pub fn validate_json_stream(
+        &self,
+        py: Python,
+        input: &Bound<'_, PyAny>,
+        strict: Option<bool>,
+        context: Option<&Bound<'_, PyAny>>,
+        fail_fast: Option<bool>,
+        chunk_size: usize,
+    ) -> PyResult<PyObject> {
+        if chunk_size == 0 {
+            return Err(PyValueError::new_err("chunk_size must be greater than 0"));
+        }
+        self._validate_json_stream(py, input, strict, context, fail_fast, chunk_size)
+            .map_err(|e| self.prepare_validation_err(py, e, InputType::Json))
+    }
+
+    #[pyo3(signature = (input, *, strict=None, context=None, self_instance=None))]
+    pub fn validate_msgpack(
+        &self,
+        py: Python,
+        input: &Bound<'_, PyAny>,
+        strict: Option<bool>,
+        context: Option<&Bound<'_, PyAny>>,
+        self_instance: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<PyObject> {
+        let r = match validate_msgpack_bytes(input) {
+            Ok(msgpack_bytes) => match MsgpackValue::parse(&msgpack_bytes) {
+                Ok(msgpack_value) => self._validate(
+                    py,
+                    &msgpack_value,
+                    InputType::Msgpack,
+                    strict,
+                    None,
+                    None,
+                    context,
+                    self_instance,
+                    None,
+                    ResourceUsage::default(),
+                ),
+                Err(e) => Err(map_msgpack_err(input, e)),
+            },
+            Err(err) => Err(err),
+        };
+        r.map_err(|e| self.prepare_validation_err(py, e, InputType::Msgpack))
+    }
+
+    #[pyo3(signature = (inputs, *, strict=None, from_attributes=None, context=None, on_error=ManyErrorMode::Raise))]
+    pub fn validate_many<'py>(
+        &self,
+        py: Python<'py>,
+        inputs: &Bound<'py, PyAny>,
+        strict: Option<bool>,
+        from_attributes: Option<bool>,
+        context: Option<&Bound<'py, PyAny>>,
+        on_error: ManyErrorMode,
+    ) -> PyResult<(Bound<'py, PyList>, Bound<'py, PyList>)> {
+        let output = PyList::empty_bound(py);
+        let errors = PyList::empty_bound(py);
+
+        // the same recursion guard and extra are used for every input, only the union tie-breaking
+        // state and resource usage are reset between inputs
+        let mut recursion_guard = RecursionState::new(self.recursion);
+        let mut extra = Extra::new(
+            strict,
+            from_attributes,
+            context,
+            None,
+            InputType::Python,
+            self.cache_str,
+            self.resource_limits,
+        );
+        extra.max_errors = self.error_output.max_errors();
+        let mut state = ValidationState::new(extra, &mut recursion_guard);
+        for (index, input) in inputs.iter()?.enumerate() {
+            let input = input?;
+            state.exactness = None;
+            state.fields_set_count = None;
+            state.resource_usage = ResourceUsage::default();
+            match (self.validator.validate(py, &input, &mut state), on_error) {
+                (Ok(value), _) => output.append(value)?,
+                (Err(ValError::LineErrors(_) | ValError::LimitExceeded(_)), ManyErrorMode::Skip) => {}
+                (Err(err @ (ValError::LineErrors(_) | ValError::LimitExceeded(_))), ManyErrorMode::Collect) => {
+                    let py_err = self.prepare_validation_err(py, err, InputType::Python);
+                    errors.append((index, py_err.into_value(py)))?;
+                }
+                (Err(err), _) => {
+                    return Err(self.prepare_validation_err(py, err.with_outer_location(index), InputType::Python))
+                }
+            }
+        }
+        Ok((output, errors))
+    }
+
+    #[pyo3(signature = (input, *, strict=None, context=None, return_errors=false))]
+    pub fn validate_json_lines(
+        &self,
+        py: Python,
+        input: &Bound<'_, PyAny>,
+        strict: Option<bool>,
+        context: Option<&Bound<'_, PyAny>>,
+        return_errors: bool,
+    ) -> PyResult<PyObject> {
+        let v_match =
+            json::validate_json_bytes(input).map_err(|e| self.prepare_validation_err(py, e, InputType::Json))?;
+        let json_either_bytes = v_match.into_inner();
+
+        let mut output = Vec::new();
+        let mut errors: Vec<ValLineError> = Vec::new();
+        for (index, line) in json_either_bytes.as_slice().split(|b| *b == b'\n').enumerate() {
+            let line = line.strip_suffix(b"\r").unwrap_or(line);
+            // only JSON whitespace makes a line blank, `u8::is_ascii_whitespace` would also accept form feeds
+            if line.iter().all(|b| matches!(b, b' ' | b'\t' | b'\r' | b'\n')) {
+                continue;
+            }
+            // line numbers are 1-based to match the numbering used in JSON parsing errors
+            let line_number = index + 1;
+            let line_input = JsonValue::Str(String::from_utf8_lossy(line));
+            let result = self
+                ._validate_json(py, &line_input, line, strict, None, context, None, PartialMode::Off)
+                .map_err(|e| e.with_outer_location(line_number));
+            match (result, return_errors) {
+                (Ok(value), false) => output.push(value),
+                (Ok(value), true) => output.push((value, py.None()).into_py(py)),
+                (Err(ValError::LineErrors(line_errors)), false) => errors.extend(line_errors),
+                (Err(ValError::LimitExceeded(line_error)), false) => errors.push(*line_error),
+                (Err(err @ (ValError::LineErrors(_) | ValError::LimitExceeded(_))), true) => {
+                    let py_err = self.prepare_validation_err(py, err, InputType::Json);
+                    output.push((py.None(), py_err.into_value(py)).into_py(py));
+                }
+                (Err(err), _) => return Err(self.prepare_validation_err(py, err, InputType::Json)),
+            }
+        }
+        if !errors.is_empty() {
+            return Err(self.prepare_validation_err(py, ValError::LineErrors(errors), InputType::Json));
+        }
+        Ok(PyList::new_bound(py, output).into_py(py))
+    }
+
     #[pyo3(signature = (input, *, strict=None, context=None))]
     pub fn validate_strings(
         &self,
@@ -247,7 +479,18 @@ impl SchemaValidator {
         let t = InputType::String;
         let string_mapping = StringMapping::new_value(input).map_err(|e| self.prepare_validation_err(py, e, t))?;
 
-        match self._validate(py, &string_mapping, t, strict, None, context, None) {
+        match self._validate(
+            py,
+            &string_mapping,
+            t,
+            strict,
+            None,
+            None,
+            context,
+            None,
+            None,
+            ResourceUsage::default(),
+        ) {
             Ok(r) => Ok(r),
             Err(e) => Err(self.prepare_validation_err(py, e, t)),
         }
@@ -270,12 +513,15 @@ impl SchemaValidator {
             data: None,
             strict,
             from_attributes,
+            fail_fast: None,
             context,
             self_instance: None,
             cache_str: self.cache_str,
+            resource_limits: self.resource_limits,
+            max_errors: self.error_output.max_errors(),
         };
 
-        let guard = &mut RecursionState::default();
+        let guard = &mut RecursionState::new(self.recursion);
         let mut state = ValidationState::new(extra, guard);
         self.validator
             .validate_assignment(py, &obj, field_name, &field_value, &mut state)
@@ -294,11 +540,14 @@ impl SchemaValidator {
             data: None,
             strict,
             from_attributes: None,
+            fail_fast: None,
             context,
             self_instance: None,
             cache_str: self.cache_str,
+            resource_limits: self.resource_limits,
+            max_errors: self.error_output.max_errors(),
         };
-        let recursion_guard = &mut RecursionState::default();
+        let recursion_guard = &mut RecursionState::new(self.recursion);
         let mut state = ValidationState::new(extra, recursion_guard);
         let r = self.validator.default_value(py, None::<i64>, &mut state);
         match r {
@@ -310,6 +559,13 @@ impl SchemaValidator {
         }
     }
 
+    #[pyo3(signature = (*, ref_template="#/$defs/{model}"))]
+    pub fn json_schema<'py>(&self, py: Python<'py>, ref_template: &str) -> PyResult<Bound<'py, PyDict>> {
+        let mut state = JsonSchemaState::new(py, ref_template);
+        let schema = self.validator.json_schema(&mut state)?;
+        state.finish(schema)
+    }
+
     pub fn __repr__(&self, py: Python) -> String {
         format!(
             "SchemaValidator(title={:?}, validator={:#?}, definitions={:#?}, cache_strings={})",
@@ -335,6 +591,44 @@ impl SchemaValidator {
 }
 
 impl SchemaValidator {
+    /// `validate_python` without partial validation and with `fail_fast` from config, for use from Rust
+    pub fn validate_python(
+        &self,
+        py: Python,
+        input: &Bound<'_, PyAny>,
+        strict: Option<bool>,
+        from_attributes: Option<bool>,
+        context: Option<&Bound<'_, PyAny>>,
+        self_instance: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<PyObject> {
+        self.py_validate_python(py, input, strict, from_attributes, context, self_instance, false, None)
+    }
+
+    /// `isinstance_python` with `fail_fast` from config, for use from Rust
+    pub fn isinstance_python(
+        &self,
+        py: Python,
+        input: &Bound<'_, PyAny>,
+        strict: Option<bool>,
+        from_attributes: Option<bool>,
+        context: Option<&Bound<'_, PyAny>>,
+        self_instance: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<bool> {
+        self.py_isinstance_python(py, input, strict, from_attributes, context, self_instance, None)
+    }
+
+    /// `validate_json` without partial validation and with `fail_fast` from config, for use from Rust
+    pub fn validate_json(
+        &self,
+        py: Python,
+        input: &Bound<'_, PyAny>,
+        strict: Option<bool>,
+        context: Option<&Bound<'_, PyAny>>,
+        self_instance: Option<&Bound<'_, PyAny>>,
+    ) -> PyResult<PyObject> {
+        self.py_validate_json(py, input, strict, context, self_instance, PartialMode::Off, None)
+    }
+
     #[allow(clippy::too_many_arguments)]
     fn _validate<'py>(
         &self,
@@ -343,36 +637,109 @@ impl SchemaValidator {
         input_type: InputType,
         strict: Option<bool>,
         from_attributes: Option<bool>,
+        fail_fast: Option<bool>,
         context: Option<&Bound<'py, PyAny>>,
         self_instance: Option<&Bound<'py, PyAny>>,
+        partial_tail: Option<PartialTail>,
+        resource_usage: ResourceUsage,
     ) -> ValResult<PyObject> {
-        let mut recursion_guard = RecursionState::default();
-        let mut state = ValidationState::new(
-            Extra::new(
-                strict,
-                from_attributes,
-                context,
-                self_instance,
-                input_type,
-                self.cache_str,
-            ),
-            &mut recursion_guard,
+        let mut recursion_guard = RecursionState::new(self.recursion);
+        let mut extra = Extra::new(
+            strict,
+            from_attributes,
+            context,
+            self_instance,
+            input_type,
+            self.cache_str,
+            self.resource_limits,
         );
+        extra.fail_fast = fail_fast;
+        extra.max_errors = self.error_output.max_errors();
+        let mut state = ValidationState::new(extra, &mut recursion_guard);
+        state.resource_usage = resource_usage;
+        if let Some(partial_tail) = partial_tail {
+            state.allow_partial = true;
+            state.partial_tail = partial_tail;
+        }
         self.validator.validate(py, input, &mut state)
     }
 
-    fn _validate_json(
+    #[allow(clippy::too_many_arguments)]
+    fn _validate_json<'py>(
         &self,
-        py: Python,
-        input: &Bound<'_, PyAny>,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
         json_data: &[u8],
         strict: Option<bool>,
-        context: Option<&Bound<'_, PyAny>>,
-        self_instance: Option<&Bound<'_, PyAny>>,
+        fail_fast: Option<bool>,
+        context: Option<&Bound<'py, PyAny>>,
+        self_instance: Option<&Bound<'py, PyAny>>,
+        allow_partial: PartialMode,
     ) -> ValResult<PyObject> {
-        let json_value =
-            jiter::JsonValue::parse(json_data, true).map_err(|e| json::map_json_err(input, e, json_data))?;
-        self._validate(py, &json_value, InputType::Json, strict, None, context, self_instance)
+        let mut resource_usage = ResourceUsage::default();
+        self.resource_limits.check_json(json_data, &mut resource_usage, input)?;
+        // partial validation only applies if the data really was truncated
+        let (json_value, partial_tail) = JsonParser::new(json_data)
+            .with_partial_mode(allow_partial)
+            .parse()
+            .map_err(|e| json::map_json_err(input, e, json_data))?;
+        self._validate(
+            py,
+            &json_value,
+            InputType::Json,
+            strict,
+            None,
+            fail_fast,
+            context,
+            self_instance,
+            partial_tail,
+            resource_usage,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn _validate_json_stream<'py>(
+        &self,
+        py: Python<'py>,
+        input: &Bound<'py, PyAny>,
+        strict: Option<bool>,
+        context: Option<&Bound<'py, PyAny>>,
+        fail_fast: Option<bool>,
+        chunk_size: usize,
+    ) -> ValResult<PyObject> {
+        let mut stream = json::JsonStream::new(input, chunk_size)?;
+        let list_validator = match self.validator {
+            CombinedValidator::List(ref list_validator) if stream.is_array()? => list_validator,
+            // only the items of a top-level array validated by a list schema can be validated incrementally,
+            // anything else is read in full and validated as `validate_json` would
+            _ => {
+                let json_data = stream.read_to_end()?;
+                return self._validate_json(
+                    py,
+                    input,
+                    &json_data,
+                    strict,
+                    fail_fast,
+                    context,
+                    None,
+                    PartialMode::Off,
+                );
+            }
+        };
+        let mut recursion_guard = RecursionState::new(self.recursion);
+        let mut extra = Extra::new(
+            strict,
+            None,
+            context,
+            None,
+            InputType::Json,
+            self.cache_str,
+            self.resource_limits,
+        );
+        extra.fail_fast = fail_fast;
+        extra.max_errors = self.error_output.max_errors();
+        let mut state = ValidationState::new(extra, &mut recursion_guard);
+        list_validator.validate_json_stream(py, input, &mut stream, &mut state)
     }
 
     fn prepare_validation_err(&self, py: Python, error: ValError, input_type: InputType) -> PyErr {
@@ -380,10 +747,11 @@ impl SchemaValidator {
             py,
             self.title.clone_ref(py),
             input_type,
-            error,
+            self.error_output.truncate_errors(error),
             None,
             self.hide_input_in_errors,
             self.validation_error_cause,
+            &self.error_output,
         )
     }
 }
@@ -408,7 +776,15 @@ impl<'py> SelfValidator<'py> {
         let py = schema.py();
         let mut recursion_guard = RecursionState::default();
         let mut state = ValidationState::new(
-            Extra::new(strict, None, None, None, InputType::Python, true.into()),
+            Extra::new(
+                strict,
+                None,
+                None,
+                None,
+                InputType::Python,
+                true.into(),
+                ResourceLimits::default(),
+            ),
             &mut recursion_guard,
         );
         match self.validator.validator.validate(py, schema, &mut state) {
@@ -438,7 +814,10 @@ impl<'py> SelfValidator<'py> {
             title: "Self Schema".into_py(py),
             hide_input_in_errors: false,
             validation_error_cause: false,
+            error_output: ErrorOutputConfig::default(),
             cache_str: true.into(),
+            recursion: RecursionConfig::default(),
+            resource_limits: ResourceLimits::default(),
         })
     }
 }
@@ -556,6 +935,8 @@ pub fn build_validator(
         frozenset::FrozenSetValidator,
         // timedelta
         timedelta::TimeDeltaValidator,
+        // time zones
+        timezone::TimezoneValidator,
         // introspection types
         is_instance::IsInstanceValidator,
         is_subclass::IsSubclassValidator,
@@ -581,6 +962,12 @@ pub fn build_validator(
         url::MultiHostUrlValidator,
         // uuid types
         uuid::UuidValidator,
+        // ip address types
+        ip::IpAddressBuilder,
+        ip::IpNetworkBuilder,
+        ip::IpInterfaceBuilder,
+        // path types
+        path::PathValidator,
         // recursive (self-referencing) models
         definitions::DefinitionRefValidator,
         definitions::DefinitionsValidatorBuilder,
@@ -600,12 +987,18 @@ pub struct Extra<'a, 'py> {
     pub strict: Option<bool>,
     /// Validation time setting of `from_attributes`
     pub from_attributes: Option<bool>,
+    /// Validation time setting of `fail_fast`, whether to stop at the first error
+    pub fail_fast: Option<bool>,
     /// context used in validator functions
     pub context: Option<&'a Bound<'py, PyAny>>,
     /// This is an instance of the model or dataclass being validated, when validation is performed from `__init__`
     self_instance: Option<&'a Bound<'py, PyAny>>,
     /// Whether to use a cache of short strings to accelerate python string construction
     cache_str: StringCacheMode,
+    /// Limits on the size of the input from config
+    resource_limits: ResourceLimits,
+    /// The `max_errors` setting from config, collections stop validating items once they've found this many errors
+    pub max_errors: Option<usize>,
 }
 
 impl<'a, 'py> Extra<'a, 'py> {
@@ -616,15 +1009,19 @@ impl<'a, 'py> Extra<'a, 'py> {
         self_instance: Option<&'a Bound<'py, PyAny>>,
         input_type: InputType,
         cache_str: StringCacheMode,
+        resource_limits: ResourceLimits,
     ) -> Self {
         Extra {
             input_type,
             data: None,
             strict,
             from_attributes,
+            fail_fast: None,
             context,
             self_instance,
             cache_str,
+            resource_limits,
+            max_errors: None,
         }
     }
 }
@@ -636,9 +1033,12 @@ impl Extra<'_, '_> {
             data: self.data.clone(),
             strict: Some(true),
             from_attributes: self.from_attributes,
+            fail_fast: self.fail_fast,
             context: self.context,
             self_instance: self.self_instance,
             cache_str: self.cache_str,
+            resource_limits: self.resource_limits,
+            max_errors: self.max_errors,
         }
     }
 }
@@ -711,6 +1111,8 @@ pub enum CombinedValidator {
     FrozenSet(frozenset::FrozenSetValidator),
     // timedelta
     Timedelta(timedelta::TimeDeltaValidator),
+    // time zones
+    Timezone(timezone::TimezoneValidator),
     // introspection types
     IsInstance(is_instance::IsInstanceValidator),
     IsSubclass(is_subclass::IsSubclassValidator),
@@ -734,6 +1136,10 @@ pub enum CombinedValidator {
     MultiHostUrl(url::MultiHostUrlValidator),
     // uuid types
     Uuid(uuid::UuidValidator),
+    // ip address types
+    Ip(ip::IpValidator),
+    // path types
+    Path(path::PathValidator),
     // reference to definition, useful for recursive (self-referencing) models
     DefinitionRef(definitions::DefinitionRefValidator),
     // input dependent
@@ -741,6 +1147,23 @@ pub enum CombinedValidator {
     Complex(complex::ComplexValidator),
 }
 
+impl CombinedValidator {
+    /// Validate `input`, this shadows `Validator::validate` so that every call to a nested validator is
+    /// recorded when tracing is enabled, without each validator needing to know about tracing
+    pub fn validate<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        if state.tracer.is_some() {
+            trace::traced_validate(self, py, input, state)
+        } else {
+            Validator::validate(self, py, input, state)
+        }
+    }
+}
+
 /// This trait must be implemented by all validators, it allows various validators to be accessed consistently,
 /// validators defined in `build_validator` also need `EXPECTED_TYPE` as a const, but that can't be part of the trait
 #[enum_dispatch(CombinedValidator)]
@@ -777,6 +1200,12 @@ pub trait Validator: Send + Sync + Debug {
         Err(py_err.into())
     }
 
+    /// Generate a JSON Schema for the inputs this validator accepts, constraints are taken straight from the
+    /// validator so the schema matches what's enforced; validators which can't be represented raise a `TypeError`
+    fn json_schema<'py>(&self, _state: &mut JsonSchemaState<'py>) -> PyResult<Bound<'py, PyDict>> {
+        json_schema::unsupported(self.get_name())
+    }
+
     /// `get_name` generally returns `Self::EXPECTED_TYPE` or some other clear identifier of the validator
     /// this is used in the error location in unions, and in the top level message in `ValidationError`
     fn get_name(&self) -> &str;
@@ -6,6 +6,7 @@ use pyo3::types::{PyDict, PySet, PyString, PyTuple, PyType};
 use pyo3::{intern, prelude::*};
 
 use super::function::convert_err;
+use super::json_schema::JsonSchemaState;
 use super::validation_state::Exactness;
 use super::{
     build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, Extra, ValidationState, Validator,
@@ -216,6 +217,14 @@ impl Validator for ModelValidator {
         Ok(model.into_py(py))
     }
 
+    fn json_schema<'py>(&self, state: &mut JsonSchemaState<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let schema = self.validator.json_schema(state)?;
+        if !self.root_model {
+            state.set_class_title(&schema, &self.class)?;
+        }
+        Ok(schema)
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
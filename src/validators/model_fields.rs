@@ -14,6 +14,7 @@ use crate::input::{BorrowInput, Input, ValidatedDict, ValidationMatch};
 use crate::lookup_key::LookupKey;
 use crate::tools::SchemaDict;
 
+use super::json_schema::{has_default, JsonSchemaState};
 use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
 #[derive(Debug)]
@@ -36,6 +37,7 @@ pub struct ModelFieldsValidator {
     strict: bool,
     from_attributes: bool,
     loc_by_alias: bool,
+    fail_fast: bool,
 }
 
 impl BuildValidator for ModelFieldsValidator {
@@ -103,6 +105,7 @@ impl BuildValidator for ModelFieldsValidator {
             strict,
             from_attributes,
             loc_by_alias: config.get_as(intern!(py, "loc_by_alias"))?.unwrap_or(true),
+            fail_fast: config.get_as(intern!(py, "fail_fast"))?.unwrap_or(false),
         }
         .into())
     }
@@ -122,6 +125,7 @@ impl Validator for ModelFieldsValidator {
     ) -> ValResult<PyObject> {
         let strict = state.strict_or(self.strict);
         let from_attributes = state.extra().from_attributes.unwrap_or(self.from_attributes);
+        let fail_fast = state.fail_fast_or(self.fail_fast);
 
         // we convert the DictType error to a ModelType error
         let dict = match input.validate_model_fields(strict, from_attributes) {
@@ -163,8 +167,22 @@ impl Validator for ModelFieldsValidator {
 
         {
             let state = &mut state.rebind_extra(|extra| extra.data = Some(model_dict.clone()));
+            let allow_partial = state.allow_partial;
+            let partial_tail = state.partial_tail;
+            // if partial input ended inside this dict, the value of its last key may be incomplete, and since keys
+            // can come in any order, any field which isn't present may just not have been reached yet
+            let truncated = state.is_truncated();
+            let last_key: Option<LocItem> = if truncated {
+                dict.last_key().map(Into::into)
+            } else {
+                None
+            };
+            state.partial_tail = partial_tail.last_item();
 
             for field in &self.fields {
+                if fail_fast && !errors.is_empty() {
+                    break;
+                }
                 let op_key_value = match dict.get_item(&field.lookup_key) {
                     Ok(v) => v,
                     Err(ValError::LineErrors(line_errors)) => {
@@ -181,13 +199,21 @@ impl Validator for ModelFieldsValidator {
                         // extra logic either way
                         used_keys.insert(lookup_path.first_key());
                     }
-                    match field.validator.validate(py, value.borrow_input(), state) {
+                    let is_last_partial = matches!(&last_key, Some(LocItem::S(key)) if key == lookup_path.first_key());
+                    state.allow_partial = is_last_partial;
+                    state.trace_loc(|| &field.name);
+                    let result = field.validator.validate(py, value.borrow_input(), state);
+                    let incomplete_tail =
+                        is_last_partial && result.is_err() && state.is_incomplete(value.borrow_input());
+                    match result {
                         Ok(value) => {
                             model_dict.set_item(&field.name_py, value)?;
                             fields_set_vec.push(field.name_py.clone_ref(py));
                             fields_set_count += 1;
                         }
                         Err(ValError::Omit) => continue,
+                        // an incomplete value at the tail of partial input is treated as if the field were missing
+                        Err(ValError::LineErrors(_)) if incomplete_tail => (),
                         Err(ValError::LineErrors(line_errors)) => {
                             for err in line_errors {
                                 errors.push(lookup_path.apply_error_loc(err, self.loc_by_alias, &field.name));
@@ -195,7 +221,9 @@ impl Validator for ModelFieldsValidator {
                         }
                         Err(err) => return Err(err),
                     }
-                    continue;
+                    if !incomplete_tail {
+                        continue;
+                    }
                 }
 
                 match field.validator.default_value(py, Some(field.name.as_str()), state) {
@@ -205,12 +233,14 @@ impl Validator for ModelFieldsValidator {
                     }
                     Ok(None) => {
                         // This means there was no default value
-                        errors.push(field.lookup_key.error(
-                            ErrorTypeDefaults::Missing,
-                            input,
-                            self.loc_by_alias,
-                            &field.name,
-                        ));
+                        if !truncated {
+                            errors.push(field.lookup_key.error(
+                                ErrorTypeDefaults::Missing,
+                                input,
+                                self.loc_by_alias,
+                                &field.name,
+                            ));
+                        }
                     }
                     Err(ValError::Omit) => continue,
                     Err(ValError::LineErrors(line_errors)) => {
@@ -225,6 +255,12 @@ impl Validator for ModelFieldsValidator {
                     Err(err) => return Err(err),
                 }
             }
+            state.allow_partial = allow_partial;
+            state.partial_tail = partial_tail;
+        }
+
+        if fail_fast && !errors.is_empty() {
+            return Err(ValError::LineErrors(errors));
         }
 
         if let Some(used_keys) = used_keys {
@@ -236,6 +272,7 @@ impl Validator for ModelFieldsValidator {
                 extra_behavior: ExtraBehavior,
                 extras_validator: Option<&'a CombinedValidator>,
                 state: &'a mut ValidationState<'s, 'py>,
+                fail_fast: bool,
             }
 
             impl<'py, Key, Value> ConsumeIterator<ValResult<(Key, Value)>> for ValidateToModelExtra<'_, '_, 'py>
@@ -250,6 +287,9 @@ impl Validator for ModelFieldsValidator {
                 ) -> ValResult<Bound<'py, PyDict>> {
                     let model_extra_dict = PyDict::new_bound(self.py);
                     for item_result in iterator {
+                        if self.fail_fast && !self.errors.is_empty() {
+                            break;
+                        }
                         let (raw_key, value) = item_result?;
                         let either_str = match raw_key
                             .borrow_input()
@@ -287,6 +327,7 @@ impl Validator for ModelFieldsValidator {
                             ExtraBehavior::Allow => {
                                 let py_key = either_str.as_py_string(self.py, self.state.cache_str());
                                 if let Some(validator) = self.extras_validator {
+                                    self.state.trace_loc(|| raw_key.clone());
                                     match validator.validate(self.py, value, self.state) {
                                         Ok(value) => {
                                             model_extra_dict.set_item(&py_key, value)?;
@@ -318,6 +359,7 @@ impl Validator for ModelFieldsValidator {
                 extra_behavior: self.extra_behavior,
                 extras_validator: self.extras_validator.as_deref(),
                 state,
+                fail_fast,
             })??;
 
             if matches!(self.extra_behavior, ExtraBehavior::Allow) {
@@ -435,6 +477,17 @@ impl Validator for ModelFieldsValidator {
         Ok((new_data.to_object(py), new_extra, fields_set.to_object(py)).to_object(py))
     }
 
+    fn json_schema<'py>(&self, state: &mut JsonSchemaState<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let fields = self.fields.iter().map(|field| {
+            (
+                field.lookup_key.first_key(),
+                &field.validator,
+                !has_default(&field.validator),
+            )
+        });
+        state.object_schema(fields, self.extra_behavior, self.extras_validator.as_deref())
+    }
+
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
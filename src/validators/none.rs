@@ -4,6 +4,7 @@ use pyo3::types::PyDict;
 use crate::errors::{ErrorTypeDefaults, ValError, ValResult};
 use crate::input::Input;
 
+use super::json_schema::JsonSchemaState;
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
 #[derive(Debug, Clone)]
@@ -36,6 +37,10 @@ impl Validator for NoneValidator {
         }
     }
 
+    fn json_schema<'py>(&self, state: &mut JsonSchemaState<'py>) -> PyResult<Bound<'py, PyDict>> {
+        state.type_schema("null")
+    }
+
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
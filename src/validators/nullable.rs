@@ -6,6 +6,7 @@ use crate::errors::ValResult;
 use crate::input::Input;
 use crate::tools::SchemaDict;
 
+use super::json_schema::JsonSchemaState;
 use super::ValidationState;
 use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, Validator};
 
@@ -45,6 +46,11 @@ impl Validator for NullableValidator {
         }
     }
 
+    fn json_schema<'py>(&self, state: &mut JsonSchemaState<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let choices = vec![self.validator.json_schema(state)?, state.type_schema("null")?];
+        state.choices_schema("anyOf", choices)
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
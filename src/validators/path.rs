@@ -0,0 +1,179 @@
+use ahash::AHashSet;
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::sync::GILOnceCell;
+use pyo3::types::{PyDict, PyList, PyString, PyType};
+
+use crate::build_tools::{is_strict, py_schema_err};
+use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValResult};
+use crate::input::input_as_python_instance;
+use crate::input::Input;
+use crate::input::InputType;
+use crate::input::ValidationMatch;
+use crate::tools::SchemaDict;
+
+use super::config::ValBytesMode;
+use super::json_schema::JsonSchemaState;
+use super::literal::expected_repr_name;
+use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, ValidationState, Validator};
+
+static PATH_TYPE: GILOnceCell<Py<PyType>> = GILOnceCell::new();
+
+fn get_path_type(py: Python<'_>) -> PyResult<&Bound<'_, PyType>> {
+    PATH_TYPE
+        .get_or_try_init(py, || {
+            py.import_bound(intern!(py, "pathlib"))?
+                .getattr(intern!(py, "Path"))?
+                .extract()
+        })
+        .map(|t| t.bind(py))
+}
+
+#[derive(Debug, Clone)]
+pub struct PathValidator {
+    strict: bool,
+    absolute: bool,
+    allowed_suffixes: Option<(AHashSet<String>, String)>,
+    exists: bool,
+    is_file: bool,
+    is_dir: bool,
+    name: String,
+}
+
+impl BuildValidator for PathValidator {
+    const EXPECTED_TYPE: &'static str = "path";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        _definitions: &mut DefinitionsBuilder<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        let py = schema.py();
+        let is_file = schema.get_as(intern!(py, "is_file"))?.unwrap_or(false);
+        let is_dir = schema.get_as(intern!(py, "is_dir"))?.unwrap_or(false);
+        if is_file && is_dir {
+            return py_schema_err!("`is_file` and `is_dir` cannot both be set");
+        }
+
+        let (allowed_suffixes, name) = match schema.get_as::<Bound<'_, PyList>>(intern!(py, "allowed_suffixes"))? {
+            Some(list) => {
+                if list.is_empty() {
+                    return py_schema_err!("`allowed_suffixes` should have length > 0");
+                }
+                let mut expected: AHashSet<String> = AHashSet::new();
+                let mut repr_args = Vec::new();
+                for item in list {
+                    let suffix: String = item.extract()?;
+                    repr_args.push(format!("'{suffix}'"));
+                    expected.insert(suffix);
+                }
+                let (repr, name) = expected_repr_name(repr_args, Self::EXPECTED_TYPE);
+                (Some((expected, repr)), name)
+            }
+            None => (None, Self::EXPECTED_TYPE.to_string()),
+        };
+
+        Ok(Self {
+            strict: is_strict(schema, config)?,
+            absolute: schema.get_as(intern!(py, "absolute"))?.unwrap_or(false),
+            allowed_suffixes,
+            exists: schema.get_as(intern!(py, "exists"))?.unwrap_or(false),
+            is_file,
+            is_dir,
+            name,
+        }
+        .into())
+    }
+}
+
+impl_py_gc_traverse!(PathValidator {});
+
+impl Validator for PathValidator {
+    fn validate<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        let class = get_path_type(py)?;
+        let path = if let Some(py_input) = input_as_python_instance(input, class) {
+            py_input.clone()
+        } else if state.strict_or(self.strict) && state.extra().input_type == InputType::Python {
+            return Err(ValError::new(
+                ErrorType::IsInstanceOf {
+                    class: "Path".to_string(),
+                    context: None,
+                },
+                input,
+            ));
+        } else {
+            // as with UUIDs, in python mode this is a coercion, in JSON mode a string is an exact match
+            if state.extra().input_type == InputType::Python {
+                state.floor_exactness(Exactness::Lax);
+            }
+            class.call1((self.get_path_str(py, input)?,))?
+        };
+
+        if self.absolute && !path.call_method0(intern!(py, "is_absolute"))?.is_truthy()? {
+            return Err(ValError::new(ErrorTypeDefaults::PathNotAbsolute, input));
+        }
+        if let Some((ref allowed_suffixes, ref expected_suffixes)) = self.allowed_suffixes {
+            let suffix = path.getattr(intern!(py, "suffix"))?;
+            if !allowed_suffixes.contains(suffix.downcast::<PyString>()?.to_cow()?.as_ref()) {
+                return Err(ValError::new(
+                    ErrorType::PathSuffix {
+                        expected_suffixes: expected_suffixes.clone(),
+                        context: None,
+                    },
+                    input,
+                ));
+            }
+        }
+        // the filesystem checks are left to `pathlib` so they behave exactly as they do in python
+        if self.exists && !path.call_method0(intern!(py, "exists"))?.is_truthy()? {
+            return Err(ValError::new(ErrorTypeDefaults::PathNotExists, input));
+        }
+        if self.is_file && !path.call_method0(intern!(py, "is_file"))?.is_truthy()? {
+            return Err(ValError::new(ErrorTypeDefaults::PathNotFile, input));
+        }
+        if self.is_dir && !path.call_method0(intern!(py, "is_dir"))?.is_truthy()? {
+            return Err(ValError::new(ErrorTypeDefaults::PathNotDirectory, input));
+        }
+        Ok(path.into())
+    }
+
+    fn json_schema<'py>(&self, state: &mut JsonSchemaState<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let format = if self.is_file {
+            "file-path"
+        } else if self.is_dir {
+            "directory-path"
+        } else {
+            "path"
+        };
+        state.format_schema(format)
+    }
+
+    fn get_name(&self) -> &str {
+        &self.name
+    }
+}
+
+impl PathValidator {
+    /// Get the input as a python `str`, bytes and `os.PathLike` objects are decoded with `os.fsdecode`
+    fn get_path_str<'py>(&self, py: Python<'py>, input: &(impl Input<'py> + ?Sized)) -> ValResult<Bound<'py, PyAny>> {
+        if let Ok(either_str) = input.validate_str(true, false).map(ValidationMatch::into_inner) {
+            return Ok(PyString::new_bound(py, either_str.as_cow()?.as_ref()).into_any());
+        }
+        let to_decode = if let Ok(either_bytes) = input.validate_bytes(true, ValBytesMode::default()) {
+            either_bytes.into_inner().into_py(py).into_bound(py)
+        } else {
+            match input.as_python() {
+                Some(py_input) if py_input.hasattr(intern!(py, "__fspath__"))? => py_input.clone(),
+                _ => return Err(ValError::new(ErrorTypeDefaults::PathType, input)),
+            }
+        };
+        py.import_bound(intern!(py, "os"))?
+            .call_method1(intern!(py, "fsdecode"), (to_decode,))
+            .map_err(|_| ValError::new(ErrorTypeDefaults::PathType, input))
+    }
+}
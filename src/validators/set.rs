@@ -1,3 +1,4 @@
+use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PySet};
 
@@ -5,6 +6,7 @@ use crate::errors::ValResult;
 use crate::input::{validate_iter_to_set, BorrowInput, ConsumeIterator, Input, ValidatedSet};
 use crate::tools::SchemaDict;
 
+use super::json_schema::JsonSchemaState;
 use super::list::min_length_check;
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
@@ -43,7 +45,8 @@ macro_rules! set_build {
                 min_length: schema.get_as(pyo3::intern!(py, "min_length"))?,
                 max_length,
                 name,
-                fail_fast: schema.get_as(pyo3::intern!(py, "fail_fast"))?.unwrap_or(false),
+                fail_fast: crate::build_tools::schema_or_config_same(schema, config, pyo3::intern!(py, "fail_fast"))?
+                    .unwrap_or(false),
             }
             .into())
         }
@@ -67,6 +70,7 @@ impl Validator for SetValidator {
     ) -> ValResult<PyObject> {
         let collection = input.validate_set(state.strict_or(self.strict))?.unpack(state);
         let set = PySet::empty_bound(py)?;
+        let fail_fast = state.fail_fast_or(self.fail_fast);
         collection.iterate(ValidateToSet {
             py,
             input,
@@ -74,12 +78,18 @@ impl Validator for SetValidator {
             max_length: self.max_length,
             item_validator: &self.item_validator,
             state,
-            fail_fast: self.fail_fast,
+            fail_fast,
         })??;
         min_length_check!(input, "Set", self.min_length, set);
         Ok(set.into_py(py))
     }
 
+    fn json_schema<'py>(&self, state: &mut JsonSchemaState<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let schema = state.array_schema(Some(&self.item_validator), self.min_length, self.max_length)?;
+        schema.set_item(intern!(state.py(), "uniqueItems"), true)?;
+        Ok(schema)
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
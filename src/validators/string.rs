@@ -1,3 +1,4 @@
+use pyo3::exceptions::PyRuntimeError;
 use pyo3::intern;
 use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyString};
@@ -8,6 +9,8 @@ use crate::errors::{ErrorType, ValError, ValResult};
 use crate::input::Input;
 use crate::tools::SchemaDict;
 
+use super::json_schema::{set_constraint, JsonSchemaState};
+use super::validation_state::limit_exceeded;
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
 #[derive(Debug)]
@@ -47,9 +50,15 @@ impl Validator for StrValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        input
-            .validate_str(state.strict_or(self.strict), self.coerce_numbers_to_str)
-            .map(|val_match| val_match.unpack(state).as_py_string(py, state.cache_str()).into_py(py))
+        let either_str = input
+            .validate_str(state.strict_or(self.strict), self.coerce_numbers_to_str)?
+            .unpack(state);
+        state.count_string_bytes(&either_str, input)?;
+        Ok(either_str.as_py_string(py, state.cache_str()).into_py(py))
+    }
+
+    fn json_schema<'py>(&self, state: &mut JsonSchemaState<'py>) -> PyResult<Bound<'py, PyDict>> {
+        state.type_schema("string")
     }
 
     fn get_name(&self) -> &str {
@@ -82,6 +91,7 @@ impl Validator for StrConstrainedValidator {
         let either_str = input
             .validate_str(state.strict_or(self.strict), self.coerce_numbers_to_str)?
             .unpack(state);
+        state.count_string_bytes(&either_str, input)?;
         let cow = either_str.as_cow()?;
         let mut str = cow.as_ref();
         if self.strip_whitespace {
@@ -117,7 +127,7 @@ impl Validator for StrConstrainedValidator {
         }
 
         if let Some(pattern) = &self.pattern {
-            if !pattern.is_match(py, str)? {
+            if !pattern.is_match(py, str, input)? {
                 return Err(ValError::new(
                     ErrorType::StringPatternMismatch {
                         pattern: pattern.pattern.clone(),
@@ -141,6 +151,14 @@ impl Validator for StrConstrainedValidator {
         Ok(py_string.into_py(py))
     }
 
+    fn json_schema<'py>(&self, state: &mut JsonSchemaState<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let schema = state.type_schema("string")?;
+        set_constraint(&schema, "minLength", self.min_length)?;
+        set_constraint(&schema, "maxLength", self.max_length)?;
+        set_constraint(&schema, "pattern", self.pattern.as_ref().map(|p| p.pattern.as_str()))?;
+        Ok(schema)
+    }
+
     fn get_name(&self) -> &str {
         "constrained-str"
     }
@@ -164,7 +182,9 @@ impl StrConstrainedValidator {
                     .map(|s| s.to_str())
                     .transpose()?
                     .unwrap_or(RegexEngine::RUST_REGEX);
-                Pattern::compile(s, regex_engine)
+                let backtrack_limit: Option<usize> =
+                    schema_or_config_same(schema, config, intern!(py, "regex_backtrack_limit"))?;
+                Pattern::compile(s, regex_engine, backtrack_limit)
             })
             .transpose()?;
         let min_length: Option<usize> =
@@ -221,12 +241,17 @@ struct Pattern {
 #[derive(Debug, Clone)]
 enum RegexEngine {
     RustRegex(Regex),
+    // with the backtrack limit the regex was built with
+    FancyRegex(fancy_regex::Regex, usize),
     PythonRe(PyObject),
 }
 
 impl RegexEngine {
     const RUST_REGEX: &'static str = "rust-regex";
+    const FANCY_REGEX: &'static str = "fancy-regex";
     const PYTHON_RE: &'static str = "python-re";
+    // matches fancy-regex's own default
+    const DEFAULT_BACKTRACK_LIMIT: usize = 1_000_000;
 }
 
 impl Pattern {
@@ -241,7 +266,7 @@ impl Pattern {
         }
     }
 
-    fn compile(pattern: Bound<'_, PyAny>, engine: &str) -> PyResult<Self> {
+    fn compile(pattern: Bound<'_, PyAny>, engine: &str, backtrack_limit: Option<usize>) -> PyResult<Self> {
         let pattern_str = Self::extract_pattern_str(&pattern)?;
 
         let py = pattern.py();
@@ -262,6 +287,14 @@ impl Pattern {
                 RegexEngine::RUST_REGEX => {
                     RegexEngine::RustRegex(Regex::new(&pattern_str).map_err(|e| py_schema_error_type!("{}", e))?)
                 }
+                RegexEngine::FANCY_REGEX => {
+                    let backtrack_limit = backtrack_limit.unwrap_or(RegexEngine::DEFAULT_BACKTRACK_LIMIT);
+                    let regex = fancy_regex::RegexBuilder::new(&pattern_str)
+                        .backtrack_limit(backtrack_limit)
+                        .build()
+                        .map_err(|e| py_schema_error_type!("{}", e))?;
+                    RegexEngine::FancyRegex(regex, backtrack_limit)
+                }
                 RegexEngine::PYTHON_RE => RegexEngine::PythonRe(re_compile.call1((pattern,))?.into()),
                 _ => return Err(py_schema_error_type!("Invalid regex engine: {}", engine)),
             };
@@ -273,9 +306,17 @@ impl Pattern {
         }
     }
 
-    fn is_match(&self, py: Python<'_>, target: &str) -> PyResult<bool> {
+    fn is_match<'py>(&self, py: Python<'py>, target: &str, input: &(impl Input<'py> + ?Sized)) -> ValResult<bool> {
         match &self.engine {
             RegexEngine::RustRegex(regex) => Ok(regex.is_match(target)),
+            RegexEngine::FancyRegex(regex, backtrack_limit) => match regex.is_match(target) {
+                Ok(is_match) => Ok(is_match),
+                // the input isn't known not to match, matching it would just take too long
+                Err(fancy_regex::Error::RuntimeError(fancy_regex::RuntimeError::BacktrackLimitExceeded)) => {
+                    Err(limit_exceeded("regex_backtrack_limit", *backtrack_limit, input))
+                }
+                Err(e) => Err(ValError::InternalErr(PyRuntimeError::new_err(e.to_string()))),
+            },
             RegexEngine::PythonRe(py_regex) => {
                 Ok(!py_regex.call_method1(py, intern!(py, "search"), (target,))?.is_none(py))
             }
@@ -10,7 +10,10 @@ use crate::input::{EitherTime, Input};
 use crate::tools::SchemaDict;
 
 use super::datetime::extract_microseconds_precision;
+use super::datetime::DateTimeFormats;
 use super::datetime::TZConstraint;
+use super::json_schema::JsonSchemaState;
+use super::Exactness;
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
 #[derive(Debug, Clone)]
@@ -18,6 +21,7 @@ pub struct TimeValidator {
     strict: bool,
     constraints: Option<TimeConstraints>,
     microseconds_precision: speedate::MicrosecondsPrecisionOverflowBehavior,
+    formats: Option<DateTimeFormats>,
 }
 
 impl BuildValidator for TimeValidator {
@@ -32,6 +36,7 @@ impl BuildValidator for TimeValidator {
             strict: is_strict(schema, config)?,
             constraints: TimeConstraints::from_py(schema)?,
             microseconds_precision: extract_microseconds_precision(schema, config)?,
+            formats: DateTimeFormats::from_py(schema)?,
         };
         Ok(s.into())
     }
@@ -46,9 +51,20 @@ impl Validator for TimeValidator {
         input: &(impl Input<'py> + ?Sized),
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
-        let time = input
-            .validate_time(state.strict_or(self.strict), self.microseconds_precision)?
-            .unpack(state);
+        let strict = state.strict_or(self.strict);
+        let time = match input.validate_time(strict, self.microseconds_precision) {
+            Ok(val_match) => val_match.unpack(state),
+            // in lax mode, strings which aren't ISO 8601 can still match one of the `formats`
+            Err(line_errors @ ValError::LineErrors(..)) if !strict => match self.formats {
+                Some(ref formats) => {
+                    let time = formats.parse(input, line_errors)?.time;
+                    state.floor_exactness(Exactness::Lax);
+                    EitherTime::Raw(time)
+                }
+                None => return Err(line_errors),
+            },
+            Err(otherwise) => return Err(otherwise),
+        };
         if let Some(constraints) = &self.constraints {
             let raw_time = time.as_raw()?;
 
@@ -80,6 +96,10 @@ impl Validator for TimeValidator {
         Ok(time.try_into_py(py)?)
     }
 
+    fn json_schema<'py>(&self, state: &mut JsonSchemaState<'py>) -> PyResult<Bound<'py, PyDict>> {
+        state.format_schema("time")
+    }
+
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
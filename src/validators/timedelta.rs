@@ -7,6 +7,7 @@ use crate::errors::{ErrorType, ValError, ValResult};
 use crate::input::{duration_as_pytimedelta, EitherTimedelta, Input};
 
 use super::datetime::extract_microseconds_precision;
+use super::json_schema::JsonSchemaState;
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
 #[derive(Debug, Clone)]
@@ -103,6 +104,10 @@ impl Validator for TimeDeltaValidator {
         Ok(py_timedelta.into())
     }
 
+    fn json_schema<'py>(&self, state: &mut JsonSchemaState<'py>) -> PyResult<Bound<'py, PyDict>> {
+        state.format_schema("duration")
+    }
+
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
@@ -0,0 +1,145 @@
+use pyo3::exceptions::{PyKeyError, PyValueError};
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::sync::GILOnceCell;
+use pyo3::types::{PyDict, PyType, PyTzInfo};
+
+use crate::build_tools::is_strict;
+use crate::errors::{ErrorType, ErrorTypeDefaults, ValError, ValResult};
+use crate::input::{Input, InputType, Int, TzInfo, ValidationMatch};
+
+use super::json_schema::JsonSchemaState;
+use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, ValidationState, Validator};
+
+static ZONE_INFO_TYPE: GILOnceCell<Py<PyType>> = GILOnceCell::new();
+
+fn get_zone_info_type(py: Python<'_>) -> PyResult<&Bound<'_, PyType>> {
+    ZONE_INFO_TYPE
+        .get_or_try_init(py, || {
+            py.import_bound(intern!(py, "zoneinfo"))?
+                .getattr(intern!(py, "ZoneInfo"))?
+                .extract()
+        })
+        .map(|t| t.bind(py))
+}
+
+/// Parse a UTC offset such as `+02:00`, `-0530`, `+01` or `Z` into seconds
+fn parse_utc_offset(s: &str) -> Option<i32> {
+    if s == "Z" {
+        return Some(0);
+    }
+    let (sign, rest) = match s.as_bytes().first()? {
+        b'+' => (1, &s[1..]),
+        b'-' => (-1, &s[1..]),
+        _ => return None,
+    };
+    let parts: Vec<&str> = if rest.contains(':') {
+        rest.split(':').collect()
+    } else if rest.len() % 2 == 0 && rest.is_ascii() {
+        (0..rest.len()).step_by(2).map(|i| &rest[i..i + 2]).collect()
+    } else {
+        return None;
+    };
+    if parts.is_empty() || parts.len() > 3 {
+        return None;
+    }
+    let mut seconds = 0;
+    for (part, (multiplier, max)) in parts.iter().zip([(3600, 24), (60, 60), (1, 60)]) {
+        if part.len() != 2 || !part.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        let value: i32 = part.parse().ok()?;
+        if value >= max {
+            return None;
+        }
+        seconds += value * multiplier;
+    }
+    Some(sign * seconds)
+}
+
+/// Parse a time zone from a UTC offset or an IANA name, `Ok(None)` means the string isn't a valid time zone
+pub(super) fn parse_timezone<'py>(py: Python<'py>, s: &str) -> PyResult<Option<Bound<'py, PyAny>>> {
+    if let Some(seconds) = parse_utc_offset(s) {
+        return Ok(Some(Bound::new(py, TzInfo::try_from(seconds)?)?.into_any()));
+    }
+    match get_zone_info_type(py)?.call1((s,)) {
+        Ok(zone_info) => Ok(Some(zone_info)),
+        // `ZoneInfoNotFoundError` is a subclass of `KeyError`, invalid keys raise `ValueError`
+        Err(err) if err.is_instance_of::<PyKeyError>(py) || err.is_instance_of::<PyValueError>(py) => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct TimezoneValidator {
+    strict: bool,
+}
+
+impl BuildValidator for TimezoneValidator {
+    const EXPECTED_TYPE: &'static str = "timezone";
+
+    fn build(
+        schema: &Bound<'_, PyDict>,
+        config: Option<&Bound<'_, PyDict>>,
+        _definitions: &mut DefinitionsBuilder<CombinedValidator>,
+    ) -> PyResult<CombinedValidator> {
+        Ok(Self {
+            strict: is_strict(schema, config)?,
+        }
+        .into())
+    }
+}
+
+impl_py_gc_traverse!(TimezoneValidator {});
+
+impl Validator for TimezoneValidator {
+    fn validate<'py>(
+        &self,
+        py: Python<'py>,
+        input: &(impl Input<'py> + ?Sized),
+        state: &mut ValidationState<'_, 'py>,
+    ) -> ValResult<PyObject> {
+        if let Some(py_tz) = input.as_python().filter(|any| any.is_instance_of::<PyTzInfo>()) {
+            return Ok(py_tz.to_object(py));
+        }
+        if state.strict_or(self.strict) && state.extra().input_type == InputType::Python {
+            return Err(ValError::new(
+                ErrorType::IsInstanceOf {
+                    class: "tzinfo".to_string(),
+                    context: None,
+                },
+                input,
+            ));
+        }
+        // as with UUIDs, in python mode this is a coercion, in JSON mode a string is an exact match
+        if state.extra().input_type == InputType::Python {
+            state.floor_exactness(Exactness::Lax);
+        }
+        if let Ok(either_str) = input.validate_str(true, false).map(ValidationMatch::into_inner) {
+            match parse_timezone(py, either_str.as_cow()?.as_ref())? {
+                Some(tz) => Ok(tz.into()),
+                None => Err(ValError::new(ErrorTypeDefaults::TimezoneParsing, input)),
+            }
+        } else if let Ok(either_int) = input.validate_int(true).map(ValidationMatch::into_inner) {
+            // integers are offsets from UTC in seconds
+            let seconds = match either_int.as_int()? {
+                Int::I64(i) => i32::try_from(i).ok(),
+                Int::Big(_) => None,
+            };
+            match seconds.and_then(|seconds| TzInfo::try_from(seconds).ok()) {
+                Some(tz_info) => Ok(Bound::new(py, tz_info)?.into_py(py)),
+                None => Err(ValError::new(ErrorTypeDefaults::TimezoneParsing, input)),
+            }
+        } else {
+            Err(ValError::new(ErrorTypeDefaults::TimezoneType, input))
+        }
+    }
+
+    fn json_schema<'py>(&self, state: &mut JsonSchemaState<'py>) -> PyResult<Bound<'py, PyDict>> {
+        state.type_schema("string")
+    }
+
+    fn get_name(&self) -> &str {
+        Self::EXPECTED_TYPE
+    }
+}
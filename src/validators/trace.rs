@@ -0,0 +1,127 @@
+use pyo3::intern;
+use pyo3::prelude::*;
+use pyo3::types::{PyDict, PyList, PyTuple};
+
+use crate::errors::{LocItem, ValResult};
+use crate::input::Input;
+
+use super::{CombinedValidator, Exactness, ValidationState, Validator};
+
+/// Records a tree of the validators which ran during validation, see `SchemaValidator.explain`.
+///
+/// Nodes are pushed when a validator starts and popped when it finishes, so the last node on the stack is always
+/// the validator currently running.
+#[derive(Debug)]
+pub struct ValidationTracer {
+    stack: Vec<TraceNode>,
+}
+
+#[derive(Debug)]
+struct TraceNode {
+    validator: String,
+    loc: Vec<LocItem>,
+    // location of the next child, set by containers before validating each item
+    child_loc: Option<LocItem>,
+    input: PyObject,
+    valid: bool,
+    exactness: Option<Exactness>,
+    fields_set_count: Option<usize>,
+    notes: Vec<String>,
+    children: Vec<TraceNode>,
+}
+
+impl ValidationTracer {
+    pub fn new(py: Python) -> Self {
+        // a root node collects the top level validator so there's always a parent to push to
+        let root = TraceNode::new(String::new(), Vec::new(), py.None());
+        Self { stack: vec![root] }
+    }
+
+    fn current(&mut self) -> &mut TraceNode {
+        self.stack.last_mut().expect("trace stack is never empty")
+    }
+
+    fn start(&mut self, validator: &str, input: PyObject) {
+        let parent = self.current();
+        let mut loc = parent.loc.clone();
+        loc.extend(parent.child_loc.take());
+        self.stack.push(TraceNode::new(validator.to_string(), loc, input));
+    }
+
+    fn finish(&mut self, valid: bool, exactness: Option<Exactness>, fields_set_count: Option<usize>) {
+        let mut node = self.stack.pop().expect("trace stack is never empty");
+        node.valid = valid;
+        node.exactness = exactness;
+        node.fields_set_count = fields_set_count;
+        self.current().children.push(node);
+    }
+
+    pub fn set_child_loc(&mut self, loc: LocItem) {
+        self.current().child_loc = Some(loc);
+    }
+
+    pub fn add_note(&mut self, note: String) {
+        self.current().notes.push(note);
+    }
+
+    /// The trace of the top level validator as a Python dict
+    pub fn into_py_dict(mut self, py: Python<'_>) -> PyResult<Bound<'_, PyDict>> {
+        let root = self.stack.swap_remove(0);
+        match root.children.into_iter().next() {
+            Some(node) => node.into_py_dict(py),
+            None => Ok(PyDict::new_bound(py)),
+        }
+    }
+}
+
+impl TraceNode {
+    fn new(validator: String, loc: Vec<LocItem>, input: PyObject) -> Self {
+        Self {
+            validator,
+            loc,
+            child_loc: None,
+            input,
+            valid: false,
+            exactness: None,
+            fields_set_count: None,
+            notes: Vec::new(),
+            children: Vec::new(),
+        }
+    }
+
+    fn into_py_dict(self, py: Python<'_>) -> PyResult<Bound<'_, PyDict>> {
+        let dict = PyDict::new_bound(py);
+        dict.set_item(intern!(py, "validator"), self.validator)?;
+        dict.set_item(intern!(py, "loc"), PyTuple::new_bound(py, self.loc))?;
+        dict.set_item(intern!(py, "input"), self.input)?;
+        dict.set_item(intern!(py, "valid"), self.valid)?;
+        dict.set_item(intern!(py, "exactness"), self.exactness.map(Exactness::as_str))?;
+        dict.set_item(intern!(py, "fields_set_count"), self.fields_set_count)?;
+        dict.set_item(intern!(py, "notes"), self.notes)?;
+        let children = self
+            .children
+            .into_iter()
+            .map(|child| child.into_py_dict(py))
+            .collect::<PyResult<Vec<_>>>()?;
+        dict.set_item(intern!(py, "children"), PyList::new_bound(py, children))?;
+        Ok(dict)
+    }
+}
+
+/// Run `validator`, recording it in the trace, used by `CombinedValidator::validate` when tracing is enabled
+pub fn traced_validate<'py>(
+    validator: &CombinedValidator,
+    py: Python<'py>,
+    input: &(impl Input<'py> + ?Sized),
+    state: &mut ValidationState<'_, 'py>,
+) -> ValResult<PyObject> {
+    if let Some(tracer) = state.tracer.as_mut() {
+        tracer.start(validator.get_name(), input.to_object(py));
+    }
+    let result = Validator::validate(validator, py, input, state);
+    let (exactness, fields_set_count) = (state.exactness, state.fields_set_count);
+    if let Some(tracer) = state.tracer.as_mut() {
+        tracer.finish(result.is_ok(), exactness, fields_set_count);
+    }
+    result
+}
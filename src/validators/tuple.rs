@@ -3,12 +3,13 @@ use pyo3::prelude::*;
 use pyo3::types::{PyDict, PyList, PyTuple};
 use std::collections::VecDeque;
 
-use crate::build_tools::is_strict;
+use crate::build_tools::{is_strict, schema_or_config_same};
 use crate::errors::{py_err_string, ErrorType, ErrorTypeDefaults, ValError, ValLineError, ValResult};
 use crate::input::ConsumeIterator;
 use crate::input::{BorrowInput, Input, ValidatedTuple};
 use crate::tools::SchemaDict;
 
+use super::json_schema::{set_constraint, unsupported, JsonSchemaState};
 use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
 #[derive(Debug)]
@@ -51,7 +52,7 @@ impl BuildValidator for TupleValidator {
             min_length: schema.get_as(intern!(py, "min_length"))?,
             max_length: schema.get_as(intern!(py, "max_length"))?,
             name,
-            fail_fast: schema.get_as(intern!(py, "fail_fast"))?.unwrap_or(false),
+            fail_fast: schema_or_config_same(schema, config, intern!(py, "fail_fast"))?.unwrap_or(false),
         }
         .into())
     }
@@ -76,14 +77,18 @@ impl TupleValidator {
         // Validate the head:
         for validator in item_validators {
             match collection_iter.next() {
-                Some((index, input_item)) => match validator.validate(py, input_item.borrow_input(), state) {
-                    Ok(item) => self.push_output_item(input, output, item, actual_length)?,
-                    Err(ValError::LineErrors(line_errors)) => {
-                        errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index)));
+                Some((index, input_item)) => {
+                    state.count_items(1, input)?;
+                    state.trace_loc(|| index);
+                    match validator.validate(py, input_item.borrow_input(), state) {
+                        Ok(item) => self.push_output_item(input, output, item, actual_length)?,
+                        Err(ValError::LineErrors(line_errors)) => {
+                            errors.extend(line_errors.into_iter().map(|err| err.with_outer_location(index)));
+                        }
+                        Err(ValError::Omit) => (),
+                        Err(err) => return Err(err),
                     }
-                    Err(ValError::Omit) => (),
-                    Err(err) => return Err(err),
-                },
+                }
                 None => {
                     let index = collection_iter.next_calls() - 1;
                     if let Some(value) = validator.default_value(py, Some(index), state)? {
@@ -116,6 +121,7 @@ impl TupleValidator {
         } else {
             self.validators.len()
         };
+        let fail_fast = state.fail_fast_or(self.fail_fast);
         let mut output = Vec::with_capacity(expected_length);
         if let Some(variable_validator_index) = self.variadic_item_index {
             let (head_validators, [variable_validator, tail_validators @ ..]) =
@@ -134,16 +140,18 @@ impl TupleValidator {
                 head_validators,
                 collection_iter,
                 actual_length,
-                self.fail_fast,
+                fail_fast,
             )?;
 
-            if self.fail_fast && !errors.is_empty() {
+            if fail_fast && !errors.is_empty() {
                 return Ok(output);
             }
 
             let n_tail_validators = tail_validators.len();
             if n_tail_validators == 0 {
                 for (index, input_item) in collection_iter {
+                    state.count_items(1, input)?;
+                    state.trace_loc(|| index);
                     match variable_validator.validate(py, input_item.borrow_input(), state) {
                         Ok(item) => self.push_output_item(input, &mut output, item, actual_length)?,
                         Err(ValError::LineErrors(line_errors)) => {
@@ -153,7 +161,7 @@ impl TupleValidator {
                         Err(err) => return Err(err),
                     }
 
-                    if self.fail_fast && !errors.is_empty() {
+                    if fail_fast && !errors.is_empty() {
                         return Ok(output);
                     }
                 }
@@ -175,6 +183,8 @@ impl TupleValidator {
                     let buffered_item = tail_buffer.pop_front().unwrap();
                     tail_buffer.push_back(input_item);
 
+                    state.count_items(1, input)?;
+                    state.trace_loc(|| buffer_item_index);
                     match variable_validator.validate(py, buffered_item.borrow_input(), state) {
                         Ok(item) => self.push_output_item(input, &mut output, item, actual_length)?,
                         Err(ValError::LineErrors(line_errors)) => {
@@ -188,7 +198,7 @@ impl TupleValidator {
                         Err(err) => return Err(err),
                     }
 
-                    if self.fail_fast && !errors.is_empty() {
+                    if fail_fast && !errors.is_empty() {
                         return Ok(output);
                     }
                 }
@@ -203,7 +213,7 @@ impl TupleValidator {
                     tail_validators,
                     &mut NextCountingIterator::new(tail_buffer.into_iter(), index),
                     actual_length,
-                    self.fail_fast,
+                    fail_fast,
                 )?;
             }
         } else {
@@ -217,10 +227,10 @@ impl TupleValidator {
                 &self.validators,
                 collection_iter,
                 actual_length,
-                self.fail_fast,
+                fail_fast,
             )?;
 
-            if self.fail_fast && !errors.is_empty() {
+            if fail_fast && !errors.is_empty() {
                 return Ok(output);
             }
 
@@ -308,6 +318,39 @@ impl Validator for TupleValidator {
         }
     }
 
+    fn json_schema<'py>(&self, state: &mut JsonSchemaState<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let (prefix, variadic) = match self.variadic_item_index {
+            None => (self.validators.as_slice(), None),
+            Some(index) if index + 1 == self.validators.len() => {
+                (&self.validators[..index], Some(&self.validators[index]))
+            }
+            // items after the variadic item can't be described in JSON Schema
+            Some(_) => return unsupported(&self.name),
+        };
+        let schema = state.type_schema("array")?;
+        if !prefix.is_empty() {
+            let prefix_items = prefix
+                .iter()
+                .map(|validator| validator.json_schema(state))
+                .collect::<PyResult<Vec<_>>>()?;
+            schema.set_item(intern!(state.py(), "prefixItems"), prefix_items)?;
+        }
+        if let Some(validator) = variadic {
+            schema.set_item(intern!(state.py(), "items"), validator.json_schema(state)?)?;
+        }
+        let min_length = self.min_length.unwrap_or(0).max(prefix.len());
+        set_constraint(&schema, "minItems", (min_length > 0).then_some(min_length))?;
+        let max_length = match variadic {
+            Some(_) => self.max_length,
+            None => Some(
+                self.max_length
+                    .map_or(prefix.len(), |max_length| max_length.min(prefix.len())),
+            ),
+        };
+        set_constraint(&schema, "maxItems", max_length)?;
+        Ok(schema)
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
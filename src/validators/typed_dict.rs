@@ -15,6 +15,7 @@ use crate::input::{Input, ValidatedDict};
 use crate::lookup_key::LookupKey;
 use crate::tools::SchemaDict;
 
+use super::json_schema::JsonSchemaState;
 use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 
 #[derive(Debug)]
@@ -35,6 +36,7 @@ pub struct TypedDictValidator {
     extras_validator: Option<Box<CombinedValidator>>,
     strict: bool,
     loc_by_alias: bool,
+    fail_fast: bool,
 }
 
 impl BuildValidator for TypedDictValidator {
@@ -131,6 +133,7 @@ impl BuildValidator for TypedDictValidator {
             extras_validator,
             strict,
             loc_by_alias: config.get_as(intern!(py, "loc_by_alias"))?.unwrap_or(true),
+            fail_fast: config.get_as(intern!(py, "fail_fast"))?.unwrap_or(false),
         }
         .into())
     }
@@ -150,6 +153,7 @@ impl Validator for TypedDictValidator {
     ) -> ValResult<PyObject> {
         let strict = state.strict_or(self.strict);
         let dict = input.validate_dict(strict)?;
+        let fail_fast = state.fail_fast_or(self.fail_fast);
 
         let output_dict = PyDict::new_bound(py);
         let mut errors: Vec<ValLineError> = Vec::with_capacity(self.fields.len());
@@ -165,9 +169,23 @@ impl Validator for TypedDictValidator {
 
         {
             let state = &mut state.rebind_extra(|extra| extra.data = Some(output_dict.clone()));
+            let allow_partial = state.allow_partial;
+            let partial_tail = state.partial_tail;
+            // if partial input ended inside this dict, the value of its last key may be incomplete, and since keys
+            // can come in any order, any field which isn't present may just not have been reached yet
+            let truncated = state.is_truncated();
+            let last_key: Option<LocItem> = if truncated {
+                dict.last_key().map(Into::into)
+            } else {
+                None
+            };
+            state.partial_tail = partial_tail.last_item();
             let mut fields_set_count: usize = 0;
 
             for field in &self.fields {
+                if fail_fast && !errors.is_empty() {
+                    break;
+                }
                 let op_key_value = match dict.get_item(&field.lookup_key) {
                     Ok(v) => v,
                     Err(ValError::LineErrors(line_errors)) => {
@@ -184,12 +202,20 @@ impl Validator for TypedDictValidator {
                         // extra logic either way
                         used_keys.insert(lookup_path.first_key());
                     }
-                    match field.validator.validate(py, value.borrow_input(), state) {
+                    let is_last_partial = matches!(&last_key, Some(LocItem::S(key)) if key == lookup_path.first_key());
+                    state.allow_partial = is_last_partial;
+                    state.trace_loc(|| &field.name);
+                    let result = field.validator.validate(py, value.borrow_input(), state);
+                    let incomplete_tail =
+                        is_last_partial && result.is_err() && state.is_incomplete(value.borrow_input());
+                    match result {
                         Ok(value) => {
                             output_dict.set_item(&field.name_py, value)?;
                             fields_set_count += 1;
                         }
                         Err(ValError::Omit) => continue,
+                        // an incomplete value at the tail of partial input is treated as if the field were missing
+                        Err(ValError::LineErrors(_)) if incomplete_tail => (),
                         Err(ValError::LineErrors(line_errors)) => {
                             for err in line_errors {
                                 errors.push(lookup_path.apply_error_loc(err, self.loc_by_alias, &field.name));
@@ -197,7 +223,9 @@ impl Validator for TypedDictValidator {
                         }
                         Err(err) => return Err(err),
                     }
-                    continue;
+                    if !incomplete_tail {
+                        continue;
+                    }
                 }
 
                 match field.validator.default_value(py, Some(field.name.as_str()), state) {
@@ -207,7 +235,7 @@ impl Validator for TypedDictValidator {
                     }
                     Ok(None) => {
                         // This means there was no default value
-                        if field.required {
+                        if field.required && !truncated {
                             errors.push(field.lookup_key.error(
                                 ErrorTypeDefaults::Missing,
                                 input,
@@ -229,10 +257,16 @@ impl Validator for TypedDictValidator {
                     Err(err) => return Err(err),
                 }
             }
+            state.allow_partial = allow_partial;
+            state.partial_tail = partial_tail;
 
             state.add_fields_set(fields_set_count);
         }
 
+        if fail_fast && !errors.is_empty() {
+            return Err(ValError::LineErrors(errors));
+        }
+
         if let Some(used_keys) = used_keys {
             struct ValidateExtras<'a, 's, 'py> {
                 py: Python<'py>,
@@ -242,6 +276,7 @@ impl Validator for TypedDictValidator {
                 output_dict: &'a Bound<'py, PyDict>,
                 state: &'a mut ValidationState<'s, 'py>,
                 extra_behavior: ExtraBehavior,
+                fail_fast: bool,
             }
 
             impl<'py, Key, Value> ConsumeIterator<ValResult<(Key, Value)>> for ValidateExtras<'_, '_, 'py>
@@ -252,6 +287,9 @@ impl Validator for TypedDictValidator {
                 type Output = ValResult<()>;
                 fn consume_iterator(self, iterator: impl Iterator<Item = ValResult<(Key, Value)>>) -> ValResult<()> {
                     for item_result in iterator {
+                        if self.fail_fast && !self.errors.is_empty() {
+                            break;
+                        }
                         let (raw_key, value) = item_result?;
                         let either_str = match raw_key
                             .borrow_input()
@@ -289,6 +327,7 @@ impl Validator for TypedDictValidator {
                             ExtraBehavior::Allow => {
                                 let py_key = either_str.as_py_string(self.py, self.state.cache_str());
                                 if let Some(validator) = self.extras_validator {
+                                    self.state.trace_loc(|| raw_key.clone());
                                     match validator.validate(self.py, value, self.state) {
                                         Ok(value) => {
                                             self.output_dict.set_item(py_key, value)?;
@@ -319,6 +358,7 @@ impl Validator for TypedDictValidator {
                 output_dict: &output_dict,
                 state,
                 extra_behavior: self.extra_behavior,
+                fail_fast,
             })??;
         }
 
@@ -329,6 +369,14 @@ impl Validator for TypedDictValidator {
         }
     }
 
+    fn json_schema<'py>(&self, state: &mut JsonSchemaState<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let fields = self
+            .fields
+            .iter()
+            .map(|field| (field.lookup_key.first_key(), &field.validator, field.required));
+        state.object_schema(fields, self.extra_behavior, self.extras_validator.as_deref())
+    }
+
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
@@ -12,9 +12,11 @@ use crate::build_tools::{is_strict, schema_or_config};
 use crate::common::union::{Discriminator, SMALL_UNION_THRESHOLD};
 use crate::errors::{ErrorType, ToErrorValue, ValError, ValLineError, ValResult};
 use crate::input::{BorrowInput, Input, ValidatedDict};
+use crate::lookup_key::LookupKey;
 use crate::tools::SchemaDict;
 
 use super::custom_error::CustomError;
+use super::json_schema::JsonSchemaState;
 use super::literal::LiteralLookup;
 use super::{
     build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, ValidationState, Validator,
@@ -109,13 +111,18 @@ impl UnionValidator {
     ) -> ValResult<PyObject> {
         let old_exactness = state.exactness;
         let old_fields_set_count = state.fields_set_count;
+        // each choice counts towards the resource limits as if it was the only one, only the selected choice's
+        // usage is kept
+        let old_resource_usage = state.resource_usage;
+        let mut best_resource_usage = old_resource_usage;
 
         let strict = state.strict_or(self.strict);
         let mut errors = MaybeErrors::new(self.custom_error.as_ref());
 
-        let mut best_match: Option<(Py<PyAny>, Exactness, Option<usize>)> = None;
+        let mut best_match: Option<(Py<PyAny>, Exactness, Option<usize>, &str)> = None;
 
         for (choice, label) in &self.choices {
+            let choice_name = label.as_deref().unwrap_or(choice.get_name());
             let state = &mut state.rebind_extra(|extra| {
                 if strict {
                     extra.strict = Some(strict);
@@ -123,6 +130,7 @@ impl UnionValidator {
             });
             state.exactness = Some(Exactness::Exact);
             state.fields_set_count = None;
+            state.resource_usage = old_resource_usage;
             let result = choice.validate(py, input, state);
             match result {
                 Ok(new_success) => match (state.exactness, state.fields_set_count) {
@@ -132,6 +140,7 @@ impl UnionValidator {
                             // exact match, return, restore any previous exactness
                             state.exactness = old_exactness;
                             state.fields_set_count = old_fields_set_count;
+                            state.trace_note(|| format!("selected `{choice_name}`, an exact match"));
                             Ok(new_success)
                         };
                     }
@@ -151,7 +160,7 @@ impl UnionValidator {
                         let new_success_is_best_match: bool =
                             best_match
                                 .as_ref()
-                                .map_or(true, |(_, cur_exactness, cur_fields_set_count)| {
+                                .map_or(true, |(_, cur_exactness, cur_fields_set_count, _)| {
                                     match (*cur_fields_set_count, new_fields_set_count) {
                                         (Some(cur), Some(new)) if cur != new => cur < new,
                                         _ => *cur_exactness < new_exactness,
@@ -159,7 +168,8 @@ impl UnionValidator {
                                 });
 
                         if new_success_is_best_match {
-                            best_match = Some((new_success, new_exactness, new_fields_set_count));
+                            best_match = Some((new_success, new_exactness, new_fields_set_count, choice_name));
+                            best_resource_usage = state.resource_usage;
                         }
                     }
                 },
@@ -176,8 +186,16 @@ impl UnionValidator {
         // restore previous validation state to prepare for any future validations
         state.exactness = old_exactness;
         state.fields_set_count = old_fields_set_count;
-
-        if let Some((best_match, exactness, fields_set_count)) = best_match {
+        state.resource_usage = best_resource_usage;
+
+        if let Some((best_match, exactness, fields_set_count, choice_name)) = best_match {
+            state.trace_note(|| match fields_set_count {
+                Some(count) => format!(
+                    "selected `{choice_name}` with {} exactness and {count} fields set",
+                    exactness.as_str()
+                ),
+                None => format!("selected `{choice_name}` with {} exactness", exactness.as_str()),
+            });
             state.floor_exactness(exactness);
             if let Some(count) = fields_set_count {
                 state.add_fields_set(count);
@@ -205,10 +223,22 @@ impl UnionValidator {
             state
         };
 
+        let old_resource_usage = state.resource_usage;
+
         for (validator, label) in &self.choices {
             match validator.validate(py, input, state) {
-                Err(ValError::LineErrors(lines)) => errors.push(validator, label.as_deref(), lines),
-                otherwise => return otherwise,
+                Err(ValError::LineErrors(lines)) => {
+                    // a failed choice doesn't count towards the resource limits
+                    state.resource_usage = old_resource_usage;
+                    errors.push(validator, label.as_deref(), lines);
+                }
+                otherwise => {
+                    if otherwise.is_ok() {
+                        let choice_name = label.as_deref().unwrap_or(validator.get_name());
+                        state.trace_note(|| format!("selected `{choice_name}`, the first valid choice"));
+                    }
+                    return otherwise;
+                }
             };
         }
 
@@ -236,6 +266,15 @@ impl Validator for UnionValidator {
         }
     }
 
+    fn json_schema<'py>(&self, state: &mut JsonSchemaState<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let choices = self
+            .choices
+            .iter()
+            .map(|(validator, _)| validator.json_schema(state))
+            .collect::<PyResult<Vec<_>>>()?;
+        state.choices_schema("anyOf", choices)
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
@@ -391,6 +430,41 @@ impl Validator for TaggedUnionValidator {
         }
     }
 
+    fn json_schema<'py>(&self, state: &mut JsonSchemaState<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let py = state.py();
+        let tag_schemas = self
+            .lookup
+            .values
+            .iter()
+            .map(|validator| validator.json_schema(state))
+            .collect::<PyResult<Vec<_>>>()?;
+        // several tags often map to the same choice, e.g. for `Literal['a', 'b']`
+        let mut choices: Vec<Bound<'py, PyDict>> = Vec::with_capacity(tag_schemas.len());
+        for tag_schema in &tag_schemas {
+            if !choices.iter().any(|choice| choice.eq(tag_schema).unwrap_or(false)) {
+                choices.push(tag_schema.clone());
+            }
+        }
+        let schema = state.choices_schema("oneOf", choices)?;
+
+        // the discriminator keyword can only describe a simple property, not a path or function
+        if let Discriminator::LookupKey(LookupKey::Simple { key, .. }) = &self.discriminator {
+            let discriminator = PyDict::new_bound(py);
+            discriminator.set_item(intern!(py, "propertyName"), key)?;
+            let mapping = PyDict::new_bound(py);
+            for (tag, id) in self.lookup.str_keys() {
+                if let Some(reference) = tag_schemas[id].get_item(intern!(py, "$ref"))? {
+                    mapping.set_item(tag, reference)?;
+                }
+            }
+            if !mapping.is_empty() {
+                discriminator.set_item(intern!(py, "mapping"), mapping)?;
+            }
+            schema.set_item(intern!(py, "discriminator"), discriminator)?;
+        }
+        Ok(schema)
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
@@ -405,6 +479,7 @@ impl TaggedUnionValidator {
         state: &mut ValidationState<'_, 'py>,
     ) -> ValResult<PyObject> {
         if let Ok(Some((tag, validator))) = self.lookup.validate(py, tag) {
+            state.trace_note(|| format!("selected tag `{tag}`"));
             return match validator.validate(py, input, state) {
                 Ok(res) => Ok(res),
                 Err(err) => Err(err.with_outer_location(tag)),
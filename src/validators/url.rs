@@ -17,6 +17,7 @@ use crate::input::Input;
 use crate::tools::SchemaDict;
 use crate::url::{schema_is_special, PyMultiHostUrl, PyUrl};
 
+use super::json_schema::{set_constraint, JsonSchemaState};
 use super::literal::expected_repr_name;
 use super::Exactness;
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
@@ -99,6 +100,13 @@ impl Validator for UrlValidator {
         }
     }
 
+    fn json_schema<'py>(&self, state: &mut JsonSchemaState<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let schema = state.format_schema("uri")?;
+        schema.set_item(intern!(state.py(), "minLength"), 1)?;
+        set_constraint(&schema, "maxLength", self.max_length)?;
+        Ok(schema)
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
@@ -264,6 +272,13 @@ impl Validator for MultiHostUrlValidator {
         }
     }
 
+    fn json_schema<'py>(&self, state: &mut JsonSchemaState<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let schema = state.format_schema("multi-host-uri")?;
+        schema.set_item(intern!(state.py(), "minLength"), 1)?;
+        set_constraint(&schema, "maxLength", self.max_length)?;
+        Ok(schema)
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
@@ -17,6 +17,7 @@ use crate::serializers::BytesMode;
 use crate::tools::SchemaDict;
 
 use super::config::ValBytesMode;
+use super::json_schema::JsonSchemaState;
 use super::model::create_class;
 use super::model::force_setattr;
 use super::{BuildValidator, CombinedValidator, DefinitionsBuilder, Exactness, ValidationState, Validator};
@@ -151,6 +152,10 @@ impl Validator for UuidValidator {
         }
     }
 
+    fn json_schema<'py>(&self, state: &mut JsonSchemaState<'py>) -> PyResult<Bound<'py, PyDict>> {
+        state.format_schema("uuid")
+    }
+
     fn get_name(&self) -> &str {
         Self::EXPECTED_TYPE
     }
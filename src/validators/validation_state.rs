@@ -1,11 +1,17 @@
+use ahash::AHashSet;
+use pyo3::intern;
 use pyo3::prelude::*;
-use pyo3::types::PyString;
+use pyo3::types::{PyDict, PyFrozenSet, PyList, PySet, PyString, PyTuple};
 
 use jiter::StringCacheMode;
 
+use crate::build_tools::py_schema_err;
+use crate::errors::{ErrorType, LocItem, ToErrorValue, ValError, ValLineError, ValResult};
+use crate::input::{EitherString, Input, InputType};
 use crate::recursion_guard::{ContainsRecursionState, RecursionState};
-use crate::tools::new_py_string;
+use crate::tools::{new_py_string, SchemaDict};
 
+use super::trace::ValidationTracer;
 use super::Extra;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Ord, PartialOrd, Hash)]
@@ -15,6 +21,208 @@ pub enum Exactness {
     Exact,
 }
 
+impl Exactness {
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Self::Lax => "lax",
+            Self::Strict => "strict",
+            Self::Exact => "exact",
+        }
+    }
+}
+
+/// Limits on the size of a single input, set in config, so untrusted input can't use unbounded memory or time
+/// before field constraints are checked.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ResourceLimits {
+    // maximum total number of items in all lists, tuples, sets, frozensets and dicts
+    total_items: Option<usize>,
+    // maximum total length in bytes of all strings, after encoding as UTF-8
+    total_string_bytes: Option<usize>,
+    // maximum nesting depth of arrays and objects, checked before JSON is parsed
+    json_depth: Option<usize>,
+}
+
+impl ResourceLimits {
+    pub fn from_config(config: Option<&Bound<'_, PyDict>>) -> PyResult<Self> {
+        let Some(config) = config else {
+            return Ok(Self::default());
+        };
+        let py = config.py();
+        let get_limit = |key: &Bound<'_, PyString>| match config.get_as::<usize>(key)? {
+            Some(0) => py_schema_err!("`{}` must be greater than 0", key),
+            limit => Ok(limit),
+        };
+        Ok(Self {
+            total_items: get_limit(intern!(py, "max_total_items"))?,
+            total_string_bytes: get_limit(intern!(py, "max_total_string_bytes"))?,
+            json_depth: get_limit(intern!(py, "max_json_depth"))?,
+        })
+    }
+
+    /// Check the nesting depth of `json_data`, and count its items and string bytes towards `usage`, without
+    /// parsing it, invalid JSON is left for the parser to report.
+    ///
+    /// jiter's `JsonValue::parse` always uses its own fixed recursion limit, so a lower limit can't be passed to
+    /// the parser and is checked with this scan first instead. Items and strings are counted here too, so every
+    /// value is counted whatever validates it, and a payload over the limits fails before it's parsed; values
+    /// parsed from JSON aren't counted again as they're validated. Object keys count as strings, and escapes
+    /// count as the bytes they decode to.
+    pub fn check_json(&self, json_data: &[u8], usage: &mut ResourceUsage, input: impl ToErrorValue) -> ValResult<()> {
+        self.check_nested_json(json_data, 0, usage, input)
+    }
+
+    /// As `check_json`, for a value nested `outer_depth` levels deep in a larger document, which also counts as
+    /// an item of its container
+    pub fn check_nested_json(
+        &self,
+        json_data: &[u8],
+        outer_depth: usize,
+        usage: &mut ResourceUsage,
+        input: impl ToErrorValue,
+    ) -> ValResult<()> {
+        if self.json_depth.is_none() && self.total_items.is_none() && self.total_string_bytes.is_none() {
+            return Ok(());
+        }
+        let mut depth = outer_depth;
+        let mut items = usage.items + usize::from(outer_depth > 0);
+        let mut string_bytes = usage.string_bytes;
+        let mut in_string = false;
+        // whether the next value is an item of an array or object, i.e. it follows `[`, `{` or `,`
+        let mut item_next = false;
+        // the scan stops as soon as the items or string bytes go over their limit, which is reported below
+        let mut index = 0;
+        while let Some(&byte) = json_data.get(index) {
+            index += 1;
+            if in_string {
+                match byte {
+                    b'"' => {
+                        in_string = false;
+                        if self.total_string_bytes.is_some_and(|max| string_bytes > max) {
+                            break;
+                        }
+                    }
+                    b'\\' if json_data.get(index) == Some(&b'u') => {
+                        string_bytes += unicode_escape_len(json_data.get(index + 1..index + 5));
+                        index += 5;
+                    }
+                    b'\\' => {
+                        string_bytes += 1;
+                        index += 1;
+                    }
+                    _ => string_bytes += 1,
+                }
+                continue;
+            }
+            if matches!(byte, b' ' | b'\t' | b'\n' | b'\r') {
+                continue;
+            }
+            if item_next && !matches!(byte, b']' | b'}') {
+                items += 1;
+                if self.total_items.is_some_and(|max| items > max) {
+                    break;
+                }
+            }
+            item_next = false;
+            match byte {
+                b'"' => in_string = true,
+                b'[' | b'{' => {
+                    depth += 1;
+                    if let Some(max_json_depth) = self.json_depth.filter(|max| depth > *max) {
+                        return Err(limit_exceeded("max_json_depth", max_json_depth, input));
+                    }
+                    item_next = true;
+                }
+                b',' => item_next = true,
+                b']' | b'}' => depth = depth.saturating_sub(1),
+                _ => (),
+            }
+        }
+        if let Some(max_total_items) = self.total_items.filter(|max| items > *max) {
+            return Err(limit_exceeded("max_total_items", max_total_items, input));
+        }
+        if let Some(max_total_string_bytes) = self.total_string_bytes.filter(|max| string_bytes > *max) {
+            return Err(limit_exceeded("max_total_string_bytes", max_total_string_bytes, input));
+        }
+        usage.items = items;
+        usage.string_bytes = string_bytes;
+        Ok(())
+    }
+}
+
+/// The length in UTF-8 of the character a `\u` escape with `hex` digits decodes to; the pair of escapes for a
+/// character outside the BMP is counted against the first
+fn unicode_escape_len(hex: Option<&[u8]>) -> usize {
+    let code = hex
+        .and_then(|hex| std::str::from_utf8(hex).ok())
+        .and_then(|hex| u32::from_str_radix(hex, 16).ok())
+        .unwrap_or_default();
+    match code {
+        0..=0x7F => 1,
+        0x80..=0x7FF => 2,
+        0xD800..=0xDBFF => 4,
+        0xDC00..=0xDFFF => 0,
+        _ => 3,
+    }
+}
+
+pub(super) fn limit_exceeded(limit: &str, limit_value: usize, input: impl ToErrorValue) -> ValError {
+    let error_type = ErrorType::ResourceLimitExceeded {
+        limit: limit.to_string(),
+        limit_value,
+        context: None,
+    };
+    ValError::LimitExceeded(Box::new(ValLineError::new(error_type, input)))
+}
+
+/// Where partial input was cut off, so only the values it ended part way through are treated as incomplete,
+/// see `ValidationState::is_truncated` and `ValidationState::is_incomplete`.
+#[derive(Debug, Clone, Copy, Default)]
+pub enum PartialTail {
+    /// Input from `validate_python(..., allow_partial=True)`, where any value in the tail may be incomplete
+    #[default]
+    Unknown,
+    /// Truncated JSON, as reported by the partial parser
+    Json {
+        // how many of the arrays and objects along the tail, starting from the current value, the data ended inside
+        open: usize,
+        // whether the data ended part way through a string or number after the last of those
+        incomplete_scalar: bool,
+    },
+}
+
+impl PartialTail {
+    /// Whether the data may have ended inside the current value
+    fn is_open(self) -> bool {
+        match self {
+            Self::Unknown => true,
+            Self::Json { open, .. } => open > 0,
+        }
+    }
+
+    /// The tail starting from the last item of the current value
+    #[must_use]
+    pub fn last_item(self) -> Self {
+        match self {
+            Self::Unknown => Self::Unknown,
+            Self::Json {
+                open,
+                incomplete_scalar,
+            } => Self::Json {
+                open: open.saturating_sub(1),
+                incomplete_scalar,
+            },
+        }
+    }
+}
+
+/// Totals counted towards `ResourceLimits` during a single validation
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ResourceUsage {
+    items: usize,
+    string_bytes: usize,
+}
+
 pub struct ValidationState<'a, 'py> {
     pub recursion_guard: &'a mut RecursionState,
     pub exactness: Option<Exactness>,
@@ -23,6 +231,18 @@ pub struct ValidationState<'a, 'py> {
     // `model_fields_set` attached to a model. `model_fields_set` includes extra fields
     // when extra='allow', whereas this tally does not.
     pub fields_set_count: Option<usize>,
+    // Whether the input may be incomplete, e.g. truncated JSON, in which case the last item of lists and dicts
+    // (and the value of the last key of typed dicts and models) is dropped if it's incomplete and invalid, and
+    // missing fields of typed dicts and models the data ended inside aren't reported.
+    // This is set for the last item of a container and cleared for all others, so it only ever applies to
+    // the tail of the input.
+    pub allow_partial: bool,
+    // Where in the tail the partial input ended, stepped along with `allow_partial`, see `is_truncated`.
+    pub partial_tail: PartialTail,
+    // Set by `SchemaValidator.explain` to record which validators ran, see `CombinedValidator::validate`.
+    pub tracer: Option<ValidationTracer>,
+    // Counted against `extra.resource_limits`, see `count_items` and `count_string_bytes`.
+    pub resource_usage: ResourceUsage,
     // deliberately make Extra readonly
     extra: Extra<'a, 'py>,
 }
@@ -33,6 +253,10 @@ impl<'a, 'py> ValidationState<'a, 'py> {
             recursion_guard, // Don't care about exactness unless doing union validation
             exactness: None,
             fields_set_count: None,
+            allow_partial: false,
+            partial_tail: PartialTail::default(),
+            tracer: None,
+            resource_usage: ResourceUsage::default(),
             extra,
         }
     }
@@ -57,6 +281,11 @@ impl<'a, 'py> ValidationState<'a, 'py> {
         self.extra.strict.unwrap_or(default)
     }
 
+    /// Whether to stop at the first error, `default` is the validator's `fail_fast` from its schema or config
+    pub fn fail_fast_or(&self, default: bool) -> bool {
+        self.extra.fail_fast.unwrap_or(default)
+    }
+
     /// Sets the exactness to the lower of the current exactness
     /// and the given exactness.
     ///
@@ -78,6 +307,148 @@ impl<'a, 'py> ValidationState<'a, 'py> {
         *self.fields_set_count.get_or_insert(0) += fields_set_count;
     }
 
+    /// Whether the current value is at the tail of partial input and the data may have ended inside it, in which
+    /// case its last item may be incomplete and fields may be missing because they hadn't been reached yet.
+    pub fn is_truncated(&self) -> bool {
+        self.allow_partial && self.partial_tail.is_open()
+    }
+
+    /// Enumerate `iter`, also yielding whether each item is the last one and the current value `is_truncated`,
+    /// i.e. whether that item is at the tail of partial input, see `is_incomplete`.
+    ///
+    /// While validating the items, `partial_tail` must be set to `PartialTail::last_item`.
+    pub fn enumerate_last_partial<I>(&self, iter: impl Iterator<Item = I>) -> impl Iterator<Item = (usize, bool, I)> {
+        let truncated = self.is_truncated();
+        let mut iter = iter.enumerate().peekable();
+        std::iter::from_fn(move || {
+            let (index, item) = iter.next()?;
+            let is_last_partial = truncated && iter.peek().is_none();
+            Some((index, is_last_partial, item))
+        })
+    }
+
+    /// Whether `item`, the last item of a container at the tail of partial input, may itself be incomplete, in
+    /// which case it's dropped if it's invalid.
+    ///
+    /// Lists, dicts and other containers are never incomplete here, their validators make the same allowance
+    /// for their own last item instead, so errors elsewhere in them are still reported.
+    pub fn is_incomplete(&self, item: &(impl Input<'py> + ?Sized)) -> bool {
+        match self.partial_tail {
+            PartialTail::Unknown => item.as_python().is_some_and(|item| {
+                !(item.is_instance_of::<PyDict>()
+                    || item.is_instance_of::<PyList>()
+                    || item.is_instance_of::<PyTuple>()
+                    || item.is_instance_of::<PySet>()
+                    || item.is_instance_of::<PyFrozenSet>())
+            }),
+            // the item is the last one in the innermost array or object the data ended inside, so if the data
+            // ended in a scalar, this is it
+            PartialTail::Json {
+                open,
+                incomplete_scalar,
+            } => open == 0 && incomplete_scalar,
+        }
+    }
+
+    /// Record the location of the next item a container validates, if tracing is enabled
+    pub fn trace_loc<L: Into<LocItem>>(&mut self, loc: impl FnOnce() -> L) {
+        if let Some(tracer) = self.tracer.as_mut() {
+            tracer.set_child_loc(loc().into());
+        }
+    }
+
+    /// Add a note to the validator currently running, if tracing is enabled
+    pub fn trace_note(&mut self, note: impl FnOnce() -> String) {
+        if let Some(tracer) = self.tracer.as_mut() {
+            tracer.add_note(note());
+        }
+    }
+
+    /// Count items of a container towards the `max_total_items` limit
+    pub fn count_items(&mut self, count: usize, input: impl ToErrorValue) -> ValResult<()> {
+        if let Some(max_total_items) = self.counted_limits().total_items {
+            self.resource_usage.items += count;
+            if self.resource_usage.items > max_total_items {
+                return Err(limit_exceeded("max_total_items", max_total_items, input));
+            }
+        }
+        Ok(())
+    }
+
+    /// Count a string towards the `max_total_string_bytes` limit
+    pub fn count_string_bytes(&mut self, either_str: &EitherString<'_>, input: impl ToErrorValue) -> ValResult<()> {
+        if let Some(max_total_string_bytes) = self.counted_limits().total_string_bytes {
+            self.resource_usage.string_bytes += either_str.as_cow()?.len();
+            if self.resource_usage.string_bytes > max_total_string_bytes {
+                return Err(limit_exceeded("max_total_string_bytes", max_total_string_bytes, input));
+            }
+        }
+        Ok(())
+    }
+
+    /// Count a value accepted without validating its contents, e.g. by `any`, towards the limits, including
+    /// everything nested in it
+    pub fn count_value(&mut self, value: &Bound<'py, PyAny>, input: impl ToErrorValue) -> ValResult<()> {
+        let limits = self.counted_limits();
+        if limits.total_items.is_none() && limits.total_string_bytes.is_none() {
+            return Ok(());
+        }
+        let input: &dyn ToErrorValue = &input;
+        let mut seen = AHashSet::new();
+        let mut values = vec![value.clone()];
+        while let Some(value) = values.pop() {
+            if let Ok(py_str) = value.downcast::<PyString>() {
+                self.count_string_bytes(&EitherString::Py(py_str.clone()), input)?;
+                continue;
+            }
+            // other objects are only counted once, so a container which contains itself doesn't loop forever
+            if !seen.insert(value.as_ptr() as usize) {
+                continue;
+            }
+            if let Ok(list) = value.downcast::<PyList>() {
+                self.count_items(list.len(), input)?;
+                values.extend(list.iter());
+            } else if let Ok(tuple) = value.downcast::<PyTuple>() {
+                self.count_items(tuple.len(), input)?;
+                values.extend(tuple.iter());
+            } else if let Ok(dict) = value.downcast::<PyDict>() {
+                self.count_items(dict.len(), input)?;
+                for (k, v) in dict {
+                    values.push(k);
+                    values.push(v);
+                }
+            } else if let Ok(set) = value.downcast::<PySet>() {
+                self.count_items(set.len(), input)?;
+                values.extend(set.iter());
+            } else if let Ok(frozenset) = value.downcast::<PyFrozenSet>() {
+                self.count_items(frozenset.len(), input)?;
+                values.extend(frozenset.iter());
+            }
+        }
+        Ok(())
+    }
+
+    /// Count JSON towards the limits before it's parsed, see `ResourceLimits::check_json`
+    pub fn count_json(&mut self, json_data: &[u8], outer_depth: usize, input: impl ToErrorValue) -> ValResult<()> {
+        self.extra
+            .resource_limits
+            .check_nested_json(json_data, outer_depth, &mut self.resource_usage, input)
+    }
+
+    /// The limits which values are counted towards as they're validated, JSON is counted in full before it's
+    /// parsed instead
+    fn counted_limits(&self) -> ResourceLimits {
+        match self.extra.input_type {
+            InputType::Json => ResourceLimits::default(),
+            _ => self.extra.resource_limits,
+        }
+    }
+
+    /// Whether `count` errors reach the `max_errors` limit, in which case collections stop validating items
+    pub fn errors_capped(&self, count: usize) -> bool {
+        self.extra.max_errors.is_some_and(|max_errors| count >= max_errors)
+    }
+
     pub fn cache_str(&self) -> StringCacheMode {
         self.extra.cache_str
     }
@@ -6,6 +6,7 @@ use pyo3::types::PyString;
 use pyo3::PyTraverseError;
 use pyo3::PyVisit;
 
+use super::json_schema::JsonSchemaState;
 use super::{build_validator, BuildValidator, CombinedValidator, DefinitionsBuilder, ValidationState, Validator};
 use crate::build_tools::py_schema_err;
 use crate::build_tools::schema_or_config_same;
@@ -147,6 +148,8 @@ impl Validator for WithDefaultValidator {
                 Ok(v) => Ok(v),
                 Err(e) => match e {
                     ValError::UseDefault => Ok(self.default_value(py, None::<usize>, state)?.ok_or(e)?),
+                    // resource limits apply to the whole input, so `on_error` doesn't apply to them
+                    e @ ValError::LimitExceeded(_) => Err(e),
                     e => match self.on_error {
                         OnError::Raise => Err(e),
                         OnError::Default => Ok(self.default_value(py, None::<usize>, state)?.ok_or(e)?),
@@ -165,6 +168,11 @@ impl Validator for WithDefaultValidator {
     ) -> ValResult<Option<PyObject>> {
         match self.default.default_value(py)? {
             Some(stored_dft) => {
+                let outer_loc: Option<LocItem> = outer_loc.map(Into::into);
+                state.trace_note(|| match &outer_loc {
+                    Some(loc) => format!("default applied for `{loc}`"),
+                    None => "default applied".to_string(),
+                });
                 let dft: Py<PyAny> = if self.copy_default {
                     let deepcopy_func = COPY_DEEPCOPY.get_or_init(py, || get_deepcopy(py).unwrap());
                     deepcopy_func.call1(py, (&stored_dft,))?.into_py(py)
@@ -190,6 +198,15 @@ impl Validator for WithDefaultValidator {
         }
     }
 
+    fn json_schema<'py>(&self, state: &mut JsonSchemaState<'py>) -> PyResult<Bound<'py, PyDict>> {
+        let schema = self.validator.json_schema(state)?;
+        // default factories are called at validation time, so only static defaults can be included
+        if let DefaultType::Default(default) = &self.default {
+            schema.set_item(intern!(state.py(), "default"), default)?;
+        }
+        Ok(schema)
+    }
+
     fn get_name(&self) -> &str {
         &self.name
     }
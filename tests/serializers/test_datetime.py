@@ -1,8 +1,9 @@
+import json
 from datetime import date, datetime, time, timedelta, timezone
 
 import pytest
 
-from pydantic_core import SchemaSerializer, core_schema
+from pydantic_core import SchemaError, SchemaSerializer, core_schema, to_json, to_jsonable_python
 
 
 def test_datetime():
@@ -113,3 +114,142 @@ def test_date_datetime_union():
     assert v.to_python(datetime(2022, 12, 2, 1)) == datetime(2022, 12, 2, 1)
     assert v.to_python(datetime(2022, 12, 2, 1), mode='json') == '2022-12-02T01:00:00'
     assert v.to_json(datetime(2022, 12, 2, 1)) == b'"2022-12-02T01:00:00"'
+
+
+def test_datetime_ser_format():
+    v = SchemaSerializer(core_schema.datetime_schema(ser_format='%a %d %b %Y %I:%M:%S.%f %p %z'))
+    value = datetime(2022, 6, 8, 13, 4, 5, 6, tzinfo=timezone(timedelta(hours=-5, minutes=-30)))
+    assert v.to_python(value) == value
+    assert v.to_python(value, mode='json') == 'Wed 08 Jun 2022 01:04:05.000006 PM -0530'
+    assert v.to_json(value) == b'"Wed 08 Jun 2022 01:04:05.000006 PM -0530"'
+    assert v.to_python(value, mode='json') == value.strftime('%a %d %b %Y %I:%M:%S.%f %p %z')
+
+    # naive datetimes have an empty offset
+    assert v.to_json(datetime(2000, 1, 1)) == b'"Sat 01 Jan 2000 12:00:00.000000 AM "'
+
+
+def test_date_ser_format():
+    v = SchemaSerializer(core_schema.date_schema(ser_format='%d/%m/%Y %H:%M'))
+    assert v.to_python(date(2022, 6, 8), mode='json') == '08/06/2022 00:00'
+    assert v.to_json(date(2022, 6, 8)) == b'"08/06/2022 00:00"'
+
+
+def test_time_ser_format():
+    v = SchemaSerializer(core_schema.time_schema(ser_format='%A %y %I%p %M'))
+    assert v.to_python(time(0, 13), mode='json') == time(0, 13).strftime('%A %y %I%p %M') == 'Monday 00 12AM 13'
+    assert v.to_json(time(23, 13)) == b'"Monday 00 11PM 13"'
+
+
+def test_ser_format_key():
+    v = SchemaSerializer(
+        core_schema.dict_schema(
+            core_schema.date_schema(ser_format='%B %d'), core_schema.datetime_schema(ser_format='%Y%m%d%H%M')
+        )
+    )
+    assert v.to_json({date(2022, 12, 2): datetime(2022, 12, 2, 12, 13, 14)}) == b'{"December 02":"202212021213"}'
+
+
+def test_ser_format_invalid():
+    with pytest.raises(SchemaError, match="Invalid ser_format \"%Y-%j\", unsupported directive '%j'"):
+        SchemaSerializer(core_schema.datetime_schema(ser_format='%Y-%j'))
+
+
+@pytest.mark.parametrize(
+    'mode,value,expected',
+    [
+        ('iso8601', datetime(2022, 6, 8, 12, 13, 14, 500_000), '2022-06-08T12:13:14.500000'),
+        ('epoch_seconds', datetime(2022, 6, 8, 12, 13, 14, 500_000), 1654690394.5),
+        ('epoch_milliseconds', datetime(2022, 6, 8, 12, 13, 14, 500_000), 1654690394500.0),
+        ('epoch_microseconds', datetime(2022, 6, 8, 12, 13, 14, 500_000), 1654690394500000),
+        ('epoch_seconds', datetime(2022, 6, 8, 14, 13, 14, tzinfo=timezone(timedelta(hours=2))), 1654690394.0),
+        ('epoch_microseconds', datetime(1, 1, 1), -62135596800000000),
+        ('epoch_microseconds', datetime(9999, 12, 31, 23, 59, 59, 999_999), 253402300799999999),
+        ('epoch_seconds', date(2022, 6, 8), 1654646400.0),
+        ('epoch_milliseconds', date(1969, 12, 31), -86400000.0),
+        # sub-second times before 1970 don't carry float error from adding the fractional part
+        ('epoch_seconds', datetime(1969, 12, 31, 23, 59, 59, 999_999, tzinfo=timezone.utc), -0.000001),
+        ('epoch_milliseconds', datetime(1969, 12, 31, 23, 59, 59, 999_999, tzinfo=timezone.utc), -0.001),
+        ('epoch_milliseconds', datetime(1969, 12, 31, 23, 59, 58, 500_250), -1499.75),
+    ],
+)
+def test_ser_json_datetime(mode, value, expected):
+    schema = core_schema.date_schema() if type(value) is date else core_schema.datetime_schema()
+    s = SchemaSerializer(schema, config={'ser_json_datetime': mode})
+    assert s.to_python(value) == value
+    assert s.to_python(value, mode='json') == expected
+    assert json.loads(s.to_json(value)) == expected
+
+    s = SchemaSerializer(core_schema.any_schema(), config={'ser_json_datetime': mode})
+    assert s.to_python(value, mode='json') == expected
+    assert json.loads(s.to_json(value)) == expected
+    assert to_jsonable_python(value, datetime_mode=mode) == expected
+
+
+def test_ser_json_datetime_matches_timestamp():
+    value = datetime(2022, 6, 8, 12, 13, 14, 123_456, tzinfo=timezone(timedelta(hours=-5)))
+    s = SchemaSerializer(core_schema.datetime_schema(), config={'ser_json_datetime': 'epoch_seconds'})
+    assert s.to_python(value, mode='json') == value.timestamp()
+    # naive datetimes are treated as UTC
+    assert s.to_python(value.replace(tzinfo=None), mode='json') == value.replace(tzinfo=timezone.utc).timestamp()
+
+
+def test_ser_json_datetime_key():
+    s = SchemaSerializer(
+        core_schema.dict_schema(core_schema.datetime_schema(), core_schema.date_schema()),
+        config={'ser_json_datetime': 'epoch_microseconds'},
+    )
+    value = {datetime(2022, 6, 8, 12, 13, 14, 5): date(2022, 6, 8)}
+    assert s.to_python(value, mode='json') == {'1654690394000005': 1654646400000000}
+    assert s.to_json(value) == b'{"1654690394000005":1654646400000000}'
+    assert to_json(value, datetime_mode='epoch_seconds') == b'{"1654690394.000005":1654646400.0}'
+
+
+@pytest.mark.parametrize(
+    'mode,key,value',
+    [
+        ('epoch_seconds', '1704067200.0', 1704067200.0),
+        ('epoch_milliseconds', '1704067200000.0', 1704067200000.0),
+        ('epoch_microseconds', '1704067200000000', 1704067200000000),
+    ],
+)
+def test_ser_json_datetime_key_matches_value(mode, key, value):
+    dt = datetime(2024, 1, 1)
+    s = SchemaSerializer(
+        core_schema.dict_schema(core_schema.datetime_schema(), core_schema.datetime_schema()),
+        config={'ser_json_datetime': mode},
+    )
+    assert s.to_python({dt: dt}, mode='json') == {key: value}
+    assert s.to_json({dt: dt}) == f'{{"{key}":{key}}}'.encode()
+    assert to_json({dt: dt}, datetime_mode=mode) == f'{{"{key}":{key}}}'.encode()
+    assert to_jsonable_python({date(2024, 1, 1): 1}, datetime_mode=mode) == {key: 1}
+
+
+def test_ser_json_datetime_time():
+    s = SchemaSerializer(core_schema.time_schema(), config={'ser_json_datetime': 'epoch_seconds'})
+    assert s.to_json(time(12, 13, 14)) == b'"12:13:14"'
+    assert to_json(time(12, 13, 14), datetime_mode='epoch_seconds') == b'"12:13:14"'
+
+
+def test_ser_json_datetime_ser_format():
+    s = SchemaSerializer(core_schema.date_schema(ser_format='%d/%m/%Y'), config={'ser_json_datetime': 'epoch_seconds'})
+    assert s.to_json(date(2022, 6, 8)) == b'"08/06/2022"'
+
+
+def test_datetime_mode_override():
+    s = SchemaSerializer(
+        core_schema.list_schema(core_schema.datetime_schema()), config={'ser_json_datetime': 'epoch_seconds'}
+    )
+    value = [datetime(2022, 6, 8, 12, 13, 14)]
+    assert s.to_json(value) == b'[1654690394.0]'
+    assert s.to_json(value, datetime_mode='iso8601') == b'["2022-06-08T12:13:14"]'
+    assert s.to_python(value, mode='json', datetime_mode='epoch_microseconds') == [1654690394000000]
+    assert s.to_python(value, datetime_mode='epoch_microseconds') == value
+    assert s.to_msgpack(value, datetime_mode='epoch_microseconds') == b'\x91\xcf\x00\x05\xe0\xee\xa1%\xca\x80'
+
+
+def test_datetime_mode_invalid():
+    s = SchemaSerializer(core_schema.datetime_schema())
+    with pytest.raises(SchemaError, match='Invalid DatetimeMode serialization mode: `epoch_days`'):
+        s.to_json(datetime(2022, 6, 8), datetime_mode='epoch_days')
+    with pytest.raises(SchemaError, match='Invalid DatetimeMode serialization mode: `epoch_days`'):
+        SchemaSerializer(core_schema.datetime_schema(), config={'ser_json_datetime': 'epoch_days'})
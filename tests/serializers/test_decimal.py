@@ -59,3 +59,51 @@ def test_any_decimal_key():
 
     assert v.to_python(input_value, mode='json') == {'123.456': 1}
     assert v.to_json(input_value) == b'{"123.456":1}'
+
+
+@pytest.mark.parametrize(
+    'schema_kwargs,config,value,expected_python,expected_json',
+    [
+        ({'float_precision': 2}, {}, Decimal('1.5'), '1.50', '"1.50"'),
+        ({'float_precision': 2}, {}, Decimal('1.005'), '1.00', '"1.00"'),
+        ({'float_precision': 2}, {}, Decimal('1.015'), '1.02', '"1.02"'),
+        ({'float_precision': 0}, {}, Decimal('7'), '7', '"7"'),
+        ({'number_as_string': False}, {}, Decimal('1.10'), 1.1, '1.10'),
+        (
+            {'number_as_string': False},
+            {},
+            Decimal('123456789123456789.123456789123456789'),
+            123456789123456789.123456789123456789,
+            '123456789123456789.123456789123456789',
+        ),
+        ({'float_precision': 3, 'number_as_string': False}, {}, Decimal('2'), 2.0, '2.000'),
+        ({}, {'ser_json_float_precision': 1}, Decimal('2.25'), '2.2', '"2.2"'),
+        ({}, {'ser_json_number_as_string': False}, Decimal('2.25'), 2.25, '2.25'),
+    ],
+)
+def test_decimal_precision_and_number_as_string(schema_kwargs, config, value, expected_python, expected_json):
+    v = SchemaSerializer(core_schema.decimal_schema(**schema_kwargs), config)
+    assert v.to_python(value) == value
+    assert v.to_python(value, mode='json') == expected_python
+    assert v.to_json(value).decode() == expected_json
+
+
+def test_decimal_not_as_string_inf_nan():
+    v = SchemaSerializer(core_schema.decimal_schema(number_as_string=False))
+    # numbers which aren't valid in JSON are still strings
+    assert v.to_python(Decimal('NaN'), mode='json') == 'NaN'
+    assert v.to_json(Decimal('NaN')) == b'"NaN"'
+    assert v.to_json(Decimal('-Infinity')) == b'"-Infinity"'
+
+
+def test_decimal_precision_key():
+    v = SchemaSerializer(
+        core_schema.dict_schema(core_schema.decimal_schema(float_precision=2), core_schema.decimal_schema())
+    )
+    assert v.to_json({Decimal('1.5'): Decimal('1.5')}) == b'{"1.50":"1.5"}'
+
+
+def test_any_decimal_precision():
+    v = SchemaSerializer(core_schema.any_schema(), {'ser_json_float_precision': 2, 'ser_json_number_as_string': False})
+    assert v.to_python([Decimal('1.5')], mode='json') == [1.5]
+    assert v.to_json({Decimal('0.125'): Decimal('1.5')}) == b'{"0.12":1.50}'
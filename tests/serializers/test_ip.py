@@ -0,0 +1,49 @@
+from ipaddress import IPv4Address, IPv4Interface, IPv4Network, IPv6Address, IPv6Interface, IPv6Network
+
+import pytest
+
+from pydantic_core import SchemaSerializer, core_schema
+
+
+@pytest.mark.parametrize(
+    'schema,value,expected',
+    [
+        (core_schema.ip_address_schema(), IPv4Address('192.168.0.1'), '192.168.0.1'),
+        (core_schema.ip_address_schema(), IPv6Address('::1'), '::1'),
+        (core_schema.ip_network_schema(), IPv4Network('192.168.0.0/24'), '192.168.0.0/24'),
+        (core_schema.ip_network_schema(), IPv6Network('2001:db8::/32'), '2001:db8::/32'),
+        (core_schema.ip_interface_schema(), IPv4Interface('192.168.0.1/24'), '192.168.0.1/24'),
+        (core_schema.ip_interface_schema(), IPv6Interface('::1/64'), '::1/64'),
+    ],
+)
+def test_ip(schema, value, expected):
+    s = SchemaSerializer(schema)
+    assert s.to_python(value) is value
+    assert s.to_python(value, mode='json') == expected
+    assert s.to_json(value) == f'"{expected}"'.encode()
+
+    s = SchemaSerializer(core_schema.dict_schema(schema, core_schema.int_schema()))
+    assert s.to_python({value: 1}, mode='json') == {expected: 1}
+    assert s.to_json({value: 1}) == f'{{"{expected}":1}}'.encode()
+
+
+def test_ip_fallback():
+    s = SchemaSerializer(core_schema.ip_network_schema())
+    with pytest.warns(UserWarning, match='Expected `ip-network` but got `int` with value `123`'):
+        assert s.to_json(123) == b'123'
+
+
+@pytest.mark.parametrize(
+    'value,expected',
+    [
+        (IPv4Address('192.168.0.1'), '192.168.0.1'),
+        (IPv6Network('2001:db8::/32'), '2001:db8::/32'),
+        (IPv4Interface('192.168.0.1/24'), '192.168.0.1/24'),
+    ],
+)
+def test_any_ip(value, expected):
+    s = SchemaSerializer(core_schema.any_schema())
+    assert s.to_python(value) is value
+    assert s.to_python(value, mode='json') == expected
+    assert s.to_json(value) == f'"{expected}"'.encode()
+    assert s.to_json({value: [value]}) == f'{{"{expected}":["{expected}"]}}'.encode()
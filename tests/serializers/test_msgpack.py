@@ -0,0 +1,135 @@
+import dataclasses
+from datetime import timedelta
+
+import pytest
+
+from pydantic_core import PydanticSerializationError, SchemaError, SchemaSerializer, core_schema, to_msgpack
+
+
+def test_simple_values():
+    assert to_msgpack(None) == b'\xc0'
+    assert to_msgpack(True) == b'\xc3'
+    assert to_msgpack(1) == b'\x01'
+    assert to_msgpack(-1) == b'\xff'
+    assert to_msgpack(2**64 - 1) == b'\xcf\xff\xff\xff\xff\xff\xff\xff\xff'
+    assert to_msgpack(1.5) == b'\xcb?\xf8\x00\x00\x00\x00\x00\x00'
+    assert to_msgpack('foo') == b'\xa3foo'
+    assert to_msgpack([1, 2]) == b'\x92\x01\x02'
+    assert to_msgpack({'a': 1}) == b'\x81\xa1a\x01'
+
+
+def test_big_int():
+    assert to_msgpack(-(2**63)) == b'\xd3\x80\x00\x00\x00\x00\x00\x00\x00'
+    # larger than MessagePack's integer types
+    with pytest.raises(PydanticSerializationError, match='1180591620717411303424 is outside the range of 64 bit'):
+        to_msgpack(2**70)
+    with pytest.raises(PydanticSerializationError, match='-18446744073709551616 is outside the range of 64 bit'):
+        to_msgpack([-(2**64)])
+    s = SchemaSerializer(core_schema.int_schema())
+    with pytest.raises(PydanticSerializationError, match='18446744073709551616 is outside the range of 64 bit'):
+        s.to_msgpack(2**64)
+    assert s.to_json(2**64) == b'18446744073709551616'
+
+
+def test_bytes():
+    assert to_msgpack(b'foo') == b'\xc4\x03foo'
+    assert to_msgpack(bytearray(b'\xff')) == b'\xc4\x01\xff'
+    assert to_msgpack(b'foo', bytes_mode='binary') == b'\xc4\x03foo'
+    assert to_msgpack(b'foo', bytes_mode='utf8') == b'\xa3foo'
+    assert to_msgpack(b'\xff', bytes_mode='base64') == b'\xa4_w=='
+    assert to_msgpack(bytearray(b'\xff'), bytes_mode='hex') == b'\xa2ff'
+
+    s = SchemaSerializer(core_schema.bytes_schema(), core_schema.CoreConfig(ser_json_bytes='base64'))
+    assert s.to_msgpack(b'\xff') == b'\xc4\x01\xff'
+    assert s.to_json(b'\xff') == b'"_w=="'
+    assert s.to_msgpack(b'\xff', bytes_mode='binary') == b'\xc4\x01\xff'
+    assert s.to_msgpack(b'foo', bytes_mode='utf8') == b'\xa3foo'
+    assert s.to_msgpack(b'\xff', bytes_mode='base64') == b'\xa4_w=='
+    assert s.to_msgpack(b'\xff', bytes_mode='hex') == b'\xa2ff'
+
+    # bytes inside values without a bytes schema use the same mode
+    s = SchemaSerializer(core_schema.list_schema())
+    assert s.to_msgpack([b'\xff'], bytes_mode='hex') == b'\x91\xa2ff'
+
+
+def test_bytes_mode_invalid():
+    with pytest.raises(SchemaError, match='Invalid MsgpackBytesMode serialization mode: `foo`'):
+        to_msgpack(b'foo', bytes_mode='foo')
+
+
+def test_timedelta_mode():
+    assert to_msgpack(timedelta(seconds=1)) == b'\xa4PT1S'
+    assert to_msgpack(timedelta(seconds=1), timedelta_mode='seconds_float') == b'\xcb?\xf0\x00\x00\x00\x00\x00\x00'
+
+
+def test_typed_dict():
+    s = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(
+                    core_schema.nullable_schema(core_schema.bytes_schema()), serialization_alias='B'
+                ),
+                'c': core_schema.typed_dict_field(core_schema.list_schema(core_schema.int_schema())),
+            }
+        )
+    )
+    value = {'a': 1, 'b': None, 'c': [1, 2, 3]}
+    assert s.to_msgpack(value) == b'\x83\xa1a\x01\xa1B\xc0\xa1c\x93\x01\x02\x03'
+    assert s.to_msgpack(value, by_alias=False) == b'\x83\xa1a\x01\xa1b\xc0\xa1c\x93\x01\x02\x03'
+    # the lengths of maps and arrays must reflect the entries left after filtering
+    assert s.to_msgpack(value, exclude_none=True) == b'\x82\xa1a\x01\xa1c\x93\x01\x02\x03'
+    assert s.to_msgpack(value, include={'a'}) == b'\x81\xa1a\x01'
+    assert s.to_msgpack(value, exclude={'a': True, 'c': {1}}) == b'\x82\xa1B\xc0\xa1c\x92\x01\x03'
+
+
+def test_dict_list_filtering():
+    s = SchemaSerializer(core_schema.dict_schema(core_schema.str_schema(), core_schema.list_schema()))
+    assert s.to_msgpack({'a': [1, 2], 'b': []}, exclude={'b'}) == b'\x81\xa1a\x92\x01\x02'
+    assert s.to_msgpack({'a': [1, 2]}, include={'a': {0}}) == b'\x81\xa1a\x91\x01'
+
+
+def test_untyped_filtering():
+    assert to_msgpack([1, 2, 3], exclude={1}) == b'\x92\x01\x03'
+    assert to_msgpack((1, 2, 3), include={0}) == b'\x91\x01'
+    assert to_msgpack({'a': 1, 'b': 2}, exclude={'a'}) == b'\x81\xa1b\x02'
+
+    s = SchemaSerializer(core_schema.list_schema(serialization=core_schema.filter_seq_schema(exclude={0})))
+    assert s.to_msgpack([1, 2]) == b'\x91\x02'
+    s = SchemaSerializer(core_schema.tuple_schema([core_schema.int_schema()], variadic_item_index=0))
+    assert s.to_msgpack((1, 2, 3), exclude={2}) == b'\x92\x01\x02'
+
+
+def test_dataclass():
+    @dataclasses.dataclass
+    class Foo:
+        a: int
+        b: str
+        c: dataclasses.InitVar[int] = 0
+
+    assert to_msgpack(Foo(1, 'x')) == b'\x82\xa1a\x01\xa1b\xa1x'
+    assert to_msgpack(Foo(1, 'x'), exclude={'b'}) == b'\x81\xa1a\x01'
+
+
+def test_nested_serializer_bytes_mode():
+    @dataclasses.dataclass
+    class Foo:
+        a: bytes
+
+    Foo.__pydantic_serializer__ = SchemaSerializer(
+        core_schema.dataclass_schema(
+            Foo,
+            core_schema.dataclass_args_schema(
+                'Foo', [core_schema.dataclass_field(name='a', schema=core_schema.bytes_schema())]
+            ),
+            ['a'],
+        )
+    )
+    assert to_msgpack(Foo(b'\xff')) == b'\x81\xa1a\xc4\x01\xff'
+    assert to_msgpack(Foo(b'\xff'), bytes_mode='hex') == b'\x81\xa1a\xa2ff'
+
+
+def test_unknown_type():
+    with pytest.raises(PydanticSerializationError, match='Unable to serialize unknown type'):
+        to_msgpack(object())
+    assert to_msgpack(object(), fallback=lambda v: 'x') == b'\xa1x'
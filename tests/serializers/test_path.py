@@ -0,0 +1,22 @@
+from pathlib import Path
+
+import pytest
+
+from pydantic_core import SchemaSerializer, core_schema
+
+
+def test_path():
+    s = SchemaSerializer(core_schema.path_schema())
+    path = Path('foo/bar.txt')
+    assert s.to_python(path) is path
+    assert s.to_python(path, mode='json') == 'foo/bar.txt'
+    assert s.to_json(path) == b'"foo/bar.txt"'
+
+    with pytest.warns(UserWarning, match='Expected `path` but got `int` with value `123`'):
+        assert s.to_json(123) == b'123'
+
+
+def test_path_key():
+    s = SchemaSerializer(core_schema.dict_schema(core_schema.path_schema(), core_schema.int_schema()))
+    assert s.to_python({Path('foo'): 1}, mode='json') == {'foo': 1}
+    assert s.to_json({Path('foo'): 1}) == b'{"foo":1}'
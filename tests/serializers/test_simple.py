@@ -169,3 +169,57 @@ def test_float_inf_and_nan_serializers(value, expected_json, config):
 
     # Serialized JSON value respects the ser_json_inf_nan setting
     assert s.to_json(value).decode() == expected_json
+
+
+@pytest.mark.parametrize(
+    'schema_kwargs,config,value,expected_python,expected_json',
+    [
+        ({'float_precision': 2}, {}, 1.5, 1.5, '1.50'),
+        ({'float_precision': 2}, {}, 1.239, 1.24, '1.24'),
+        ({'float_precision': 0}, {}, 2.5, 2.0, '2'),
+        ({'number_as_string': True}, {}, 0.1, '0.1', '"0.1"'),
+        ({'float_precision': 3, 'number_as_string': True}, {}, 1.5, '1.500', '"1.500"'),
+        ({}, {'ser_json_float_precision': 1}, 2.25, 2.2, '2.2'),
+        ({}, {'ser_json_number_as_string': True}, 2.25, '2.25', '"2.25"'),
+        # large floats have no fractional digits to round, they keep exponent notation
+        ({'float_precision': 2}, {}, 1e300, 1e300, '1e300'),
+        ({'float_precision': 2}, {}, -2.5e16, -2.5e16, '-2.5e16'),
+        ({'float_precision': 2}, {}, 9.5e15, 9.5e15, '9500000000000000.00'),
+        ({'float_precision': 2, 'number_as_string': True}, {}, 1e300, '1e300', '"1e300"'),
+        # the schema takes precedence over config
+        ({'float_precision': 2}, {'ser_json_float_precision': 4}, 1.0, 1.0, '1.00'),
+        ({'number_as_string': False}, {'ser_json_number_as_string': True}, 1.0, 1.0, '1.0'),
+    ],
+)
+def test_float_precision_and_number_as_string(schema_kwargs, config, value, expected_python, expected_json):
+    s = SchemaSerializer(core_schema.float_schema(**schema_kwargs), config)
+    assert s.to_python(value) == value
+    assert s.to_python(value, mode='json') == expected_python
+    assert type(s.to_python(value, mode='json')) is type(expected_python)
+    assert s.to_json(value).decode() == expected_json
+
+
+def test_float_precision_inf_nan():
+    s = SchemaSerializer(core_schema.float_schema(float_precision=2, number_as_string=True))
+    assert s.to_json(float('inf')) == b'null'
+    assert s.to_json(float('nan')) == b'null'
+
+
+def test_float_precision_large_json_round_trip():
+    s = SchemaSerializer(core_schema.float_schema(float_precision=2))
+    for value in (1e16, 1.7976931348623157e308, -1e300, 1e-300):
+        assert json.loads(s.to_json(value)) == s.to_python(value, mode='json')
+
+
+def test_float_precision_key():
+    s = SchemaSerializer(
+        core_schema.dict_schema(core_schema.float_schema(float_precision=2), core_schema.float_schema())
+    )
+    assert s.to_python({1.5: 1.5}, mode='json') == {'1.50': 1.5}
+    assert s.to_json({1.5: 1.5}) == b'{"1.50":1.5}'
+
+
+def test_float_precision_any():
+    s = SchemaSerializer(core_schema.any_schema(), {'ser_json_float_precision': 2})
+    assert s.to_python({'a': [1.5, 2]}, mode='json') == {'a': [1.5, 2]}
+    assert s.to_json({'a': [1.5, 2], 0.125: None}) == b'{"a":[1.50,2],"0.12":null}'
@@ -0,0 +1,42 @@
+from datetime import timedelta, timezone
+
+import pytest
+
+from pydantic_core import SchemaSerializer, SchemaValidator, core_schema
+
+try:
+    import zoneinfo
+except ImportError:
+    # TODO: can remove this once we drop support for python 3.8
+    from backports import zoneinfo
+
+
+@pytest.mark.parametrize(
+    'value,expected',
+    [
+        (zoneinfo.ZoneInfo('Europe/Helsinki'), 'Europe/Helsinki'),
+        (timezone(timedelta(hours=2)), '+02:00'),
+        (timezone(-timedelta(hours=5, minutes=30)), '-05:30'),
+        (timezone.utc, 'UTC'),
+    ],
+)
+def test_timezone(value, expected):
+    s = SchemaSerializer(core_schema.timezone_schema())
+    assert s.to_python(value) is value
+    assert s.to_python(value, mode='json') == expected
+    assert s.to_json(value) == f'"{expected}"'.encode()
+
+    # the output can be validated again
+    v = SchemaValidator(core_schema.timezone_schema())
+    assert v.validate_json(s.to_json(value)).utcoffset(None) == value.utcoffset(None)
+
+
+def test_timezone_key():
+    s = SchemaSerializer(core_schema.dict_schema(core_schema.timezone_schema(), core_schema.int_schema()))
+    assert s.to_json({zoneinfo.ZoneInfo('Europe/Helsinki'): 1}) == b'{"Europe/Helsinki":1}'
+
+
+def test_timezone_fallback():
+    s = SchemaSerializer(core_schema.timezone_schema())
+    with pytest.warns(UserWarning, match='Expected `timezone` but got `int` with value `123`'):
+        assert s.to_json(123) == b'123'
@@ -88,6 +88,9 @@ a = A()
                     py,
                     &a,
                     None,
+                    false,
+                    false,
+                    false,
                     None,
                     None,
                     true,
@@ -95,6 +98,7 @@ a = A()
                     false,
                     false,
                     false,
+                    None,
                     WarningsArg::Bool(true),
                     None,
                     false,
@@ -192,6 +196,9 @@ dump_json_input_2 = {'a': 'something'}
                     py,
                     &dump_json_input_1,
                     None,
+                    false,
+                    false,
+                    false,
                     None,
                     None,
                     false,
@@ -199,6 +206,7 @@ dump_json_input_2 = {'a': 'something'}
                     false,
                     false,
                     false,
+                    None,
                     WarningsArg::Bool(false),
                     None,
                     false,
@@ -213,6 +221,9 @@ dump_json_input_2 = {'a': 'something'}
                     py,
                     &dump_json_input_2,
                     None,
+                    false,
+                    false,
+                    false,
                     None,
                     None,
                     false,
@@ -220,6 +231,7 @@ dump_json_input_2 = {'a': 'something'}
                     false,
                     false,
                     false,
+                    None,
                     WarningsArg::Bool(false),
                     None,
                     false,
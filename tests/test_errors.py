@@ -1,4 +1,5 @@
 import enum
+import json
 import os
 import pickle
 import re
@@ -262,7 +263,14 @@ all_errors = [
         'Cannot check `isinstance` when validating from json, use a JsonOrPython validator instead',
         {'method_name': 'isinstance'},
     ),
+    ('msgpack_invalid', 'Invalid MessagePack: foobar', {'error': 'foobar'}),
+    ('msgpack_type', 'MessagePack input should be bytes or bytearray', None),
     ('recursion_loop', 'Recursion error - cyclic reference detected', None),
+    (
+        'resource_limit_exceeded',
+        'Input exceeds the max_total_items limit of 42',
+        {'limit': 'max_total_items', 'limit_value': 42},
+    ),
     ('model_type', 'Input should be a valid dictionary or instance of Foobar', {'class_name': 'Foobar'}),
     ('model_attributes_type', 'Input should be a valid dictionary or object to extract fields from', None),
     ('dataclass_exact_type', 'Input should be an instance of Foobar', {'class_name': 'Foobar'}),
@@ -350,12 +358,19 @@ all_errors = [
     ('datetime_type', 'Input should be a valid datetime', None),
     ('datetime_parsing', 'Input should be a valid datetime, foobar', {'error': 'foobar'}),
     ('datetime_from_date_parsing', 'Input should be a valid datetime or date, foobar', {'error': 'foobar'}),
+    (
+        'datetime_format',
+        "Input should be in ISO 8601 format or match the format '%d/%m/%Y'",
+        {'formats': "'%d/%m/%Y'"},
+    ),
     ('datetime_object_invalid', 'Invalid datetime object, got foobar', {'error': 'foobar'}),
     ('datetime_past', 'Input should be in the past', None),
     ('datetime_future', 'Input should be in the future', None),
     ('timezone_naive', 'Input should not have timezone info', None),
     ('timezone_aware', 'Input should have timezone info', None),
     ('timezone_offset', 'Timezone offset of 0 required, got 60', {'tz_expected': 0, 'tz_actual': 60}),
+    ('timezone_type', 'Input should be a time zone name, UTC offset or tzinfo object', None),
+    ('timezone_parsing', 'Input should be a valid IANA time zone name or UTC offset', None),
     ('time_delta_type', 'Input should be a valid timedelta', None),
     ('time_delta_parsing', 'Input should be a valid timedelta, foobar', {'error': 'foobar'}),
     ('frozen_set_type', 'Input should be a valid frozenset', None),
@@ -384,6 +399,16 @@ all_errors = [
     ('uuid_type', 'UUID input should be a string, bytes or UUID object', None),
     ('uuid_parsing', 'Input should be a valid UUID, Foobar', {'error': 'Foobar'}),
     ('uuid_version', 'UUID version 42 expected', {'expected_version': 42}),
+    ('ip_type', 'Input should be a string, integer, bytes or ipaddress object', None),
+    ('ip_address_parsing', 'Input should be a valid IPv4 address', {'expected': 'IPv4'}),
+    ('ip_network_parsing', 'Input should be a valid IPv6 network', {'expected': 'IPv6'}),
+    ('ip_interface_parsing', 'Input should be a valid IPv4 or IPv6 interface', {'expected': 'IPv4 or IPv6'}),
+    ('path_type', 'Path input should be a string, bytes or os.PathLike object', None),
+    ('path_not_absolute', 'Path should be absolute', None),
+    ('path_suffix', "Path suffix should be '.txt' or '.csv'", {'expected_suffixes': "'.txt' or '.csv'"}),
+    ('path_not_exists', 'Path does not exist', None),
+    ('path_not_file', 'Path does not point to a file', None),
+    ('path_not_directory', 'Path does not point to a directory', None),
     ('decimal_type', 'Decimal input should be an integer, float, string or Decimal object', None),
     ('decimal_parsing', 'Input should be a valid decimal', None),
     ('decimal_max_digits', 'Decimal input should have no more than 42 digits in total', {'max_digits': 42}),
@@ -497,24 +522,28 @@ def test_all_errors():
             'message_template_python': "Object has no attribute '{attribute}'",
             'example_message_python': "Object has no attribute ''",
             'example_context': {'attribute': ''},
+            'placeholders': ['attribute'],
         },
         {
             'type': 'json_invalid',
             'message_template_python': 'Invalid JSON: {error}',
             'example_message_python': 'Invalid JSON: ',
             'example_context': {'error': ''},
+            'placeholders': ['error'],
         },
         {
             'type': 'json_type',
             'message_template_python': 'JSON input should be string, bytes or bytearray',
             'example_message_python': 'JSON input should be string, bytes or bytearray',
             'example_context': None,
+            'placeholders': [],
         },
         {
             'type': 'needs_python_object',
             'message_template_python': 'Cannot check `{method_name}` when validating from json, use a JsonOrPython validator instead',
             'example_message_python': 'Cannot check `` when validating from json, use a JsonOrPython validator instead',
             'example_context': {'method_name': ''},
+            'placeholders': ['method_name'],
         },
     ]
 
@@ -527,8 +556,12 @@ def test_all_errors():
         'message_template_json': 'Input should be null',
         'example_message_json': 'Input should be null',
         'example_context': None,
+        'placeholders': [],
     }
 
+    too_short = next(e for e in errors if e['type'] == 'too_short')
+    assert too_short['placeholders'] == ['field_type', 'min_length', 'expected_plural', 'actual_length']
+
     error_types = [e['type'] for e in errors]
     if error_types != list(core_schema.ErrorType.__args__):
         literal = ''.join(f'\n    {e!r},' for e in error_types)
@@ -1119,6 +1152,106 @@ def test_validation_error_pickle() -> None:
     assert original.errors() == roundtripped.errors()
 
 
+FINNISH_MESSAGES = {
+    'int_parsing': 'Syötteen tulee olla kelvollinen kokonaisluku',
+    'greater_than': 'Syötteen tulee olla suurempi kuin {gt}',
+    'string_too_short': 'Merkkijonon pituuden tulee olla vähintään {min_length}',
+}
+
+
+def test_error_messages_config():
+    v = SchemaValidator(
+        {
+            'type': 'typed-dict',
+            'fields': {
+                'a': {'type': 'typed-dict-field', 'schema': {'type': 'int'}},
+                'b': {'type': 'typed-dict-field', 'schema': {'type': 'int', 'gt': 5}},
+                'c': {'type': 'typed-dict-field', 'schema': {'type': 'str', 'min_length': 3}},
+                'd': {'type': 'typed-dict-field', 'schema': {'type': 'bool'}},
+            },
+        },
+        {'error_messages': FINNISH_MESSAGES},
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 'x', 'b': 1, 'c': 'x', 'd': 'x'})
+
+    # insert_assert([e['msg'] for e in exc_info.value.errors()])
+    assert [e['msg'] for e in exc_info.value.errors()] == [
+        'Syötteen tulee olla kelvollinen kokonaisluku',
+        'Syötteen tulee olla suurempi kuin 5',
+        'Merkkijonon pituuden tulee olla vähintään 3',
+        # not in the catalog, so the default message is used
+        'Input should be a valid boolean, unable to interpret input',
+    ]
+    assert '  Syötteen tulee olla suurempi kuin 5 [type=greater_than' in str(exc_info.value)
+    assert [e['msg'] for e in json.loads(exc_info.value.json())][:2] == [
+        'Syötteen tulee olla kelvollinen kokonaisluku',
+        'Syötteen tulee olla suurempi kuin 5',
+    ]
+
+    roundtripped = pickle.loads(pickle.dumps(exc_info.value))
+    assert roundtripped.errors() == exc_info.value.errors()
+
+
+def test_error_messages_per_call():
+    v = SchemaValidator({'type': 'int', 'gt': 5}, {'error_messages': FINNISH_MESSAGES})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(1)
+
+    error = exc_info.value
+    assert error.errors()[0]['msg'] == 'Syötteen tulee olla suurempi kuin 5'
+    # per-call templates are used instead of the config
+    german = {'greater_than': 'Die Eingabe muss größer als {gt} sein'}
+    assert error.errors(error_messages=german)[0]['msg'] == 'Die Eingabe muss größer als 5 sein'
+    assert json.loads(error.json(error_messages=german))[0]['msg'] == 'Die Eingabe muss größer als 5 sein'
+    assert error.errors(error_messages={})[0]['msg'] == 'Input should be greater than 5'
+
+
+def test_error_messages_custom_error():
+    # custom errors use their own template, even if their type is in the catalog
+    def f(input_value, info):
+        raise PydanticCustomError('greater_than', 'Error: {foo}', {'foo': 'bar'})
+
+    v = SchemaValidator(
+        core_schema.with_info_plain_validator_function(f), core_schema.CoreConfig(error_messages=FINNISH_MESSAGES)
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(1)
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'greater_than', 'loc': (), 'msg': 'Error: bar', 'input': 1, 'ctx': {'foo': 'bar'}}
+    ]
+
+
+def test_error_messages_from_exception_data():
+    error = ValidationError.from_exception_data(
+        'Foobar',
+        [{'type': 'greater_than', 'loc': ('a',), 'input': 1, 'ctx': {'gt': 5}}],
+        error_messages=FINNISH_MESSAGES,
+    )
+    assert error.errors(include_url=False) == [
+        {
+            'type': 'greater_than',
+            'loc': ('a',),
+            'msg': 'Syötteen tulee olla suurempi kuin 5',
+            'input': 1,
+            'ctx': {'gt': 5},
+        }
+    ]
+
+
+def test_error_messages_invalid():
+    with pytest.raises(TypeError):
+        SchemaValidator({'type': 'int'}, {'error_messages': {'int_parsing': 1}})
+    with pytest.raises(ValueError, match=re.escape('Invalid error_messages: unknown error type `bogus_type`')):
+        SchemaValidator({'type': 'int'}, {'error_messages': {'bogus_type': 'x'}})
+    with pytest.raises(ValueError, match=re.escape('`greater_than` has no placeholder `{input}`, expected `{gt}`')):
+        SchemaValidator({'type': 'int'}, {'error_messages': {'greater_than': '{input} <= {gt}'}})
+
+    error = ValidationError.from_exception_data('Foobar', [{'type': 'greater_than', 'input': 1, 'ctx': {'gt': 5}}])
+    with pytest.raises(ValueError, match=re.escape('`int_type` has no placeholder `{nope}`, expected no placeholders')):
+        error.errors(error_messages={'int_type': '{nope}'})
+
+
 @pytest.mark.skipif('PYDANTIC_ERRORS_INCLUDE_URL' in os.environ, reason="can't test when envvar is set")
 def test_errors_include_url() -> None:
     s = SchemaValidator({'type': 'int'})
@@ -1165,3 +1298,299 @@ def test_errors_include_url_envvar(env_var, env_var_value, expected_to_have_url)
     if 'PYDANTIC_ERRORS_OMIT_URL' in env:
         assert 'PYDANTIC_ERRORS_OMIT_URL is deprecated' in result.stdout
     assert ('https://errors.pydantic.dev' in result.stdout) == expected_to_have_url
+
+
+@pytest.fixture(scope='module')
+def format_error() -> ValidationError:
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema(gt=5)),
+                'b': core_schema.typed_dict_field(
+                    core_schema.dict_schema(core_schema.str_schema(), core_schema.list_schema(core_schema.int_schema()))
+                ),
+            }
+        ),
+        core_schema.CoreConfig(title='Model'),
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 1, 'b': {'x/y~z': [1, 'wrong']}})
+    return exc_info.value
+
+
+def test_errors_format_rfc7807(format_error: ValidationError):
+    assert format_error.errors(include_url=False, format='rfc7807') == {
+        'title': 'Validation Error',
+        'detail': '2 validation errors for Model',
+        'errors': [
+            {
+                'type': 'greater_than',
+                'detail': 'Input should be greater than 5',
+                'source': {'pointer': '/a'},
+                'input': 1,
+                'ctx': {'gt': 5},
+            },
+            {
+                'type': 'int_parsing',
+                'detail': 'Input should be a valid integer, unable to parse string as an integer',
+                'source': {'pointer': '/b/x~1y~0z/1'},
+                'input': 'wrong',
+            },
+        ],
+    }
+
+
+def test_errors_format_jsonapi(format_error: ValidationError):
+    assert format_error.errors(include_url=False, format='jsonapi') == {
+        'errors': [
+            {
+                'code': 'greater_than',
+                'detail': 'Input should be greater than 5',
+                'source': {'pointer': '/a'},
+                'meta': {'input': 1, 'ctx': {'gt': 5}},
+            },
+            {
+                'code': 'int_parsing',
+                'detail': 'Input should be a valid integer, unable to parse string as an integer',
+                'source': {'pointer': '/b/x~1y~0z/1'},
+                'meta': {'input': 'wrong'},
+            },
+        ]
+    }
+    errors = format_error.errors(include_input=False, include_context=False, format='jsonapi')['errors']
+    assert errors[0] == {
+        'code': 'greater_than',
+        'detail': 'Input should be greater than 5',
+        'source': {'pointer': '/a'},
+        'links': {'about': IsStr(regex=r'https://errors\.pydantic\.dev/.+/v/greater_than')},
+    }
+
+
+@pytest.mark.parametrize('format', ['pydantic', 'rfc7807', 'jsonapi'])
+@pytest.mark.parametrize('include_url', [True, False])
+def test_json_format_matches_errors(format_error: ValidationError, format, include_url):
+    assert json.loads(format_error.json(include_url=include_url, format=format)) == json.loads(
+        json.dumps(format_error.errors(include_url=include_url, format=format))
+    )
+
+
+def test_errors_format_empty_loc_custom_error():
+    def f(input_value, info):
+        raise PydanticCustomError('my_error', 'this is a custom error')
+
+    v = SchemaValidator(core_schema.with_info_plain_validator_function(f))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(1)
+
+    # custom errors don't have a documentation URL, and an empty location points at the whole input
+    assert exc_info.value.errors(format='jsonapi') == {
+        'errors': [
+            {'code': 'my_error', 'detail': 'this is a custom error', 'source': {'pointer': ''}, 'meta': {'input': 1}}
+        ]
+    }
+    assert json.loads(exc_info.value.json(format='jsonapi')) == exc_info.value.errors(format='jsonapi')
+
+
+def test_errors_format_invalid(format_error: ValidationError):
+    with pytest.raises(ValueError, match="Invalid format, should be `'pydantic'`, `'rfc7807'` or `'jsonapi'`"):
+        format_error.errors(format='problem')
+
+
+@pytest.mark.parametrize(
+    'loc_format,expected',
+    [
+        ('tuple', [('a',), ('b', 'x/y~z', 1), ('c.d', 0)]),
+        ('json_pointer', ['/a', '/b/x~1y~0z/1', '/c.d/0']),
+        ('dotted', ['a', 'b.x/y~z[1]', '`c.d`[0]']),
+    ],
+)
+def test_loc_format(loc_format, expected):
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(
+                    core_schema.dict_schema(core_schema.str_schema(), core_schema.list_schema(core_schema.int_schema()))
+                ),
+                'c.d': core_schema.typed_dict_field(core_schema.list_schema(core_schema.int_schema())),
+            }
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 'x', 'b': {'x/y~z': [1, 'x']}, 'c.d': ['x']})
+
+    error = exc_info.value
+    assert [e['loc'] for e in error.errors(loc_format=loc_format)] == expected
+    assert [e['loc'] for e in json.loads(error.json(loc_format=loc_format))] == [
+        list(loc) if isinstance(loc, tuple) else loc for loc in expected
+    ]
+
+
+def test_loc_format_config():
+    v = SchemaValidator(
+        core_schema.list_schema(
+            core_schema.typed_dict_schema({'a': core_schema.typed_dict_field(core_schema.int_schema())})
+        ),
+        core_schema.CoreConfig(loc_format='dotted'),
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([{'a': 1}, {'a': 'x'}])
+
+    error = exc_info.value
+    assert error.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': '[1].a',
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        }
+    ]
+    assert json.loads(error.json(include_url=False))[0]['loc'] == '[1].a'
+    assert str(error).splitlines()[:3] == [
+        '1 validation error for list[typed-dict]',
+        '[1].a',
+        '  Input should be a valid integer, unable to parse string as an integer '
+        "[type=int_parsing, input_value='x', input_type=str]",
+    ]
+    # the per-call argument overrides the config
+    assert error.errors(loc_format='json_pointer')[0]['loc'] == '/1/a'
+    assert error.errors(loc_format='tuple')[0]['loc'] == (1, 'a')
+
+    roundtripped = pickle.loads(pickle.dumps(error))
+    assert roundtripped.errors() == error.errors()
+    assert str(roundtripped) == str(error)
+
+
+def test_loc_format_json_pointer_str():
+    error = ValidationError.from_exception_data(
+        'Foobar',
+        [
+            {'type': 'int_type', 'loc': ('a', 0), 'input': 'x'},
+            {'type': 'missing', 'loc': (), 'input': {}},
+        ],
+        loc_format='json_pointer',
+    )
+    message = str(error)
+    assert message.startswith('2 validation errors for Foobar\n/a/0\n  Input should be a valid integer [type=int_type')
+    # an empty location isn't printed
+    assert '\n  Field required [type=missing, input_value={}, input_type=dict]' in message
+
+
+def test_loc_format_invalid():
+    with pytest.raises(ValueError, match="Invalid loc_format, should be `'tuple'`, `'json_pointer'` or `'dotted'`"):
+        SchemaValidator(core_schema.int_schema(), core_schema.CoreConfig(loc_format='slashes'))
+
+
+def group_errors_validator(**config):
+    return SchemaValidator(
+        core_schema.list_schema(
+            core_schema.typed_dict_schema(
+                {
+                    'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                    'b': core_schema.typed_dict_field(core_schema.str_schema()),
+                }
+            )
+        ),
+        core_schema.CoreConfig(**config),
+    )
+
+
+GROUP_ERRORS_INPUT = [{'a': 'x', 'b': 'ok'}] * 7 + [{'a': 1, 'b': 2}]
+
+
+def test_group_errors():
+    with pytest.raises(ValidationError) as exc_info:
+        group_errors_validator().validate_python(GROUP_ERRORS_INPUT)
+
+    error = exc_info.value
+    assert error.error_count() == 8
+    assert len(error.errors()) == 8
+    assert error.errors(group_errors=True, include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': (0, 'a'),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+            'count': 7,
+            'sample_locs': [(0, 'a'), (1, 'a'), (2, 'a'), (3, 'a'), (4, 'a')],
+        },
+        {
+            'type': 'string_type',
+            'loc': (7, 'b'),
+            'msg': 'Input should be a valid string',
+            'input': 2,
+            'count': 1,
+            'sample_locs': [(7, 'b')],
+        },
+    ]
+    assert json.loads(error.json(group_errors=True, include_url=False)) == [
+        {
+            'type': 'int_parsing',
+            'loc': [0, 'a'],
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+            'count': 7,
+            'sample_locs': [[0, 'a'], [1, 'a'], [2, 'a'], [3, 'a'], [4, 'a']],
+        },
+        {
+            'type': 'string_type',
+            'loc': [7, 'b'],
+            'msg': 'Input should be a valid string',
+            'input': 2,
+            'count': 1,
+            'sample_locs': [[7, 'b']],
+        },
+    ]
+
+
+def test_group_errors_config():
+    with pytest.raises(ValidationError) as exc_info:
+        group_errors_validator(group_errors=True, loc_format='dotted').validate_python(GROUP_ERRORS_INPUT)
+
+    error = exc_info.value
+    assert [(e['loc'], e['count'], e['sample_locs']) for e in error.errors()] == [
+        ('[0].a', 7, ['[0].a', '[1].a', '[2].a', '[3].a', '[4].a']),
+        ('[7].b', 1, ['[7].b']),
+    ]
+    # the per-call argument overrides the config
+    assert len(error.errors(group_errors=False)) == 8
+
+    message = str(error)
+    assert message.startswith('8 validation errors for list[typed-dict]\n[0].a\n  Input should be a valid integer')
+    assert '\n    Same error at 7 locations, e.g. [0].a, [1].a, [2].a, [3].a, [4].a\n[7].b\n' in message
+    assert message.count('Same error at') == 1
+
+    roundtripped = pickle.loads(pickle.dumps(error))
+    assert roundtripped.errors() == error.errors()
+    assert str(roundtripped) == message
+
+
+@pytest.mark.parametrize(
+    'format,key',
+    [('rfc7807', None), ('jsonapi', 'meta')],
+)
+def test_group_errors_format(format, key):
+    with pytest.raises(ValidationError) as exc_info:
+        group_errors_validator().validate_python(GROUP_ERRORS_INPUT)
+
+    error = exc_info.value
+    errors = error.errors(format=format, group_errors=True)['errors']
+    assert len(errors) == 2
+    group = errors[0][key] if key else errors[0]
+    assert group['count'] == 7
+    assert group['sample_pointers'] == ['/0/a', '/1/a', '/2/a', '/3/a', '/4/a']
+    assert json.loads(error.json(format=format, group_errors=True))['errors'] == errors
+
+
+def test_group_errors_different_messages():
+    error = ValidationError.from_exception_data(
+        'Foobar',
+        [
+            {'type': 'greater_than', 'loc': (0,), 'input': 1, 'ctx': {'gt': 2}},
+            {'type': 'greater_than', 'loc': (1,), 'input': 1, 'ctx': {'gt': 3}},
+            {'type': 'greater_than', 'loc': (2,), 'input': 2, 'ctx': {'gt': 3}},
+        ],
+        group_errors=True,
+    )
+    # errors are only grouped when their messages match
+    assert [(e['loc'], e['count']) for e in error.errors()] == [((0,), 1), ((1,), 2)]
@@ -0,0 +1,140 @@
+import pytest
+
+from pydantic_core import ArgsKwargs, SchemaValidator, ValidationError, core_schema
+
+
+@pytest.mark.parametrize(
+    'schema,input_value,expected_locs',
+    [
+        (core_schema.list_schema(core_schema.int_schema()), ['a', 'b'], [(0,), (1,)]),
+        (core_schema.set_schema(core_schema.int_schema()), ['a', 'b'], [(0,), (1,)]),
+        (core_schema.frozenset_schema(core_schema.int_schema()), ['a', 'b'], [(0,), (1,)]),
+        (core_schema.tuple_schema([core_schema.int_schema(), core_schema.int_schema()]), ['a', 'b'], [(0,), (1,)]),
+        (
+            core_schema.dict_schema(core_schema.str_schema(), core_schema.int_schema()),
+            {'a': 'x', 'b': 'y'},
+            [('a',), ('b',)],
+        ),
+        (
+            core_schema.model_fields_schema(
+                {
+                    'a': core_schema.model_field(core_schema.int_schema()),
+                    'b': core_schema.model_field(core_schema.int_schema()),
+                }
+            ),
+            {'a': 'x'},
+            [('a',), ('b',)],
+        ),
+        (
+            core_schema.dataclass_args_schema(
+                'MyDataclass',
+                [
+                    core_schema.dataclass_field('a', core_schema.int_schema(), kw_only=False),
+                    core_schema.dataclass_field('b', core_schema.int_schema(), kw_only=False),
+                ],
+            ),
+            ArgsKwargs(('x',)),
+            [(0,), ('b',)],
+        ),
+        (
+            core_schema.arguments_schema(
+                [
+                    core_schema.arguments_parameter('a', core_schema.int_schema()),
+                    core_schema.arguments_parameter('b', core_schema.int_schema()),
+                ]
+            ),
+            ArgsKwargs(('x',), {'c': 1}),
+            [(0,), ('b',), ('c',)],
+        ),
+    ],
+)
+def test_fail_fast_config(schema, input_value, expected_locs):
+    v = SchemaValidator(schema)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(input_value)
+    assert [e['loc'] for e in exc_info.value.errors()] == expected_locs
+
+    v = SchemaValidator(schema, core_schema.CoreConfig(fail_fast=True))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(input_value)
+    assert [e['loc'] for e in exc_info.value.errors()] == expected_locs[:1]
+
+    # the per-call argument overrides the config
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(input_value, fail_fast=False)
+    assert [e['loc'] for e in exc_info.value.errors()] == expected_locs
+
+
+def test_fail_fast_typed_dict_config():
+    def typed_dict_schema(config):
+        return core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(core_schema.int_schema()),
+            },
+            config=config,
+        )
+
+    # typed dicts use their own config rather than the validator's
+    v = SchemaValidator(typed_dict_schema(None), core_schema.CoreConfig(fail_fast=True))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 'x'})
+    assert [e['loc'] for e in exc_info.value.errors()] == [('a',), ('b',)]
+
+    v = SchemaValidator(typed_dict_schema(core_schema.CoreConfig(fail_fast=True)))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 'x'})
+    assert [e['loc'] for e in exc_info.value.errors()] == [('a',)]
+
+
+def test_fail_fast_call():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(core_schema.list_schema(core_schema.int_schema())),
+            }
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 1, 'b': ['x', 'y']}, fail_fast=True)
+    assert [e['loc'] for e in exc_info.value.errors()] == [('b', 0)]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"a": "x", "b": ["x", "y"]}', fail_fast=True)
+    assert [e['loc'] for e in exc_info.value.errors()] == [('a',)]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"a": "x", "b": ["x", "y"]}')
+    assert [e['loc'] for e in exc_info.value.errors()] == [('a',), ('b', 0), ('b', 1)]
+
+    assert v.isinstance_python({'a': 'x', 'b': ['x']}, fail_fast=True) is False
+    assert v.isinstance_python({'a': 1, 'b': [1]}, fail_fast=True) is True
+
+
+def test_fail_fast_schema_precedence():
+    v = SchemaValidator(
+        core_schema.list_schema(core_schema.int_schema(), fail_fast=False), core_schema.CoreConfig(fail_fast=True)
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(['a', 'b'])
+    assert exc_info.value.error_count() == 2
+
+    # but the per-call argument overrides both
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(['a', 'b'], fail_fast=True)
+    assert exc_info.value.error_count() == 1
+
+
+def test_fail_fast_model_extra():
+    v = SchemaValidator(
+        core_schema.model_fields_schema(
+            {'a': core_schema.model_field(core_schema.int_schema())}, extra_behavior='forbid'
+        ),
+        core_schema.CoreConfig(fail_fast=True),
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python({'a': 1, 'b': 1, 'c': 1})
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'extra_forbidden', 'loc': ('b',), 'msg': 'Extra inputs are not permitted', 'input': 1}
+    ]
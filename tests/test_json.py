@@ -1,6 +1,9 @@
+import io
 import json
 import platform
 import re
+import struct
+from decimal import Decimal
 from typing import List
 
 import pytest
@@ -231,6 +234,195 @@ def test_to_json_fallback():
     assert to_json(Foobar(), fallback=fallback_func) == b'"fallback:Foobar"'
 
 
+def test_to_json_sort_keys():
+    value = {'b': 1, 'a': {'d': [{'z': 1, 'y': 2}], 'c': None}, 'A': 3, 'é': 4, '\n': 5}
+    expected = json.dumps(value, sort_keys=True, ensure_ascii=False, separators=(',', ':'))
+    assert to_json(value, sort_keys=True) == expected.encode()
+    expected = json.dumps(value, sort_keys=True, ensure_ascii=False, indent=2)
+    assert to_json(value, sort_keys=True, indent=2) == expected.encode()
+    assert to_json({}, sort_keys=True) == b'{}'
+    assert to_json({}, sort_keys=True, indent=2) == b'{}'
+    # keys are sorted after conversion to strings
+    assert to_json({2: 'a', 10: 'b', 1: 'c'}, sort_keys=True) == b'{"1":"c","10":"b","2":"a"}'
+
+
+def test_to_json_ensure_ascii():
+    value = ['é', '\U0001f600', 'a"\\\n\x00', {'ключ': 'значение'}]
+    assert to_json(value, ensure_ascii=True) == json.dumps(value, separators=(',', ':')).encode()
+    assert to_json(value) == json.dumps(value, ensure_ascii=False, separators=(',', ':')).encode()
+    assert to_json('foo', ensure_ascii=True) == b'"foo"'
+
+
+@pytest.mark.parametrize(
+    'hex_value,expected',
+    [
+        # number serialization samples from RFC 8785, appendix B
+        ('0000000000000000', b'0'),
+        ('8000000000000000', b'0'),
+        ('0000000000000001', b'5e-324'),
+        ('8000000000000001', b'-5e-324'),
+        ('7fefffffffffffff', b'1.7976931348623157e+308'),
+        ('ffefffffffffffff', b'-1.7976931348623157e+308'),
+        ('4340000000000000', b'9007199254740992'),
+        ('c340000000000000', b'-9007199254740992'),
+        ('4430000000000000', b'295147905179352830000'),
+        ('44b52d02c7e14af5', b'9.999999999999997e+22'),
+        ('44b52d02c7e14af6', b'1e+23'),
+        ('44b52d02c7e14af7', b'1.0000000000000001e+23'),
+        ('444b1ae4d6e2ef4e', b'999999999999999700000'),
+        ('444b1ae4d6e2ef4f', b'999999999999999900000'),
+        ('444b1ae4d6e2ef50', b'1e+21'),
+        ('3eb0c6f7a0b5ed8c', b'9.999999999999997e-7'),
+        ('3eb0c6f7a0b5ed8d', b'0.000001'),
+        ('41b3de4355555553', b'333333333.3333332'),
+        ('41b3de4355555554', b'333333333.33333325'),
+        ('41b3de4355555555', b'333333333.3333333'),
+        ('41b3de4355555556', b'333333333.3333334'),
+        ('41b3de4355555557', b'333333333.33333343'),
+        ('becbf647612f3696', b'-0.0000033333333333333333'),
+        ('43143ff3c1cb0959', b'1424953923781206.2'),
+    ],
+)
+def test_to_json_canonical_numbers(hex_value, expected):
+    value = struct.unpack('>d', bytes.fromhex(hex_value))[0]
+    assert to_json(value, canonical=True) == expected
+
+
+def test_to_json_canonical():
+    # example from RFC 8785, section 3.2.2
+    value = {
+        'numbers': [333333333.33333329, 1e30, 4.50, 2e-3, 0.000000000000000000000000001],
+        'string': '€$\u000f\u000aA\'B"\\\\"/',
+        'literals': [None, True, False],
+    }
+    assert to_json(value, canonical=True) == (
+        b'{"literals":[null,true,false],"numbers":[333333333.3333333,1e+30,4.5,0.002,1e-27],'
+        b'"string":"\xe2\x82\xac$\\u000f\\nA\'B\\"\\\\\\\\\\"/"}'
+    )
+    assert to_json({'a': [1, -2, 2**53, 0]}, canonical=True) == b'{"a":[1,-2,9007199254740992,0]}'
+
+
+def test_to_json_canonical_key_order():
+    # example from RFC 8785, section 3.2.3, keys are sorted by UTF-16 code units, not code points
+    value = {
+        '\u20ac': 'Euro Sign',
+        '\r': 'Carriage Return',
+        '\ufb33': 'Hebrew Letter Dalet With Dagesh',
+        '1': 'One',
+        '\U0001f600': 'Emoji: Grinning Face',
+        '\u0080': 'Control',
+        '\u00f6': 'Latin Small Letter O With Diaeresis',
+    }
+    output = json.loads(to_json(value, canonical=True))
+    assert list(output.values()) == [
+        'Carriage Return',
+        'One',
+        'Control',
+        'Latin Small Letter O With Diaeresis',
+        'Euro Sign',
+        'Emoji: Grinning Face',
+        'Hebrew Letter Dalet With Dagesh',
+    ]
+    # sort_keys uses code point order
+    assert list(json.loads(to_json(value, sort_keys=True)))[-2:] == ['\ufb33', '\U0001f600']
+
+
+@pytest.mark.parametrize(
+    'value,error',
+    [
+        (float('nan'), 'NaN and Infinity are not permitted in canonical JSON'),
+        (float('-inf'), 'NaN and Infinity are not permitted in canonical JSON'),
+        (2**53 + 1, '9007199254740993 cannot be represented exactly as an IEEE 754 double'),
+        (2**64 + 1, '18446744073709551617 cannot be represented exactly as an IEEE 754 double'),
+    ],
+)
+def test_to_json_canonical_invalid_numbers(value, error):
+    with pytest.raises(PydanticSerializationError, match=f'^Error serializing to JSON: {error}'):
+        to_json([value], canonical=True)
+
+
+def test_to_json_canonical_options():
+    assert to_json(2**64, canonical=True) == b'18446744073709552000'
+    assert to_json(float('nan'), canonical=True, inf_nan_mode='null') == b'null'
+    with pytest.raises(ValueError, match='`indent` and `ensure_ascii` cannot be used with `canonical=True`'):
+        to_json({}, canonical=True, indent=2)
+    with pytest.raises(ValueError, match='`indent` and `ensure_ascii` cannot be used with `canonical=True`'):
+        to_json({}, canonical=True, ensure_ascii=True)
+
+    s = SchemaSerializer(
+        core_schema.typed_dict_schema(
+            {
+                'b': core_schema.typed_dict_field(core_schema.float_schema()),
+                'a': core_schema.typed_dict_field(core_schema.list_schema(core_schema.int_schema())),
+            }
+        )
+    )
+    assert s.to_json({'b': 1.0, 'a': [1, 2]}, canonical=True) == b'{"a":[1,2],"b":1}'
+
+
+def test_to_json_canonical_number_format():
+    s = SchemaSerializer(core_schema.list_schema(core_schema.float_schema(float_precision=2)))
+    assert s.to_json([1 / 3, 1.5, 1e20], canonical=True) == b'[0.33,1.5,100000000000000000000]'
+    s = SchemaSerializer(core_schema.float_schema(), {'ser_json_float_precision': 3})
+    assert s.to_json(2.5, canonical=True) == b'2.5'
+
+    s = SchemaSerializer(core_schema.decimal_schema(number_as_string=False))
+    assert s.to_json(Decimal('1.50'), canonical=True) == b'1.5'
+    assert s.to_json(Decimal('0.1'), canonical=True) == b'0.1'
+    assert s.to_json(Decimal('-12.5e3'), canonical=True) == b'-12500'
+    with pytest.raises(
+        PydanticSerializationError,
+        match='^Error serializing to JSON: 0.1000000000000000055511151231257827 cannot be represented exactly',
+    ):
+        s.to_json(Decimal('0.1000000000000000055511151231257827'), canonical=True)
+    # as strings, numbers are left as they're formatted
+    s = SchemaSerializer(core_schema.decimal_schema())
+    assert s.to_json(Decimal('1.50'), canonical=True) == b'"1.50"'
+
+
+def test_sort_keys_schema_serializer():
+    class Model:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    s = SchemaSerializer(
+        core_schema.model_schema(
+            Model,
+            core_schema.model_fields_schema(
+                {
+                    'z': core_schema.model_field(core_schema.int_schema()),
+                    'y': core_schema.model_field(
+                        core_schema.typed_dict_schema(
+                            {
+                                'b': core_schema.typed_dict_field(core_schema.str_schema()),
+                                'a': core_schema.typed_dict_field(core_schema.str_schema(), serialization_alias='c'),
+                            }
+                        )
+                    ),
+                    'x': core_schema.model_field(
+                        core_schema.dict_schema(core_schema.str_schema(), core_schema.any_schema())
+                    ),
+                }
+            ),
+        )
+    )
+    m = Model()
+    m.__dict__ = {'z': 1, 'y': {'b': 'ü', 'a': 'x'}, 'x': {'q': 1, 'p': [{'n': 2, 'm': 3}]}}
+    m.__pydantic_fields_set__ = set()
+    m.__pydantic_extra__ = None
+    m.__pydantic_private__ = None
+    assert s.to_json(m) == b'{"z":1,"y":{"b":"\xc3\xbc","c":"x"},"x":{"q":1,"p":[{"n":2,"m":3}]}}'
+    assert s.to_json(m, sort_keys=True, ensure_ascii=True) == (
+        b'{"x":{"p":[{"m":3,"n":2}],"q":1},"y":{"b":"\\u00fc","c":"x"},"z":1}'
+    )
+    assert s.to_json(m, sort_keys=True, indent=2) == json.dumps(
+        {'z': 1, 'y': {'b': 'ü', 'c': 'x'}, 'x': {'q': 1, 'p': [{'n': 2, 'm': 3}]}},
+        sort_keys=True,
+        ensure_ascii=False,
+        indent=2,
+    ).encode()
+    assert s.to_json(m, sort_keys=True, exclude={'x'}) == b'{"y":{"b":"\xc3\xbc","c":"x"},"z":1}'
+
+
 def test_to_jsonable_python():
     assert to_jsonable_python([1, 2]) == [1, 2]
     assert to_jsonable_python({1, 2}) == IsList(1, 2, check_order=False)
@@ -464,3 +656,262 @@ def test_json_bytes_hex_invalid():
             'input': wrong_input,
         }
     ]
+
+
+def test_validate_json_lines():
+    v = SchemaValidator(core_schema.typed_dict_schema({'a': core_schema.typed_dict_field(core_schema.int_schema())}))
+    assert v.validate_json_lines('{"a": 1}\n{"a": "2"}\n') == [{'a': 1}, {'a': 2}]
+    assert v.validate_json_lines(b'{"a": 1}\r\n\n  \n{"a": 2}') == [{'a': 1}, {'a': 2}]
+    assert v.validate_json_lines('') == []
+
+
+def test_validate_json_lines_errors():
+    v = SchemaValidator(core_schema.typed_dict_schema({'a': core_schema.typed_dict_field(core_schema.int_schema())}))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json_lines('{"a": 1}\n\n{"a": "x"}\n{bad\n{"a": 3}\n')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': (3, 'a'),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        },
+        {
+            'type': 'json_invalid',
+            'loc': (4,),
+            'msg': 'Invalid JSON: key must be a string at line 1 column 2',
+            'input': '{bad',
+            'ctx': {'error': 'key must be a string at line 1 column 2'},
+        },
+    ]
+
+    with pytest.raises(ValidationError, match=r'JSON input should be string, bytes or bytearray \[type=json_type,'):
+        v.validate_json_lines(123)
+
+
+def test_validate_json_lines_return_errors():
+    v = SchemaValidator(core_schema.typed_dict_schema({'a': core_schema.typed_dict_field(core_schema.int_schema())}))
+    results = v.validate_json_lines('{"a": 1}\n{"a": "x"}\n', return_errors=True)
+    assert len(results) == 2
+    assert results[0] == ({'a': 1}, None)
+    value, error = results[1]
+    assert value is None
+    assert isinstance(error, ValidationError)
+    assert error.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': (2, 'a'),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        }
+    ]
+
+
+def test_validate_json_lines_form_feed():
+    v = SchemaValidator(core_schema.int_schema())
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json_lines('1\n\x0c\n2')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'json_invalid',
+            'loc': (2,),
+            'msg': 'Invalid JSON: expected value at line 1 column 1',
+            'input': '\x0c',
+            'ctx': {'error': 'expected value at line 1 column 1'},
+        }
+    ]
+
+
+def test_validate_json_lines_limits_per_line():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()), {'max_total_items': 2, 'max_errors': 1})
+    assert v.validate_json_lines('[1, 2]\n[3, 4]') == [[1, 2], [3, 4]]
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json_lines('["a"]\n["b"]')
+    assert exc_info.value.error_count() == 1
+
+
+@pytest.mark.parametrize(
+    'input_value',
+    [
+        io.BytesIO(b'[1, 2, 3]'),
+        io.StringIO('[1, 2, 3]'),
+        [b'[1,', ' 2', bytearray(b', 3]')],
+        iter([b'[1', b'', b', 2, 3', b']  \n']),
+        b'[1, 2, 3]',
+    ],
+)
+def test_validate_json_stream(input_value):
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    assert v.validate_json_stream(input_value, chunk_size=2) == [1, 2, 3]
+
+
+@pytest.mark.parametrize('chunk_size', [1, 3, 7, 64])
+def test_validate_json_stream_split_values(chunk_size):
+    data = '[12345, -1.5e3, "ab\\u00e9\\"c", "\u00e9\U0001f600", true, null, {"a": [1, {"b": "x"}]}, [], 7]'
+    v = SchemaValidator(core_schema.list_schema())
+    assert v.validate_json_stream(io.BytesIO(data.encode()), chunk_size=chunk_size) == json.loads(data)
+    assert v.validate_json_stream(io.StringIO(data), chunk_size=chunk_size) == json.loads(data)
+
+
+def test_validate_json_stream_incremental():
+    chunks_read = []
+    items_validated = []
+
+    def chunks():
+        for chunk in (b'[1', b', 2', b', 3]'):
+            chunks_read.append(chunk)
+            yield chunk
+
+    def record(value):
+        items_validated.append((value, len(chunks_read)))
+        return value
+
+    v = SchemaValidator(
+        core_schema.list_schema(core_schema.no_info_after_validator_function(record, core_schema.int_schema()))
+    )
+    assert v.validate_json_stream(chunks()) == [1, 2, 3]
+    # each item is validated as soon as the chunk after it shows it's complete
+    assert items_validated == [(1, 2), (2, 3), (3, 3)]
+
+
+@pytest.mark.parametrize(
+    'data',
+    [
+        '[1, 2',
+        '[1, 2,',
+        '[1, 2,]',
+        '[1 2]',
+        '[1,\n 2,\n  x]',
+        '[1,\n 2] 3',
+        '[1,\n "\\u12"]',
+        '[1,\n 2e]',
+        '',
+        '{"a": 1',
+    ],
+)
+def test_validate_json_stream_invalid_json(data):
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json(data)
+    expected_msg = exc_info.value.errors()[0]['msg']
+
+    for chunk_size in (1, 2, 5, 100):
+        with pytest.raises(ValidationError) as exc_info:
+            v.validate_json_stream(io.StringIO(data), chunk_size=chunk_size)
+        errors = exc_info.value.errors(include_url=False)
+        assert [(e['type'], e['msg']) for e in errors] == [('json_invalid', expected_msg)]
+
+
+def test_validate_json_stream_errors():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json_stream(io.BytesIO(b'[1, "x", 3, "y"]'), chunk_size=3)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': (1,),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        },
+        {
+            'type': 'int_parsing',
+            'loc': (3,),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'y',
+        },
+    ]
+
+    # invalid JSON is reported instead of validation errors, even after validation has stopped
+    with pytest.raises(ValidationError, match=r'Invalid JSON: expected value at line 1 column 13'):
+        v.validate_json_stream([b'[1, "x", 3, x]'], fail_fast=True)
+
+    # JSON which isn't an array is reported as `validate_json` would
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json_stream(io.StringIO('{"a": 1}'), chunk_size=2)
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'list_type', 'loc': (), 'msg': 'Input should be a valid array', 'input': {'a': 1}}
+    ]
+
+    with pytest.raises(ValidationError, match=r'JSON input should be string, bytes or bytearray \[type=json_type,'):
+        v.validate_json_stream([b'[1,', 2])
+
+    with pytest.raises(ValidationError, match=r'JSON input should be string, bytes or bytearray \[type=json_type,'):
+        v.validate_json_stream(123)
+
+    with pytest.raises(ValueError, match='chunk_size must be greater than 0'):
+        v.validate_json_stream(io.BytesIO(b'[]'), chunk_size=0)
+
+
+def test_validate_json_stream_other_schemas():
+    v = SchemaValidator(core_schema.int_schema())
+    assert v.validate_json_stream([b'12', b'3']) == 123
+
+    v = SchemaValidator(core_schema.typed_dict_schema({'a': core_schema.typed_dict_field(core_schema.int_schema())}))
+    assert v.validate_json_stream(io.StringIO('{"a": "1"}'), chunk_size=2) == {'a': 1}
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json_stream([b'{"a"', b': "x"}'])
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': ('a',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        }
+    ]
+
+    v = SchemaValidator(core_schema.tuple_schema([core_schema.int_schema(), core_schema.str_schema()]))
+    assert v.validate_json_stream(io.BytesIO(b'[1, "a"]'), chunk_size=3) == (1, 'a')
+
+
+def test_validate_json_stream_constraints():
+    v = SchemaValidator(
+        core_schema.list_schema(core_schema.int_schema(), min_length=2, max_length=3, fail_fast=True),
+        core_schema.CoreConfig(max_json_depth=2),
+    )
+    assert v.validate_json_stream(io.BytesIO(b'[1, 2]')) == [1, 2]
+
+    stream = io.BytesIO(b'[1, 2, 3, 4]')
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json_stream(stream)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'too_long',
+            'loc': (),
+            'msg': 'List should have at most 3 items after validation, not more',
+            'input': stream,
+            'ctx': {'field_type': 'List', 'max_length': 3, 'actual_length': None},
+        }
+    ]
+
+    with pytest.raises(ValidationError, match=r'List should have at least 2 items after validation, not 1'):
+        v.validate_json_stream(io.BytesIO(b'[1]'))
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json_stream(io.BytesIO(b'["a", "b"]'))
+    assert [e['loc'] for e in exc_info.value.errors()] == [(0,)]
+
+    with pytest.raises(ValidationError, match=r'max_json_depth'):
+        v.validate_json_stream(io.BytesIO(b'[1, [[2]]]'))
+
+
+def test_validate_json_stream_decimal():
+    v = SchemaValidator(core_schema.list_schema(core_schema.decimal_schema()))
+    output = v.validate_json_stream(io.BytesIO(b'[1.10, 2.5e1, 0.1000000000000000055511151231257827]'), chunk_size=4)
+    assert [str(d) for d in output] == ['1.10', '25', '0.1000000000000000055511151231257827']
+
+
+def test_from_json_float_mode():
+    assert from_json('[1.10, {"a": [2.5e1, 3]}, "1.5"]') == [1.1, {'a': [25.0, 3]}, '1.5']
+
+    output = from_json('[1.10, {"a": [2.5e1, 3]}, "1.5"]', float_mode='decimal')
+    assert output == [Decimal('1.10'), {'a': [Decimal('25'), 3]}, '1.5']
+    assert str(output[0]) == '1.10'
+    assert type(output[1]['a'][1]) is int
+
+    assert from_json('0.1000000000000000055511151231257827', float_mode='decimal') == Decimal(
+        '0.1000000000000000055511151231257827'
+    )
+    assert from_json('[1.25, 2.50', float_mode='decimal', allow_partial=True) == [Decimal('1.25'), Decimal('2.50')]
+
+    with pytest.raises(ValueError, match="Invalid float_mode, should be `'float'` or `'decimal'`"):
+        from_json('1.5', float_mode='lossless')
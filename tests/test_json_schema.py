@@ -0,0 +1,260 @@
+import re
+from decimal import Decimal
+from enum import Enum
+
+import pytest
+
+from pydantic_core import SchemaValidator, core_schema
+
+
+@pytest.mark.parametrize(
+    'schema,expected',
+    [
+        (core_schema.any_schema(), {}),
+        (core_schema.none_schema(), {'type': 'null'}),
+        (core_schema.bool_schema(), {'type': 'boolean'}),
+        (core_schema.int_schema(), {'type': 'integer'}),
+        (
+            core_schema.int_schema(multiple_of=5, lt=100, ge=0),
+            {'type': 'integer', 'multipleOf': 5, 'exclusiveMaximum': 100, 'minimum': 0},
+        ),
+        (core_schema.float_schema(), {'type': 'number'}),
+        (core_schema.float_schema(le=1.5, gt=-1), {'type': 'number', 'maximum': 1.5, 'exclusiveMinimum': -1.0}),
+        (
+            core_schema.decimal_schema(ge=Decimal('0.5')),
+            {'anyOf': [{'type': 'number', 'minimum': 0.5}, {'type': 'string'}]},
+        ),
+        (core_schema.str_schema(), {'type': 'string'}),
+        (
+            core_schema.str_schema(min_length=1, max_length=10, pattern=r'^\w+$'),
+            {'type': 'string', 'minLength': 1, 'maxLength': 10, 'pattern': r'^\w+$'},
+        ),
+        (core_schema.bytes_schema(max_length=4), {'type': 'string', 'format': 'binary', 'maxLength': 4}),
+        (core_schema.date_schema(), {'type': 'string', 'format': 'date'}),
+        (core_schema.time_schema(), {'type': 'string', 'format': 'time'}),
+        (core_schema.datetime_schema(), {'type': 'string', 'format': 'date-time'}),
+        (core_schema.timedelta_schema(), {'type': 'string', 'format': 'duration'}),
+        (core_schema.uuid_schema(), {'type': 'string', 'format': 'uuid'}),
+        (core_schema.url_schema(max_length=99), {'type': 'string', 'format': 'uri', 'minLength': 1, 'maxLength': 99}),
+        (core_schema.literal_schema(['a']), {'type': 'string', 'const': 'a'}),
+        (core_schema.literal_schema([1, 2]), {'type': 'integer', 'enum': [1, 2]}),
+        (core_schema.literal_schema(['a', 1, None]), {'enum': ['a', 1, None]}),
+        (
+            core_schema.list_schema(core_schema.int_schema(), min_length=1),
+            {'type': 'array', 'items': {'type': 'integer'}, 'minItems': 1},
+        ),
+        (core_schema.list_schema(), {'type': 'array'}),
+        (
+            core_schema.set_schema(core_schema.str_schema()),
+            {'type': 'array', 'items': {'type': 'string'}, 'uniqueItems': True},
+        ),
+        (
+            core_schema.frozenset_schema(core_schema.str_schema(), max_length=2),
+            {'type': 'array', 'items': {'type': 'string'}, 'maxItems': 2, 'uniqueItems': True},
+        ),
+        (
+            core_schema.tuple_schema([core_schema.int_schema(), core_schema.str_schema()]),
+            {
+                'type': 'array',
+                'prefixItems': [{'type': 'integer'}, {'type': 'string'}],
+                'minItems': 2,
+                'maxItems': 2,
+            },
+        ),
+        (
+            core_schema.tuple_schema([core_schema.int_schema()], variadic_item_index=0),
+            {'type': 'array', 'items': {'type': 'integer'}},
+        ),
+        (
+            core_schema.dict_schema(core_schema.str_schema(), core_schema.int_schema(), max_length=5),
+            {'type': 'object', 'additionalProperties': {'type': 'integer'}, 'maxProperties': 5},
+        ),
+        (
+            core_schema.dict_schema(core_schema.str_schema(pattern='^x'), core_schema.any_schema()),
+            {'type': 'object', 'propertyNames': {'type': 'string', 'pattern': '^x'}, 'additionalProperties': {}},
+        ),
+        (
+            core_schema.nullable_schema(core_schema.int_schema()),
+            {'anyOf': [{'type': 'integer'}, {'type': 'null'}]},
+        ),
+        (
+            core_schema.union_schema([core_schema.int_schema(), core_schema.str_schema()]),
+            {'anyOf': [{'type': 'integer'}, {'type': 'string'}]},
+        ),
+        (
+            core_schema.json_schema(core_schema.int_schema()),
+            {'type': 'string', 'contentMediaType': 'application/json', 'contentSchema': {'type': 'integer'}},
+        ),
+        (
+            core_schema.chain_schema([core_schema.str_schema(), core_schema.int_schema()]),
+            {'type': 'string'},
+        ),
+        (
+            core_schema.lax_or_strict_schema(core_schema.str_schema(), core_schema.int_schema()),
+            {'type': 'string'},
+        ),
+        (
+            core_schema.json_or_python_schema(core_schema.str_schema(), core_schema.int_schema()),
+            {'type': 'string'},
+        ),
+        (
+            core_schema.no_info_after_validator_function(lambda x: x, core_schema.int_schema(gt=1)),
+            {'type': 'integer', 'exclusiveMinimum': 1},
+        ),
+    ],
+)
+def test_json_schema(schema, expected):
+    v = SchemaValidator(schema)
+    assert v.json_schema() == expected
+
+
+def test_enum():
+    class Color(Enum):
+        RED = 'red'
+        BLUE = 'blue'
+
+    v = SchemaValidator(core_schema.enum_schema(Color, list(Color.__members__.values()), sub_type='str'))
+    assert v.json_schema() == {'type': 'string', 'enum': ['red', 'blue'], 'title': 'Color'}
+
+    v = SchemaValidator(core_schema.literal_schema([Color.RED]))
+    assert v.json_schema() == {'type': 'string', 'const': 'red'}
+
+
+def test_typed_dict():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema(), validation_alias='A'),
+                'b': core_schema.typed_dict_field(
+                    core_schema.with_default_schema(core_schema.str_schema(), default='x'), required=False
+                ),
+            },
+            extra_behavior='allow',
+            extras_schema=core_schema.int_schema(),
+        )
+    )
+    assert v.json_schema() == {
+        'type': 'object',
+        'properties': {'A': {'type': 'integer'}, 'b': {'type': 'string', 'default': 'x'}},
+        'required': ['A'],
+        'additionalProperties': {'type': 'integer'},
+    }
+
+
+def test_model():
+    class MyModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    v = SchemaValidator(
+        core_schema.model_schema(
+            MyModel,
+            core_schema.model_fields_schema(
+                {
+                    'a': core_schema.model_field(core_schema.int_schema()),
+                    'b': core_schema.model_field(
+                        core_schema.with_default_schema(core_schema.list_schema(), default_factory=list)
+                    ),
+                },
+                extra_behavior='forbid',
+            ),
+        )
+    )
+    assert v.json_schema() == {
+        'type': 'object',
+        'properties': {'a': {'type': 'integer'}, 'b': {'type': 'array'}},
+        'required': ['a'],
+        'additionalProperties': False,
+        'title': 'MyModel',
+    }
+
+
+def test_recursive_definitions():
+    v = SchemaValidator(
+        core_schema.definitions_schema(
+            core_schema.list_schema(core_schema.definition_reference_schema('Branch')),
+            [
+                core_schema.typed_dict_schema(
+                    {
+                        'children': core_schema.typed_dict_field(
+                            core_schema.list_schema(core_schema.definition_reference_schema('Branch'))
+                        )
+                    },
+                    ref='Branch',
+                )
+            ],
+        )
+    )
+    expected_branch = {
+        'type': 'object',
+        'properties': {'children': {'type': 'array', 'items': {'$ref': '#/$defs/Branch'}}},
+        'required': ['children'],
+    }
+    assert v.json_schema() == {
+        'type': 'array',
+        'items': {'$ref': '#/$defs/Branch'},
+        '$defs': {'Branch': expected_branch},
+    }
+    assert v.json_schema(ref_template='#/components/schemas/{model}')['items'] == {
+        '$ref': '#/components/schemas/Branch'
+    }
+
+
+def test_tagged_union():
+    v = SchemaValidator(
+        core_schema.definitions_schema(
+            core_schema.tagged_union_schema(
+                {
+                    'cat': core_schema.definition_reference_schema('Cat'),
+                    'dog': core_schema.typed_dict_schema(
+                        {'kind': core_schema.typed_dict_field(core_schema.literal_schema(['dog', 'puppy']))}
+                    ),
+                    'puppy': core_schema.typed_dict_schema(
+                        {'kind': core_schema.typed_dict_field(core_schema.literal_schema(['dog', 'puppy']))}
+                    ),
+                },
+                discriminator='kind',
+            ),
+            [
+                core_schema.typed_dict_schema(
+                    {'kind': core_schema.typed_dict_field(core_schema.literal_schema(['cat']))}, ref='Cat'
+                ),
+                # referenced twice so the definition isn't inlined
+                core_schema.list_schema(core_schema.definition_reference_schema('Cat'), ref='Cats'),
+            ],
+        )
+    )
+    schema = v.json_schema()
+    assert schema['oneOf'] == [
+        {'$ref': '#/$defs/Cat'},
+        {
+            'type': 'object',
+            'properties': {'kind': {'type': 'string', 'enum': ['dog', 'puppy']}},
+            'required': ['kind'],
+        },
+    ]
+    assert schema['discriminator'] == {'propertyName': 'kind', 'mapping': {'cat': '#/$defs/Cat'}}
+
+
+def test_tagged_union_function():
+    v = SchemaValidator(
+        core_schema.tagged_union_schema(
+            {'int': core_schema.int_schema(), 'str': core_schema.str_schema()},
+            discriminator=lambda x: type(x).__name__,
+        )
+    )
+    assert v.json_schema() == {'oneOf': [{'type': 'integer'}, {'type': 'string'}]}
+
+
+@pytest.mark.parametrize(
+    'schema',
+    [
+        core_schema.no_info_plain_validator_function(lambda x: x),
+        core_schema.is_instance_schema(int),
+        core_schema.callable_schema(),
+        core_schema.list_schema(core_schema.is_subclass_schema(int)),
+    ],
+)
+def test_unsupported(schema):
+    v = SchemaValidator(schema)
+    with pytest.raises(TypeError, match=re.escape('Cannot generate a JSON Schema for `')):
+        v.json_schema()
@@ -0,0 +1,259 @@
+import re
+from datetime import datetime, timezone
+from decimal import Decimal
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema, to_msgpack
+
+
+@pytest.mark.parametrize(
+    'schema,input_value,expected',
+    [
+        (core_schema.none_schema(), b'\xc0', None),
+        (core_schema.bool_schema(), b'\xc3', True),
+        (core_schema.int_schema(), b'\x01', 1),
+        (core_schema.int_schema(), b'\xff', -1),
+        (core_schema.int_schema(), b'\xd1\xfc\x18', -1000),
+        (core_schema.int_schema(), b'\xcf\xff\xff\xff\xff\xff\xff\xff\xff', 2**64 - 1),
+        (core_schema.float_schema(), b'\xcb?\xf8\x00\x00\x00\x00\x00\x00', 1.5),
+        (core_schema.float_schema(), b'\xca?\xc0\x00\x00', 1.5),
+        (core_schema.float_schema(), b'\x02', 2.0),
+        (core_schema.str_schema(), b'\xa3foo', 'foo'),
+        (core_schema.bytes_schema(), b'\xc4\x03\x00\x01\xff', b'\x00\x01\xff'),
+        (core_schema.decimal_schema(), b'\xa41.25', Decimal('1.25')),
+        (core_schema.list_schema(core_schema.int_schema()), b'\x92\x01\x02', [1, 2]),
+        (core_schema.tuple_schema([core_schema.int_schema(), core_schema.str_schema()]), b'\x92\x01\xa1a', (1, 'a')),
+        (core_schema.set_schema(core_schema.int_schema()), b'\x92\x01\x02', {1, 2}),
+        (core_schema.dict_schema(core_schema.str_schema(), core_schema.int_schema()), b'\x81\xa1a\x01', {'a': 1}),
+        (core_schema.dict_schema(core_schema.int_schema(), core_schema.str_schema()), b'\x81\x01\xa1a', {1: 'a'}),
+        (core_schema.any_schema(), b'\x82\xa1a\x92\x01\xc0\xa1b\xc4\x01x', {'a': [1, None], 'b': b'x'}),
+    ],
+)
+def test_types(schema, input_value, expected):
+    v = SchemaValidator(schema)
+    output = v.validate_msgpack(input_value)
+    assert output == expected
+    assert type(output) is type(expected)
+
+
+@pytest.mark.parametrize('input_value', [b'\x93\x01\x02\x03', bytearray(b'\x93\x01\x02\x03')])
+def test_input_types(input_value):
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    assert v.validate_msgpack(input_value) == [1, 2, 3]
+
+
+@pytest.mark.parametrize('input_value', ['[1, 2, 3]', [1, 2, 3]])
+def test_input_type_invalid(input_value):
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    with pytest.raises(ValidationError, match=r'MessagePack input should be bytes or bytearray \[type=msgpack_type,'):
+        v.validate_msgpack(input_value)
+
+
+@pytest.mark.parametrize(
+    'input_value,error',
+    [
+        (b'', 'unexpected end of data at byte 0'),
+        (b'\x92\x01', 'unexpected end of data at byte 2'),
+        (b'\xc1', 'invalid marker 0xc1 at byte 0'),
+        (b'\x01\x02', 'trailing data at byte 1'),
+        (b'\xa2a\xff', 'invalid UTF-8 in string at byte 2'),
+        (b'\x81\x90\x01', 'map keys must not be arrays or maps at byte 1'),
+        (b'\xd4\x01\x00', 'unsupported extension type 1 at byte 1'),
+        (b'\xd5\xff\x00\x00', 'invalid timestamp at byte 1'),
+        # outside the years python supports
+        (
+            b'\xc7\x0c\xff' + (0).to_bytes(4, 'big') + (-62135596801).to_bytes(8, 'big', signed=True),
+            'invalid timestamp at byte 2',
+        ),
+        (
+            b'\xc7\x0c\xff' + (0).to_bytes(4, 'big') + (253402300800).to_bytes(8, 'big', signed=True),
+            'invalid timestamp at byte 2',
+        ),
+        (
+            b'\xc7\x0c\xff' + (0).to_bytes(4, 'big') + (10**12).to_bytes(8, 'big', signed=True),
+            'invalid timestamp at byte 2',
+        ),
+        (b'\x91' * 201 + b'\x01', 'recursion limit exceeded at byte 201'),
+    ],
+)
+def test_msgpack_invalid(input_value, error):
+    v = SchemaValidator(core_schema.any_schema())
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_msgpack(input_value)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'msgpack_invalid',
+            'loc': (),
+            'msg': f'Invalid MessagePack: {error}',
+            'input': input_value,
+            'ctx': {'error': error},
+        }
+    ]
+
+
+def test_bytes_stay_bytes():
+    v = SchemaValidator(core_schema.str_schema())
+    assert v.validate_msgpack(b'\xc4\x03foo') == 'foo'
+    with pytest.raises(ValidationError, match=r'Input should be a valid string \[type=string_type,'):
+        v.validate_msgpack(b'\xc4\x03foo', strict=True)
+    with pytest.raises(ValidationError, match=r'Input should be a valid string, unable to parse raw data as a unicode'):
+        v.validate_msgpack(b'\xc4\x01\xff')
+
+    v = SchemaValidator(core_schema.bytes_schema(strict=True))
+    assert v.validate_msgpack(b'\xc4\x03foo') == b'foo'
+    with pytest.raises(ValidationError, match=r'Input should be a valid bytes \[type=bytes_type,'):
+        v.validate_msgpack(b'\xa3foo')
+
+    v = SchemaValidator(core_schema.bytes_schema())
+    assert v.validate_msgpack(b'\xa3foo') == b'foo'
+    assert v.validate_msgpack(to_msgpack(b'\xff')) == b'\xff'
+
+
+def test_ints_stay_ints():
+    v = SchemaValidator(core_schema.int_schema())
+    assert v.validate_msgpack(b'\xa242') == 42
+    assert v.validate_msgpack(b'\xcb@E\x00\x00\x00\x00\x00\x00') == 42
+    with pytest.raises(ValidationError, match=r'Input should be a valid integer \[type=int_type,'):
+        v.validate_msgpack(b'\xa242', strict=True)
+    with pytest.raises(ValidationError, match=r'Input should be a valid integer \[type=int_type,'):
+        v.validate_msgpack(b'\xc3', strict=True)
+
+
+def test_timestamp():
+    v = SchemaValidator(core_schema.datetime_schema(strict=True))
+    expected = datetime(2024, 1, 1, tzinfo=timezone.utc)
+    # timestamp 32
+    assert v.validate_msgpack(b'\xd6\xff\x65\x92\x00\x80') == expected
+    # timestamp 64, with 123456789 nanoseconds truncated to microseconds
+    nanoseconds = 123_456_789
+    data = ((nanoseconds << 34) | 1704067200).to_bytes(8, 'big')
+    assert v.validate_msgpack(b'\xd7\xff' + data) == expected.replace(microsecond=123456)
+    # timestamp 96, which supports dates before the epoch
+    data = (0).to_bytes(4, 'big') + (-86400).to_bytes(8, 'big', signed=True)
+    assert v.validate_msgpack(b'\xc7\x0c\xff' + data) == datetime(1969, 12, 31, tzinfo=timezone.utc)
+    data = (999_999_000).to_bytes(4, 'big') + (-1).to_bytes(8, 'big', signed=True)
+    assert v.validate_msgpack(b'\xc7\x0c\xff' + data) == datetime(
+        1969, 12, 31, 23, 59, 59, 999999, tzinfo=timezone.utc
+    )
+    # the largest timestamp 64 value
+    data = (2**34 - 1).to_bytes(8, 'big')
+    assert v.validate_msgpack(b'\xd7\xff' + data) == datetime(2514, 5, 30, 1, 53, 3, tzinfo=timezone.utc)
+    # the first and last datetimes python supports
+    data = (0).to_bytes(4, 'big') + (-62135596800).to_bytes(8, 'big', signed=True)
+    assert v.validate_msgpack(b'\xc7\x0c\xff' + data) == datetime(1, 1, 1, tzinfo=timezone.utc)
+    data = (999_999_999).to_bytes(4, 'big') + (253402300799).to_bytes(8, 'big', signed=True)
+    assert v.validate_msgpack(b'\xc7\x0c\xff' + data) == datetime(
+        9999, 12, 31, 23, 59, 59, 999999, tzinfo=timezone.utc
+    )
+
+    # strings are allowed as with JSON
+    assert v.validate_msgpack(b'\xb42024-01-01T00:00:00Z') == expected
+    assert SchemaValidator(core_schema.any_schema()).validate_msgpack(b'\xd6\xff\x65\x92\x00\x80') == expected
+
+
+def test_model_round_trip():
+    class Model:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    schema = core_schema.model_schema(
+        Model,
+        core_schema.model_fields_schema(
+            {
+                'a': core_schema.model_field(core_schema.int_schema()),
+                'b': core_schema.model_field(core_schema.bytes_schema(), serialization_alias='B'),
+                'c': core_schema.model_field(
+                    core_schema.list_schema(core_schema.datetime_schema()),
+                    validation_alias=['C', 0],
+                ),
+            }
+        ),
+    )
+    v = SchemaValidator(schema)
+    m = v.validate_msgpack(to_msgpack({'a': 1, 'b': b'foo', 'C': [['2024-01-01T00:00:00Z']]}), strict=True)
+    assert m.__dict__ == {'a': 1, 'b': b'foo', 'c': [datetime(2024, 1, 1, tzinfo=timezone.utc)]}
+
+
+def test_error_details():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {'x': core_schema.typed_dict_field(core_schema.list_schema(core_schema.int_schema()))}
+        )
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_msgpack(b'\x81\xa1x\x92\x01\xc4\x01z')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_type',
+            'loc': ('x', 1),
+            'msg': 'Input should be a valid integer',
+            'input': b'z',
+        }
+    ]
+    assert re.search(r"input_value=b'z', input_type=bytes", str(exc_info.value))
+
+
+def test_non_string_keys():
+    v = SchemaValidator(core_schema.dict_schema(core_schema.int_schema(), core_schema.int_schema()))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_msgpack(b'\x82\x01\x02\xa1x\x03')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': ('x', '[key]'),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        }
+    ]
+
+    v = SchemaValidator(core_schema.dict_schema(core_schema.any_schema(), core_schema.int_schema()))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_msgpack(b'\x83\xc0\xa1a\xc3\xa1b\xcb?\xf8\x00\x00\x00\x00\x00\x00\xa1c')
+    assert [e['loc'] for e in exc_info.value.errors()] == [('None',), ('True',), ('1.5',)]
+
+    # timestamp keys are rendered in ISO 8601 format
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_msgpack(b'\x81\xd6\xff\x65\x92\x00\x80\xa1x')
+    assert [e['loc'] for e in exc_info.value.errors()] == [('2024-01-01T00:00:00Z',)]
+
+
+def test_info_mode():
+    def f(input_value, info):
+        return f'{input_value} mode={info.mode}'
+
+    v = SchemaValidator(core_schema.with_info_after_validator_function(f, core_schema.str_schema()))
+    assert v.validate_msgpack(b'\xa3foo') == 'foo mode=msgpack'
+
+
+def test_json_or_python():
+    v = SchemaValidator(
+        core_schema.json_or_python_schema(
+            json_schema=core_schema.str_schema(), python_schema=core_schema.int_schema()
+        )
+    )
+    assert v.validate_msgpack(b'\xa3foo') == 'foo'
+
+
+def test_generator():
+    v = SchemaValidator(core_schema.generator_schema(core_schema.int_schema()))
+    gen = v.validate_msgpack(b'\x92\x01\xa1x')
+    assert next(gen) == 1
+    with pytest.raises(ValidationError, match=r'Input should be a valid integer'):
+        next(gen)
+
+
+def test_arguments():
+    v = SchemaValidator(
+        core_schema.arguments_schema(
+            [
+                core_schema.arguments_parameter('a', core_schema.int_schema()),
+                core_schema.arguments_parameter('b', core_schema.bytes_schema(), mode='keyword_only'),
+            ]
+        )
+    )
+    assert v.validate_msgpack(b'\x82\xa1a\x01\xa1b\xc4\x01x') == ((), {'a': 1, 'b': b'x'})
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_msgpack(b'\x83\xa1a\x01\xa1b\xc4\x01x\x01\x02')
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'invalid_key', 'loc': (1,), 'msg': 'Keys should be strings', 'input': 1}
+    ]
@@ -0,0 +1,155 @@
+import re
+
+import pytest
+
+from pydantic_core import SchemaError, SchemaSerializer, SchemaValidator, ValidationError, core_schema
+from pydantic_core._pydantic_core import _recursion_limit
+
+
+def branches_schema(schema):
+    return core_schema.definitions_schema(
+        schema,
+        [
+            core_schema.typed_dict_schema(
+                {
+                    'name': core_schema.typed_dict_field(core_schema.str_schema()),
+                    'sub': core_schema.typed_dict_field(
+                        core_schema.nullable_schema(core_schema.definition_reference_schema('Branch'))
+                    ),
+                },
+                ref='Branch',
+            )
+        ],
+    )
+
+
+@pytest.fixture(scope='module')
+def branch_schema():
+    return branches_schema(core_schema.definition_reference_schema('Branch'))
+
+
+def nested(depth):
+    data = {'name': 'leaf', 'sub': None}
+    for _ in range(depth - 1):
+        data = {'name': 'branch', 'sub': data}
+    return data
+
+
+def test_recursion_limit_lower(branch_schema):
+    v = SchemaValidator(branch_schema, core_schema.CoreConfig(recursion_limit=5))
+    assert v.validate_python(nested(5)) == nested(5)
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(nested(6))
+    assert exc_info.value.errors(include_url=False)[0]['type'] == 'recursion_loop'
+
+
+def test_recursion_limit_higher(branch_schema):
+    depth = _recursion_limit + 10
+
+    v = SchemaValidator(branch_schema)
+    with pytest.raises(ValidationError, match='Recursion error - cyclic reference detected'):
+        v.validate_python(nested(depth))
+
+    v = SchemaValidator(branch_schema, core_schema.CoreConfig(recursion_limit=depth))
+    assert v.validate_python(nested(depth)) == nested(depth)
+
+
+@pytest.mark.parametrize(
+    'config,message',
+    [
+        (core_schema.CoreConfig(recursion_limit=0), '`recursion_limit` must be greater than 0'),
+        (
+            core_schema.CoreConfig(recursion_limit=_recursion_limit * 4 + 1),
+            f'`recursion_limit` must be at most {_recursion_limit * 4}',
+        ),
+        (core_schema.CoreConfig(recursion_limit=2**16), f'`recursion_limit` must be at most {_recursion_limit * 4}'),
+        (
+            core_schema.CoreConfig(cyclic_references='foobar'),
+            'Invalid cyclic_references: `foobar`, expected `error` or `reuse`',
+        ),
+    ],
+)
+def test_invalid_config(branch_schema, config, message):
+    with pytest.raises(SchemaError, match=re.escape(message)):
+        SchemaValidator(branch_schema, config)
+    with pytest.raises(SchemaError, match=re.escape(message)):
+        SchemaSerializer(branch_schema, config)
+
+
+def test_recursion_limit_recovers():
+    # the object which exceeded the limit can be validated again once the depth is back under it
+    item_schema = core_schema.with_default_schema(
+        core_schema.definition_reference_schema('Branch'), default=None, on_error='default'
+    )
+    v = SchemaValidator(branches_schema(core_schema.list_schema(item_schema)), core_schema.CoreConfig(recursion_limit=3))
+    data = nested(4)
+    leaf = data['sub']['sub']['sub']
+    assert v.validate_python([data, leaf]) == [None, leaf]
+
+
+def test_shared_references():
+    shared = {'name': 'shared', 'sub': None}
+    data = {'name': 'root', 'sub': {'name': 'branch', 'sub': shared}}
+    branch_ref = core_schema.definition_reference_schema('Branch')
+    schema = branches_schema(core_schema.tuple_schema([branch_ref, branch_ref]))
+
+    v = SchemaValidator(schema)
+    first, second = v.validate_python((shared, data))
+    assert first == shared
+    assert second['sub']['sub'] is not first
+
+    v = SchemaValidator(schema, core_schema.CoreConfig(cyclic_references='reuse'))
+    first, second = v.validate_python((shared, data))
+    assert first == shared
+    assert second['sub']['sub'] is first
+
+    # outputs are only reused within a single call
+    assert v.validate_python((shared, data))[0] is not first
+
+
+def test_reuse_cycles(branch_schema):
+    data = {'name': 'root', 'sub': None}
+    data['sub'] = data
+
+    for config in core_schema.CoreConfig(), core_schema.CoreConfig(cyclic_references='reuse'):
+        v = SchemaValidator(branch_schema, config)
+        with pytest.raises(ValidationError) as exc_info:
+            v.validate_python(data)
+        assert exc_info.value.errors(include_url=False)[0]['type'] == 'recursion_loop'
+
+
+def test_serializer_recursion_limit(branch_schema):
+    s = SchemaSerializer(branch_schema, core_schema.CoreConfig(recursion_limit=5))
+    assert s.to_python(nested(5)) == nested(5)
+    with pytest.raises(ValueError, match=re.escape('Circular reference detected (depth exceeded)')):
+        s.to_python(nested(6))
+    with pytest.raises(ValueError, match=re.escape('Circular reference detected (depth exceeded)')):
+        s.to_json(nested(6))
+
+    depth = _recursion_limit + 10
+    s = SchemaSerializer(branch_schema, core_schema.CoreConfig(recursion_limit=depth))
+    assert s.to_python(nested(depth)) == nested(depth)
+
+
+def test_serializer_shared_references():
+    shared = {'name': 'shared', 'sub': None}
+    data = [{'name': 'a', 'sub': shared}, {'name': 'b', 'sub': shared}]
+    schema = branches_schema(core_schema.list_schema(core_schema.definition_reference_schema('Branch')))
+
+    s = SchemaSerializer(schema)
+    a, b = s.to_python(data)
+    assert a['sub'] == b['sub']
+    assert a['sub'] is not b['sub']
+
+    s = SchemaSerializer(schema, core_schema.CoreConfig(cyclic_references='reuse'))
+    a, b = s.to_python(data)
+    assert a['sub'] == shared
+    assert a['sub'] is b['sub']
+    assert s.to_json(data) == (
+        b'[{"name":"a","sub":{"name":"shared","sub":null}},{"name":"b","sub":{"name":"shared","sub":null}}]'
+    )
+
+    # with include or exclude the output depends on the location, so isn't reused
+    a, b = s.to_python(data, exclude={0: {'sub': {'name'}}})
+    assert a['sub'] == {'sub': None}
+    assert b['sub'] == shared
@@ -0,0 +1,279 @@
+import io
+import re
+
+import pytest
+
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
+
+
+def limit_error(limit, limit_value, input_value):
+    return {
+        'type': 'resource_limit_exceeded',
+        'loc': (),
+        'msg': f'Input exceeds the {limit} limit of {limit_value}',
+        'input': input_value,
+        'ctx': {'limit': limit, 'limit_value': limit_value},
+    }
+
+
+@pytest.mark.parametrize('mode', ['python', 'json'])
+def test_max_total_items(mode):
+    v = SchemaValidator(
+        core_schema.list_schema(core_schema.list_schema(core_schema.int_schema())),
+        core_schema.CoreConfig(max_total_items=6),
+    )
+    validate = v.validate_python if mode == 'python' else v.validate_json
+
+    input_value = [[1, 2], [3, 4]]
+    assert validate(input_value if mode == 'python' else '[[1, 2], [3, 4]]') == input_value
+
+    with pytest.raises(ValidationError) as exc_info:
+        validate([[1, 2], [3, 4, 5]] if mode == 'python' else '[[1, 2], [3, 4, 5]]')
+    # JSON is counted in full before it's parsed, so the error is for the whole input
+    error_input = [3, 4, 5] if mode == 'python' else '[[1, 2], [3, 4, 5]]'
+    assert exc_info.value.errors(include_url=False) == [limit_error('max_total_items', 6, error_input)]
+
+
+@pytest.mark.parametrize(
+    'schema,input_value',
+    [
+        (core_schema.list_schema(), [1, 2, 3, 4]),
+        (core_schema.set_schema(core_schema.int_schema()), {1, 2, 3, 4}),
+        (core_schema.frozenset_schema(core_schema.int_schema()), frozenset({1, 2, 3, 4})),
+        (core_schema.tuple_schema([core_schema.int_schema()], variadic_item_index=0), (1, 2, 3, 4)),
+        (core_schema.dict_schema(core_schema.int_schema(), core_schema.int_schema()), {1: 1, 2: 2, 3: 3, 4: 4}),
+    ],
+)
+def test_max_total_items_containers(schema, input_value):
+    v = SchemaValidator(schema, core_schema.CoreConfig(max_total_items=4))
+    assert v.validate_python(input_value) == input_value
+
+    v = SchemaValidator(schema, core_schema.CoreConfig(max_total_items=3))
+    with pytest.raises(ValidationError, match=r'Input exceeds the max_total_items limit of 3 \[type=resource_limit'):
+        v.validate_python(input_value)
+
+
+def test_fail_fast():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()), core_schema.CoreConfig(max_total_items=2))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(['x', 'y', 'z'])
+    # earlier errors are dropped, since validation stops as soon as the limit is exceeded
+    assert exc_info.value.errors(include_url=False) == [limit_error('max_total_items', 2, ['x', 'y', 'z'])]
+    assert v.isinstance_python(['x', 'y', 'z']) is False
+
+
+def test_not_caught():
+    limits = core_schema.CoreConfig(max_total_items=2)
+    list_schema = core_schema.list_schema(core_schema.int_schema())
+
+    v = SchemaValidator(core_schema.union_schema([list_schema, core_schema.str_schema()]), limits)
+    with pytest.raises(ValidationError, match='resource_limit_exceeded'):
+        v.validate_python([1, 2, 3])
+
+    v = SchemaValidator(core_schema.with_default_schema(list_schema, default=[], on_error='default'), limits)
+    with pytest.raises(ValidationError, match='resource_limit_exceeded'):
+        v.validate_python([1, 2, 3])
+
+    custom_error_schema = core_schema.custom_error_schema(list_schema, 'my_error', custom_error_message='My error')
+    v = SchemaValidator(custom_error_schema, limits)
+    with pytest.raises(ValidationError, match='resource_limit_exceeded'):
+        v.validate_python([1, 2, 3])
+
+
+@pytest.mark.parametrize('mode', ['python', 'json'])
+def test_max_total_string_bytes(mode):
+    schema = core_schema.list_schema(core_schema.str_schema())
+    v = SchemaValidator(schema, core_schema.CoreConfig(max_total_string_bytes=6))
+    validate = v.validate_python if mode == 'python' else v.validate_json
+
+    # 'é' is two bytes when encoded as UTF-8
+    assert validate(['ab', 'cé'] if mode == 'python' else '["ab", "c\\u00e9"]') == ['ab', 'cé']
+
+    with pytest.raises(ValidationError) as exc_info:
+        validate(['ab', 'cé', 'é'] if mode == 'python' else '["ab", "c\\u00e9", "\\u00e9"]')
+    error_input = 'é' if mode == 'python' else '["ab", "c\\u00e9", "\\u00e9"]'
+    assert exc_info.value.errors(include_url=False) == [limit_error('max_total_string_bytes', 6, error_input)]
+
+    v = SchemaValidator(core_schema.str_schema(max_length=10), core_schema.CoreConfig(max_total_string_bytes=6))
+    with pytest.raises(ValidationError, match='resource_limit_exceeded'):
+        v.validate_python('abcdefg')
+
+
+@pytest.mark.parametrize('mode', ['python', 'json'])
+@pytest.mark.parametrize(
+    'schema,input_value,json_input,total_items',
+    [
+        (core_schema.any_schema(), [1, 2, [3, 4]], '[1, 2, [3, 4]]', 5),
+        (core_schema.list_schema(), [1, 2, [3, 4]], '[1, 2, [3, 4]]', 5),
+        (core_schema.list_schema(), (1, 2, [3, 4]), '[1, 2, [3, 4]]', 5),
+        (core_schema.dict_schema(), {'a': [1, 2, 3]}, '{"a": [1, 2, 3]}', 4),
+    ],
+)
+def test_max_total_items_unvalidated(schema, input_value, json_input, total_items, mode):
+    # values which aren't validated still count, along with everything nested in them
+    def validate(max_total_items):
+        v = SchemaValidator(schema, core_schema.CoreConfig(max_total_items=max_total_items))
+        return v.validate_python(input_value) if mode == 'python' else v.validate_json(json_input)
+
+    assert validate(total_items) == (list(input_value) if isinstance(input_value, tuple) else input_value)
+    with pytest.raises(ValidationError, match=r'Input exceeds the max_total_items limit'):
+        validate(total_items - 1)
+
+
+@pytest.mark.parametrize('mode', ['python', 'json'])
+def test_max_total_string_bytes_unvalidated(mode):
+    v = SchemaValidator(core_schema.any_schema(), core_schema.CoreConfig(max_total_string_bytes=6))
+    validate = v.validate_python if mode == 'python' else v.validate_json
+
+    # keys count as strings too
+    assert validate({'ab': ['cé', 'd']} if mode == 'python' else '{"ab": ["c\\u00e9", "d"]}') == {'ab': ['cé', 'd']}
+    with pytest.raises(ValidationError, match=r'Input exceeds the max_total_string_bytes limit of 6'):
+        validate({'ab': ['cé', 'de']} if mode == 'python' else '{"ab": ["c\\u00e9", "de"]}')
+
+
+def test_json_counted_before_parsing():
+    v = SchemaValidator(core_schema.any_schema(), core_schema.CoreConfig(max_total_items=3, max_total_string_bytes=3))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('[1,2,3,4,5,"abcdefghij"]')
+    assert exc_info.value.errors(include_url=False) == [
+        limit_error('max_total_items', 3, '[1,2,3,4,5,"abcdefghij"]')
+    ]
+
+    # escapes count as the bytes they decode to, here a pair of escapes for one four byte character
+    v = SchemaValidator(core_schema.any_schema(), core_schema.CoreConfig(max_total_string_bytes=4))
+    assert v.validate_json('["\\ud83d\\ude00"]') == ['\U0001f600']
+    assert v.validate_json('"a\\n\\"b"') == 'a\n"b'
+    with pytest.raises(ValidationError, match=r'max_total_string_bytes'):
+        v.validate_json('["\\ud83d\\ude00", "a"]')
+
+
+def test_max_total_items_json_schema():
+    # JSON in a python input counts towards the same totals
+    v = SchemaValidator(core_schema.list_schema(core_schema.json_schema()), core_schema.CoreConfig(max_total_items=5))
+    assert v.validate_python(['[1, 2]', '[3]']) == [[1, 2], [3]]
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(['[1, 2]', '[3, 4]'])
+    assert exc_info.value.errors(include_url=False) == [limit_error('max_total_items', 5, '[3, 4]')]
+
+
+def test_max_total_items_json_stream():
+    v = SchemaValidator(core_schema.list_schema(core_schema.any_schema()), core_schema.CoreConfig(max_total_items=4))
+    assert v.validate_json_stream(io.BytesIO(b'[1, [2, 3]]')) == [1, [2, 3]]
+    with pytest.raises(ValidationError, match=r'Input exceeds the max_total_items limit of 4'):
+        v.validate_json_stream(io.BytesIO(b'[1, [2, 3, 4]]'))
+
+
+def test_max_total_items_cyclic():
+    v = SchemaValidator(core_schema.any_schema(), core_schema.CoreConfig(max_total_string_bytes=2))
+    value = ['a']
+    value.append(value)
+    assert v.validate_python(value) is value
+
+
+@pytest.mark.parametrize(
+    'mode,input_value,expected',
+    [
+        ('smart', ['a', 'b', 'c'], ['a', 'b', 'c']),
+        ('smart', ['1', '2', '3'], ['1', '2', '3']),
+        ('left_to_right', ['a', 'b', 'c'], ['a', 'b', 'c']),
+        ('left_to_right', ['1', '2', '3'], [1, 2, 3]),
+    ],
+)
+def test_union(mode, input_value, expected):
+    # only the selected choice counts towards the limits, not every choice tried
+    schema = core_schema.union_schema(
+        [core_schema.list_schema(core_schema.int_schema()), core_schema.list_schema(core_schema.str_schema())],
+        mode=mode,
+    )
+    v = SchemaValidator(schema, core_schema.CoreConfig(max_total_items=3, max_total_string_bytes=3))
+    assert v.validate_python(input_value) == expected
+
+    with pytest.raises(ValidationError, match='Input exceeds the max_total_items limit of 3'):
+        v.validate_python(['a', 'b', 'c', 'd'])
+
+
+def test_max_json_depth():
+    v = SchemaValidator(core_schema.any_schema(), core_schema.CoreConfig(max_json_depth=2))
+    assert v.validate_json('[{"a": 1}]') == [{'a': 1}]
+    # brackets in strings don't count
+    assert v.validate_json('[{"a": "[[[{{{"}]') == [{'a': '[[[{{{'}]
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('[{"a": [1]}]')
+    assert exc_info.value.errors(include_url=False) == [limit_error('max_json_depth', 2, '[{"a": [1]}]')]
+
+    # invalid JSON is still reported by the parser
+    with pytest.raises(ValidationError, match=r'Invalid JSON: EOF while parsing a list'):
+        v.validate_json('[[')
+
+
+def test_max_json_depth_json_schema():
+    v = SchemaValidator(core_schema.list_schema(core_schema.json_schema()), core_schema.CoreConfig(max_json_depth=1))
+    assert v.validate_python(['[1]']) == [[1]]
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(['[[1]]'])
+    assert exc_info.value.errors(include_url=False) == [limit_error('max_json_depth', 1, '[[1]]')]
+
+
+def test_validate_many():
+    v = SchemaValidator(core_schema.list_schema(), core_schema.CoreConfig(max_total_items=2))
+    output, errors = v.validate_many([[1, 2], [3, 4], [5, 6, 7]], on_error='collect')
+    assert output == [[1, 2], [3, 4]]
+    assert [(index, e.errors(include_url=False)[0]['type']) for index, e in errors] == [(2, 'resource_limit_exceeded')]
+
+
+@pytest.mark.parametrize(
+    'schema,input_value,expected_locs',
+    [
+        (core_schema.list_schema(core_schema.int_schema()), ['a', 1, 'b', 'c', 'd'], [(0,), (2,)]),
+        (core_schema.set_schema(core_schema.int_schema()), ['a', 1, 'b', 'c', 'd'], [(0,), (2,)]),
+        (core_schema.frozenset_schema(core_schema.int_schema()), ['a', 1, 'b', 'c', 'd'], [(0,), (2,)]),
+        (
+            core_schema.dict_schema(core_schema.str_schema(), core_schema.int_schema()),
+            {'a': 'x', 'b': 1, 'c': 'y', 'd': 'z'},
+            [('a',), ('c',)],
+        ),
+    ],
+)
+def test_max_errors(schema, input_value, expected_locs):
+    v = SchemaValidator(schema, core_schema.CoreConfig(max_errors=2))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(input_value)
+    assert [e['loc'] for e in exc_info.value.errors()] == expected_locs
+    assert exc_info.value.error_count() == 2
+
+
+def test_max_errors_nested():
+    v = SchemaValidator(
+        core_schema.list_schema(core_schema.list_schema(core_schema.int_schema())),
+        core_schema.CoreConfig(max_errors=3),
+    )
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([['a', 'b'], ['c', 'd'], ['e']])
+    # each inner list stops at the limit, the errors found in total are then truncated to it
+    assert [e['loc'] for e in exc_info.value.errors()] == [(0, 0), (0, 1), (1, 0)]
+
+    # below the limit, all errors are reported
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python([['a'], [1, 'b']])
+    assert [e['loc'] for e in exc_info.value.errors()] == [(0, 0), (1, 1)]
+
+
+@pytest.mark.parametrize('key', ['max_total_items', 'max_total_string_bytes', 'max_json_depth', 'max_errors'])
+def test_invalid_config(key):
+    with pytest.raises(SchemaError, match=re.escape(f'`{key}` must be greater than 0')):
+        SchemaValidator(core_schema.any_schema(), core_schema.CoreConfig(**{key: 0}))
+
+
+def test_max_errors_union():
+    v = SchemaValidator(
+        core_schema.union_schema(
+            [core_schema.list_schema(core_schema.int_schema()), core_schema.list_schema(core_schema.float_schema())]
+        ),
+        core_schema.CoreConfig(max_errors=2, max_total_items=5),
+    )
+    # each choice stops at the limit independently of the resource limits, the union's errors are then truncated
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(['a', 'b', 'c'])
+    assert [e['loc'] for e in exc_info.value.errors()] == [('list[int]', 0), ('list[int]', 1)]
+    assert v.validate_python([1, 2, 3, 4, 5]) == [1, 2, 3, 4, 5]
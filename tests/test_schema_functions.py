@@ -65,6 +65,7 @@ all_schema_functions = [
     (core_schema.time_schema, args(), {'type': 'time', 'microseconds_precision': 'truncate'}),
     (core_schema.datetime_schema, args(), {'type': 'datetime', 'microseconds_precision': 'truncate'}),
     (core_schema.timedelta_schema, args(), {'type': 'timedelta', 'microseconds_precision': 'truncate'}),
+    (core_schema.timezone_schema, args(), {'type': 'timezone'}),
     (
         core_schema.time_schema,
         args(microseconds_precision='error'),
@@ -288,6 +289,15 @@ all_schema_functions = [
         {'type': 'dataclass', 'schema': {'type': 'int'}, 'fields': ['foobar'], 'cls': MyDataclass, 'slots': True},
     ),
     (core_schema.uuid_schema, args(), {'type': 'uuid'}),
+    (core_schema.ip_address_schema, args(), {'type': 'ip-address'}),
+    (core_schema.ip_network_schema, args(version=4), {'type': 'ip-network', 'version': 4}),
+    (core_schema.ip_interface_schema, args(strict=True), {'type': 'ip-interface', 'strict': True}),
+    (core_schema.path_schema, args(), {'type': 'path'}),
+    (
+        core_schema.path_schema,
+        args(absolute=True, allowed_suffixes=['.txt'], is_file=True),
+        {'type': 'path', 'absolute': True, 'allowed_suffixes': ['.txt'], 'is_file': True},
+    ),
     (core_schema.decimal_schema, args(), {'type': 'decimal'}),
     (core_schema.decimal_schema, args(multiple_of=5, gt=1.2), {'type': 'decimal', 'multiple_of': 5, 'gt': 1.2}),
     (core_schema.complex_schema, args(), {'type': 'complex'}),
@@ -0,0 +1,69 @@
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+
+def test_validate_many():
+    v = SchemaValidator(core_schema.int_schema())
+    assert v.validate_many([1, '2', 3.0]) == ([1, 2, 3], [])
+    assert v.validate_many(iter(['4', 5])) == ([4, 5], [])
+    assert v.validate_many([]) == ([], [])
+
+
+def test_validate_many_raise():
+    v = SchemaValidator(core_schema.int_schema())
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_many([1, 'x', 3, 'y'])
+    # only the first invalid input is reported
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': (1,),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        }
+    ]
+
+
+def test_validate_many_collect():
+    v = SchemaValidator(core_schema.typed_dict_schema({'a': core_schema.typed_dict_field(core_schema.int_schema())}))
+    output, errors = v.validate_many([{'a': 1}, {'a': 'x'}, {'a': '3'}, {}], on_error='collect')
+    assert output == [{'a': 1}, {'a': 3}]
+    assert [index for index, _ in errors] == [1, 3]
+    assert all(isinstance(error, ValidationError) for _, error in errors)
+    assert errors[0][1].errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': ('a',),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        }
+    ]
+    assert errors[1][1].errors(include_url=False) == [
+        {'type': 'missing', 'loc': ('a',), 'msg': 'Field required', 'input': {}}
+    ]
+
+
+def test_validate_many_skip():
+    v = SchemaValidator(core_schema.int_schema())
+    assert v.validate_many([1, 'x', '3', None], on_error='skip') == ([1, 3], [])
+
+
+def test_validate_many_strict():
+    v = SchemaValidator(core_schema.int_schema())
+    assert v.validate_many([1, '2'], strict=True, on_error='skip') == ([1], [])
+
+
+def test_validate_many_internal_error():
+    def f(input_value):
+        raise RuntimeError('boom')
+
+    v = SchemaValidator(core_schema.no_info_plain_validator_function(f))
+    with pytest.raises(RuntimeError, match='boom'):
+        v.validate_many([1], on_error='collect')
+
+
+def test_validate_many_invalid_on_error():
+    v = SchemaValidator(core_schema.int_schema())
+    with pytest.raises(ValueError, match="Invalid on_error parameter, should be `'raise'`, `'collect'` or `'skip'`"):
+        v.validate_many([1], on_error='foo')
@@ -0,0 +1,310 @@
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('[1, 2, 3]', [1, 2, 3]),
+        ('[1, 2, 3', [1, 2, 3]),
+        ('[1, 2, ', [1, 2]),
+        ('[1, 2, "x', [1, 2]),
+        ('[1, 2, "3', [1, 2]),
+        ('[', []),
+    ],
+)
+def test_list(input_value, expected):
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    assert v.validate_json(input_value, allow_partial=True) == expected
+
+
+def test_list_errors():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    with pytest.raises(ValidationError, match='EOF while parsing a list at line 1 column 5'):
+        v.validate_json('[1, 2')
+    with pytest.raises(ValidationError, match='EOF while parsing a list at line 1 column 5'):
+        v.validate_json('[1, 2', allow_partial=False)
+
+    # only errors in the last item are ignored
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('[1, "x", 3', allow_partial=True)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': (1,),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        }
+    ]
+    # complete JSON is validated as usual
+    with pytest.raises(ValidationError, match=r'1\n  Input should be a valid integer'):
+        v.validate_json('[1, "x"]', allow_partial=True)
+    # as is a last item which is complete, even if the list isn't
+    with pytest.raises(ValidationError, match=r'1\n  Input should be a valid integer'):
+        v.validate_json('[1, "x"', allow_partial=True)
+    # but an incomplete last item is dropped
+    assert v.validate_json('[1, "x', allow_partial='trailing-strings') == [1]
+
+
+@pytest.mark.parametrize('input_value', ['[1, 2] x', '[1, 2 x', '{"a": 1 x', '[1, }'])
+def test_invalid_json(input_value):
+    v = SchemaValidator(core_schema.any_schema())
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json(input_value, allow_partial=True)
+    assert exc_info.value.errors(include_url=False)[0]['type'] == 'json_invalid'
+
+
+@pytest.mark.parametrize(
+    'input_value,expected,expected_trailing',
+    [
+        ('["aa", "bb', ['aa'], ['aa', 'bb']),
+        ('["aa", "b\\u00e9', ['aa'], ['aa', 'b\u00e9']),
+        ('{"a": "aa", "b": "bb', {'a': 'aa'}, {'a': 'aa', 'b': 'bb'}),
+    ],
+)
+def test_trailing_strings(input_value, expected, expected_trailing):
+    v = SchemaValidator(core_schema.any_schema())
+    assert v.validate_json(input_value, allow_partial=True) == expected
+    assert v.validate_json(input_value, allow_partial='on') == expected
+    assert v.validate_json(input_value, allow_partial='trailing-strings') == expected_trailing
+
+
+def test_invalid_mode():
+    v = SchemaValidator(core_schema.any_schema())
+    with pytest.raises(ValueError, match="Invalid partial mode, should be `'off'`, `'on'`, `'trailing-strings'`"):
+        v.validate_json('[1', allow_partial='foobar')
+
+
+def test_dict():
+    v = SchemaValidator(core_schema.dict_schema(core_schema.str_schema(), core_schema.int_schema()))
+    assert v.validate_json('{"a": 1, "b": 2', allow_partial=True) == {'a': 1, 'b': 2}
+    assert v.validate_json('{"a": 1, "b": "2', allow_partial=True) == {'a': 1}
+    assert v.validate_json('{"a": 1, "b": "x', allow_partial='trailing-strings') == {'a': 1}
+    assert v.validate_json('{"a": 1, "b', allow_partial=True) == {'a': 1}
+    with pytest.raises(ValidationError, match=r'b\n  Input should be a valid integer'):
+        v.validate_json('{"a": 1, "b": "x"', allow_partial=True)
+    with pytest.raises(ValidationError, match=r'a\n  Input should be a valid integer'):
+        v.validate_json('{"a": "x", "b": 2', allow_partial=True)
+
+
+def test_typed_dict():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(core_schema.str_schema(min_length=3)),
+                'c': core_schema.typed_dict_field(core_schema.int_schema()),
+            }
+        )
+    )
+    assert v.validate_json('{"a": 1, "b": "abc", "c": 3}', allow_partial=True) == {'a': 1, 'b': 'abc', 'c': 3}
+    assert v.validate_json('{"a": 1, "b": "abc"', allow_partial=True) == {'a': 1, 'b': 'abc'}
+    assert v.validate_json('{"a": 1, "b": "ab', allow_partial=True) == {'a': 1}
+    # the last value is too short, but might be incomplete
+    assert v.validate_json('{"a": 1, "b": "ab', allow_partial='trailing-strings') == {'a': 1}
+    assert v.validate_json('{"a": 1, "b": "abc', allow_partial='trailing-strings') == {'a': 1, 'b': 'abc'}
+    assert v.validate_json('{', allow_partial=True) == {}
+
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"a": 1, "b": "ab", "c": 3', allow_partial=True)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'string_too_short',
+            'loc': ('b',),
+            'msg': 'String should have at least 3 characters',
+            'input': 'ab',
+            'ctx': {'min_length': 3},
+        }
+    ]
+
+
+def test_typed_dict_missing():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(core_schema.int_schema()),
+                'c': core_schema.typed_dict_field(core_schema.int_schema()),
+            }
+        )
+    )
+    # keys can come in any order, so no field missing from a dict the data ended inside is reported
+    assert v.validate_json('{"a": 1, "b": 2', allow_partial=True) == {'a': 1, 'b': 2}
+    assert v.validate_json('{"a": 1, "c', allow_partial=True) == {'a': 1}
+    assert v.validate_json('{"a": 1, "c": 3', allow_partial=True) == {'a': 1, 'c': 3}
+    assert v.validate_json('{"c": 3, "a', allow_partial=True) == {'c': 3}
+    assert v.validate_json('{"c": 3, "a": 1', allow_partial=True) == {'c': 3, 'a': 1}
+
+    # but once the dict is closed, fields missing from it are reported
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"a": 1, "c": 3}', allow_partial=True)
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'missing', 'loc': ('b',), 'msg': 'Field required', 'input': {'a': 1, 'c': 3}}
+    ]
+
+
+def test_typed_dict_missing_closed_in_tail():
+    v = SchemaValidator(
+        core_schema.list_schema(
+            core_schema.typed_dict_schema(
+                {
+                    'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                    'b': core_schema.typed_dict_field(core_schema.list_schema(core_schema.str_schema())),
+                }
+            )
+        )
+    )
+    assert v.validate_json('[{"b": ["x"], "a', allow_partial=True) == [{'b': ['x']}]
+    assert v.validate_json('[{"b": ["x"], "a": "1', allow_partial=True) == [{'b': ['x']}]
+
+    # the last dict was closed before the data ended, so it's complete and missing fields are reported
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('[{"b": ["x"]}', allow_partial=True)
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'missing', 'loc': (0, 'a'), 'msg': 'Field required', 'input': {'b': ['x']}}
+    ]
+    # as is anything invalid at its end, including its own last item
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('[{"a": 1, "b": ["x", 2]}', allow_partial=True)
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'string_type', 'loc': (0, 'b', 1), 'msg': 'Input should be a valid string', 'input': 2}
+    ]
+
+
+def test_nested():
+    v = SchemaValidator(
+        core_schema.list_schema(
+            core_schema.typed_dict_schema(
+                {
+                    'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                    'b': core_schema.typed_dict_field(core_schema.list_schema(core_schema.int_schema())),
+                }
+            )
+        )
+    )
+    assert v.validate_json('[{"a": 1, "b": [1, 2]}, {"a": 2, "b": [3, "4', allow_partial=True) == [
+        {'a': 1, 'b': [1, 2]},
+        {'a': 2, 'b': [3]},
+    ]
+    assert v.validate_json('[{"a": 1, "b": [1, 2]}, {"a": 2', allow_partial=True) == [
+        {'a': 1, 'b': [1, 2]},
+        {'a': 2},
+    ]
+
+    # only the tail of the input may be incomplete, so missing fields are reported in earlier items
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('[{"a": 1}, {"a": 2', allow_partial=True)
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'missing', 'loc': (0, 'b'), 'msg': 'Field required', 'input': {'a': 1}}
+    ]
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('[{"a": 1, "b": [1, "x"]}, {"a": 2', allow_partial=True)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': (0, 'b', 1),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        }
+    ]
+
+
+def test_nested_errors():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                'b': core_schema.typed_dict_field(core_schema.list_schema(core_schema.int_schema())),
+            }
+        )
+    )
+    assert v.validate_json('{"a": 1, "b": [1, 2, "x', allow_partial='trailing-strings') == {'a': 1, 'b': [1, 2]}
+
+    # the last value is a list, which only drops its own last item, so errors before it are reported
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('{"a": 1, "b": [1, "x", 3', allow_partial=True)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'int_parsing',
+            'loc': ('b', 1),
+            'msg': 'Input should be a valid integer, unable to parse string as an integer',
+            'input': 'x',
+        }
+    ]
+
+
+def test_model():
+    class MyModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    v = SchemaValidator(
+        core_schema.model_schema(
+            MyModel,
+            core_schema.model_fields_schema(
+                {
+                    'a': core_schema.model_field(core_schema.int_schema()),
+                    'b': core_schema.model_field(core_schema.list_schema(core_schema.int_schema())),
+                }
+            ),
+        )
+    )
+    m = v.validate_json('{"a": 1, "b": [1, 2', allow_partial=True)
+    assert m.__dict__ == {'a': 1, 'b': [1, 2]}
+    m = v.validate_json('{"a": 1, "b', allow_partial=True)
+    assert m.__dict__ == {'a': 1}
+    assert m.__pydantic_fields_set__ == {'a'}
+
+    with pytest.raises(ValidationError, match=r'b\n  Field required'):
+        v.validate_json('{"a": 1}')
+    m = v.validate_json('{"b": [1, 2', allow_partial=True)
+    assert m.__dict__ == {'b': [1, 2]}
+    with pytest.raises(ValidationError, match=r'a\n  Field required'):
+        v.validate_json('{"b": [1, 2]}', allow_partial=True)
+
+
+def test_model_default():
+    class MyModel:
+        __slots__ = '__dict__', '__pydantic_fields_set__', '__pydantic_extra__', '__pydantic_private__'
+
+    v = SchemaValidator(
+        core_schema.model_schema(
+            MyModel,
+            core_schema.model_fields_schema(
+                {
+                    'a': core_schema.model_field(core_schema.int_schema()),
+                    'b': core_schema.model_field(
+                        core_schema.with_default_schema(core_schema.int_schema(), default=5)
+                    ),
+                }
+            ),
+        )
+    )
+    m = v.validate_json('{"a": 1', allow_partial=True)
+    assert m.__dict__ == {'a': 1, 'b': 5}
+    # an incomplete last value is treated the same as a missing one
+    m = v.validate_json('{"a": 1, "b": "x', allow_partial='trailing-strings')
+    assert m.__dict__ == {'a': 1, 'b': 5}
+    assert m.__pydantic_fields_set__ == {'a'}
+
+
+def test_python():
+    v = SchemaValidator(
+        core_schema.list_schema(
+            core_schema.typed_dict_schema(
+                {
+                    'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                    'b': core_schema.typed_dict_field(core_schema.int_schema()),
+                }
+            )
+        )
+    )
+    assert v.validate_python([{'a': 1, 'b': 2}, {'a': 3}], allow_partial=True) == [{'a': 1, 'b': 2}, {'a': 3}]
+    assert v.validate_python([{'a': 1, 'b': 2}, {'a': 3, 'b': 'x'}], allow_partial=True) == [
+        {'a': 1, 'b': 2},
+        {'a': 3},
+    ]
+    with pytest.raises(ValidationError, match=r'1\.b\n  Field required'):
+        v.validate_python([{'a': 1, 'b': 2}, {'a': 3}])
+    with pytest.raises(ValidationError, match=r'0\.b\n  Field required'):
+        v.validate_python([{'a': 1}, {'a': 3}], allow_partial=True)
@@ -293,3 +293,26 @@ def test_date_past_future_today():
 def test_offset_too_large():
     with pytest.raises(SchemaError, match=r'Input should be less than 86400 \[type=less_than,'):
         validate_core_schema(core_schema.date_schema(now_op='past', now_utc_offset=24 * 3600))
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('08/06/2022', date(2022, 6, 8)),
+        ('Wed 8 June 2022', date(2022, 6, 8)),
+        ('2022-06-08', date(2022, 6, 8)),
+        ('2022-06-08T00:00:00', date(2022, 6, 8)),
+        ('08/06/2022 12:13', Err('Datetimes provided to dates should have zero time - e.g. be exact dates')),
+        ('08/06/2022 00:00', date(2022, 6, 8)),
+        ('29/02/2022', Err("Input should be in ISO 8601 format or match the format '%d/%m/%Y', '%a %d %B %Y'")),
+        ('2022-06-08T12:13:14', Err('Input should be in ISO 8601 format or match the format')),
+        (datetime(2022, 6, 8, 12), Err('Datetimes provided to dates should have zero time - e.g. be exact dates')),
+    ],
+)
+def test_formats(input_value, expected):
+    v = SchemaValidator(core_schema.date_schema(formats=['%d/%m/%Y', '%a %d %B %Y', '%d/%m/%Y %H:%M']))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
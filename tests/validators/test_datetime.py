@@ -517,3 +517,105 @@ def test_tz_cmp() -> None:
 
     assert validated1 > validated2
     assert validated2 < validated1
+
+
+def test_normalize_tz_utc():
+    v = SchemaValidator(core_schema.datetime_schema(normalize_tz='+00:00'))
+    output = v.validate_python('2022-06-08T12:13:14+02:00')
+    assert output == datetime(2022, 6, 8, 10, 13, 14, tzinfo=timezone.utc)
+    assert output.utcoffset() == timedelta(0)
+    assert v.validate_json('"2022-06-08T12:13:14-01:00"').hour == 13
+
+    # naive datetimes are left unchanged
+    assert v.validate_python('2022-06-08T12:13:14') == datetime(2022, 6, 8, 12, 13, 14)
+
+
+def test_normalize_tz_zone():
+    helsinki = zoneinfo.ZoneInfo('Europe/Helsinki')
+    v = SchemaValidator(core_schema.datetime_schema(normalize_tz='Europe/Helsinki'))
+    output = v.validate_python(datetime(2022, 6, 8, 12, 0, tzinfo=timezone.utc))
+    assert output == datetime(2022, 6, 8, 15, 0, tzinfo=helsinki)
+    assert output.tzinfo is helsinki
+    # winter time
+    assert v.validate_python('2022-01-08T12:00:00Z').hour == 14
+
+
+def test_normalize_tz_with_constraints():
+    v = SchemaValidator(core_schema.datetime_schema(normalize_tz='UTC', tz_constraint='aware'))
+    assert v.validate_python('2022-06-08T12:13:14+02:00').hour == 10
+    with pytest.raises(ValidationError, match='Input should have timezone info'):
+        v.validate_python('2022-06-08T12:13:14')
+
+
+def test_normalize_tz_invalid():
+    with pytest.raises(SchemaError, match='Invalid normalize_tz "Mars/Olympus_Mons", expected an IANA time zone name'):
+        SchemaValidator(core_schema.datetime_schema(normalize_tz='Mars/Olympus_Mons'))
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('08/06/2022 12:13', datetime(2022, 6, 8, 12, 13)),
+        ('8/6/2022  9:05', datetime(2022, 6, 8, 9, 5)),
+        ('June 08 2022 01:13:14.5 PM', datetime(2022, 6, 8, 13, 13, 14, 500_000)),
+        ('jun 08 2022 12:13:14.123456 am', datetime(2022, 6, 8, 0, 13, 14, 123_456)),
+        ('08/06/22 12:13 +0130', datetime(2022, 6, 8, 12, 13, tzinfo=timezone(timedelta(hours=1, minutes=30)))),
+        ('08/06/22 12:13 -01:00', datetime(2022, 6, 8, 12, 13, tzinfo=timezone(timedelta(hours=-1)))),
+        ('08/06/22 12:13 Z', datetime(2022, 6, 8, 12, 13, tzinfo=timezone.utc)),
+        # ISO 8601 is still tried first
+        ('2022-06-08T12:13:14', datetime(2022, 6, 8, 12, 13, 14)),
+        ('2022-06-08', datetime(2022, 6, 8)),
+        ('31/06/2022 12:13', Err("Input should be in ISO 8601 format or match the format '%d/%m/%Y %H:%M', '%B")),
+        ('08/06/2022 24:00', Err('Input should be in ISO 8601 format or match the format')),
+        ('08/06/2022 12:13 extra', Err('Input should be in ISO 8601 format or match the format')),
+        (b'08/06/2022 12:13', datetime(2022, 6, 8, 12, 13)),
+        (123.4, datetime(1970, 1, 1, 0, 2, 3, 400_000, tzinfo=timezone.utc)),
+        ([], Err('Input should be a valid datetime [type=datetime_type')),
+    ],
+)
+def test_formats(input_value, expected):
+    v = SchemaValidator(
+        core_schema.datetime_schema(
+            formats=['%d/%m/%Y %H:%M', '%B %d %Y %I:%M:%S.%f %p', '%d/%m/%y %H:%M %z', '%b %d %Y %I:%M:%S.%f %p']
+        )
+    )
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+def test_formats_error():
+    v = SchemaValidator(core_schema.datetime_schema(formats=['%d/%m/%Y %H:%M']))
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_json('"2022/06/08"')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'datetime_format',
+            'loc': (),
+            'msg': "Input should be in ISO 8601 format or match the format '%d/%m/%Y %H:%M'",
+            'input': '2022/06/08',
+            'ctx': {'formats': "'%d/%m/%Y %H:%M'"},
+        }
+    ]
+    assert v.validate_json('"08/06/2022 12:13"') == datetime(2022, 6, 8, 12, 13)
+
+
+def test_formats_strict():
+    v = SchemaValidator(core_schema.datetime_schema(formats=['%d/%m/%Y %H:%M'], strict=True))
+    with pytest.raises(ValidationError, match='Input should be a valid datetime, invalid character in year'):
+        v.validate_json('"08/06/2022 12:13"')
+
+
+@pytest.mark.parametrize(
+    'formats,message',
+    [
+        ([], '`formats` should have length > 0'),
+        (['%d/%m/%Y %Q'], "Invalid format \"%d/%m/%Y %Q\", unsupported directive '%Q'"),
+        (['%d/%m/%Y %'], "Invalid format \"%d/%m/%Y %\", stray '%' at the end of the format"),
+    ],
+)
+def test_formats_invalid(formats, message):
+    with pytest.raises(SchemaError, match=re.escape(message)):
+        SchemaValidator(core_schema.datetime_schema(formats=formats))
@@ -487,3 +487,82 @@ def test_union_with_str_prefers_str() -> None:
 
     assert s.validate_python('1.23') == '1.23'
     assert s.validate_python(1.23) == Decimal('1.23')
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('0.1000000000000000055511151231257827', '0.1000000000000000055511151231257827'),
+        ('0.30000000000000000001', '0.30000000000000000001'),
+        ('1.10', '1.10'),
+        ('-2.500', '-2.500'),
+        ('1.5e-20', '1.5E-20'),
+        ('123456789012345678901234567890.5', '123456789012345678901234567890.5'),
+    ],
+)
+def test_json_float_source_text(input_value, expected):
+    v = SchemaValidator(core_schema.decimal_schema())
+    output = v.validate_json(input_value)
+    assert isinstance(output, Decimal)
+    assert str(output) == expected
+
+
+def test_json_float_source_text_nested():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'name': core_schema.typed_dict_field(core_schema.str_schema()),
+                'values': core_schema.typed_dict_field(core_schema.list_schema(core_schema.decimal_schema())),
+                'ratio': core_schema.typed_dict_field(core_schema.float_schema()),
+            }
+        )
+    )
+    output = v.validate_json('{"name": "1.5 \\" [2.5]", "ratio": 0.1, "values": [1, 0.10, 2.20e1, -1.0e-19]}')
+    assert output == {
+        'name': '1.5 " [2.5]',
+        'ratio': 0.1,
+        'values': [Decimal(1), Decimal('0.10'), Decimal('22.0'), Decimal('-1E-19')],
+    }
+    assert [str(d) for d in output['values']] == ['1', '0.10', '22.0', '-1.0E-19']
+
+
+def test_json_float_source_text_json_schema():
+    v = SchemaValidator(core_schema.json_schema(core_schema.decimal_schema()))
+    assert str(v.validate_python('0.1000000000000000055511151231257827')) == '0.1000000000000000055511151231257827'
+
+    v = SchemaValidator(core_schema.json_schema(core_schema.list_schema(core_schema.decimal_schema())))
+    assert [str(d) for d in v.validate_json('"[1.10, 2.250e1]"')] == ['1.10', '22.50']
+
+
+def test_json_float_source_text_constraints():
+    v = SchemaValidator(core_schema.decimal_schema(max_digits=20))
+    assert str(v.validate_json('0.1000000000000000001')) == '0.1000000000000000001'
+    with pytest.raises(ValidationError, match='Decimal input should have no more than 20 digits in total'):
+        # as an `f64` this is `0.1`, which would pass
+        v.validate_json('0.100000000000000000001')
+
+
+def test_json_float_source_text_partial():
+    v = SchemaValidator(core_schema.list_schema(core_schema.decimal_schema()))
+    assert [str(d) for d in v.validate_json('[1.10, 2.250, 3.', allow_partial=True)] == ['1.10', '2.250']
+
+
+def test_json_float_source_text_mixed():
+    v = SchemaValidator(
+        core_schema.dict_schema(core_schema.str_schema(), core_schema.decimal_schema(allow_inf_nan=True))
+    )
+    output = v.validate_json(
+        '{"1.5": 0.10, "x\\"2.5": NaN, "3e5": Infinity, "-4": -Infinity, "d": 1.20, "d": 2.30, "[5, 6.5]": 7.50}'
+    )
+    assert {k: str(d) for k, d in output.items()} == {
+        '1.5': '0.10',
+        'x"2.5': 'NaN',
+        '3e5': 'Infinity',
+        '-4': '-Infinity',
+        'd': '2.30',
+        '[5, 6.5]': '7.50',
+    }
+
+    output = v.validate_json('{"a": 1.10, "b": 2.20, "c": "3.5', allow_partial=True)
+    assert {k: str(d) for k, d in output.items()} == {'a': '1.10', 'b': '2.20'}
+
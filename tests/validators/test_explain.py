@@ -0,0 +1,135 @@
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+
+def test_simple():
+    v = SchemaValidator(core_schema.int_schema())
+    result, error, trace = v.explain('42')
+    assert result == 42
+    assert error is None
+    assert trace == {
+        'validator': 'int',
+        'loc': (),
+        'input': '42',
+        'valid': True,
+        'exactness': None,
+        'fields_set_count': None,
+        'notes': [],
+        'children': [],
+    }
+
+
+def test_invalid():
+    v = SchemaValidator(core_schema.list_schema(core_schema.int_schema()))
+    result, error, trace = v.explain([1, 'x'])
+    assert result is None
+    assert isinstance(error, ValidationError)
+    assert error.errors(include_url=False)[0]['loc'] == (1,)
+    assert trace['valid'] is False
+    assert [(c['loc'], c['input'], c['valid']) for c in trace['children']] == [((0,), 1, True), ((1,), 'x', False)]
+
+
+def test_smart_union():
+    v = SchemaValidator(core_schema.union_schema([core_schema.int_schema(), core_schema.str_schema()]))
+    result, _, trace = v.explain('1')
+    assert result == '1'
+    assert [(c['validator'], c['valid'], c['exactness']) for c in trace['children']] == [
+        ('int', True, 'lax'),
+        ('str', True, 'exact'),
+    ]
+    assert trace['notes'] == ['selected `str`, an exact match']
+
+    v = SchemaValidator(
+        core_schema.union_schema([core_schema.int_schema(), core_schema.float_schema()], strict=False)
+    )
+    result, _, trace = v.explain('1.5')
+    assert result == 1.5
+    assert [(c['validator'], c['valid'], c['exactness']) for c in trace['children']] == [
+        ('int', False, 'exact'),
+        ('float', True, 'lax'),
+    ]
+    assert trace['notes'] == ['selected `float` with lax exactness']
+
+
+def test_smart_union_fields_set():
+    v = SchemaValidator(
+        core_schema.union_schema(
+            [
+                core_schema.typed_dict_schema({'a': core_schema.typed_dict_field(core_schema.int_schema())}),
+                core_schema.typed_dict_schema(
+                    {
+                        'a': core_schema.typed_dict_field(core_schema.int_schema()),
+                        'b': core_schema.typed_dict_field(core_schema.int_schema()),
+                    }
+                ),
+            ]
+        )
+    )
+    result, _, trace = v.explain({'a': 1, 'b': 2})
+    assert result == {'a': 1, 'b': 2}
+    assert [c['fields_set_count'] for c in trace['children']] == [1, 2]
+    assert trace['notes'] == ['selected `typed-dict` with exact exactness and 2 fields set']
+
+
+def test_left_to_right_union():
+    v = SchemaValidator(
+        core_schema.union_schema([core_schema.int_schema(), core_schema.str_schema()], mode='left_to_right')
+    )
+    _, _, trace = v.explain('1')
+    assert [c['validator'] for c in trace['children']] == ['int']
+    assert trace['notes'] == ['selected `int`, the first valid choice']
+
+
+def test_tagged_union():
+    v = SchemaValidator(
+        core_schema.tagged_union_schema(
+            {
+                'a': core_schema.typed_dict_schema({'kind': core_schema.typed_dict_field(core_schema.str_schema())}),
+                'b': core_schema.typed_dict_schema({'kind': core_schema.typed_dict_field(core_schema.str_schema())}),
+            },
+            discriminator='kind',
+        )
+    )
+    _, _, trace = v.explain({'kind': 'b'})
+    assert trace['notes'] == ['selected tag `b`']
+    assert trace['children'][0]['validator'] == 'typed-dict'
+
+
+def test_defaults_and_locations():
+    v = SchemaValidator(
+        core_schema.typed_dict_schema(
+            {
+                'a': core_schema.typed_dict_field(
+                    core_schema.dict_schema(core_schema.str_schema(), core_schema.int_schema())
+                ),
+                'b': core_schema.typed_dict_field(
+                    core_schema.with_default_schema(core_schema.int_schema(), default=3), required=False
+                ),
+            }
+        )
+    )
+    result, _, trace = v.explain({'a': {'x': '1'}})
+    assert result == {'a': {'x': 1}, 'b': 3}
+    assert trace['notes'] == ['default applied for `b`']
+    (a,) = trace['children']
+    assert a['loc'] == ('a',)
+    assert [(c['validator'], c['loc']) for c in a['children']] == [('str', ('a', 'x')), ('int', ('a', 'x'))]
+
+
+@pytest.mark.parametrize('input_value,expected_loc', [((1, 'a'), [(0,), (1,)]), ((1, 'a', 'b'), [(0,), (1,), (2,)])])
+def test_tuple(input_value, expected_loc):
+    v = SchemaValidator(
+        core_schema.tuple_schema([core_schema.int_schema(), core_schema.str_schema()], variadic_item_index=1)
+    )
+    _, _, trace = v.explain(input_value)
+    assert [c['loc'] for c in trace['children']] == expected_loc
+
+
+def test_function_after():
+    v = SchemaValidator(core_schema.no_info_after_validator_function(lambda x: x * 2, core_schema.int_schema()))
+    result, _, trace = v.explain(2)
+    assert result == 4
+    assert trace['validator'] == 'function-after[<lambda>(), int]'
+    assert trace['children'][0]['validator'] == 'int'
+
@@ -0,0 +1,202 @@
+import re
+from ipaddress import (
+    IPv4Address,
+    IPv4Interface,
+    IPv4Network,
+    IPv6Address,
+    IPv6Interface,
+    IPv6Network,
+    ip_address,
+)
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+from ..conftest import Err
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('192.168.0.1', IPv4Address('192.168.0.1')),
+        ('::1', IPv6Address('::1')),
+        ('2001:db8::ff00:42:8329', IPv6Address('2001:db8::ff00:42:8329')),
+        (3232235521, IPv4Address('192.168.0.1')),
+        (2**32, IPv6Address('::1:0:0')),
+        (b'\xc0\xa8\x00\x01', IPv4Address('192.168.0.1')),
+        (b'\x00' * 15 + b'\x01', IPv6Address('::1')),
+        (IPv4Address('10.0.0.1'), IPv4Address('10.0.0.1')),
+        (IPv6Address('::2'), IPv6Address('::2')),
+        ('192.168.0.256', Err('Input should be a valid IPv4 or IPv6 address [type=ip_address_parsing,')),
+        ('192.168.0.1/24', Err('Input should be a valid IPv4 or IPv6 address [type=ip_address_parsing,')),
+        (-1, Err('Input should be a valid IPv4 or IPv6 address [type=ip_address_parsing,')),
+        (2**128, Err('Input should be a valid IPv4 or IPv6 address [type=ip_address_parsing,')),
+        (b'\x01\x02', Err('Input should be a valid IPv4 or IPv6 address [type=ip_address_parsing,')),
+        (1.5, Err('Input should be a string, integer, bytes or ipaddress object [type=ip_type,')),
+        (IPv4Network('10.0.0.0/8'), Err('Input should be a string, integer, bytes or ipaddress object')),
+    ],
+)
+def test_ip_address(input_value, expected):
+    v = SchemaValidator(core_schema.ip_address_schema())
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        output = v.validate_python(input_value)
+        assert output == expected
+        assert type(output) is type(expected)
+
+
+@pytest.mark.parametrize(
+    'version,input_value,expected',
+    [
+        (4, '192.168.0.1', IPv4Address('192.168.0.1')),
+        (4, 1, IPv4Address('0.0.0.1')),
+        (4, '::1', Err('Input should be a valid IPv4 address [type=ip_address_parsing,')),
+        (4, IPv6Address('::1'), Err('Input should be a valid IPv4 address [type=ip_address_parsing,')),
+        (4, 2**32, Err('Input should be a valid IPv4 address [type=ip_address_parsing,')),
+        (6, '::1', IPv6Address('::1')),
+        (6, 1, IPv6Address('::1')),
+        (6, '192.168.0.1', Err('Input should be a valid IPv6 address [type=ip_address_parsing,')),
+    ],
+)
+def test_ip_address_version(version, input_value, expected):
+    v = SchemaValidator(core_schema.ip_address_schema(version=version))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        output = v.validate_python(input_value)
+        assert output == expected
+        assert type(output) is type(expected)
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('192.168.0.0/24', IPv4Network('192.168.0.0/24')),
+        ('192.168.0.0/255.255.255.0', IPv4Network('192.168.0.0/24')),
+        ('192.168.0.0/0.0.0.255', IPv4Network('192.168.0.0/24')),
+        ('192.168.0.1', IPv4Network('192.168.0.1/32')),
+        ('2001:db8::/32', IPv6Network('2001:db8::/32')),
+        (3232235520, IPv4Network('192.168.0.0/32')),
+        (b'\xc0\xa8\x00\x00', IPv4Network('192.168.0.0/32')),
+        (IPv4Address('10.0.0.1'), IPv4Network('10.0.0.1/32')),
+        (IPv6Network('::/0'), IPv6Network('::/0')),
+        ('192.168.0.1/24', Err('Input should be a valid IPv4 or IPv6 network [type=ip_network_parsing,')),
+        ('192.168.0.0/33', Err('Input should be a valid IPv4 or IPv6 network [type=ip_network_parsing,')),
+        ('192.168.0.0/255.0.255.0', Err('Input should be a valid IPv4 or IPv6 network [type=ip_network_parsing,')),
+        ('2001:db8::/ffff::', Err('Input should be a valid IPv4 or IPv6 network [type=ip_network_parsing,')),
+        ('192.168.0.0/', Err('Input should be a valid IPv4 or IPv6 network [type=ip_network_parsing,')),
+    ],
+)
+def test_ip_network(input_value, expected):
+    v = SchemaValidator(core_schema.ip_network_schema())
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        output = v.validate_python(input_value)
+        assert output == expected
+        assert type(output) is type(expected)
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('192.168.0.1/24', IPv4Interface('192.168.0.1/24')),
+        ('192.168.0.1/255.255.255.0', IPv4Interface('192.168.0.1/24')),
+        ('192.168.0.1', IPv4Interface('192.168.0.1/32')),
+        ('2001:db8::1/64', IPv6Interface('2001:db8::1/64')),
+        (IPv4Address('10.0.0.1'), IPv4Interface('10.0.0.1/32')),
+        (IPv6Interface('::1/128'), IPv6Interface('::1/128')),
+        ('192.168.0.1/129', Err('Input should be a valid IPv4 or IPv6 interface [type=ip_interface_parsing,')),
+        ('foobar', Err('Input should be a valid IPv4 or IPv6 interface [type=ip_interface_parsing,')),
+    ],
+)
+def test_ip_interface(input_value, expected):
+    v = SchemaValidator(core_schema.ip_interface_schema())
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        output = v.validate_python(input_value)
+        assert output == expected
+        assert type(output) is type(expected)
+
+
+@pytest.mark.parametrize(
+    'schema,input_value,expected',
+    [
+        (core_schema.ip_address_schema(strict=True), IPv4Address('10.0.0.1'), IPv4Address('10.0.0.1')),
+        (
+            core_schema.ip_address_schema(strict=True),
+            '10.0.0.1',
+            Err('Input should be an instance of IPv4Address or IPv6Address [type=is_instance_of,'),
+        ),
+        (
+            core_schema.ip_network_schema(version=6, strict=True),
+            IPv6Address('::1'),
+            Err('Input should be an instance of IPv6Network [type=is_instance_of,'),
+        ),
+        (
+            core_schema.ip_interface_schema(version=4, strict=True),
+            IPv6Interface('::1/128'),
+            Err('Input should be an instance of IPv4Interface [type=is_instance_of,'),
+        ),
+    ],
+)
+def test_strict(schema, input_value, expected):
+    v = SchemaValidator(schema)
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+def test_json():
+    v = SchemaValidator(core_schema.ip_address_schema(strict=True))
+    assert v.validate_json('"192.168.0.1"') == IPv4Address('192.168.0.1')
+    assert v.validate_json('3232235521') == IPv4Address('192.168.0.1')
+    with pytest.raises(ValidationError, match=re.escape('Input should be a valid IPv4 or IPv6 address')):
+        v.validate_json('"foobar"')
+
+    v = SchemaValidator(core_schema.ip_network_schema(version=6))
+    assert v.validate_json('"2001:db8::/32"') == IPv6Network('2001:db8::/32')
+    with pytest.raises(ValidationError, match=r'Input should be a string, integer, bytes or ipaddress object'):
+        v.validate_json('[]')
+
+
+def test_subclass():
+    class MyAddress(IPv4Address):
+        pass
+
+    v = SchemaValidator(core_schema.ip_address_schema(strict=True))
+    address = MyAddress('10.0.0.1')
+    assert v.validate_python(address) is address
+
+
+def test_union_exactness():
+    v = SchemaValidator(core_schema.union_schema([core_schema.str_schema(), core_schema.ip_address_schema()]))
+    assert v.validate_python('10.0.0.1') == '10.0.0.1'
+    assert v.validate_python(ip_address('10.0.0.1')) == IPv4Address('10.0.0.1')
+
+
+@pytest.mark.parametrize(
+    'schema,expected',
+    [
+        (core_schema.ip_address_schema(), 'ipvanyaddress'),
+        (core_schema.ip_address_schema(version=4), 'ipv4'),
+        (core_schema.ip_network_schema(version=6), 'ipv6network'),
+        (core_schema.ip_interface_schema(), 'ipvanyinterface'),
+    ],
+)
+def test_json_schema(schema, expected):
+    assert SchemaValidator(schema).json_schema() == {'type': 'string', 'format': expected}
+
+
+def test_invalid_version():
+    with pytest.raises(Exception, match='Invalid IP version: 5, expected 4 or 6'):
+        SchemaValidator({'type': 'ip-address', 'version': 5})
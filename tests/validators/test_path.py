@@ -0,0 +1,140 @@
+import os
+import re
+from pathlib import Path, PurePath
+
+import pytest
+
+from pydantic_core import SchemaError, SchemaValidator, ValidationError, core_schema
+
+from ..conftest import Err
+
+
+class MyPathLike:
+    def __init__(self, path):
+        self.path = path
+
+    def __fspath__(self):
+        return self.path
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('foo/bar.txt', Path('foo/bar.txt')),
+        (b'foo/bar.txt', Path('foo/bar.txt')),
+        (Path('foo'), Path('foo')),
+        (MyPathLike('foo/bar'), Path('foo/bar')),
+        (MyPathLike(b'foo/bar'), Path('foo/bar')),
+        (PurePath('foo'), Path('foo')),
+        (123, Err('Path input should be a string, bytes or os.PathLike object [type=path_type,')),
+        (MyPathLike(123), Err('Path input should be a string, bytes or os.PathLike object [type=path_type,')),
+    ],
+)
+def test_path(input_value, expected):
+    v = SchemaValidator(core_schema.path_schema())
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        output = v.validate_python(input_value)
+        assert output == expected
+        assert isinstance(output, Path)
+
+
+def test_path_instance():
+    v = SchemaValidator(core_schema.path_schema())
+    path = Path('foo')
+    assert v.validate_python(path) is path
+
+
+def test_strict():
+    v = SchemaValidator(core_schema.path_schema(strict=True))
+    assert v.validate_python(Path('foo')) == Path('foo')
+    with pytest.raises(ValidationError, match=r'Input should be an instance of Path \[type=is_instance_of,'):
+        v.validate_python('foo')
+    # JSON can only provide strings
+    assert v.validate_json('"foo/bar"') == Path('foo/bar')
+    with pytest.raises(ValidationError, match=r'Path input should be a string, bytes or os.PathLike object'):
+        v.validate_json('123')
+
+
+def test_union_exactness():
+    v = SchemaValidator(core_schema.union_schema([core_schema.str_schema(), core_schema.path_schema()]))
+    assert v.validate_python('foo') == 'foo'
+    assert v.validate_python(Path('foo')) == Path('foo')
+
+
+def test_absolute():
+    v = SchemaValidator(core_schema.path_schema(absolute=True))
+    assert v.validate_python(Path.cwd()) == Path.cwd()
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('foo')
+    assert exc_info.value.errors(include_url=False) == [
+        {'type': 'path_not_absolute', 'loc': (), 'msg': 'Path should be absolute', 'input': 'foo'}
+    ]
+
+
+def test_allowed_suffixes():
+    v = SchemaValidator(core_schema.path_schema(allowed_suffixes=['.txt', '.csv']))
+    assert v.validate_python('data.csv') == Path('data.csv')
+    assert v.validate_python('archive.tar.txt') == Path('archive.tar.txt')
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python('data.json')
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'path_suffix',
+            'loc': (),
+            'msg': "Path suffix should be '.txt' or '.csv'",
+            'input': 'data.json',
+            'ctx': {'expected_suffixes': "'.txt' or '.csv'"},
+        }
+    ]
+    assert exc_info.value.title == "path['.txt','.csv']"
+
+
+def test_filesystem(tmp_path):
+    file_path = tmp_path / 'file.txt'
+    file_path.write_text('hello')
+    missing = tmp_path / 'missing'
+
+    v = SchemaValidator(core_schema.path_schema(exists=True))
+    assert v.validate_python(str(file_path)) == file_path
+    assert v.validate_python(tmp_path) == tmp_path
+    with pytest.raises(ValidationError, match=r'Path does not exist \[type=path_not_exists,'):
+        v.validate_python(missing)
+
+    v = SchemaValidator(core_schema.path_schema(is_file=True))
+    assert v.validate_python(os.fspath(file_path)) == file_path
+    with pytest.raises(ValidationError, match=r'Path does not point to a file \[type=path_not_file,'):
+        v.validate_python(tmp_path)
+    with pytest.raises(ValidationError, match=r'Path does not point to a file \[type=path_not_file,'):
+        v.validate_python(missing)
+
+    v = SchemaValidator(core_schema.path_schema(is_dir=True))
+    assert v.validate_python(tmp_path) == tmp_path
+    with pytest.raises(ValidationError, match=r'Path does not point to a directory \[type=path_not_directory,'):
+        v.validate_python(file_path)
+
+
+@pytest.mark.parametrize(
+    'schema,message',
+    [
+        (core_schema.path_schema(is_file=True, is_dir=True), '`is_file` and `is_dir` cannot both be set'),
+        (core_schema.path_schema(allowed_suffixes=[]), '`allowed_suffixes` should have length > 0'),
+    ],
+)
+def test_invalid_schema(schema, message):
+    with pytest.raises(SchemaError, match=re.escape(message)):
+        SchemaValidator(schema)
+
+
+@pytest.mark.parametrize(
+    'schema,expected',
+    [
+        (core_schema.path_schema(), 'path'),
+        (core_schema.path_schema(is_file=True), 'file-path'),
+        (core_schema.path_schema(is_dir=True), 'directory-path'),
+    ],
+)
+def test_json_schema(schema, expected):
+    assert SchemaValidator(schema).json_schema() == {'type': 'string', 'format': expected}
@@ -170,7 +170,7 @@ def test_str_constrained_config():
         v.validate_python('test long')
 
 
-@pytest.mark.parametrize('engine', [None, 'rust-regex', 'python-re'])
+@pytest.mark.parametrize('engine', [None, 'rust-regex', 'fancy-regex', 'python-re'])
 def test_invalid_regex(engine):
     # TODO uncomment and fix once #150 is done
     # with pytest.raises(SchemaError) as exc_info:
@@ -189,6 +189,11 @@ def test_invalid_regex(engine):
             '    ^\n'
             'error: unclosed group'
         )
+    elif engine == 'fancy-regex':
+        assert exc_info.value.args[0] == (
+            'Error building "str" validator:\n'
+            '  SchemaError: Parsing error at position 4: Opening parenthesis without closing parenthesis'
+        )
     elif engine == 'python-re':
         prefix = 'PatternError' if sys.version_info >= (3, 13) else 'error'
         assert exc_info.value.args[0] == (
@@ -196,7 +201,7 @@ def test_invalid_regex(engine):
         )
 
 
-@pytest.mark.parametrize('engine', [None, 'rust-regex', 'python-re'])
+@pytest.mark.parametrize('engine', [None, 'rust-regex', 'fancy-regex', 'python-re'])
 def test_regex_error(engine):
     v = SchemaValidator(core_schema.str_schema(pattern='11', regex_engine=engine))
     with pytest.raises(ValidationError) as exc_info:
@@ -373,6 +378,63 @@ def test_backtracking_regex_python(mode) -> None:
         v.validate_python('r#"#')
 
 
+@pytest.mark.parametrize('mode', ('schema', 'config'))
+def test_backtracking_regex_fancy(mode) -> None:
+    pattern = r'r(#*)".*?"\1'
+
+    if mode == 'schema':
+        v = SchemaValidator(core_schema.str_schema(pattern=pattern, regex_engine='fancy-regex'))
+    elif mode == 'config':
+        v = SchemaValidator(core_schema.str_schema(pattern=pattern), core_schema.CoreConfig(regex_engine='fancy-regex'))
+    assert v.validate_python('r""') == 'r""'
+    assert v.validate_python('r#""#') == 'r#""#'
+    with pytest.raises(ValidationError):
+        # not a valid match for the pattern
+        v.validate_python('r#"#')
+
+
+def test_lookaround_regex_fancy() -> None:
+    v = SchemaValidator(core_schema.str_schema(pattern=r'^(?=.*\d)(?!.*_)\w+$', regex_engine='fancy-regex'))
+    assert v.validate_python('abc1') == 'abc1'
+    with pytest.raises(ValidationError, match='String should match pattern'):
+        v.validate_python('abc')
+    with pytest.raises(ValidationError, match='String should match pattern'):
+        v.validate_python('abc_1')
+
+
+@pytest.mark.parametrize('mode', ('schema', 'config'))
+def test_regex_backtrack_limit(mode) -> None:
+    pattern = r'^(a+)\1$'
+    value = 'a' * 20
+
+    v = SchemaValidator(core_schema.str_schema(pattern=pattern, regex_engine='fancy-regex'))
+    assert v.validate_python(value) == value
+
+    if mode == 'schema':
+        v = SchemaValidator(
+            core_schema.str_schema(pattern=pattern, regex_engine='fancy-regex', regex_backtrack_limit=10)
+        )
+    elif mode == 'config':
+        v = SchemaValidator(
+            core_schema.str_schema(pattern=pattern),
+            core_schema.CoreConfig(regex_engine='fancy-regex', regex_backtrack_limit=10),
+        )
+    # exceeding the backtrack limit is reported separately from the value not matching
+    with pytest.raises(ValidationError) as exc_info:
+        v.validate_python(value)
+    assert exc_info.value.errors(include_url=False) == [
+        {
+            'type': 'resource_limit_exceeded',
+            'loc': (),
+            'msg': 'Input exceeds the regex_backtrack_limit limit of 10',
+            'input': value,
+            'ctx': {'limit': 'regex_backtrack_limit', 'limit_value': 10},
+        }
+    ]
+    with pytest.raises(ValidationError, match=r'String should match pattern'):
+        v.validate_python('ab')
+
+
 @pytest.mark.parametrize('number', (42, 443, 10242))
 def test_coerce_numbers_to_str_schema(number: int):
     v = SchemaValidator(core_schema.str_schema(coerce_numbers_to_str=True))
@@ -404,7 +466,7 @@ def test_coerce_numbers_to_str_schema_with_strict_mode(number: int):
         v.validate_json(str(number))
 
 
-@pytest.mark.parametrize('engine', [None, 'rust-regex', 'python-re'])
+@pytest.mark.parametrize('engine', [None, 'rust-regex', 'fancy-regex', 'python-re'])
 def test_compiled_regex(engine) -> None:
     v = SchemaValidator(core_schema.str_schema(pattern=re.compile('abc', re.IGNORECASE), regex_engine=engine))
     assert v.validate_python('abc') == 'abc'
@@ -294,3 +294,29 @@ def test_tz_constraint_too_high():
 def test_tz_constraint_wrong():
     with pytest.raises(SchemaError, match="Input should be 'aware' or 'naive"):
         validate_core_schema(core_schema.time_schema(tz_constraint='wrong'))
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('1:13 PM', time(13, 13)),
+        ('12:13 am', time(0, 13)),
+        ('12:13:14.5 +02:00', time(12, 13, 14, 500_000, tzinfo=timezone(timedelta(hours=2)))),
+        ('12:13:14', time(12, 13, 14)),
+        ('13:13 PM', Err("Input should be in ISO 8601 format or match the format '%I:%M %p' or '%H:%M:%S.%f %z'")),
+        (123, time(0, 2, 3, tzinfo=timezone.utc)),
+    ],
+)
+def test_formats(input_value, expected):
+    v = SchemaValidator(core_schema.time_schema(formats=['%I:%M %p', '%H:%M:%S.%f %z']))
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        assert v.validate_python(input_value) == expected
+
+
+def test_formats_strict():
+    v = SchemaValidator(core_schema.time_schema(formats=['%I:%M %p'], strict=True))
+    with pytest.raises(ValidationError, match=r'Input should be a valid time \[type=time_type'):
+        v.validate_python('1:13 PM')
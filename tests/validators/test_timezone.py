@@ -0,0 +1,76 @@
+import re
+from datetime import timedelta, timezone
+
+import pytest
+
+from pydantic_core import SchemaValidator, ValidationError, core_schema
+
+from ..conftest import Err
+
+try:
+    import zoneinfo
+except ImportError:
+    # TODO: can remove this once we drop support for python 3.8
+    from backports import zoneinfo
+
+
+@pytest.mark.parametrize(
+    'input_value,expected',
+    [
+        ('Europe/Helsinki', zoneinfo.ZoneInfo('Europe/Helsinki')),
+        ('UTC', zoneinfo.ZoneInfo('UTC')),
+        ('+02:00', timezone(timedelta(hours=2))),
+        ('-0530', timezone(-timedelta(hours=5, minutes=30))),
+        ('+01', timezone(timedelta(hours=1))),
+        ('+01:02:03', timezone(timedelta(hours=1, minutes=2, seconds=3))),
+        ('Z', timezone.utc),
+        (3600, timezone(timedelta(hours=1))),
+        (-60, timezone(-timedelta(minutes=1))),
+        (timezone.utc, timezone.utc),
+        (zoneinfo.ZoneInfo('America/New_York'), zoneinfo.ZoneInfo('America/New_York')),
+        ('Mars/Olympus_Mons', Err('Input should be a valid IANA time zone name or UTC offset [type=timezone_parsing,')),
+        ('', Err('Input should be a valid IANA time zone name or UTC offset [type=timezone_parsing,')),
+        ('/etc/passwd', Err('Input should be a valid IANA time zone name or UTC offset [type=timezone_parsing,')),
+        ('+24:00', Err('Input should be a valid IANA time zone name or UTC offset [type=timezone_parsing,')),
+        ('+1:00', Err('Input should be a valid IANA time zone name or UTC offset [type=timezone_parsing,')),
+        (86_400, Err('Input should be a valid IANA time zone name or UTC offset [type=timezone_parsing,')),
+        (2**64, Err('Input should be a valid IANA time zone name or UTC offset [type=timezone_parsing,')),
+        (1.5, Err('Input should be a time zone name, UTC offset or tzinfo object [type=timezone_type,')),
+    ],
+)
+def test_timezone(input_value, expected):
+    v = SchemaValidator(core_schema.timezone_schema())
+    if isinstance(expected, Err):
+        with pytest.raises(ValidationError, match=re.escape(expected.message)):
+            v.validate_python(input_value)
+    else:
+        output = v.validate_python(input_value)
+        assert output == expected
+        assert output.utcoffset(None) == expected.utcoffset(None)
+
+
+def test_tzinfo_instance():
+    v = SchemaValidator(core_schema.timezone_schema(strict=True))
+    tz = zoneinfo.ZoneInfo('Europe/Helsinki')
+    assert v.validate_python(tz) is tz
+    with pytest.raises(ValidationError, match=r'Input should be an instance of tzinfo \[type=is_instance_of,'):
+        v.validate_python('Europe/Helsinki')
+
+
+def test_json():
+    v = SchemaValidator(core_schema.timezone_schema(strict=True))
+    assert v.validate_json('"Europe/Helsinki"') == zoneinfo.ZoneInfo('Europe/Helsinki')
+    assert v.validate_json('"+02:00"') == timezone(timedelta(hours=2))
+    assert v.validate_json('7200') == timezone(timedelta(hours=2))
+    with pytest.raises(ValidationError, match=r'Input should be a time zone name, UTC offset or tzinfo object'):
+        v.validate_json('null')
+
+
+def test_union_exactness():
+    v = SchemaValidator(core_schema.union_schema([core_schema.str_schema(), core_schema.timezone_schema()]))
+    assert v.validate_python('Europe/Helsinki') == 'Europe/Helsinki'
+    assert v.validate_python(timezone.utc) is timezone.utc
+
+
+def test_json_schema():
+    assert SchemaValidator(core_schema.timezone_schema()).json_schema() == {'type': 'string'}